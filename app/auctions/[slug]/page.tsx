@@ -153,17 +153,11 @@ function AuctionDetailContent() {
           // Get bidder's payment account
           const bidderTokenAccount = await getAssociatedTokenAddress(token.mint, publicKey);
           
-          // Get previous bidder's account (set to treasury if no previous bids)
-          const previousBidderAccount = allBids.length > 0 
-            ? await getAssociatedTokenAddress(token.mint, new PublicKey(allBids[0].bidder))
-            : await getAssociatedTokenAddress(token.mint, TREASURY);
-          
           txSignature = await auctionProgram.placeBid(
             nftMintPubkey,
             Math.round(usd1Amount * (10 ** token.decimals)),
             bidderTokenAccount,
-            token.mint,
-            previousBidderAccount
+            token.mint
           );
         } catch (error) {
           const message = error instanceof Error ? error.message : "On-chain bid failed";
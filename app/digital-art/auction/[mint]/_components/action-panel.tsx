@@ -471,23 +471,8 @@ export function AuctionDetailActionPanel({
     setLoadingAction(true);
     try {
       const nftMint = new PublicKey(mint);
-      const bidderTokenAccount = await getAssociatedTokenAddress(SOL_MINT, publicKey);
-      const previousBidderAccount =
-        currentNativeListing.currentBidLamports && currentNativeListing.highestBidder
-          ? await getAssociatedTokenAddress(
-              SOL_MINT,
-              new PublicKey(currentNativeListing.highestBidder)
-            )
-          : publicKey;
-
       const auctionProgram = new AuctionProgram(connection, anchorWallet, sendTransaction);
-      await auctionProgram.placeBid(
-        nftMint,
-        newBidLamports,
-        bidderTokenAccount,
-        SOL_MINT,
-        previousBidderAccount
-      );
+      await auctionProgram.placeBidSol(nftMint, newBidLamports);
 
       showToast.success("Bid placed successfully!");
       setBidAmount("");
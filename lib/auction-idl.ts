@@ -7,35 +7,136 @@ export type Auction = {
   },
   "instructions": [
     {
-      "name": "buy_now",
+      "name": "accept_offer",
       "docs": [
-        "Buy a fixed-price listing immediately",
+        "Accept an offer on an SPL / Token-2022 NFT (owner only).",
         "",
-        "For WNS/Token-2022 NFTs: client MUST include WNS `approve_transfer` IX",
-        "(amount=0) BEFORE this instruction in the same transaction.",
+        "Listed (fixed price or Dutch): pass `listing` and `escrow_nft`; the NFT",
+        "leaves escrow, royalties follow the listing and the listing is closed.",
+        "Unlisted: pass `seller_nft_account`; royalties use `royalty_basis_points`",
+        "and `creator_address` as supplied to `list_item`.",
+        "WNS NFTs are charged the royalty in their metadata instead: the offer",
+        "escrow approves the move by CPI for the offer amount and WNS takes its",
+        "royalty from it.",
         "remaining_accounts: same layout as list_item"
       ],
       "discriminator": [
-        242,
-        42,
-        184,
-        77,
-        133,
-        152,
-        118,
-        204
+        227,
+        82,
+        234,
+        131,
+        1,
+        18,
+        48,
+        2
       ],
       "accounts": [
         {
-          "name": "listing",
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
           "writable": true
         },
         {
           "name": "nft_mint"
         },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "The item's listing — required when it is listed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
         {
           "name": "escrow_nft",
+          "docs": [
+            "Escrowed NFT — required with `listing`"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -55,20 +156,27 @@ export type Auction = {
               },
               {
                 "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
+                "path": "nft_mint"
               }
             ]
           }
         },
         {
-          "name": "buyer_payment_account",
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller's NFT account — required when the item is not listed"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "buyer_nft_account",
           "writable": true
         },
         {
           "name": "seller_payment_account",
           "docs": [
-            "Seller payment account — must be owned by listing.seller"
+            "Seller payment account — must be owned by the seller"
           ],
           "writable": true
         },
@@ -84,11 +192,7 @@ export type Auction = {
           "writable": true
         },
         {
-          "name": "buyer_nft_account",
-          "writable": true
-        },
-        {
-          "name": "buyer",
+          "name": "seller",
           "writable": true,
           "signer": true
         },
@@ -131,38 +235,112 @@ export type Auction = {
           "name": "nft_token_program"
         },
         {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "token_program"
         },
         {
           "name": "system_program",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        }
+      ]
     },
     {
-      "name": "buy_now_core",
+      "name": "accept_offer_core",
       "docs": [
-        "Public buy of a Core listing. Artifacte collection buys waive the",
-        "platform fee and only split USDC between creator royalty and seller.",
-        "Then CPI TransferV1 moves the asset to the buyer."
+        "Accept an offer on a Metaplex Core asset (owner only). A fixed-price",
+        "`core_listing`, if passed, is closed and the transfer is signed through",
+        "its TransferDelegate; otherwise the owner signs the transfer directly.",
+        "Royalties come from the on-chain Royalties plugin, as in `buy_now_core`."
       ],
       "discriminator": [
-        107,
-        235,
-        190,
-        104,
-        232,
-        171,
-        241,
-        145
+        233,
+        111,
+        45,
+        247,
+        197,
+        32,
+        103,
+        81
       ],
       "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
         {
           "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "seller",
           "docs": [
-            "Public buyer."
+            "Current owner."
           ],
           "writable": true,
           "signer": true
@@ -177,7 +355,11 @@ export type Auction = {
         },
         {
           "name": "core_listing",
+          "docs": [
+            "Fixed-price listing for the asset, if any — closed by the sale"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -204,10 +386,6 @@ export type Auction = {
             ]
           }
         },
-        {
-          "name": "seller",
-          "writable": true
-        },
         {
           "name": "core_authority",
           "pda": {
@@ -239,21 +417,20 @@ export type Auction = {
           }
         },
         {
-          "name": "payment_mint",
-          "docs": [
-            "USDC mint (validated in handler)."
-          ]
-        },
-        {
-          "name": "buyer_payment_account",
-          "writable": true
+          "name": "payment_mint"
         },
         {
           "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by the seller"
+          ],
           "writable": true
         },
         {
           "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in handler against treasury_config or fallback"
+          ],
           "writable": true
         },
         {
@@ -261,8 +438,7 @@ export type Auction = {
           "writable": true
         },
         {
-          "name": "treasury",
-          "writable": true
+          "name": "treasury"
         },
         {
           "name": "treasury_config",
@@ -297,8 +473,7 @@ export type Auction = {
           "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
         },
         {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "token_program"
         },
         {
           "name": "system_program",
@@ -308,191 +483,109 @@ export type Auction = {
       "args": []
     },
     {
-      "name": "cancel_core_listing",
+      "name": "accept_offer_pnft",
       "docs": [
-        "Cancel an active Core listing while the seller still holds the asset."
+        "Accept an offer on a pNFT (owner only). Listed pNFTs (fixed price or",
+        "Dutch) leave the `escrow_authority` escrow and the listing is closed;",
+        "unlisted ones move from the seller's wallet. Royalty arguments as in",
+        "`accept_offer`."
       ],
       "discriminator": [
-        58,
-        30,
-        81,
-        251,
-        75,
-        37,
-        173,
-        211
+        87,
+        193,
+        215,
+        232,
+        46,
+        229,
+        112,
+        186
       ],
       "accounts": [
         {
-          "name": "seller",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "asset",
-          "writable": true
-        },
-        {
-          "name": "collection",
-          "writable": true
-        },
-        {
-          "name": "core_listing",
+          "name": "offer",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
                   111,
-                  114,
+                  102,
+                  102,
                   101,
-                  95,
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "asset"
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
               }
             ]
           }
         },
         {
-          "name": "core_authority",
+          "name": "offer_escrow",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
                   111,
-                  114,
+                  102,
+                  102,
                   101,
+                  114,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
+                  101,
+                  115,
+                  99,
                   114,
-                  105,
-                  116,
-                  121
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "asset"
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
               }
             ]
           }
         },
         {
-          "name": "mpl_core_program",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "cancel_listing",
-      "docs": [
-        "Cancel a listing (seller only, auctions only if no bids)",
-        "",
-        "For WNS/Token-2022: client MUST include WNS `approve_transfer` (amount=0)",
-        "remaining_accounts: same layout as list_item"
-      ],
-      "discriminator": [
-        41,
-        183,
-        50,
-        232,
-        230,
-        233,
-        157,
-        70
-      ],
-      "accounts": [
-        {
-          "name": "listing",
+          "name": "buyer",
           "writable": true
         },
         {
           "name": "nft_mint"
         },
         {
-          "name": "escrow_nft",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  110,
-                  102,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
-              }
-            ]
-          }
-        },
-        {
-          "name": "seller_nft_account",
+          "name": "nft_metadata",
           "writable": true
         },
         {
-          "name": "seller",
-          "signer": true
+          "name": "nft_edition"
         },
         {
-          "name": "nft_token_program"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "cancel_listing_pnft",
-      "docs": [
-        "Cancel a pNFT listing — return NFT to seller."
-      ],
-      "discriminator": [
-        41,
-        48,
-        179,
-        6,
-        129,
-        16,
-        120,
-        65
-      ],
-      "accounts": [
+          "name": "payment_mint"
+        },
         {
           "name": "listing",
+          "docs": [
+            "The item's listing — required when it is listed"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -514,20 +607,10 @@ export type Auction = {
             ]
           }
         },
-        {
-          "name": "nft_mint"
-        },
-        {
-          "name": "nft_metadata",
-          "writable": true
-        },
-        {
-          "name": "nft_edition"
-        },
         {
           "name": "escrow_authority",
           "docs": [
-            "Escrow authority PDA"
+            "Escrow authority PDA — source owner for listed pNFTs"
           ],
           "pda": {
             "seeds": [
@@ -560,26 +643,82 @@ export type Auction = {
           }
         },
         {
-          "name": "escrow_nft_token",
+          "name": "source_nft_token",
           "writable": true
         },
         {
-          "name": "escrow_token_record",
+          "name": "source_token_record",
           "writable": true
         },
         {
-          "name": "seller_nft_token",
+          "name": "buyer_nft_token",
           "writable": true
         },
         {
-          "name": "seller_token_record",
+          "name": "buyer_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by the seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
           "writable": true
         },
         {
           "name": "seller",
+          "docs": [
+            "Seller; pays for the buyer's token account / token record if missing"
+          ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "token_metadata_program"
         },
@@ -587,6 +726,12 @@ export type Auction = {
           "name": "token_program",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
         {
           "name": "ata_program"
         },
@@ -606,29 +751,63 @@ export type Auction = {
           "optional": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        }
+      ]
     },
     {
-      "name": "close_stale_core_listing",
+      "name": "add_bundle_core_item",
       "docs": [
-        "Close a stale Core listing after ownership changed outside the program.",
-        "Allows the current holder to clear old state and re-list the asset."
+        "Add a Metaplex Core asset to an unopened bundle (seller only). The",
+        "asset stays in the seller's wallet, delegated to its `core_authority`",
+        "PDA and frozen until the bundle sells or is cancelled. Royalties are read",
+        "from the asset at sale time."
       ],
       "discriminator": [
-        108,
-        204,
-        165,
-        119,
-        83,
-        128,
-        227,
-        162
+        187,
+        87,
+        57,
+        117,
+        71,
+        217,
+        178,
+        9
       ],
       "accounts": [
         {
-          "name": "holder",
+          "name": "bundle",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
         },
         {
           "name": "asset",
@@ -640,7 +819,6 @@ export type Auction = {
         },
         {
           "name": "core_listing",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -697,6 +875,11 @@ export type Auction = {
             ]
           }
         },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "mpl_core_program",
           "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
@@ -706,60 +889,101 @@ export type Auction = {
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "close_stale_listing",
+      "name": "add_bundle_item",
       "docs": [
-        "Close a stale listing where escrow is empty (NFT already returned)",
-        "This allows re-listing the same NFT after a cancelled listing"
+        "Escrow an SPL / Token-2022 NFT into an unopened bundle (seller only).",
+        "`royalty_basis_points` and `creator_address` are as for `list_item`;",
+        "`weight` is the item's share of the price (0 on every item = even split).",
+        "remaining_accounts: same layout as list_item"
       ],
       "discriminator": [
-        120,
-        38,
-        229,
+        134,
+        206,
+        210,
+        166,
         87,
-        16,
-        1,
-        54,
-        10
+        228,
+        132,
+        120
       ],
       "accounts": [
         {
-          "name": "listing",
-          "writable": true
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
         },
         {
           "name": "nft_mint"
         },
         {
-          "name": "escrow_nft",
+          "name": "bundle_escrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101,
+                  95,
                   101,
                   115,
                   99,
                   114,
                   111,
-                  119,
-                  95,
-                  110,
-                  102,
-                  116
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
+                "path": "bundle"
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
               }
             ]
           }
         },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
         {
           "name": "seller",
           "writable": true,
@@ -767,29 +991,109 @@ export type Auction = {
         },
         {
           "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "initialize_treasury",
+      "name": "buy_bundle",
       "docs": [
-        "Initialize the treasury config PDA (one-time setup by deploy authority)"
+        "Buy a fixed-price bundle: every item moves to the buyer and the price is",
+        "split per item. remaining_accounts: see the section comment above."
       ],
       "discriminator": [
-        124,
-        186,
-        211,
-        195,
-        85,
-        165,
-        129,
-        166
+        51,
+        0,
+        223,
+        19,
+        152,
+        96,
+        11,
+        1
       ],
       "accounts": [
         {
-          "name": "treasury_config",
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "buyer",
           "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by bundle.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -816,9 +1120,25 @@ export type Auction = {
           }
         },
         {
-          "name": "authority",
+          "name": "collection",
           "writable": true,
-          "signer": true
+          "optional": true,
+          "address": "jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
         },
         {
           "name": "system_program",
@@ -828,173 +1148,179 @@ export type Auction = {
       "args": []
     },
     {
-      "name": "list_core_item",
+      "name": "buy_now",
       "docs": [
-        "List a Metaplex Core asset for fixed-price USDC sale."
+        "Buy a fixed-price listing immediately, or a Dutch listing at its current price",
+        "",
+        "WNS NFTs are approved by CPI for the sale price: WNS collects the royalty",
+        "in the NFT's metadata from the buyer's payment account, in place of the",
+        "listing's royalty.",
+        "remaining_accounts: same layout as list_item"
       ],
       "discriminator": [
-        249,
-        213,
-        18,
-        100,
-        230,
-        110,
-        232,
-        59
+        242,
+        42,
+        184,
+        77,
+        133,
+        152,
+        118,
+        204
       ],
       "accounts": [
         {
-          "name": "seller",
-          "docs": [
-            "Current holder-signed."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "asset",
+          "name": "listing",
           "writable": true
         },
         {
-          "name": "collection",
-          "writable": true
+          "name": "nft_mint"
         },
         {
           "name": "payment_mint",
           "docs": [
-            "USDC mint (validated in handler)."
+            "Payment mint — must match the listing's payment mint"
           ]
         },
         {
-          "name": "core_listing",
-          "docs": [
-            "CoreListing PDA — created on list, closed on cancel/buy."
-          ],
+          "name": "escrow_nft",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  101,
+                  115,
                   99,
-                  111,
                   114,
-                  101,
+                  111,
+                  119,
                   95,
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
                   110,
-                  103
+                  102,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "asset"
+                "path": "listing.nft_mint",
+                "account": "Listing"
               }
             ]
           }
         },
         {
-          "name": "core_authority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
               }
             ]
           }
         },
         {
-          "name": "mpl_core_program",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
         },
         {
           "name": "system_program",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "price_usdc",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "list_item",
+      "name": "buy_now_auction",
       "docs": [
-        "List an item for sale (either fixed price or auction)",
+        "Buy a running auction outright at its buy-now price, ending it early.",
         "",
-        "For WNS/Token-2022 NFTs: client MUST include a WNS `approve_transfer` IX",
-        "(amount=0) BEFORE this instruction in the same transaction.",
-        "remaining_accounts for Token-2022:",
-        "[0] extra_metas_account PDA (readonly) - seeds: [\"extra-account-metas\", nft_mint]",
-        "[1] approve_account PDA (writable) - seeds: [\"approve-account\", nft_mint]",
-        "[2] wns_program (readonly)"
+        "Only offered while the high bid has not passed `buy_now_cutoff_bps` of the",
+        "buy-now price. Any standing high bid is credited to its `BidRefund`.",
+        "Native-SOL auctions use `buy_now_auction_sol`.",
+        "remaining_accounts: same layout as list_item"
       ],
       "discriminator": [
-        174,
-        245,
-        22,
-        211,
-        228,
-        103,
-        121,
-        13
+        66,
+        122,
+        226,
+        13,
+        221,
+        180,
+        133,
+        169
       ],
       "accounts": [
         {
           "name": "listing",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nft_mint"
-              }
-            ]
-          }
+          "writable": true
         },
         {
           "name": "nft_mint"
         },
         {
-          "name": "payment_mint"
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
         },
         {
           "name": "escrow_nft",
@@ -1018,466 +1344,660 @@ export type Auction = {
               },
               {
                 "kind": "account",
-                "path": "nft_mint"
+                "path": "listing.nft_mint",
+                "account": "Listing"
               }
             ]
           }
         },
         {
-          "name": "seller_nft_account",
-          "writable": true
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow — if present and empty, closed to the treasury"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "seller",
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required only when there is a standing bid"
+          ],
           "writable": true,
-          "signer": true
+          "optional": true
         },
         {
-          "name": "nft_token_program"
+          "name": "buyer_payment_account",
+          "writable": true
         },
         {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by listing.seller"
+          ],
+          "writable": true
         },
         {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
         },
         {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "creator_payment_account",
+          "writable": true
+        },
         {
-          "name": "listing_type",
-          "type": {
-            "defined": {
-              "name": "ListingType"
-            }
-          }
+          "name": "buyer_nft_account",
+          "writable": true
         },
         {
-          "name": "price",
-          "type": "u64"
+          "name": "buyer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "duration_seconds",
-          "type": {
-            "option": "i64"
-          }
+          "name": "treasury",
+          "writable": true
         },
         {
-          "name": "category",
-          "type": {
-            "defined": {
-              "name": "ItemCategory"
-            }
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
         },
         {
-          "name": "royalty_basis_points",
-          "type": "u16"
+          "name": "nft_token_program"
         },
         {
-          "name": "creator_address",
-          "type": "pubkey"
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "list_item_pnft",
+      "name": "buy_now_auction_sol",
       "docs": [
-        "List a pNFT (Metaplex programmable NFT) for sale.",
-        "Uses Token Metadata TransferV1 CPI with delegate + token_record."
+        "Native-SOL `buy_now_auction`: the buyer pays seller, treasury and creator",
+        "wallets in lamports. A standing high bid stays in `sol_escrow`, credited",
+        "to its `BidRefund` for `claim_refund`.",
+        "remaining_accounts: same layout as list_item"
       ],
       "discriminator": [
-        236,
-        179,
-        101,
-        29,
-        212,
-        149,
+        64,
+        43,
+        28,
+        123,
+        150,
         190,
-        159
+        145,
+        196
       ],
       "accounts": [
         {
           "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  105,
+                  101,
                   115,
-                  116,
-                  105,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
                   110,
-                  103
+                  102,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "nft_mint"
+                "path": "listing.nft_mint",
+                "account": "Listing"
               }
             ]
           }
         },
         {
-          "name": "nft_mint"
-        },
-        {
-          "name": "nft_metadata",
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required only when there is a standing bid"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
           "writable": true
         },
         {
-          "name": "nft_edition"
+          "name": "creator",
+          "writable": true
         },
         {
-          "name": "seller_nft_token",
+          "name": "buyer_nft_account",
           "writable": true
         },
         {
-          "name": "seller_token_record",
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
           "writable": true
         },
         {
-          "name": "escrow_authority",
+          "name": "treasury_config",
           "docs": [
-            "Escrow authority PDA — owns the escrow token account"
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
           ],
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  116,
+                  114,
                   101,
+                  97,
                   115,
-                  99,
+                  117,
                   114,
-                  111,
-                  119,
+                  121,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_compressed",
+      "docs": [
+        "Buy a cNFT listing with the same fee and royalty split as `buy_now`.",
+        "`root` is the tree root the proof in remaining_accounts resolves to."
+      ],
+      "discriminator": [
+        186,
+        122,
+        147,
+        101,
+        134,
+        6,
+        219,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
                   111,
+                  109,
+                  112,
                   114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
                   105,
+                  115,
                   116,
-                  121
+                  105,
+                  110,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "nft_mint"
+                "path": "compressed_listing.merkle_tree",
+                "account": "CompressedListing"
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.leaf_nonce",
+                "account": "CompressedListing"
               }
             ]
           }
         },
         {
-          "name": "escrow_nft_token",
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "tree_config"
+        },
+        {
+          "name": "merkle_tree",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
           "docs": [
-            "Escrow token account — created by Token Metadata via ATA"
+            "Seller payment account — must be owned by compressed_listing.seller"
           ],
           "writable": true
         },
         {
-          "name": "escrow_token_record",
+          "name": "treasury_payment_account",
           "docs": [
-            "Escrow token record (pNFT programmable config)"
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
           ],
           "writable": true
         },
         {
-          "name": "payment_mint"
+          "name": "creator_payment_account",
+          "writable": true
         },
         {
-          "name": "seller",
+          "name": "buyer",
           "writable": true,
           "signer": true
         },
         {
-          "name": "token_metadata_program"
+          "name": "treasury"
         },
         {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "ata_program"
+          "name": "token_program"
         },
         {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
+          "name": "log_wrapper",
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
         },
         {
-          "name": "sysvar_instructions"
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
         },
         {
-          "name": "authorization_rules_program",
-          "optional": true
+          "name": "bubblegum_program",
+          "address": "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
         },
         {
-          "name": "authorization_rules",
-          "optional": true
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "listing_type",
-          "type": {
-            "defined": {
-              "name": "ListingType"
-            }
-          }
-        },
-        {
-          "name": "price",
-          "type": "u64"
-        },
-        {
-          "name": "duration_seconds",
-          "type": {
-            "option": "i64"
-          }
-        },
-        {
-          "name": "category",
+          "name": "root",
           "type": {
-            "defined": {
-              "name": "ItemCategory"
-            }
+            "array": [
+              "u8",
+              32
+            ]
           }
-        },
-        {
-          "name": "royalty_basis_points",
-          "type": "u16"
-        },
-        {
-          "name": "creator_address",
-          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "place_bid",
+      "name": "buy_now_core",
       "docs": [
-        "Place a bid on an active auction (payment tokens only, no NFT transfer)"
+        "Public buy of a Core listing. Artifacte collection buys waive the",
+        "platform fee and only split the payment between creator royalty and seller.",
+        "Then CPI TransferV1 moves the asset to the buyer."
       ],
       "discriminator": [
-        238,
-        77,
-        148,
-        91,
-        200,
-        151,
-        92,
-        146
+        107,
+        235,
+        190,
+        104,
+        232,
+        171,
+        241,
+        145
       ],
       "accounts": [
         {
-          "name": "listing",
+          "name": "buyer",
+          "docs": [
+            "Public buyer."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
           "writable": true
         },
         {
-          "name": "payment_mint",
-          "docs": [
-            "Payment mint — must match the listing's payment mint"
-          ]
+          "name": "collection",
+          "writable": true
         },
         {
-          "name": "bid_escrow",
+          "name": "core_listing",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  105,
-                  100,
-                  95,
-                  101,
-                  115,
                   99,
-                  114,
                   111,
-                  119
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
+                "path": "asset"
               }
             ]
           }
         },
         {
-          "name": "bidder_token_account",
-          "writable": true
-        },
-        {
-          "name": "previous_bidder_account",
+          "name": "seller",
           "writable": true
         },
         {
-          "name": "bidder",
-          "writable": true,
-          "signer": true
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
         },
         {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "payment_mint",
+          "docs": [
+            "Listing payment mint (validated in handler)."
+          ]
         },
         {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
+          "name": "buyer_payment_account",
+          "writable": true
         },
         {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "seller_payment_account",
+          "writable": true
+        },
         {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "settle_auction",
-      "docs": [
-        "Settle an auction after end time",
-        "",
-        "For WNS/Token-2022: client MUST include WNS `approve_transfer` (amount=0)",
-        "remaining_accounts: same layout as list_item"
-      ],
-      "discriminator": [
-        246,
-        196,
-        183,
-        98,
-        222,
-        139,
-        46,
-        133
-      ],
-      "accounts": [
+          "name": "treasury_payment_account",
+          "writable": true
+        },
         {
-          "name": "listing",
+          "name": "creator_payment_account",
           "writable": true
         },
         {
-          "name": "nft_mint"
+          "name": "treasury",
+          "writable": true
         },
         {
-          "name": "bid_escrow",
-          "writable": true,
+          "name": "treasury_config",
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  105,
-                  100,
-                  95,
+                  116,
+                  114,
                   101,
+                  97,
                   115,
-                  99,
+                  117,
                   114,
+                  121,
+                  95,
+                  99,
                   111,
-                  119
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
               }
             ]
           }
         },
         {
-          "name": "escrow_nft",
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_delegated",
+      "docs": [
+        "Buy a delegate listing: the listing PDA moves the NFT straight from the",
+        "seller's token account to the buyer. remaining_accounts: same layout as",
+        "list_item"
+      ],
+      "discriminator": [
+        36,
+        24,
+        170,
+        206,
+        131,
+        38,
+        37,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
                   101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
                   95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
                   110,
-                  102,
-                  116
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
               }
             ]
           }
         },
         {
-          "name": "seller_payment_account",
-          "docs": [
-            "Seller's payment token account — must be owned by listing.seller"
-          ],
+          "name": "seller",
           "writable": true
         },
         {
-          "name": "treasury_payment_account",
-          "docs": [
-            "Treasury payment account — validated in instruction body against treasury_config or fallback"
-          ],
-          "writable": true
+          "name": "nft_mint"
         },
         {
-          "name": "creator_payment_account",
+          "name": "seller_nft_account",
           "writable": true
         },
         {
           "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
           "docs": [
-            "Buyer NFT account — must be owned by highest bidder (or seller if no bids for return)"
+            "Seller payment account — must be owned by delegate_listing.seller"
           ],
           "writable": true
         },
         {
-          "name": "seller_nft_account",
+          "name": "treasury_payment_account",
           "docs": [
-            "Seller NFT account — must be owned by listing.seller (for no-bid return)"
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
           ],
           "writable": true
         },
         {
-          "name": "seller",
+          "name": "creator_payment_account",
           "writable": true
         },
         {
-          "name": "treasury",
-          "writable": true
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury"
         },
         {
           "name": "treasury_config",
-          "docs": [
-            "Treasury config PDA — if present, overrides hardcoded treasury address"
-          ],
           "optional": true,
           "pda": {
             "seeds": [
@@ -1508,921 +2028,20610 @@ export type Auction = {
           "name": "nft_token_program"
         },
         {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
+          "name": "token_program"
         }
       ],
       "args": []
     },
     {
-      "name": "update_treasury",
+      "name": "buy_now_pnft",
       "docs": [
-        "Update treasury address (deploy authority only)"
+        "Buy a fixed-price or Dutch pNFT listing.",
+        "",
+        "Same pricing and fee split as `buy_now`; the pNFT moves escrow → buyer via",
+        "Token Metadata TransferV1 so token records and rule sets are honoured."
       ],
       "discriminator": [
-        60,
-        16,
-        243,
-        66,
-        96,
-        59,
-        254,
-        131
+        26,
+        178,
+        190,
+        138,
+        45,
+        22,
+        144,
+        30
       ],
       "accounts": [
         {
-          "name": "treasury_config",
+          "name": "listing",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
-                  114,
-                  101,
-                  97,
+                  108,
+                  105,
                   115,
-                  117,
-                  114,
-                  121,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
+                  116,
                   105,
+                  110,
                   103
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "signer": true
-        }
-      ],
-      "args": [
+          "name": "nft_mint"
+        },
         {
-          "name": "new_treasury",
-          "type": "pubkey"
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "CoreListing",
-      "discriminator": [
-        205,
-        178,
-        162,
-        169,
-        199,
-        166,
-        133,
-        157
-      ]
-    },
-    {
-      "name": "Listing",
-      "discriminator": [
-        218,
-        32,
-        50,
-        73,
-        43,
-        134,
-        26,
-        58
-      ]
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_token",
+          "writable": true
+        },
+        {
+          "name": "buyer_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "TreasuryConfig",
-      "discriminator": [
-        124,
-        54,
-        212,
-        227,
-        213,
-        189,
-        168,
-        41
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "AuctionCancelled",
+      "name": "buy_now_pnft_delegated",
+      "docs": [
+        "Buy a delegated pNFT listing. The listing PDA, as Sale delegate, moves",
+        "the pNFT seller → buyer via Token Metadata TransferV1, which unlocks it,",
+        "clears the delegate and enforces the collection's rule set."
+      ],
       "discriminator": [
-        22,
-        32,
-        51,
-        83,
-        215,
-        194,
         171,
-        209
-      ]
-    },
-    {
-      "name": "AuctionSettled",
-      "discriminator": [
-        61,
-        151,
-        131,
-        170,
-        95,
+        239,
         203,
-        219,
-        147
-      ]
-    },
-    {
-      "name": "BidPlaced",
-      "discriminator": [
-        135,
-        53,
-        176,
-        83,
-        193,
-        69,
-        108,
-        61
-      ]
-    },
-    {
-      "name": "CoreListingCancelled",
-      "discriminator": [
-        165,
-        96,
-        88,
-        242,
-        37,
-        224,
-        205,
-        57
-      ]
-    },
-    {
-      "name": "CoreListingCreated",
-      "discriminator": [
-        184,
-        108,
-        121,
-        191,
-        52,
-        251,
-        30,
-        84
-      ]
-    },
-    {
-      "name": "CorePurchased",
-      "discriminator": [
-        221,
-        82,
-        110,
-        196,
-        235,
-        58,
-        114,
-        17
-      ]
-    },
-    {
-      "name": "ItemPurchased",
-      "discriminator": [
-        33,
-        219,
-        12,
-        58,
-        205,
-        48,
-        63,
-        143
-      ]
-    },
-    {
-      "name": "ListingCancelled",
-      "discriminator": [
-        11,
-        46,
-        163,
-        10,
+        13,
+        157,
         103,
-        80,
-        139,
-        194
-      ]
-    },
-    {
-      "name": "ListingCreated",
-      "discriminator": [
-        94,
-        164,
-        167,
-        255,
-        246,
-        186,
-        12,
-        96
-      ]
-    },
-    {
-      "name": "TreasuryUpdated",
-      "discriminator": [
-        80,
-        239,
-        54,
-        168,
-        43,
         38,
-        85,
-        145
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "ListingNotActive",
-      "msg": "Listing is not active"
-    },
-    {
-      "code": 6001,
-      "name": "AuctionEnded",
-      "msg": "Auction has already ended"
-    },
-    {
-      "code": 6002,
-      "name": "AuctionNotEnded",
-      "msg": "Auction has not ended yet"
-    },
-    {
-      "code": 6003,
-      "name": "BidTooLow",
-      "msg": "Bid is too low"
-    },
-    {
-      "code": 6004,
-      "name": "CalculationError",
-      "msg": "Calculation error"
-    },
-    {
-      "code": 6005,
-      "name": "Unauthorized",
-      "msg": "Unauthorized"
-    },
-    {
-      "code": 6006,
-      "name": "CannotCancelWithBids",
-      "msg": "Cannot cancel auction with existing bids"
-    },
-    {
-      "code": 6007,
-      "name": "NotAnAuction",
-      "msg": "Invalid listing type for this operation"
-    },
-    {
-      "code": 6008,
-      "name": "NotFixedPrice",
-      "msg": "Invalid listing type for this operation"
+        39
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_token",
+          "writable": true
+        },
+        {
+          "name": "buyer_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by delegate_listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6009,
-      "name": "InvalidDuration",
-      "msg": "Invalid duration for auction"
-    },
-    {
-      "code": 6010,
-      "name": "InvalidPaymentMint",
-      "msg": "Invalid payment mint for this category"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidPrice",
-      "msg": "Price must be greater than zero"
-    },
-    {
-      "code": 6012,
-      "name": "TransferFailed",
-      "msg": "Token-2022 transfer with hook failed"
-    },
-    {
-      "code": 6013,
-      "name": "SellerCannotBid",
-      "msg": "Seller cannot bid on their own auction"
-    },
-    {
-      "code": 6014,
-      "name": "InvalidRefundAccount",
-      "msg": "Invalid refund account — must be previous bidder's ATA"
-    },
-    {
-      "code": 6015,
-      "name": "RoyaltyTooHigh",
-      "msg": "Royalty basis points too high (max 1000 = 10%)"
-    },
-    {
-      "code": 6016,
-      "name": "InvalidBuyerAccount",
-      "msg": "Invalid buyer account — must be owned by highest bidder"
-    },
-    {
-      "code": 6017,
-      "name": "InvalidCreatorAccount",
-      "msg": "Invalid creator account — must be creator's ATA for payment mint"
-    },
-    {
-      "code": 6018,
-      "name": "InvalidTokenProgram",
-      "msg": "Invalid token program — must be SPL Token or Token-2022"
-    },
-    {
-      "code": 6019,
-      "name": "InvalidRoyaltyBps",
-      "msg": "Invalid royalty basis points — must be 0 or >= 100 (1%)"
-    },
-    {
-      "code": 6020,
-      "name": "InvalidCorePluginState",
-      "msg": "Invalid Metaplex Core plugin state"
-    },
-    {
-      "code": 6021,
-      "name": "StaleCoreListing",
-      "msg": "Core listing is stale because the seller no longer owns the asset"
+      "name": "buy_now_sol",
+      "docs": [
+        "Native-SOL `buy_now` for SOL-priced listings: the buyer pays seller,",
+        "treasury and creator wallets with system transfers — no wSOL account needed.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        37,
+        87,
+        9,
+        66,
+        241,
+        180,
+        52,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6022,
-      "name": "CoreListingNotStale",
-      "msg": "Core listing is not stale"
-    }
-  ],
-  "types": [
-    {
-      "name": "AuctionCancelled",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nft_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "reason",
-            "type": "string"
+      "name": "cancel_bundle",
+      "docs": [
+        "Return every item to the seller and close the bundle. The seller may",
+        "cancel before the first bid; anyone may unwind an expired fixed-price",
+        "bundle. remaining_accounts: see the section comment above."
+      ],
+      "discriminator": [
+        166,
+        121,
+        4,
+        129,
+        237,
+        255,
+        233,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "The seller, or anyone once a fixed-price bundle has expired; pays any",
+            "Core CPI storage fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "collection",
+          "writable": true,
+          "optional": true,
+          "address": "jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "AuctionSettled",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nft_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "winner",
-            "type": "pubkey"
-          },
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "platform_fee",
-            "type": "u64"
+      "name": "cancel_collection_offer",
+      "docs": [
+        "Withdraw a collection offer and reclaim the unfilled escrow (buyer only)."
+      ],
+      "discriminator": [
+        19,
+        192,
+        93,
+        201,
+        1,
+        251,
+        254,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "BidPlaced",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nft_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "bidder",
-            "type": "pubkey"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "timestamp",
-            "type": "i64"
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "CoreListing",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "seller",
-            "type": "pubkey"
-          },
-          {
-            "name": "asset",
-            "type": "pubkey"
-          },
-          {
-            "name": "collection",
-            "type": "pubkey"
-          },
-          {
-            "name": "payment_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "created_at",
-            "type": "i64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+      "name": "cancel_compressed_listing",
+      "docs": [
+        "Cancel a cNFT listing (seller only), handing leaf delegation back to the",
+        "seller. A listing whose leaf already changed is closed with",
+        "`close_stale_compressed_listing` instead."
+      ],
+      "discriminator": [
+        12,
+        108,
+        154,
+        147,
+        228,
+        206,
+        118,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.merkle_tree",
+                "account": "CompressedListing"
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.leaf_nonce",
+                "account": "CompressedListing"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tree_config"
+        },
+        {
+          "name": "merkle_tree",
+          "writable": true
+        },
+        {
+          "name": "log_wrapper",
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+        },
+        {
+          "name": "bubblegum_program",
+          "address": "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
     },
     {
-      "name": "CoreListingCancelled",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "asset",
-            "type": "pubkey"
-          },
-          {
-            "name": "seller",
-            "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "CoreListingCreated",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "asset",
-            "type": "pubkey"
-          },
-          {
-            "name": "seller",
-            "type": "pubkey"
-          },
-          {
-            "name": "price_usdc",
-            "type": "u64"
-          },
-          {
-            "name": "payment_mint",
-            "type": "pubkey"
+      "name": "cancel_core_listing",
+      "docs": [
+        "Cancel an active Core listing while the seller still holds the asset.",
+        "Core auctions can only be cancelled before the first bid; their seller",
+        "bond is returned."
+      ],
+      "discriminator": [
+        58,
+        30,
+        81,
+        251,
+        75,
+        37,
+        173,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Core auctions only: payment mint, bond escrow and seller payment account",
+            "the seller bond is returned through"
+          ],
+          "optional": true
+        },
+        {
+          "name": "core_bond",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token_program",
+          "optional": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "CorePurchased",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "asset",
-            "type": "pubkey"
-          },
-          {
-            "name": "seller",
-            "type": "pubkey"
-          },
-          {
-            "name": "buyer",
-            "type": "pubkey"
-          },
-          {
-            "name": "price_usdc",
-            "type": "u64"
-          },
-          {
-            "name": "platform_fee",
-            "type": "u64"
-          },
-          {
-            "name": "creator_royalty",
-            "type": "u64"
+      "name": "cancel_delegated_listing",
+      "docs": [
+        "Cancel a delegate listing (seller only), revoking the approval if the",
+        "listing PDA still holds it."
+      ],
+      "discriminator": [
+        134,
+        165,
+        8,
+        172,
+        16,
+        62,
+        80,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "ItemCategory",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "DigitalArt"
-          },
-          {
-            "name": "Spirits"
-          },
-          {
-            "name": "TCGCards"
-          },
-          {
-            "name": "SportsCards"
-          },
-          {
-            "name": "Watches"
+      "name": "cancel_delegated_listing_pnft",
+      "docs": [
+        "Cancel a delegated pNFT listing (seller only): revokes the Sale delegate,",
+        "which unlocks the pNFT, if the listing PDA still holds it. Also the way",
+        "to close an expired pNFT listing."
+      ],
+      "discriminator": [
+        29,
+        105,
+        251,
+        165,
+        118,
+        230,
+        125,
+        52
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "ItemPurchased",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nft_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "seller",
-            "type": "pubkey"
-          },
-          {
-            "name": "buyer",
-            "type": "pubkey"
-          },
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "platform_fee",
-            "type": "u64"
-          },
-          {
-            "name": "creator_royalty",
-            "type": "u64"
+      "name": "cancel_listing",
+      "docs": [
+        "Cancel a listing (seller only, auctions only if no bids)",
+        "",
+        "WNS NFTs are approved by CPI (amount 0, seller as authority).",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        41,
+        183,
+        50,
+        232,
+        230,
+        233,
+        157,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "Listing",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "seller",
-            "type": "pubkey"
-          },
-          {
-            "name": "nft_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "payment_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "listing_type",
-            "type": {
-              "defined": {
-                "name": "ListingType"
-              }
-            }
-          },
-          {
-            "name": "category",
-            "type": {
-              "defined": {
-                "name": "ItemCategory"
+      "name": "cancel_listing_pnft",
+      "docs": [
+        "Cancel a pNFT listing — return NFT to seller."
+      ],
+      "discriminator": [
+        41,
+        48,
+        179,
+        6,
+        129,
+        16,
+        120,
+        65
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
               }
-            }
-          },
-          {
-            "name": "start_time",
-            "type": "i64"
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_offer",
+      "docs": [
+        "Withdraw an offer and reclaim its escrow (buyer only)."
+      ],
+      "discriminator": [
+        92,
+        203,
+        223,
+        40,
+        92,
+        89,
+        53,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_swap",
+      "docs": [
+        "Cancel a swap: the escrowed asset and any maker top-up go back to the",
+        "maker. The maker may cancel at any time; anyone may unwind an expired",
+        "swap. remaining_accounts: the maker's leg (see the section comment above)."
+      ],
+      "discriminator": [
+        88,
+        174,
+        98,
+        148,
+        24,
+        252,
+        93,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap.offered_asset",
+                "account": "Swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "The maker, or anyone once the swap has expired; pays any CPI fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "escrow_authority",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "token_metadata_program",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "sysvar_instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "ata_program",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claim_refund",
+      "docs": [
+        "Withdraw outbid or unspent funds credited to the caller's `BidRefund`.",
+        "",
+        "Works for SPL, Core and bundle auctions. The record is closed unless the caller is",
+        "the current high bidder, who must keep it so a later outbid can be credited.",
+        "Once the listing instance is gone (settled, cancelled or relisted), the",
+        "last claim also closes its empty bid escrow."
+      ],
+      "discriminator": [
+        15,
+        16,
+        30,
+        161,
+        255,
+        228,
+        97,
+        60
+      ],
+      "accounts": [
+        {
+          "name": "bid_refund",
+          "writable": true
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "Validated in instruction body."
+          ]
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — required for token refunds"
+          ],
+          "optional": true
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow — required while a token refund holds funds"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "sol_escrow",
+          "docs": [
+            "Lamport escrow — required for native-SOL refunds"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "docs": [
+            "Destination for token refunds; unused for native-SOL refunds"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claim_sealed_bid",
+      "docs": [
+        "Reclaim a losing or unrevealed sealed bid once the reveal window closes.",
+        "",
+        "Revealed bids are refunded in full. Unrevealed deposits go to the seller",
+        "when the listing forfeits them, otherwise back to the bidder. The winning",
+        "bid is locked until `settle_auction` runs."
+      ],
+      "discriminator": [
+        193,
+        144,
+        248,
+        90,
+        211,
+        91,
+        113,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "sealed_bid",
+          "writable": true
+        },
+        {
+          "name": "listing",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sealed_bid.nft_mint",
+                "account": "SealedBid"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — required only when an unrevealed deposit is forfeited"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_collection_offer",
+      "docs": [
+        "Refund an expired collection offer to its buyer (permissionless)."
+      ],
+      "discriminator": [
+        188,
+        120,
+        11,
+        240,
+        208,
+        57,
+        250,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_core_listing",
+      "docs": [
+        "Close an expired fixed-price Core listing (permissionless). The",
+        "TransferDelegate revokes itself if still approved; rent goes to the seller."
+      ],
+      "discriminator": [
+        162,
+        48,
+        42,
+        214,
+        105,
+        221,
+        109,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_listing",
+      "docs": [
+        "Return the NFT of an expired fixed-price listing to its seller and close",
+        "the listing, with rent to the seller (permissionless).",
+        "",
+        "WNS NFTs are approved by CPI (amount 0, `escrow_nft` as authority).",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        150,
+        70,
+        13,
+        135,
+        9,
+        204,
+        75,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller's NFT account — receives the returned NFT"
+          ],
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_listing_pnft",
+      "docs": [
+        "Return the pNFT of an expired fixed-price listing to its seller",
+        "(permissionless). `payer` funds the seller's token record if needed;",
+        "listing rent goes to the seller."
+      ],
+      "discriminator": [
+        65,
+        18,
+        89,
+        75,
+        159,
+        115,
+        100,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for the seller's token record if it must be created"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_offer",
+      "docs": [
+        "Close an expired offer, returning escrow and rent to the buyer. Permissionless."
+      ],
+      "discriminator": [
+        174,
+        202,
+        24,
+        79,
+        174,
+        99,
+        110,
+        236
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_stale_compressed_listing",
+      "docs": [
+        "Close a cNFT listing that can no longer sell (permissionless). Before",
+        "expiry the caller proves, with a merkle proof against `root`, that the",
+        "leaf at the listing's index is now `current_leaf` and not the listed one.",
+        "Rent goes to the seller."
+      ],
+      "discriminator": [
+        229,
+        127,
+        249,
+        166,
+        36,
+        89,
+        176,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.merkle_tree",
+                "account": "CompressedListing"
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.leaf_nonce",
+                "account": "CompressedListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "merkle_tree"
+        },
+        {
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "current_leaf",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "close_stale_core_listing",
+      "docs": [
+        "Close a stale Core listing after ownership changed outside the program.",
+        "Allows the current holder to clear old state and re-list the asset."
+      ],
+      "discriminator": [
+        108,
+        204,
+        165,
+        119,
+        83,
+        128,
+        227,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Core auctions only: payment mint, bond escrow and seller payment account",
+            "the seller bond is returned through"
+          ],
+          "optional": true
+        },
+        {
+          "name": "core_bond",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token_program",
+          "optional": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_stale_delegated_listing",
+      "docs": [
+        "Close a delegate listing that can no longer sell (permissionless): the",
+        "NFT left the seller's token account, the approval was revoked or",
+        "replaced, or a fixed expiry passed. Rent goes to the seller. An expired",
+        "pNFT listing still holds its Sale delegate, which only the seller can",
+        "revoke, so it is closed with `cancel_delegated_listing_pnft` instead."
+      ],
+      "discriminator": [
+        171,
+        110,
+        248,
+        54,
+        122,
+        202,
+        85,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account"
+        },
+        {
+          "name": "seller",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_stale_listing",
+      "docs": [
+        "Close a stale listing where escrow is empty (NFT already returned)",
+        "This allows re-listing the same NFT after a cancelled listing"
+      ],
+      "discriminator": [
+        120,
+        38,
+        229,
+        87,
+        16,
+        1,
+        54,
+        10
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "commit_sealed_bid",
+      "docs": [
+        "Commit a sealed bid during the bidding phase.",
+        "",
+        "`commitment` = sha256(amount_le || salt || bidder). The `deposit` (at least",
+        "the opening price) is escrowed in `bid_escrow` now; the rest of the bid is",
+        "topped up at reveal."
+      ],
+      "discriminator": [
+        177,
+        41,
+        85,
+        103,
+        15,
+        78,
+        36,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "sealed_bid",
+          "writable": true
+        },
+        {
+          "name": "bidder_token_account",
+          "writable": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "deposit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "create_bundle",
+      "docs": [
+        "Create an empty bundle (seller only). `duration_seconds` is the auction",
+        "length, or an optional fixed-price expiry; it starts counting at `open_bundle`."
+      ],
+      "discriminator": [
+        108,
+        43,
+        176,
+        128,
+        45,
+        94,
+        197,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "arg",
+                "path": "bundle_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "bundle_id",
+          "type": "u64"
+        },
+        {
+          "name": "listing_type",
+          "type": {
+            "defined": {
+              "name": "ListingType"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "bid_increment",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "fill_collection_offer",
+      "docs": [
+        "Sell a Core asset into a collection offer (asset owner only). As with",
+        "`accept_offer_core`, a fixed-price `core_listing` for the asset is closed",
+        "and the transfer is signed through its TransferDelegate. The last fill",
+        "closes the offer and its escrow, returning rent to the buyer."
+      ],
+      "discriminator": [
+        244,
+        149,
+        206,
+        235,
+        225,
+        223,
+        253,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "docs": [
+            "Current owner."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "docs": [
+            "Fixed-price listing for the asset, if any — closed by the sale"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by the seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in handler against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fill_swap",
+      "docs": [
+        "Fill a swap with an asset the swap wants. The taker's asset goes to the",
+        "maker, the escrowed asset to the taker, and the top-up to whoever is",
+        "owed it, all in this instruction. `taker_kind` is the standard of the",
+        "taker's asset. remaining_accounts: see the section comment above."
+      ],
+      "discriminator": [
+        204,
+        165,
+        172,
+        86,
+        47,
+        94,
+        168,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap.offered_asset",
+                "account": "Swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "taker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "escrow_authority",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when the swap has a top-up"
+          ],
+          "optional": true
+        },
+        {
+          "name": "taker_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "token_metadata_program",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "sysvar_instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "ata_program",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "taker_kind",
+          "type": {
+            "defined": {
+              "name": "SwapAssetKind"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize_payment_mint_config",
+      "docs": [
+        "Register a payment mint (deploy authority only)"
+      ],
+      "discriminator": [
+        27,
+        122,
+        232,
+        56,
+        240,
+        82,
+        137,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "payment_mint_config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "settings",
+          "type": {
+            "defined": {
+              "name": "PaymentMintSettings"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize_treasury",
+      "docs": [
+        "Initialize the treasury config PDA (one-time setup by deploy authority)"
+      ],
+      "discriminator": [
+        124,
+        186,
+        211,
+        195,
+        85,
+        165,
+        129,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "treasury_config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "list_compressed_item",
+      "docs": [
+        "List a Bubblegum cNFT for a fixed price. `leaf` identifies the seller's",
+        "current leaf; the other arguments are as for `list_item_delegated`."
+      ],
+      "discriminator": [
+        41,
+        136,
+        160,
+        108,
+        114,
+        240,
+        167,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "merkle_tree"
+              },
+              {
+                "kind": "arg",
+                "path": "leaf.nonce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "previous_leaf_delegate"
+        },
+        {
+          "name": "tree_config"
+        },
+        {
+          "name": "merkle_tree",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "log_wrapper",
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+        },
+        {
+          "name": "bubblegum_program",
+          "address": "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "leaf",
+          "type": {
+            "defined": {
+              "name": "CompressedLeaf"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_core_auction",
+      "docs": [
+        "List a Metaplex Core asset for an English auction in a registered payment mint.",
+        "",
+        "Still non-custodial: besides the TransferDelegate, `core_authority` is made",
+        "FreezeDelegate so the asset can be frozen in the seller's wallet once bids",
+        "exist. Bids are escrowed in the `bid_escrow` PDA keyed by the asset.",
+        "",
+        "Freezing can't stop the seller revoking the TransferDelegate, so they post",
+        "a bond of `CORE_AUCTION_BOND_BPS` of the start price into `core_bond`. It is",
+        "returned when the auction settles, closes without bids or is cancelled, and",
+        "paid to the winner if the asset can't be delivered."
+      ],
+      "discriminator": [
+        118,
+        231,
+        157,
+        58,
+        7,
+        57,
+        163,
+        79
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "docs": [
+            "Current holder-signed; posts the seller bond."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint (validated in handler against its registry entry)."
+          ]
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_listing",
+          "docs": [
+            "CoreListing PDA — created on list, closed on settle/cancel."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_bond",
+          "docs": [
+            "Seller bond escrow — emptied and closed whenever the auction ends"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "start_price_usdc",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": "i64"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "bid_increment",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_core_item",
+      "docs": [
+        "List a Metaplex Core asset for fixed-price sale in a registered payment mint.",
+        "`start_time` schedules the listing to open later; the delegate is approved now.",
+        "`duration_seconds` optionally expires the listing that long after it opens;",
+        "anyone can then revoke the delegate with `close_expired_core_listing`."
+      ],
+      "discriminator": [
+        249,
+        213,
+        18,
+        100,
+        230,
+        110,
+        232,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "docs": [
+            "Current holder-signed."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint (validated in handler against its registry entry)."
+          ]
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_listing",
+          "docs": [
+            "CoreListing PDA — created on list, closed on cancel/buy."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "price_usdc",
+          "type": "u64"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item",
+      "docs": [
+        "List an item for sale (fixed price, English, Dutch or sealed-bid auction)",
+        "",
+        "For WNS/Token-2022 NFTs the program makes the WNS `approve_transfer` CPI",
+        "itself (amount 0 here; the sale price in `buy_now` / `settle_auction`).",
+        "remaining_accounts for WNS NFTs, validated in this order:",
+        "[0] wns_program (readonly)",
+        "[1] extra_metas_account PDA (readonly) - seeds: [\"extra-account-metas\", nft_mint]",
+        "[2] approve_account PDA (writable) - seeds: [\"approve-account\", nft_mint]",
+        "[3] wns_distribution_program (readonly)",
+        "[4] distribution_account PDA (writable) - seeds: [group_mint, payment_mint]",
+        "under the distribution program, where payment_mint is the system",
+        "program except on sales, which use the listing's payment mint",
+        "[5] distribution_token_account (writable) - distribution_account's ATA for",
+        "the payment mint on sales, else wns_program",
+        "[6] system_program (readonly)",
+        "[7] member account PDA (readonly) - seeds: [\"member\", nft_mint] under WNS",
+        "[8] group account (readonly) - the member's group; its mint is the",
+        "group_mint of [4]",
+        "Other Token-2022 transfer hooks: the hook program, its extra_metas_account",
+        "PDA and the accounts that PDA lists, in any order.",
+        "",
+        "`options` carries the optional auction settings; see `ListingOptions`.",
+        "Fixed-price listings expire after `duration_seconds` if given; anyone can",
+        "then return the NFT to the seller with `close_expired_listing`."
+      ],
+      "discriminator": [
+        174,
+        245,
+        22,
+        211,
+        228,
+        103,
+        121,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "listing_type",
+          "type": {
+            "defined": {
+              "name": "ListingType"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": {
+              "name": "ListingOptions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item_delegated",
+      "docs": [
+        "List an SPL / Token-2022 NFT without escrow by approving the listing PDA",
+        "as delegate. `royalty_basis_points` and `creator_address` are as for",
+        "`list_item`; `start_time` and `duration_seconds` as for `list_core_item`."
+      ],
+      "discriminator": [
+        200,
+        53,
+        32,
+        62,
+        108,
+        102,
+        232,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item_pnft",
+      "docs": [
+        "List a pNFT (Metaplex programmable NFT) for sale.",
+        "Uses Token Metadata TransferV1 CPI with delegate + token_record."
+      ],
+      "discriminator": [
+        236,
+        179,
+        101,
+        29,
+        212,
+        149,
+        190,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA — owns the escrow token account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "docs": [
+            "Escrow token account — created by Token Metadata via ATA"
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "docs": [
+            "Escrow token record (pNFT programmable config)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "listing_type",
+          "type": {
+            "defined": {
+              "name": "ListingType"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": {
+              "name": "ListingOptions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item_pnft_delegated",
+      "docs": [
+        "List a pNFT without escrow. The listing PDA becomes the pNFT's Token",
+        "Metadata Sale delegate, which locks it in the seller's wallet (token",
+        "record `Listed`, account frozen) until it is bought or the listing is",
+        "cancelled. Arguments as for `list_item_delegated`."
+      ],
+      "discriminator": [
+        142,
+        158,
+        181,
+        133,
+        236,
+        30,
+        167,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_collection_offer",
+      "docs": [
+        "Bid on any asset in a Core collection: escrow `price * quantity` for up",
+        "to `quantity` fills at `price` each, open until `expires_at`.",
+        "",
+        "`attributes` narrows the offer to assets whose Attributes plugin carries",
+        "every listed key/value (e.g. grader PSA, grade 10). A buyer holds one",
+        "offer per collection and predicate set."
+      ],
+      "discriminator": [
+        134,
+        26,
+        61,
+        72,
+        238,
+        88,
+        160,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true
+        },
+        {
+          "name": "collection"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "quantity",
+          "type": "u32"
+        },
+        {
+          "name": "expires_at",
+          "type": "i64"
+        },
+        {
+          "name": "attributes",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "AttributePredicate"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_offer",
+      "docs": [
+        "Make an offer on an NFT mint or Core asset, escrowing `amount` until",
+        "`expires_at`."
+      ],
+      "discriminator": [
+        214,
+        98,
+        97,
+        35,
+        59,
+        12,
+        44,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "asset"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "expires_at",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "make_swap",
+      "docs": [
+        "Make a swap offering an escrowed SPL / Token-2022 NFT.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        53,
+        60,
+        229,
+        243,
+        157,
+        94,
+        98,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "swap_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker_nft_account",
+          "writable": true
+        },
+        {
+          "name": "maker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when `terms.top_up > 0`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "docs": [
+            "Source of a maker-paid top-up"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "docs": [
+            "Escrow for a maker-paid top-up — pass only when the maker pays"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "terms",
+          "type": {
+            "defined": {
+              "name": "SwapTerms"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_swap_core",
+      "docs": [
+        "Make a swap offering a Metaplex Core asset. Like a bundle item, the asset",
+        "stays in the maker's wallet, delegated to its `core_authority` PDA and",
+        "frozen until the swap is filled or cancelled."
+      ],
+      "discriminator": [
+        44,
+        178,
+        176,
+        37,
+        166,
+        110,
+        163,
+        109
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when `terms.top_up > 0`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "docs": [
+            "Source of a maker-paid top-up"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "docs": [
+            "Escrow for a maker-paid top-up — pass only when the maker pays"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "terms",
+          "type": {
+            "defined": {
+              "name": "SwapTerms"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_swap_pnft",
+      "docs": [
+        "Make a swap offering a pNFT, escrowed via Token Metadata TransferV1."
+      ],
+      "discriminator": [
+        248,
+        179,
+        143,
+        213,
+        65,
+        106,
+        95,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "maker_nft_token",
+          "writable": true
+        },
+        {
+          "name": "maker_token_record",
+          "writable": true
+        },
+        {
+          "name": "swap_authority",
+          "docs": [
+            "Swap authority PDA — owns the escrow token account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "maker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when `terms.top_up > 0`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "docs": [
+            "Source of a maker-paid top-up"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "docs": [
+            "Escrow for a maker-paid top-up — pass only when the maker pays"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "terms",
+          "type": {
+            "defined": {
+              "name": "SwapTerms"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrate_core_listing",
+      "docs": [
+        "Grow a `CoreListing` created under the legacy layout to the current one",
+        "(permissionless; `payer` funds the extra rent). The zeroed fields read",
+        "as an open-ended fixed-price listing without a bond, as before."
+      ],
+      "discriminator": [
+        134,
+        203,
+        151,
+        65,
+        163,
+        185,
+        50,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "docs": [
+            "validated in instruction body."
+          ],
+          "writable": true
+        },
+        {
+          "name": "leader_refund",
+          "docs": [
+            "high bidder's instance-0 `BidRefund` PDA, created in instruction body."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrate_listing",
+      "docs": [
+        "Grow a `Listing` created under the legacy layout to the current one",
+        "(permissionless; `payer` funds the extra rent). The appended fields",
+        "keep it behaving as before: no soft close, reserve, buy-now or sealed",
+        "bidding, the legacy 0.1 SOL bid step, and its existing bid escrow and",
+        "refunds (instance 0). A listing with bids also needs `leader_refund`,",
+        "which is created for the standing high bidder."
+      ],
+      "discriminator": [
+        202,
+        10,
+        210,
+        83,
+        249,
+        147,
+        116,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "docs": [
+            "validated in instruction body."
+          ],
+          "writable": true
+        },
+        {
+          "name": "leader_refund",
+          "docs": [
+            "high bidder's instance-0 `BidRefund` PDA, created in instruction body."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "open_bundle",
+      "docs": [
+        "Open a bundle for sale (seller only); no items can be added afterwards.",
+        "`start_time` schedules the sale or auction to begin later."
+      ],
+      "discriminator": [
+        218,
+        44,
+        109,
+        97,
+        229,
+        52,
+        181,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.payment_mint",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "place_bid",
+      "docs": [
+        "Place a bid on an active auction (payment tokens only, no NFT transfer)",
+        "",
+        "`max_amount` turns the bid into a proxy bid: the bidder escrows up to",
+        "`max_amount` and the program raises the visible `current_bid` only as far",
+        "as needed to stay on top of competing bids. A current leader may call this",
+        "again to raise their maximum without moving the visible price."
+      ],
+      "discriminator": [
+        238,
+        77,
+        148,
+        91,
+        200,
+        151,
+        92,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "bidder_token_account",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record — must exist while they lead so an outbid",
+            "can always be credited"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required when outbidding them"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "max_amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "place_bid_sol",
+      "docs": [
+        "Native-SOL `place_bid`: the bid is escrowed as lamports in `sol_escrow`,",
+        "so bidders need no wSOL account. Same proxy and refund rules."
+      ],
+      "discriminator": [
+        222,
+        80,
+        152,
+        132,
+        103,
+        254,
+        222,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "sol_escrow",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record (see `PlaceBid`)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required when outbidding them"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "max_amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "place_bundle_bid",
+      "docs": [
+        "Bid on a bundle auction. Outbid funds are credited to the previous",
+        "leader's `BidRefund` (keyed by the bundle instance) and claimed via",
+        "`claim_refund`."
+      ],
+      "discriminator": [
+        205,
+        209,
+        39,
+        41,
+        66,
+        195,
+        184,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — keyed by the bundle instance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record (see `PlaceBid`)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required once a bid exists"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "place_core_bid",
+      "docs": [
+        "Bid on a Core auction. The previous high bid is credited to its `BidRefund`;",
+        "the first bid freezes the asset in the seller's wallet until settlement."
+      ],
+      "discriminator": [
+        179,
+        185,
+        84,
+        14,
+        29,
+        69,
+        240,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — keyed by the asset, same seeds as SPL auctions"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record (see `PlaceBid`)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required once a bid exists"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "reveal_reserve",
+      "docs": [
+        "Reveal a hidden reserve price (seller only).",
+        "",
+        "Settlement waits for the reveal until `RESERVE_REVEAL_WINDOW_SECONDS`",
+        "after the auction ends; a reserve still hidden by then counts as met and",
+        "the high bid wins, so the reveal is rejected from then on. The revealed",
+        "reserve can't be below the start price."
+      ],
+      "discriminator": [
+        35,
+        70,
+        113,
+        147,
+        101,
+        240,
+        175,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "signer": true,
+          "relations": [
+            "listing"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reserve_price",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reveal_sealed_bid",
+      "docs": [
+        "Reveal a sealed bid during the reveal window.",
+        "",
+        "The bid must match the commitment. If it exceeds the deposit, the",
+        "difference is escrowed now so the winning bid is always fully funded.",
+        "Bids below the opening price are recorded as revealed but not ranked."
+      ],
+      "discriminator": [
+        204,
+        145,
+        248,
+        15,
+        228,
+        127,
+        75,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "sealed_bid",
+          "writable": true
+        },
+        {
+          "name": "bidder_token_account",
+          "writable": true
+        },
+        {
+          "name": "bidder",
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "settle_auction",
+      "docs": [
+        "Settle an auction after end time",
+        "",
+        "WNS NFTs are approved by CPI with `bid_escrow` as authority: for the",
+        "clearing price on a sale (WNS royalty paid from escrow, as in `buy_now`),",
+        "else 0. The layout's payment_mint is always the listing's payment mint.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        246,
+        196,
+        183,
+        98,
+        222,
+        139,
+        46,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller's payment token account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "docs": [
+            "Buyer NFT account — must be owned by highest bidder (or seller if no bids for return)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller NFT account — must be owned by listing.seller (for no-bid return)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required for English auctions with bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "winner_sealed_bid",
+          "docs": [
+            "Winner's sealed bid record — required for sealed auctions with a winner"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settle_auction_pnft",
+      "docs": [
+        "Settle an ended pNFT auction (English or sealed-bid).",
+        "",
+        "Mirrors `settle_auction`: on a sale the winner receives the pNFT and any",
+        "unused escrow is credited back; otherwise the high bid is credited back and",
+        "the pNFT returns to the seller. `destination_owner` must be the winner or seller accordingly.",
+        "Permissionless — `payer` funds the destination token record if needed."
+      ],
+      "discriminator": [
+        56,
+        33,
+        21,
+        144,
+        121,
+        144,
+        169,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "destination_owner"
+        },
+        {
+          "name": "destination_nft_token",
+          "writable": true
+        },
+        {
+          "name": "destination_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller's payment token account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required for English auctions with bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "winner_sealed_bid",
+          "docs": [
+            "Winner's sealed bid record — required for sealed auctions with a winner"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for the destination token account / token record if missing"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settle_auction_sol",
+      "docs": [
+        "Settle an ended native-SOL auction. Same outcomes as `settle_auction`, but",
+        "proceeds are paid in lamports from `sol_escrow` to the seller, treasury and",
+        "creator wallets.",
+        "",
+        "WNS NFTs: on a sale `payer` must sign; it pays WNS's royalty when approving",
+        "the move for the clearing price and is reimbursed from `sol_escrow`.",
+        "Otherwise `escrow_nft` approves it for 0.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        161,
+        102,
+        218,
+        55,
+        232,
+        160,
+        236,
+        20
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "sol_escrow",
+          "docs": [
+            "Lamport bid escrow — absent when the auction drew no bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "docs": [
+            "Buyer NFT account — must be owned by highest bidder"
+          ],
+          "writable": true
+        },
+        {
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller NFT account — must be owned by listing.seller (for no-bid return)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required when there are bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Approves a WNS sale, funded from sol_escrow — required for WNS NFTs sold"
+          ],
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settle_bundle",
+      "docs": [
+        "Settle a bundle auction after it ends (permissionless). With a winning",
+        "bid every item goes to the winner and the bid is split per item;",
+        "without bids, or if a Core item can no longer be delivered, the items",
+        "return to the seller (and the winner's bid is credited back in full).",
+        "remaining_accounts: see the section comment above."
+      ],
+      "discriminator": [
+        194,
+        20,
+        148,
+        194,
+        6,
+        70,
+        117,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may settle; pays any Core CPI storage fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "winner"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — absent when the auction drew no bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "writable": true
+        },
+        {
+          "name": "winner_bid_refund",
+          "docs": [
+            "Winner's refund record — required only if the bundle can't be delivered"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection",
+          "writable": true,
+          "optional": true,
+          "address": "jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settle_core_auction",
+      "docs": [
+        "Settle an ended Core auction. Permissionless.",
+        "",
+        "With a winning bid the asset is thawed and transferred to the winner by",
+        "`core_authority`, and the escrow is split like `buy_now_core` (Artifacte",
+        "collection fee waived); the seller bond goes back to the seller. If the",
+        "seller no longer holds the asset or the delegate was revoked, the winner's",
+        "bid plus the seller bond is credited to the winner instead. With no bids",
+        "the bond is returned and the listing closes; the seller can re-list or",
+        "revoke delegates later."
+      ],
+      "discriminator": [
+        42,
+        130,
+        42,
+        240,
+        230,
+        123,
+        202,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may settle; pays any Core CPI storage fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "winner"
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — absent when the auction drew no bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "core_bond",
+          "docs": [
+            "Seller bond escrow — absent only for listings that posted no bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "winner_bid_refund",
+          "docs": [
+            "Winner's refund record — required only if the asset can't be delivered"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "update_core_listing",
+      "docs": [
+        "Reprice an active Core listing in place (seller only), keeping the",
+        "delegate approvals. Core auctions can only be repriced before the first",
+        "bid. `end_time` (fixed price only) sets a new expiry, 0 for none.",
+        "`None` leaves a field unchanged."
+      ],
+      "discriminator": [
+        223,
+        19,
+        233,
+        154,
+        61,
+        87,
+        244,
+        114
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "signer": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "core_listing.asset",
+                "account": "CoreListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the listing's payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "core_listing.payment_mint",
+                "account": "CoreListing"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "price_usdc",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "end_time",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "update_listing",
+      "docs": [
+        "Reprice an active listing in place (seller only), leaving the NFT in escrow.",
+        "`price` is the fixed, Dutch starting or auction opening price; auctions",
+        "can only be repriced before the first bid. `end_time` (fixed price only)",
+        "sets a new expiry, 0 for none. `None` leaves a field unchanged."
+      ],
+      "discriminator": [
+        192,
+        174,
+        210,
+        68,
+        116,
+        40,
+        242,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the listing's payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.payment_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "end_time",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "update_payment_mint_config",
+      "docs": [
+        "Enable, retire or retune a payment mint (deploy authority only)"
+      ],
+      "discriminator": [
+        175,
+        140,
+        188,
+        89,
+        121,
+        180,
+        177,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "payment_mint_config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint_config.mint",
+                "account": "PaymentMintConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "settings",
+          "type": {
+            "defined": {
+              "name": "PaymentMintSettings"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "update_treasury",
+      "docs": [
+        "Update treasury address (deploy authority only)"
+      ],
+      "discriminator": [
+        60,
+        16,
+        243,
+        66,
+        96,
+        59,
+        254,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "treasury_config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_treasury",
+          "type": "pubkey"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "BidRefund",
+      "discriminator": [
+        247,
+        16,
+        39,
+        213,
+        53,
+        112,
+        130,
+        7
+      ]
+    },
+    {
+      "name": "Bundle",
+      "discriminator": [
+        15,
+        82,
+        167,
+        230,
+        37,
+        214,
+        82,
+        80
+      ]
+    },
+    {
+      "name": "CollectionOffer",
+      "discriminator": [
+        164,
+        66,
+        143,
+        81,
+        85,
+        0,
+        239,
+        213
+      ]
+    },
+    {
+      "name": "CompressedListing",
+      "discriminator": [
+        164,
+        96,
+        88,
+        33,
+        255,
+        76,
+        15,
+        149
+      ]
+    },
+    {
+      "name": "CoreListing",
+      "discriminator": [
+        205,
+        178,
+        162,
+        169,
+        199,
+        166,
+        133,
+        157
+      ]
+    },
+    {
+      "name": "DelegateListing",
+      "discriminator": [
+        244,
+        216,
+        109,
+        161,
+        18,
+        187,
+        54,
+        14
+      ]
+    },
+    {
+      "name": "Listing",
+      "discriminator": [
+        218,
+        32,
+        50,
+        73,
+        43,
+        134,
+        26,
+        58
+      ]
+    },
+    {
+      "name": "Offer",
+      "discriminator": [
+        215,
+        88,
+        60,
+        71,
+        170,
+        162,
+        73,
+        229
+      ]
+    },
+    {
+      "name": "PaymentMintConfig",
+      "discriminator": [
+        94,
+        123,
+        251,
+        40,
+        76,
+        70,
+        180,
+        31
+      ]
+    },
+    {
+      "name": "SealedBid",
+      "discriminator": [
+        199,
+        9,
+        212,
+        151,
+        48,
+        136,
+        163,
+        226
+      ]
+    },
+    {
+      "name": "SolEscrow",
+      "discriminator": [
+        75,
+        199,
+        250,
+        63,
+        244,
+        209,
+        235,
+        120
+      ]
+    },
+    {
+      "name": "Swap",
+      "discriminator": [
+        53,
+        206,
+        146,
+        152,
+        44,
+        97,
+        120,
+        177
+      ]
+    },
+    {
+      "name": "TreasuryConfig",
+      "discriminator": [
+        124,
+        54,
+        212,
+        227,
+        213,
+        189,
+        168,
+        41
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "AuctionCancelled",
+      "discriminator": [
+        22,
+        32,
+        51,
+        83,
+        215,
+        194,
+        171,
+        209
+      ]
+    },
+    {
+      "name": "AuctionSettled",
+      "discriminator": [
+        61,
+        151,
+        131,
+        170,
+        95,
+        203,
+        219,
+        147
+      ]
+    },
+    {
+      "name": "BidPlaced",
+      "discriminator": [
+        135,
+        53,
+        176,
+        83,
+        193,
+        69,
+        108,
+        61
+      ]
+    },
+    {
+      "name": "BidRefundCredited",
+      "discriminator": [
+        87,
+        240,
+        146,
+        63,
+        191,
+        163,
+        59,
+        238
+      ]
+    },
+    {
+      "name": "BundleCancelled",
+      "discriminator": [
+        59,
+        27,
+        168,
+        222,
+        187,
+        229,
+        238,
+        0
+      ]
+    },
+    {
+      "name": "BundleCreated",
+      "discriminator": [
+        248,
+        105,
+        64,
+        162,
+        34,
+        23,
+        35,
+        139
+      ]
+    },
+    {
+      "name": "BundleItemAdded",
+      "discriminator": [
+        202,
+        149,
+        207,
+        86,
+        211,
+        97,
+        22,
+        57
+      ]
+    },
+    {
+      "name": "BundleOpened",
+      "discriminator": [
+        101,
+        110,
+        89,
+        61,
+        9,
+        38,
+        160,
+        49
+      ]
+    },
+    {
+      "name": "BundleSold",
+      "discriminator": [
+        142,
+        217,
+        207,
+        169,
+        2,
+        28,
+        171,
+        103
+      ]
+    },
+    {
+      "name": "CollectionOfferCancelled",
+      "discriminator": [
+        119,
+        119,
+        71,
+        140,
+        61,
+        127,
+        161,
+        166
+      ]
+    },
+    {
+      "name": "CollectionOfferFilled",
+      "discriminator": [
+        147,
+        203,
+        225,
+        183,
+        51,
+        101,
+        190,
+        235
+      ]
+    },
+    {
+      "name": "CollectionOfferMade",
+      "discriminator": [
+        76,
+        4,
+        212,
+        144,
+        22,
+        173,
+        236,
+        152
+      ]
+    },
+    {
+      "name": "CoreListingCancelled",
+      "discriminator": [
+        165,
+        96,
+        88,
+        242,
+        37,
+        224,
+        205,
+        57
+      ]
+    },
+    {
+      "name": "CoreListingCreated",
+      "discriminator": [
+        184,
+        108,
+        121,
+        191,
+        52,
+        251,
+        30,
+        84
+      ]
+    },
+    {
+      "name": "CorePurchased",
+      "discriminator": [
+        221,
+        82,
+        110,
+        196,
+        235,
+        58,
+        114,
+        17
+      ]
+    },
+    {
+      "name": "ItemPurchased",
+      "discriminator": [
+        33,
+        219,
+        12,
+        58,
+        205,
+        48,
+        63,
+        143
+      ]
+    },
+    {
+      "name": "ListingCancelled",
+      "discriminator": [
+        11,
+        46,
+        163,
+        10,
+        103,
+        80,
+        139,
+        194
+      ]
+    },
+    {
+      "name": "ListingCreated",
+      "discriminator": [
+        94,
+        164,
+        167,
+        255,
+        246,
+        186,
+        12,
+        96
+      ]
+    },
+    {
+      "name": "ListingExpired",
+      "discriminator": [
+        86,
+        77,
+        98,
+        166,
+        213,
+        159,
+        72,
+        61
+      ]
+    },
+    {
+      "name": "ListingUpdated",
+      "discriminator": [
+        190,
+        215,
+        199,
+        138,
+        255,
+        248,
+        98,
+        62
+      ]
+    },
+    {
+      "name": "OfferAccepted",
+      "discriminator": [
+        81,
+        238,
+        238,
+        115,
+        140,
+        18,
+        8,
+        20
+      ]
+    },
+    {
+      "name": "OfferCancelled",
+      "discriminator": [
+        45,
+        42,
+        175,
+        214,
+        51,
+        192,
+        154,
+        9
+      ]
+    },
+    {
+      "name": "OfferMade",
+      "discriminator": [
+        11,
+        5,
+        86,
+        210,
+        3,
+        4,
+        25,
+        154
+      ]
+    },
+    {
+      "name": "PaymentMintConfigUpdated",
+      "discriminator": [
+        240,
+        22,
+        24,
+        218,
+        0,
+        194,
+        141,
+        250
+      ]
+    },
+    {
+      "name": "RefundClaimed",
+      "discriminator": [
+        136,
+        64,
+        242,
+        99,
+        4,
+        244,
+        208,
+        130
+      ]
+    },
+    {
+      "name": "ReserveRevealed",
+      "discriminator": [
+        231,
+        30,
+        8,
+        215,
+        173,
+        191,
+        233,
+        251
+      ]
+    },
+    {
+      "name": "SealedBidClaimed",
+      "discriminator": [
+        54,
+        148,
+        88,
+        192,
+        108,
+        253,
+        121,
+        48
+      ]
+    },
+    {
+      "name": "SealedBidCommitted",
+      "discriminator": [
+        71,
+        9,
+        215,
+        14,
+        106,
+        238,
+        98,
+        181
+      ]
+    },
+    {
+      "name": "SealedBidRevealed",
+      "discriminator": [
+        206,
+        13,
+        74,
+        50,
+        124,
+        191,
+        46,
+        166
+      ]
+    },
+    {
+      "name": "SellerBondForfeited",
+      "discriminator": [
+        253,
+        113,
+        119,
+        98,
+        16,
+        157,
+        27,
+        203
+      ]
+    },
+    {
+      "name": "SwapCancelled",
+      "discriminator": [
+        210,
+        232,
+        53,
+        121,
+        126,
+        236,
+        66,
+        142
+      ]
+    },
+    {
+      "name": "SwapCreated",
+      "discriminator": [
+        95,
+        3,
+        86,
+        52,
+        73,
+        22,
+        116,
+        203
+      ]
+    },
+    {
+      "name": "SwapFilled",
+      "discriminator": [
+        26,
+        35,
+        81,
+        151,
+        223,
+        241,
+        23,
+        44
+      ]
+    },
+    {
+      "name": "TreasuryUpdated",
+      "discriminator": [
+        80,
+        239,
+        54,
+        168,
+        43,
+        38,
+        85,
+        145
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ListingNotActive",
+      "msg": "Listing is not active"
+    },
+    {
+      "code": 6001,
+      "name": "AuctionEnded",
+      "msg": "Auction has already ended"
+    },
+    {
+      "code": 6002,
+      "name": "AuctionNotEnded",
+      "msg": "Auction has not ended yet"
+    },
+    {
+      "code": 6003,
+      "name": "BidTooLow",
+      "msg": "Bid is below the required minimum"
+    },
+    {
+      "code": 6004,
+      "name": "CalculationError",
+      "msg": "Calculation error"
+    },
+    {
+      "code": 6005,
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6006,
+      "name": "CannotCancelWithBids",
+      "msg": "Cannot cancel auction with existing bids"
+    },
+    {
+      "code": 6007,
+      "name": "NotAnAuction",
+      "msg": "Invalid listing type for this operation"
+    },
+    {
+      "code": 6008,
+      "name": "NotFixedPrice",
+      "msg": "Invalid listing type for this operation"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidDuration",
+      "msg": "Invalid duration for auction"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidPaymentMint",
+      "msg": "Invalid payment mint for this category"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidPrice",
+      "msg": "Price must be greater than zero"
+    },
+    {
+      "code": 6012,
+      "name": "TransferFailed",
+      "msg": "Token-2022 transfer with hook failed"
+    },
+    {
+      "code": 6013,
+      "name": "SellerCannotBid",
+      "msg": "Seller cannot bid on their own auction"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidRefundAccount",
+      "msg": "Invalid refund account — must be previous bidder's ATA"
+    },
+    {
+      "code": 6015,
+      "name": "RoyaltyTooHigh",
+      "msg": "Royalty basis points too high (max 1000 = 10%)"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidBuyerAccount",
+      "msg": "Invalid buyer account — must be owned by highest bidder"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidCreatorAccount",
+      "msg": "Invalid creator account — must be creator's ATA for payment mint"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidTokenProgram",
+      "msg": "Invalid token program — must be SPL Token or Token-2022"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRoyaltyBps",
+      "msg": "Invalid royalty basis points — must be 0 or >= 100 (1%)"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidCorePluginState",
+      "msg": "Invalid Metaplex Core plugin state"
+    },
+    {
+      "code": 6021,
+      "name": "StaleCoreListing",
+      "msg": "Core listing is stale because the seller no longer owns the asset"
+    },
+    {
+      "code": 6022,
+      "name": "CoreListingNotStale",
+      "msg": "Core listing is not stale"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidSoftClose",
+      "msg": "Invalid soft-close settings — window and extension must be positive"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidReservePrice",
+      "msg": "Invalid reserve price — must exceed the opening price"
+    },
+    {
+      "code": 6025,
+      "name": "NoHiddenReserve",
+      "msg": "Listing has no hidden reserve to reveal"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidReserveReveal",
+      "msg": "Reserve reveal does not match the committed hash"
+    },
+    {
+      "code": 6027,
+      "name": "ReserveNotRevealed",
+      "msg": "Hidden reserve has not been revealed yet"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidBuyNowPrice",
+      "msg": "Invalid buy-now price — must exceed the opening price and reserve"
+    },
+    {
+      "code": 6029,
+      "name": "BuyNowUnavailable",
+      "msg": "Buy-now is not available on this auction"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidBidIncrement",
+      "msg": "Invalid bid increment — step must be positive and percentages at most 100%"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidStartTime",
+      "msg": "Scheduled start time must not be in the past"
+    },
+    {
+      "code": 6032,
+      "name": "ListingNotStarted",
+      "msg": "Listing has not started yet"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidDutchConfig",
+      "msg": "Invalid Dutch auction settings — floor must be positive and below the start price"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMaxBid",
+      "msg": "Invalid maximum bid — must be at least the bid and above any existing maximum"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidSealedConfig",
+      "msg": "Invalid sealed auction settings — reveal window must be positive"
+    },
+    {
+      "code": 6036,
+      "name": "NotSealedAuction",
+      "msg": "Listing is not a sealed-bid auction"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidSealedBid",
+      "msg": "Sealed bid does not match its commitment or was already revealed"
+    },
+    {
+      "code": 6038,
+      "name": "NotInRevealWindow",
+      "msg": "Outside the sealed-bid reveal window"
+    },
+    {
+      "code": 6039,
+      "name": "WinningBidLocked",
+      "msg": "Winning sealed bid is locked until the auction is settled"
+    },
+    {
+      "code": 6040,
+      "name": "PriceBelowMinimum",
+      "msg": "Price is below the payment mint's minimum"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidOfferExpiry",
+      "msg": "Offer expiry must be in the future"
+    },
+    {
+      "code": 6042,
+      "name": "OfferExpired",
+      "msg": "Offer has expired"
+    },
+    {
+      "code": 6043,
+      "name": "OfferNotExpired",
+      "msg": "Offer has not expired yet"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidOfferAsset",
+      "msg": "Offers can only be made on SPL / Token-2022 mints or Core assets, and accepted with the matching accounts"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidOfferQuantity",
+      "msg": "Collection offer quantity must be positive"
+    },
+    {
+      "code": 6046,
+      "name": "OfferFilled",
+      "msg": "Collection offer is fully filled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidAttributePredicate",
+      "msg": "Attribute predicates must be unique, non-empty and within size limits"
+    },
+    {
+      "code": 6048,
+      "name": "AttributeMismatch",
+      "msg": "Asset attributes do not match the offer"
+    },
+    {
+      "code": 6049,
+      "name": "CannotUpdateWithBids",
+      "msg": "Cannot reprice an auction with bids"
+    },
+    {
+      "code": 6050,
+      "name": "ListingExpired",
+      "msg": "Listing has expired"
+    },
+    {
+      "code": 6051,
+      "name": "ListingNotExpired",
+      "msg": "Listing has not expired"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidBundle",
+      "msg": "Bundle is already open, full, empty, or already holds this item"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidBundleWeights",
+      "msg": "Bundle weights must be all zero or all positive"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidBundleAccounts",
+      "msg": "Bundle item accounts do not match the bundle"
+    },
+    {
+      "code": 6055,
+      "name": "CoreAssetCommitted",
+      "msg": "Core asset is already listed, bundled or offered in a swap"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidSwapTerms",
+      "msg": "Swap must want another asset or collection, with attributes only for collections and a future expiry"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidSwapAccounts",
+      "msg": "Swap accounts do not match the swap"
+    },
+    {
+      "code": 6058,
+      "name": "SwapMismatch",
+      "msg": "Asset does not satisfy the swap"
+    },
+    {
+      "code": 6059,
+      "name": "StaleListing",
+      "msg": "Listing is stale because the seller's token account no longer holds the NFT under its delegation"
+    },
+    {
+      "code": 6060,
+      "name": "ListingNotStale",
+      "msg": "Listing is not stale"
+    },
+    {
+      "code": 6061,
+      "name": "InvalidCompressedProof",
+      "msg": "Merkle proof does not verify the compressed NFT leaf"
+    },
+    {
+      "code": 6062,
+      "name": "MissingTransferHookProgram",
+      "msg": "Transfer-hook program missing from remaining_accounts"
+    },
+    {
+      "code": 6063,
+      "name": "MissingExtraMetasAccount",
+      "msg": "Transfer-hook extra-account-metas PDA missing from remaining_accounts"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidTransferHookAccounts",
+      "msg": "remaining_accounts lack accounts listed by the transfer hook's extra-account-metas"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidWnsAccountLayout",
+      "msg": "WNS remaining_accounts must be: wns_program, extra_metas, approve_account, distribution_program, distribution_account, distribution_token_account, system_program, member, group"
+    },
+    {
+      "code": 6066,
+      "name": "WnsApproveFailed",
+      "msg": "WNS approve_transfer failed"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidSellerBond",
+      "msg": "Seller bond accounts are missing or invalid"
+    },
+    {
+      "code": 6068,
+      "name": "NotLegacyListing",
+      "msg": "Account is not a listing in the legacy layout"
+    },
+    {
+      "code": 6069,
+      "name": "ReserveRevealClosed",
+      "msg": "Hidden reserve reveal window has closed"
+    },
+    {
+      "code": 6070,
+      "name": "PayoutBelowRent",
+      "msg": "Lamport payout would leave its wallet below the rent-exempt minimum"
+    }
+  ],
+  "types": [
+    {
+      "name": "AttributePredicate",
+      "docs": [
+        "Exact-match requirement on a Core `Attributes` plugin entry."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "string"
+          },
+          {
+            "name": "value",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidIncrement",
+      "docs": [
+        "Minimum raise rule for English auctions."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Standard"
+          },
+          {
+            "name": "Absolute",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "Percentage",
+            "fields": [
+              "u16"
+            ]
+          },
+          {
+            "name": "Tiered"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidPlaced",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auction end time after any soft-close extension"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidRefund",
+      "docs": [
+        "Funds owed back to a bidder on one auction (SPL or Core), held in that",
+        "auction's `bid_escrow` until claimed. Outlives the listing."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "nft_mint",
+            "docs": [
+              "NFT mint, or Core asset, the auction is keyed by"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "native",
+            "docs": [
+              "Held as lamports in `sol_escrow` rather than tokens in `bid_escrow`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "listing_created_at",
+            "docs": [
+              "`created_at` of the listing instance whose escrow holds the funds"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidRefundCredited",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Bundle",
+      "docs": [
+        "Several NFTs sold as one lot, at a fixed price or by English auction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundle_id",
+            "type": "u64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Fixed price, or auction starting price"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "duration_seconds",
+            "docs": [
+              "Applied when the bundle opens (0 = fixed price without expiry)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "open",
+            "docs": [
+              "Set by `open_bundle`; items can only be added before"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "current_bid",
+            "type": "u64"
+          },
+          {
+            "name": "highest_bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "bid_increment",
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "BundleItem"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "created_at",
+            "docs": [
+              "Identifies this bundle instance; scopes its bid escrow and refund",
+              "records, since a closed bundle's PDA can be created again"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "Unwound after expiry rather than cancelled by the seller"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundle_id",
+            "type": "u64"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "docs": [
+              "NFT mint or Core asset"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "BundleItemKind"
+              }
+            }
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Share of the bundle price (0 on every item = even split)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "royalty_basis_points",
+            "docs": [
+              "Seller-declared royalty (SPL / Token-2022 only; Core reads the plugin)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "`bundle_escrow` bump, or `core_authority` bump for Core items"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleItemAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleItemKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Spl"
+          },
+          {
+            "name": "Token2022"
+          },
+          {
+            "name": "Core"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleOpened",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "item_count",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleSold",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          },
+          {
+            "name": "item_count",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyNowConfig",
+      "docs": [
+        "Buy-it-now settings for auctions, supplied at listing time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "cutoff_bps",
+            "docs": [
+              "Share of `price` (in bps) the high bid may reach before buy-now is withdrawn"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionOffer",
+      "docs": [
+        "Standing bid for up to `quantity_remaining` assets of a Core collection at",
+        "`price` each, escrowed in `collection_offer_escrow`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity_remaining",
+            "type": "u32"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "attributes",
+            "docs": [
+              "Asset must match all of these (empty = any asset in the collection)"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
+              }
+            }
+          },
+          {
+            "name": "attributes_hash",
+            "docs": [
+              "`attribute_predicates_hash(attributes)`, part of the PDA seeds"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrow_bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionOfferCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "quantity_remaining",
+            "type": "u32"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "Closed after expiry rather than withdrawn by the buyer"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionOfferFilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          },
+          {
+            "name": "quantity_remaining",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionOfferMade",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u32"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "attributes",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedLeaf",
+      "docs": [
+        "A Bubblegum leaf: its hashes and position, plus the root its proof is against."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "data_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creator_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedListing",
+      "docs": [
+        "Fixed-price listing of a cNFT; the PDA is the leaf delegate."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset_id",
+            "docs": [
+              "Bubblegum asset id, derived from tree and nonce"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "merkle_tree",
+            "type": "pubkey"
+          },
+          {
+            "name": "leaf_nonce",
+            "type": "u64"
+          },
+          {
+            "name": "leaf_index",
+            "type": "u32"
+          },
+          {
+            "name": "data_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creator_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "0 = no expiry"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "royalty_basis_points",
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "start_time",
+            "docs": [
+              "Purchases are rejected before this time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "listing_type",
+            "docs": [
+              "FixedPrice or Auction — other listing types are not offered for Core"
+            ],
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auctions: bidding closes at this time. Fixed price: expiry (0 = none)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "current_bid",
+            "type": "u64"
+          },
+          {
+            "name": "highest_bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "bid_increment",
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "seller_bond",
+            "docs": [
+              "Auctions: bond held in `core_bond` until settlement or cancellation"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreListingCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreListingCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "price_usdc",
+            "type": "u64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auction close, or fixed-price expiry (0 = none)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CorePurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price_usdc",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DelegateListing",
+      "docs": [
+        "Escrowless fixed-price listing of an SPL / Token-2022 NFT or pNFT; the PDA",
+        "is the token delegate of `seller_nft_account`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller_nft_account",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "0 = no expiry"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "is_token2022",
+            "type": "bool"
+          },
+          {
+            "name": "is_pnft",
+            "docs": [
+              "Sale-delegated pNFT (see `list_item_pnft_delegated`)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "royalty_basis_points",
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DutchConfig",
+      "docs": [
+        "Dutch auction settings, supplied at listing time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "floor_price",
+            "type": "u64"
+          },
+          {
+            "name": "curve",
+            "type": {
+              "defined": {
+                "name": "DutchCurve"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DutchCurve",
+      "docs": [
+        "How a Dutch listing's price decays between start and end time."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Stepwise",
+            "fields": [
+              {
+                "name": "step_seconds",
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "ItemCategory",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "DigitalArt"
+          },
+          {
+            "name": "Spirits"
+          },
+          {
+            "name": "TCGCards"
+          },
+          {
+            "name": "SportsCards"
+          },
+          {
+            "name": "Watches"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ItemPurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Listing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "ListingStatus"
+              }
+            }
+          },
+          {
+            "name": "escrow_nft_account",
+            "type": "pubkey"
+          },
+          {
+            "name": "current_bid",
+            "type": "u64"
+          },
+          {
+            "name": "highest_bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "baxus_fee",
+            "type": "bool"
+          },
+          {
+            "name": "is_token2022",
+            "type": "bool"
+          },
+          {
+            "name": "is_pnft",
+            "type": "bool"
+          },
+          {
+            "name": "royalty_basis_points",
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "soft_close_window",
+            "docs": [
+              "Seconds before `end_time` in which a bid extends the auction (0 = off)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "soft_close_extension",
+            "docs": [
+              "Seconds added past the bid time when soft-close triggers"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "max_end_time",
+            "docs": [
+              "Hard cap on `end_time` after extensions (0 = uncapped)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "reserve_price",
+            "docs": [
+              "Minimum winning bid; 0 = no reserve beyond the opening `price`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserve_commitment",
+            "docs": [
+              "sha256 commitment of a hidden reserve, zeroed once revealed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buy_now_price",
+            "docs": [
+              "Price at which a buyer can end the auction early (0 = not offered)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "buy_now_cutoff_bps",
+            "docs": [
+              "Buy-now is withdrawn once the high bid passes this share of `buy_now_price`"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bid_increment",
+            "docs": [
+              "How far each new bid must exceed `current_bid`"
+            ],
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "dutch_floor_price",
+            "docs": [
+              "Dutch listings: price reached at `end_time` (`price` is the start price)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dutch_curve",
+            "type": {
+              "defined": {
+                "name": "DutchCurve"
+              }
+            }
+          },
+          {
+            "name": "highest_bidder_max",
+            "docs": [
+              "Leader's proxy maximum — the amount actually held in `bid_escrow`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sealed_pricing",
+            "docs": [
+              "Sealed auctions: pricing rule, reveal deadline and bid bookkeeping"
+            ],
+            "type": {
+              "defined": {
+                "name": "SealedPricing"
+              }
+            }
+          },
+          {
+            "name": "reveal_end_time",
+            "type": "i64"
+          },
+          {
+            "name": "second_bid",
+            "type": "u64"
+          },
+          {
+            "name": "sealed_bid_count",
+            "type": "u32"
+          },
+          {
+            "name": "forfeit_unrevealed",
+            "type": "bool"
+          },
+          {
+            "name": "native_sol",
+            "docs": [
+              "SOL English auctions escrow bids as lamports in `sol_escrow` and must use",
+              "the `_sol` instructions"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "created_at",
+            "docs": [
+              "Identifies this listing instance; scopes its bid escrows and refund",
+              "records. Zero for listings migrated from the legacy layout."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListingCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListingCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListingExpired",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "docs": [
+              "NFT mint, or Core asset for `close_expired_core_listing`"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListingOptions",
+      "docs": [
+        "Optional settings for `list_item` / `list_item_pnft`; `None` leaves a",
+        "setting off."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "soft_close",
+            "docs": [
+              "Auctions only: extends `end_time` when bids land near the close."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "SoftCloseConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "reserve",
+            "docs": [
+              "Auctions only: a sale floor above the opening `price`, either public",
+              "or hidden behind a sha256 commitment."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "ReservePrice"
+                }
+              }
+            }
+          },
+          {
+            "name": "buy_now",
+            "docs": [
+              "Auctions only: lets a buyer end the auction early at a fixed price."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "BuyNowConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "bid_increment",
+            "docs": [
+              "Auctions only: defaults to the payment mint's configured increment."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "BidIncrement"
+                }
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "docs": [
+              "Schedules the listing to open later; the NFT is escrowed now."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "dutch",
+            "docs": [
+              "Dutch listings only: the floor and decay curve; `price` is the starting",
+              "price and `duration_seconds` the time taken to decay to the floor."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "DutchConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "sealed",
+            "docs": [
+              "Sealed-bid listings only: the pricing rule and reveal window;",
+              "`duration_seconds` is then the commit phase."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "SealedConfig"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListingStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Settled"
+          },
+          {
+            "name": "Cancelled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListingType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FixedPrice"
+          },
+          {
+            "name": "Auction"
+          },
+          {
+            "name": "Dutch"
+          },
+          {
+            "name": "Sealed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListingUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "docs": [
+              "NFT mint, or Core asset for `update_core_listing`"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auction close, or fixed-price expiry (0 = none)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Offer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "docs": [
+              "NFT mint or Metaplex Core asset"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Escrowed in `offer_escrow`; the full sale price on acceptance"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrow_bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OfferAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OfferCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "Closed after expiry rather than withdrawn by the buyer"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OfferMade",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PaymentMintConfig",
+      "docs": [
+        "Accepted payment mint. Listings may only use a mint whose config is",
+        "enabled and allows the item's category (or Core, for Core listings)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "allowed_categories",
+            "docs": [
+              "Bitmask of allowed `ItemCategory` values, one bit per variant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "allow_core",
+            "type": "bool"
+          },
+          {
+            "name": "min_price",
+            "docs": [
+              "Lowest price a listing may sell for, in payment-token base units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bid_increment",
+            "docs": [
+              "Used by auctions that don't choose their own increment"
+            ],
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PaymentMintConfigUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "allowed_categories",
+            "type": "u8"
+          },
+          {
+            "name": "allow_core",
+            "type": "bool"
+          },
+          {
+            "name": "min_price",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PaymentMintSettings",
+      "docs": [
+        "Admin-supplied settings for a `PaymentMintConfig`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "categories",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "ItemCategory"
+                }
+              }
+            }
+          },
+          {
+            "name": "allow_core",
+            "type": "bool"
+          },
+          {
+            "name": "min_price",
+            "type": "u64"
+          },
+          {
+            "name": "bid_increment",
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RefundClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReservePrice",
+      "docs": [
+        "Auction reserve, supplied at listing time."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Public",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "Hidden",
+            "fields": [
+              {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReserveRevealed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reserve_price",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBid",
+      "docs": [
+        "One bidder's sealed bid on one listing instance. Outlives the listing so",
+        "losing and unrevealed bids can be reclaimed after settlement."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "escrowed",
+            "docs": [
+              "Payment tokens held in `bid_escrow` for this bid"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "revealed",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reveal_end_time",
+            "type": "i64"
+          },
+          {
+            "name": "forfeit_unrevealed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "listing_created_at",
+            "docs": [
+              "`created_at` of the listing instance the bid was made on; scopes this",
+              "record and the escrow holding its deposit"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "forfeited",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidCommitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidRevealed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedConfig",
+      "docs": [
+        "Sealed-bid auction settings, supplied at listing time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pricing",
+            "type": {
+              "defined": {
+                "name": "SealedPricing"
+              }
+            }
+          },
+          {
+            "name": "reveal_seconds",
+            "docs": [
+              "Length of the reveal window after the commit phase ends"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "forfeit_unrevealed",
+            "docs": [
+              "Unrevealed deposits go to the seller instead of back to the bidder"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedPricing",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstPrice"
+          },
+          {
+            "name": "SecondPrice"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellerBondForfeited",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SoftCloseConfig",
+      "docs": [
+        "Anti-sniping settings for English auctions, supplied at listing time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "window_seconds",
+            "type": "i64"
+          },
+          {
+            "name": "extension_seconds",
+            "type": "i64"
+          },
+          {
+            "name": "max_extension_seconds",
+            "docs": [
+              "Maximum total extension past the original end time (None = uncapped)"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolEscrow",
+      "docs": [
+        "Lamport escrow for native-SOL auction bids. Program-owned so the program",
+        "can debit it directly; its rent-exempt reserve is never paid out as bids."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Swap",
+      "docs": [
+        "One escrowed asset offered for another, keyed by the offered asset."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "offered_asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "offered_kind",
+            "type": {
+              "defined": {
+                "name": "SwapAssetKind"
+              }
+            }
+          },
+          {
+            "name": "want",
+            "type": {
+              "defined": {
+                "name": "SwapWant"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": "pubkey"
+          },
+          {
+            "name": "attributes",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
+              }
+            }
+          },
+          {
+            "name": "payment_mint",
+            "docs": [
+              "Default pubkey when there is no top-up"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "top_up",
+            "type": "u64"
+          },
+          {
+            "name": "top_up_payer",
+            "type": {
+              "defined": {
+                "name": "TopUpPayer"
+              }
+            }
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrow_bump",
+            "docs": [
+              "Bump of the escrow PDA for `offered_kind` (see `swap_escrow_seed`)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "payment_escrow_bump",
+            "docs": [
+              "`swap_payment_escrow` bump when the maker pays the top-up"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapAssetKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Spl"
+          },
+          {
+            "name": "Token2022"
+          },
+          {
+            "name": "Pnft"
+          },
+          {
+            "name": "Core"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swap",
+            "type": "pubkey"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "offered_asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "Unwound after expiry rather than cancelled by the maker"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swap",
+            "type": "pubkey"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "offered_asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "offered_kind",
+            "type": {
+              "defined": {
+                "name": "SwapAssetKind"
+              }
+            }
+          },
+          {
+            "name": "want",
+            "type": {
+              "defined": {
+                "name": "SwapWant"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": "pubkey"
+          },
+          {
+            "name": "attributes",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
+              }
+            }
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "top_up",
+            "type": "u64"
+          },
+          {
+            "name": "top_up_payer",
+            "type": {
+              "defined": {
+                "name": "TopUpPayer"
+              }
+            }
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapFilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swap",
+            "type": "pubkey"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "taker",
+            "type": "pubkey"
+          },
+          {
+            "name": "offered_asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "received_asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "top_up",
+            "type": "u64"
+          },
+          {
+            "name": "top_up_payer",
+            "type": {
+              "defined": {
+                "name": "TopUpPayer"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapTerms",
+      "docs": [
+        "What a swap maker wants back, and the optional cash leg."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "want",
+            "type": {
+              "defined": {
+                "name": "SwapWant"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": "pubkey"
+          },
+          {
+            "name": "attributes",
+            "docs": [
+              "Core attributes the taker's asset must carry (collection swaps only)"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
+              }
+            }
+          },
+          {
+            "name": "top_up",
+            "docs": [
+              "Payment-mint amount that changes hands with the assets (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "top_up_payer",
+            "type": {
+              "defined": {
+                "name": "TopUpPayer"
+              }
+            }
+          },
+          {
+            "name": "expires_at",
+            "docs": [
+              "0 = never expires"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapWant",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Asset"
           },
           {
-            "name": "end_time",
-            "type": "i64"
+            "name": "Collection"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TopUpPayer",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Taker"
           },
           {
-            "name": "status",
-            "type": {
+            "name": "Maker"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TreasuryConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TreasuryUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "old_treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "new_treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    }
+  ]
+};
+
+export const IDL: Auction = {
+  "address": "81s1tEx4MPdVvqS6X84Mok5K4N5fMbRLzcsT5eo2K8J3",
+  "metadata": {
+    "name": "auction",
+    "version": "0.1.0",
+    "spec": "0.1.0"
+  },
+  "instructions": [
+    {
+      "name": "accept_offer",
+      "docs": [
+        "Accept an offer on an SPL / Token-2022 NFT (owner only).",
+        "",
+        "Listed (fixed price or Dutch): pass `listing` and `escrow_nft`; the NFT",
+        "leaves escrow, royalties follow the listing and the listing is closed.",
+        "Unlisted: pass `seller_nft_account`; royalties use `royalty_basis_points`",
+        "and `creator_address` as supplied to `list_item`.",
+        "WNS NFTs are charged the royalty in their metadata instead: the offer",
+        "escrow approves the move by CPI for the offer amount and WNS takes its",
+        "royalty from it.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        227,
+        82,
+        234,
+        131,
+        1,
+        18,
+        48,
+        2
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "The item's listing — required when it is listed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft",
+          "docs": [
+            "Escrowed NFT — required with `listing`"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller's NFT account — required when the item is not listed"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by the seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "accept_offer_core",
+      "docs": [
+        "Accept an offer on a Metaplex Core asset (owner only). A fixed-price",
+        "`core_listing`, if passed, is closed and the transfer is signed through",
+        "its TransferDelegate; otherwise the owner signs the transfer directly.",
+        "Royalties come from the on-chain Royalties plugin, as in `buy_now_core`."
+      ],
+      "discriminator": [
+        233,
+        111,
+        45,
+        247,
+        197,
+        32,
+        103,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "docs": [
+            "Current owner."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "docs": [
+            "Fixed-price listing for the asset, if any — closed by the sale"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by the seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in handler against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "accept_offer_pnft",
+      "docs": [
+        "Accept an offer on a pNFT (owner only). Listed pNFTs (fixed price or",
+        "Dutch) leave the `escrow_authority` escrow and the listing is closed;",
+        "unlisted ones move from the seller's wallet. Royalty arguments as in",
+        "`accept_offer`."
+      ],
+      "discriminator": [
+        87,
+        193,
+        215,
+        232,
+        46,
+        229,
+        112,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "The item's listing — required when it is listed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA — source owner for listed pNFTs"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "source_nft_token",
+          "writable": true
+        },
+        {
+          "name": "source_token_record",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_token",
+          "writable": true
+        },
+        {
+          "name": "buyer_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by the seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "docs": [
+            "Seller; pays for the buyer's token account / token record if missing"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "add_bundle_core_item",
+      "docs": [
+        "Add a Metaplex Core asset to an unopened bundle (seller only). The",
+        "asset stays in the seller's wallet, delegated to its `core_authority`",
+        "PDA and frozen until the bundle sells or is cancelled. Royalties are read",
+        "from the asset at sale time."
+      ],
+      "discriminator": [
+        187,
+        87,
+        57,
+        117,
+        71,
+        217,
+        178,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "add_bundle_item",
+      "docs": [
+        "Escrow an SPL / Token-2022 NFT into an unopened bundle (seller only).",
+        "`royalty_basis_points` and `creator_address` are as for `list_item`;",
+        "`weight` is the item's share of the price (0 on every item = even split).",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        134,
+        206,
+        210,
+        166,
+        87,
+        228,
+        132,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "bundle_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle"
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "buy_bundle",
+      "docs": [
+        "Buy a fixed-price bundle: every item moves to the buyer and the price is",
+        "split per item. remaining_accounts: see the section comment above."
+      ],
+      "discriminator": [
+        51,
+        0,
+        223,
+        19,
+        152,
+        96,
+        11,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by bundle.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection",
+          "writable": true,
+          "optional": true,
+          "address": "jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now",
+      "docs": [
+        "Buy a fixed-price listing immediately, or a Dutch listing at its current price",
+        "",
+        "WNS NFTs are approved by CPI for the sale price: WNS collects the royalty",
+        "in the NFT's metadata from the buyer's payment account, in place of the",
+        "listing's royalty.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        242,
+        42,
+        184,
+        77,
+        133,
+        152,
+        118,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_auction",
+      "docs": [
+        "Buy a running auction outright at its buy-now price, ending it early.",
+        "",
+        "Only offered while the high bid has not passed `buy_now_cutoff_bps` of the",
+        "buy-now price. Any standing high bid is credited to its `BidRefund`.",
+        "Native-SOL auctions use `buy_now_auction_sol`.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        66,
+        122,
+        226,
+        13,
+        221,
+        180,
+        133,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow — if present and empty, closed to the treasury"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required only when there is a standing bid"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_auction_sol",
+      "docs": [
+        "Native-SOL `buy_now_auction`: the buyer pays seller, treasury and creator",
+        "wallets in lamports. A standing high bid stays in `sol_escrow`, credited",
+        "to its `BidRefund` for `claim_refund`.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        64,
+        43,
+        28,
+        123,
+        150,
+        190,
+        145,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required only when there is a standing bid"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_compressed",
+      "docs": [
+        "Buy a cNFT listing with the same fee and royalty split as `buy_now`.",
+        "`root` is the tree root the proof in remaining_accounts resolves to."
+      ],
+      "discriminator": [
+        186,
+        122,
+        147,
+        101,
+        134,
+        6,
+        219,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.merkle_tree",
+                "account": "CompressedListing"
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.leaf_nonce",
+                "account": "CompressedListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "tree_config"
+        },
+        {
+          "name": "merkle_tree",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by compressed_listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "log_wrapper",
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+        },
+        {
+          "name": "bubblegum_program",
+          "address": "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "buy_now_core",
+      "docs": [
+        "Public buy of a Core listing. Artifacte collection buys waive the",
+        "platform fee and only split the payment between creator royalty and seller.",
+        "Then CPI TransferV1 moves the asset to the buyer."
+      ],
+      "discriminator": [
+        107,
+        235,
+        190,
+        104,
+        232,
+        171,
+        241,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "buyer",
+          "docs": [
+            "Public buyer."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Listing payment mint (validated in handler)."
+          ]
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_delegated",
+      "docs": [
+        "Buy a delegate listing: the listing PDA moves the NFT straight from the",
+        "seller's token account to the buyer. remaining_accounts: same layout as",
+        "list_item"
+      ],
+      "discriminator": [
+        36,
+        24,
+        170,
+        206,
+        131,
+        38,
+        37,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by delegate_listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_pnft",
+      "docs": [
+        "Buy a fixed-price or Dutch pNFT listing.",
+        "",
+        "Same pricing and fee split as `buy_now`; the pNFT moves escrow → buyer via",
+        "Token Metadata TransferV1 so token records and rule sets are honoured."
+      ],
+      "discriminator": [
+        26,
+        178,
+        190,
+        138,
+        45,
+        22,
+        144,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_token",
+          "writable": true
+        },
+        {
+          "name": "buyer_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_pnft_delegated",
+      "docs": [
+        "Buy a delegated pNFT listing. The listing PDA, as Sale delegate, moves",
+        "the pNFT seller → buyer via Token Metadata TransferV1, which unlocks it,",
+        "clears the delegate and enforces the collection's rule set."
+      ],
+      "discriminator": [
+        171,
+        239,
+        203,
+        13,
+        157,
+        103,
+        38,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_token",
+          "writable": true
+        },
+        {
+          "name": "buyer_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by delegate_listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buy_now_sol",
+      "docs": [
+        "Native-SOL `buy_now` for SOL-priced listings: the buyer pays seller,",
+        "treasury and creator wallets with system transfers — no wSOL account needed.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        37,
+        87,
+        9,
+        66,
+        241,
+        180,
+        52,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_bundle",
+      "docs": [
+        "Return every item to the seller and close the bundle. The seller may",
+        "cancel before the first bid; anyone may unwind an expired fixed-price",
+        "bundle. remaining_accounts: see the section comment above."
+      ],
+      "discriminator": [
+        166,
+        121,
+        4,
+        129,
+        237,
+        255,
+        233,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "The seller, or anyone once a fixed-price bundle has expired; pays any",
+            "Core CPI storage fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "collection",
+          "writable": true,
+          "optional": true,
+          "address": "jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_collection_offer",
+      "docs": [
+        "Withdraw a collection offer and reclaim the unfilled escrow (buyer only)."
+      ],
+      "discriminator": [
+        19,
+        192,
+        93,
+        201,
+        1,
+        251,
+        254,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_compressed_listing",
+      "docs": [
+        "Cancel a cNFT listing (seller only), handing leaf delegation back to the",
+        "seller. A listing whose leaf already changed is closed with",
+        "`close_stale_compressed_listing` instead."
+      ],
+      "discriminator": [
+        12,
+        108,
+        154,
+        147,
+        228,
+        206,
+        118,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.merkle_tree",
+                "account": "CompressedListing"
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.leaf_nonce",
+                "account": "CompressedListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tree_config"
+        },
+        {
+          "name": "merkle_tree",
+          "writable": true
+        },
+        {
+          "name": "log_wrapper",
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+        },
+        {
+          "name": "bubblegum_program",
+          "address": "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancel_core_listing",
+      "docs": [
+        "Cancel an active Core listing while the seller still holds the asset.",
+        "Core auctions can only be cancelled before the first bid; their seller",
+        "bond is returned."
+      ],
+      "discriminator": [
+        58,
+        30,
+        81,
+        251,
+        75,
+        37,
+        173,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Core auctions only: payment mint, bond escrow and seller payment account",
+            "the seller bond is returned through"
+          ],
+          "optional": true
+        },
+        {
+          "name": "core_bond",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token_program",
+          "optional": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_delegated_listing",
+      "docs": [
+        "Cancel a delegate listing (seller only), revoking the approval if the",
+        "listing PDA still holds it."
+      ],
+      "discriminator": [
+        134,
+        165,
+        8,
+        172,
+        16,
+        62,
+        80,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_delegated_listing_pnft",
+      "docs": [
+        "Cancel a delegated pNFT listing (seller only): revokes the Sale delegate,",
+        "which unlocks the pNFT, if the listing PDA still holds it. Also the way",
+        "to close an expired pNFT listing."
+      ],
+      "discriminator": [
+        29,
+        105,
+        251,
+        165,
+        118,
+        230,
+        125,
+        52
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_listing",
+      "docs": [
+        "Cancel a listing (seller only, auctions only if no bids)",
+        "",
+        "WNS NFTs are approved by CPI (amount 0, seller as authority).",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        41,
+        183,
+        50,
+        232,
+        230,
+        233,
+        157,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_listing_pnft",
+      "docs": [
+        "Cancel a pNFT listing — return NFT to seller."
+      ],
+      "discriminator": [
+        41,
+        48,
+        179,
+        6,
+        129,
+        16,
+        120,
+        65
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_offer",
+      "docs": [
+        "Withdraw an offer and reclaim its escrow (buyer only)."
+      ],
+      "discriminator": [
+        92,
+        203,
+        223,
+        40,
+        92,
+        89,
+        53,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_swap",
+      "docs": [
+        "Cancel a swap: the escrowed asset and any maker top-up go back to the",
+        "maker. The maker may cancel at any time; anyone may unwind an expired",
+        "swap. remaining_accounts: the maker's leg (see the section comment above)."
+      ],
+      "discriminator": [
+        88,
+        174,
+        98,
+        148,
+        24,
+        252,
+        93,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap.offered_asset",
+                "account": "Swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "The maker, or anyone once the swap has expired; pays any CPI fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "escrow_authority",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "token_metadata_program",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "sysvar_instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "ata_program",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claim_refund",
+      "docs": [
+        "Withdraw outbid or unspent funds credited to the caller's `BidRefund`.",
+        "",
+        "Works for SPL, Core and bundle auctions. The record is closed unless the caller is",
+        "the current high bidder, who must keep it so a later outbid can be credited.",
+        "Once the listing instance is gone (settled, cancelled or relisted), the",
+        "last claim also closes its empty bid escrow."
+      ],
+      "discriminator": [
+        15,
+        16,
+        30,
+        161,
+        255,
+        228,
+        97,
+        60
+      ],
+      "accounts": [
+        {
+          "name": "bid_refund",
+          "writable": true
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "Validated in instruction body."
+          ]
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — required for token refunds"
+          ],
+          "optional": true
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow — required while a token refund holds funds"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "sol_escrow",
+          "docs": [
+            "Lamport escrow — required for native-SOL refunds"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "docs": [
+            "Destination for token refunds; unused for native-SOL refunds"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claim_sealed_bid",
+      "docs": [
+        "Reclaim a losing or unrevealed sealed bid once the reveal window closes.",
+        "",
+        "Revealed bids are refunded in full. Unrevealed deposits go to the seller",
+        "when the listing forfeits them, otherwise back to the bidder. The winning",
+        "bid is locked until `settle_auction` runs."
+      ],
+      "discriminator": [
+        193,
+        144,
+        248,
+        90,
+        211,
+        91,
+        113,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "sealed_bid",
+          "writable": true
+        },
+        {
+          "name": "listing",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sealed_bid.nft_mint",
+                "account": "SealedBid"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — required only when an unrevealed deposit is forfeited"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_collection_offer",
+      "docs": [
+        "Refund an expired collection offer to its buyer (permissionless)."
+      ],
+      "discriminator": [
+        188,
+        120,
+        11,
+        240,
+        208,
+        57,
+        250,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.collection",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_core_listing",
+      "docs": [
+        "Close an expired fixed-price Core listing (permissionless). The",
+        "TransferDelegate revokes itself if still approved; rent goes to the seller."
+      ],
+      "discriminator": [
+        162,
+        48,
+        42,
+        214,
+        105,
+        221,
+        109,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_listing",
+      "docs": [
+        "Return the NFT of an expired fixed-price listing to its seller and close",
+        "the listing, with rent to the seller (permissionless).",
+        "",
+        "WNS NFTs are approved by CPI (amount 0, `escrow_nft` as authority).",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        150,
+        70,
+        13,
+        135,
+        9,
+        204,
+        75,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller's NFT account — receives the returned NFT"
+          ],
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_listing_pnft",
+      "docs": [
+        "Return the pNFT of an expired fixed-price listing to its seller",
+        "(permissionless). `payer` funds the seller's token record if needed;",
+        "listing rent goes to the seller."
+      ],
+      "discriminator": [
+        65,
+        18,
+        89,
+        75,
+        159,
+        115,
+        100,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for the seller's token record if it must be created"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_expired_offer",
+      "docs": [
+        "Close an expired offer, returning escrow and rent to the buyer. Permissionless."
+      ],
+      "discriminator": [
+        174,
+        202,
+        24,
+        79,
+        174,
+        99,
+        110,
+        236
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.asset",
+                "account": "Offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.buyer",
+                "account": "Offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_stale_compressed_listing",
+      "docs": [
+        "Close a cNFT listing that can no longer sell (permissionless). Before",
+        "expiry the caller proves, with a merkle proof against `root`, that the",
+        "leaf at the listing's index is now `current_leaf` and not the listed one.",
+        "Rent goes to the seller."
+      ],
+      "discriminator": [
+        229,
+        127,
+        249,
+        166,
+        36,
+        89,
+        176,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.merkle_tree",
+                "account": "CompressedListing"
+              },
+              {
+                "kind": "account",
+                "path": "compressed_listing.leaf_nonce",
+                "account": "CompressedListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "merkle_tree"
+        },
+        {
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "current_leaf",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "close_stale_core_listing",
+      "docs": [
+        "Close a stale Core listing after ownership changed outside the program.",
+        "Allows the current holder to clear old state and re-list the asset."
+      ],
+      "discriminator": [
+        108,
+        204,
+        165,
+        119,
+        83,
+        128,
+        227,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Core auctions only: payment mint, bond escrow and seller payment account",
+            "the seller bond is returned through"
+          ],
+          "optional": true
+        },
+        {
+          "name": "core_bond",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token_program",
+          "optional": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_stale_delegated_listing",
+      "docs": [
+        "Close a delegate listing that can no longer sell (permissionless): the",
+        "NFT left the seller's token account, the approval was revoked or",
+        "replaced, or a fixed expiry passed. Rent goes to the seller. An expired",
+        "pNFT listing still holds its Sale delegate, which only the seller can",
+        "revoke, so it is closed with `cancel_delegated_listing_pnft` instead."
+      ],
+      "discriminator": [
+        171,
+        110,
+        248,
+        54,
+        122,
+        202,
+        85,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_listing.nft_mint",
+                "account": "DelegateListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account"
+        },
+        {
+          "name": "seller",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "close_stale_listing",
+      "docs": [
+        "Close a stale listing where escrow is empty (NFT already returned)",
+        "This allows re-listing the same NFT after a cancelled listing"
+      ],
+      "discriminator": [
+        120,
+        38,
+        229,
+        87,
+        16,
+        1,
+        54,
+        10
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "commit_sealed_bid",
+      "docs": [
+        "Commit a sealed bid during the bidding phase.",
+        "",
+        "`commitment` = sha256(amount_le || salt || bidder). The `deposit` (at least",
+        "the opening price) is escrowed in `bid_escrow` now; the rest of the bid is",
+        "topped up at reveal."
+      ],
+      "discriminator": [
+        177,
+        41,
+        85,
+        103,
+        15,
+        78,
+        36,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "sealed_bid",
+          "writable": true
+        },
+        {
+          "name": "bidder_token_account",
+          "writable": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "deposit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "create_bundle",
+      "docs": [
+        "Create an empty bundle (seller only). `duration_seconds` is the auction",
+        "length, or an optional fixed-price expiry; it starts counting at `open_bundle`."
+      ],
+      "discriminator": [
+        108,
+        43,
+        176,
+        128,
+        45,
+        94,
+        197,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "arg",
+                "path": "bundle_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "bundle_id",
+          "type": "u64"
+        },
+        {
+          "name": "listing_type",
+          "type": {
+            "defined": {
+              "name": "ListingType"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "bid_increment",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "fill_collection_offer",
+      "docs": [
+        "Sell a Core asset into a collection offer (asset owner only). As with",
+        "`accept_offer_core`, a fixed-price `core_listing` for the asset is closed",
+        "and the transfer is signed through its TransferDelegate. The last fill",
+        "closes the offer and its escrow, returning rent to the buyer."
+      ],
+      "discriminator": [
+        244,
+        149,
+        206,
+        235,
+        225,
+        223,
+        253,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collection"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.buyer",
+                "account": "CollectionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "collection_offer.attributes_hash",
+                "account": "CollectionOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "docs": [
+            "Current owner."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "docs": [
+            "Fixed-price listing for the asset, if any — closed by the sale"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller payment account — must be owned by the seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in handler against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fill_swap",
+      "docs": [
+        "Fill a swap with an asset the swap wants. The taker's asset goes to the",
+        "maker, the escrowed asset to the taker, and the top-up to whoever is",
+        "owed it, all in this instruction. `taker_kind` is the standard of the",
+        "taker's asset. remaining_accounts: see the section comment above."
+      ],
+      "discriminator": [
+        204,
+        165,
+        172,
+        86,
+        47,
+        94,
+        168,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap.offered_asset",
+                "account": "Swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "taker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "escrow_authority",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when the swap has a top-up"
+          ],
+          "optional": true
+        },
+        {
+          "name": "taker_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "token_metadata_program",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "sysvar_instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "ata_program",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "taker_kind",
+          "type": {
+            "defined": {
+              "name": "SwapAssetKind"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize_payment_mint_config",
+      "docs": [
+        "Register a payment mint (deploy authority only)"
+      ],
+      "discriminator": [
+        27,
+        122,
+        232,
+        56,
+        240,
+        82,
+        137,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "payment_mint_config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "settings",
+          "type": {
+            "defined": {
+              "name": "PaymentMintSettings"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize_treasury",
+      "docs": [
+        "Initialize the treasury config PDA (one-time setup by deploy authority)"
+      ],
+      "discriminator": [
+        124,
+        186,
+        211,
+        195,
+        85,
+        165,
+        129,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "treasury_config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "list_compressed_item",
+      "docs": [
+        "List a Bubblegum cNFT for a fixed price. `leaf` identifies the seller's",
+        "current leaf; the other arguments are as for `list_item_delegated`."
+      ],
+      "discriminator": [
+        41,
+        136,
+        160,
+        108,
+        114,
+        240,
+        167,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "compressed_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  114,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "merkle_tree"
+              },
+              {
+                "kind": "arg",
+                "path": "leaf.nonce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "previous_leaf_delegate"
+        },
+        {
+          "name": "tree_config"
+        },
+        {
+          "name": "merkle_tree",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "log_wrapper",
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "compression_program",
+          "address": "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+        },
+        {
+          "name": "bubblegum_program",
+          "address": "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "leaf",
+          "type": {
+            "defined": {
+              "name": "CompressedLeaf"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_core_auction",
+      "docs": [
+        "List a Metaplex Core asset for an English auction in a registered payment mint.",
+        "",
+        "Still non-custodial: besides the TransferDelegate, `core_authority` is made",
+        "FreezeDelegate so the asset can be frozen in the seller's wallet once bids",
+        "exist. Bids are escrowed in the `bid_escrow` PDA keyed by the asset.",
+        "",
+        "Freezing can't stop the seller revoking the TransferDelegate, so they post",
+        "a bond of `CORE_AUCTION_BOND_BPS` of the start price into `core_bond`. It is",
+        "returned when the auction settles, closes without bids or is cancelled, and",
+        "paid to the winner if the asset can't be delivered."
+      ],
+      "discriminator": [
+        118,
+        231,
+        157,
+        58,
+        7,
+        57,
+        163,
+        79
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "docs": [
+            "Current holder-signed; posts the seller bond."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint (validated in handler against its registry entry)."
+          ]
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_listing",
+          "docs": [
+            "CoreListing PDA — created on list, closed on settle/cancel."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_bond",
+          "docs": [
+            "Seller bond escrow — emptied and closed whenever the auction ends"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "start_price_usdc",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": "i64"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "bid_increment",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_core_item",
+      "docs": [
+        "List a Metaplex Core asset for fixed-price sale in a registered payment mint.",
+        "`start_time` schedules the listing to open later; the delegate is approved now.",
+        "`duration_seconds` optionally expires the listing that long after it opens;",
+        "anyone can then revoke the delegate with `close_expired_core_listing`."
+      ],
+      "discriminator": [
+        249,
+        213,
+        18,
+        100,
+        230,
+        110,
+        232,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "docs": [
+            "Current holder-signed."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint (validated in handler against its registry entry)."
+          ]
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_listing",
+          "docs": [
+            "CoreListing PDA — created on list, closed on cancel/buy."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "price_usdc",
+          "type": "u64"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item",
+      "docs": [
+        "List an item for sale (fixed price, English, Dutch or sealed-bid auction)",
+        "",
+        "For WNS/Token-2022 NFTs the program makes the WNS `approve_transfer` CPI",
+        "itself (amount 0 here; the sale price in `buy_now` / `settle_auction`).",
+        "remaining_accounts for WNS NFTs, validated in this order:",
+        "[0] wns_program (readonly)",
+        "[1] extra_metas_account PDA (readonly) - seeds: [\"extra-account-metas\", nft_mint]",
+        "[2] approve_account PDA (writable) - seeds: [\"approve-account\", nft_mint]",
+        "[3] wns_distribution_program (readonly)",
+        "[4] distribution_account PDA (writable) - seeds: [group_mint, payment_mint]",
+        "under the distribution program, where payment_mint is the system",
+        "program except on sales, which use the listing's payment mint",
+        "[5] distribution_token_account (writable) - distribution_account's ATA for",
+        "the payment mint on sales, else wns_program",
+        "[6] system_program (readonly)",
+        "[7] member account PDA (readonly) - seeds: [\"member\", nft_mint] under WNS",
+        "[8] group account (readonly) - the member's group; its mint is the",
+        "group_mint of [4]",
+        "Other Token-2022 transfer hooks: the hook program, its extra_metas_account",
+        "PDA and the accounts that PDA lists, in any order.",
+        "",
+        "`options` carries the optional auction settings; see `ListingOptions`.",
+        "Fixed-price listings expire after `duration_seconds` if given; anyone can",
+        "then return the NFT to the seller with `close_expired_listing`."
+      ],
+      "discriminator": [
+        174,
+        245,
+        22,
+        211,
+        228,
+        103,
+        121,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "listing_type",
+          "type": {
+            "defined": {
+              "name": "ListingType"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": {
+              "name": "ListingOptions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item_delegated",
+      "docs": [
+        "List an SPL / Token-2022 NFT without escrow by approving the listing PDA",
+        "as delegate. `royalty_basis_points` and `creator_address` are as for",
+        "`list_item`; `start_time` and `duration_seconds` as for `list_core_item`."
+      ],
+      "discriminator": [
+        200,
+        53,
+        32,
+        62,
+        108,
+        102,
+        232,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "seller_nft_account",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item_pnft",
+      "docs": [
+        "List a pNFT (Metaplex programmable NFT) for sale.",
+        "Uses Token Metadata TransferV1 CPI with delegate + token_record."
+      ],
+      "discriminator": [
+        236,
+        179,
+        101,
+        29,
+        212,
+        149,
+        190,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA — owns the escrow token account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "docs": [
+            "Escrow token account — created by Token Metadata via ATA"
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "docs": [
+            "Escrow token record (pNFT programmable config)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "listing_type",
+          "type": {
+            "defined": {
+              "name": "ListingType"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": {
+              "name": "ListingOptions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "list_item_pnft_delegated",
+      "docs": [
+        "List a pNFT without escrow. The listing PDA becomes the pNFT's Token",
+        "Metadata Sale delegate, which locks it in the seller's wallet (token",
+        "record `Listed`, account frozen) until it is bought or the listing is",
+        "cancelled. Arguments as for `list_item_delegated`."
+      ],
+      "discriminator": [
+        142,
+        158,
+        181,
+        133,
+        236,
+        30,
+        167,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "delegate_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "seller_nft_token",
+          "writable": true
+        },
+        {
+          "name": "seller_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "ItemCategory"
+            }
+          }
+        },
+        {
+          "name": "royalty_basis_points",
+          "type": "u16"
+        },
+        {
+          "name": "creator_address",
+          "type": "pubkey"
+        },
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "duration_seconds",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_collection_offer",
+      "docs": [
+        "Bid on any asset in a Core collection: escrow `price * quantity` for up",
+        "to `quantity` fills at `price` each, open until `expires_at`.",
+        "",
+        "`attributes` narrows the offer to assets whose Attributes plugin carries",
+        "every listed key/value (e.g. grader PSA, grade 10). A buyer holds one",
+        "offer per collection and predicate set."
+      ],
+      "discriminator": [
+        134,
+        26,
+        61,
+        72,
+        238,
+        88,
+        160,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "collection_offer",
+          "writable": true
+        },
+        {
+          "name": "collection"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection_offer_escrow",
+          "writable": true
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "quantity",
+          "type": "u32"
+        },
+        {
+          "name": "expires_at",
+          "type": "i64"
+        },
+        {
+          "name": "attributes",
+          "type": {
+            "vec": {
               "defined": {
-                "name": "ListingStatus"
+                "name": "AttributePredicate"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_offer",
+      "docs": [
+        "Make an offer on an NFT mint or Core asset, escrowing `amount` until",
+        "`expires_at`."
+      ],
+      "discriminator": [
+        214,
+        98,
+        97,
+        35,
+        59,
+        12,
+        44,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "asset"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "expires_at",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "make_swap",
+      "docs": [
+        "Make a swap offering an escrowed SPL / Token-2022 NFT.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        53,
+        60,
+        229,
+        243,
+        157,
+        94,
+        98,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "swap_escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker_nft_account",
+          "writable": true
+        },
+        {
+          "name": "maker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when `terms.top_up > 0`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "docs": [
+            "Source of a maker-paid top-up"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "docs": [
+            "Escrow for a maker-paid top-up — pass only when the maker pays"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "terms",
+          "type": {
+            "defined": {
+              "name": "SwapTerms"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_swap_core",
+      "docs": [
+        "Make a swap offering a Metaplex Core asset. Like a bundle item, the asset",
+        "stays in the maker's wallet, delegated to its `core_authority` PDA and",
+        "frozen until the swap is filled or cancelled."
+      ],
+      "discriminator": [
+        44,
+        178,
+        176,
+        37,
+        166,
+        110,
+        163,
+        109
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when `terms.top_up > 0`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "docs": [
+            "Source of a maker-paid top-up"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "docs": [
+            "Escrow for a maker-paid top-up — pass only when the maker pays"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "terms",
+          "type": {
+            "defined": {
+              "name": "SwapTerms"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "make_swap_pnft",
+      "docs": [
+        "Make a swap offering a pNFT, escrowed via Token Metadata TransferV1."
+      ],
+      "discriminator": [
+        248,
+        179,
+        143,
+        213,
+        65,
+        106,
+        95,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "swap",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "maker_nft_token",
+          "writable": true
+        },
+        {
+          "name": "maker_token_record",
+          "writable": true
+        },
+        {
+          "name": "swap_authority",
+          "docs": [
+            "Swap authority PDA — owns the escrow token account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "maker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Top-up payment mint — required when `terms.top_up > 0`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "optional": true
+        },
+        {
+          "name": "maker_payment_account",
+          "docs": [
+            "Source of a maker-paid top-up"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "swap_payment_escrow",
+          "docs": [
+            "Escrow for a maker-paid top-up — pass only when the maker pays"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "swap"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "terms",
+          "type": {
+            "defined": {
+              "name": "SwapTerms"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrate_core_listing",
+      "docs": [
+        "Grow a `CoreListing` created under the legacy layout to the current one",
+        "(permissionless; `payer` funds the extra rent). The zeroed fields read",
+        "as an open-ended fixed-price listing without a bond, as before."
+      ],
+      "discriminator": [
+        134,
+        203,
+        151,
+        65,
+        163,
+        185,
+        50,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "docs": [
+            "validated in instruction body."
+          ],
+          "writable": true
+        },
+        {
+          "name": "leader_refund",
+          "docs": [
+            "high bidder's instance-0 `BidRefund` PDA, created in instruction body."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrate_listing",
+      "docs": [
+        "Grow a `Listing` created under the legacy layout to the current one",
+        "(permissionless; `payer` funds the extra rent). The appended fields",
+        "keep it behaving as before: no soft close, reserve, buy-now or sealed",
+        "bidding, the legacy 0.1 SOL bid step, and its existing bid escrow and",
+        "refunds (instance 0). A listing with bids also needs `leader_refund`,",
+        "which is created for the standing high bidder."
+      ],
+      "discriminator": [
+        202,
+        10,
+        210,
+        83,
+        249,
+        147,
+        116,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "docs": [
+            "validated in instruction body."
+          ],
+          "writable": true
+        },
+        {
+          "name": "leader_refund",
+          "docs": [
+            "high bidder's instance-0 `BidRefund` PDA, created in instruction body."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "open_bundle",
+      "docs": [
+        "Open a bundle for sale (seller only); no items can be added afterwards.",
+        "`start_time` schedules the sale or auction to begin later."
+      ],
+      "discriminator": [
+        218,
+        44,
+        109,
+        97,
+        229,
+        52,
+        181,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.payment_mint",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "start_time",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "place_bid",
+      "docs": [
+        "Place a bid on an active auction (payment tokens only, no NFT transfer)",
+        "",
+        "`max_amount` turns the bid into a proxy bid: the bidder escrows up to",
+        "`max_amount` and the program raises the visible `current_bid` only as far",
+        "as needed to stay on top of competing bids. A current leader may call this",
+        "again to raise their maximum without moving the visible price."
+      ],
+      "discriminator": [
+        238,
+        77,
+        148,
+        91,
+        200,
+        151,
+        92,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "bidder_token_account",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record — must exist while they lead so an outbid",
+            "can always be credited"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required when outbidding them"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "max_amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "place_bid_sol",
+      "docs": [
+        "Native-SOL `place_bid`: the bid is escrowed as lamports in `sol_escrow`,",
+        "so bidders need no wSOL account. Same proxy and refund rules."
+      ],
+      "discriminator": [
+        222,
+        80,
+        152,
+        132,
+        103,
+        254,
+        222,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "sol_escrow",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record (see `PlaceBid`)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required when outbidding them"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "max_amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "place_bundle_bid",
+      "docs": [
+        "Bid on a bundle auction. Outbid funds are credited to the previous",
+        "leader's `BidRefund` (keyed by the bundle instance) and claimed via",
+        "`claim_refund`."
+      ],
+      "discriminator": [
+        205,
+        209,
+        39,
+        41,
+        66,
+        195,
+        184,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — keyed by the bundle instance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record (see `PlaceBid`)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required once a bid exists"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "place_core_bid",
+      "docs": [
+        "Bid on a Core auction. The previous high bid is credited to its `BidRefund`;",
+        "the first bid freezes the asset in the seller's wallet until settlement."
+      ],
+      "discriminator": [
+        179,
+        185,
+        84,
+        14,
+        29,
+        69,
+        240,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — keyed by the asset, same seeds as SPL auctions"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bidder_payment_account",
+          "writable": true
+        },
+        {
+          "name": "bidder_refund",
+          "docs": [
+            "Bidder's own refund record (see `PlaceBid`)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "previous_bidder_refund",
+          "docs": [
+            "Previous high bidder's refund record — required once a bid exists"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "reveal_reserve",
+      "docs": [
+        "Reveal a hidden reserve price (seller only).",
+        "",
+        "Settlement waits for the reveal until `RESERVE_REVEAL_WINDOW_SECONDS`",
+        "after the auction ends; a reserve still hidden by then counts as met and",
+        "the high bid wins, so the reveal is rejected from then on. The revealed",
+        "reserve can't be below the start price."
+      ],
+      "discriminator": [
+        35,
+        70,
+        113,
+        147,
+        101,
+        240,
+        175,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "seller",
+          "signer": true,
+          "relations": [
+            "listing"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reserve_price",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reveal_sealed_bid",
+      "docs": [
+        "Reveal a sealed bid during the reveal window.",
+        "",
+        "The bid must match the commitment. If it exceeds the deposit, the",
+        "difference is escrowed now so the winning bid is always fully funded.",
+        "Bids below the opening price are recorded as revealed but not ranked."
+      ],
+      "discriminator": [
+        204,
+        145,
+        248,
+        15,
+        228,
+        127,
+        75,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "sealed_bid",
+          "writable": true
+        },
+        {
+          "name": "bidder_token_account",
+          "writable": true
+        },
+        {
+          "name": "bidder",
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "settle_auction",
+      "docs": [
+        "Settle an auction after end time",
+        "",
+        "WNS NFTs are approved by CPI with `bid_escrow` as authority: for the",
+        "clearing price on a sale (WNS royalty paid from escrow, as in `buy_now`),",
+        "else 0. The layout's payment_mint is always the listing's payment mint.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        246,
+        196,
+        183,
+        98,
+        222,
+        139,
+        46,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller's payment token account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "docs": [
+            "Buyer NFT account — must be owned by highest bidder (or seller if no bids for return)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller NFT account — must be owned by listing.seller (for no-bid return)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required for English auctions with bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "winner_sealed_bid",
+          "docs": [
+            "Winner's sealed bid record — required for sealed auctions with a winner"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settle_auction_pnft",
+      "docs": [
+        "Settle an ended pNFT auction (English or sealed-bid).",
+        "",
+        "Mirrors `settle_auction`: on a sale the winner receives the pNFT and any",
+        "unused escrow is credited back; otherwise the high bid is credited back and",
+        "the pNFT returns to the seller. `destination_owner` must be the winner or seller accordingly.",
+        "Permissionless — `payer` funds the destination token record if needed."
+      ],
+      "discriminator": [
+        56,
+        33,
+        21,
+        144,
+        121,
+        144,
+        169,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
               }
-            }
-          },
-          {
-            "name": "escrow_nft_account",
-            "type": "pubkey"
-          },
-          {
-            "name": "current_bid",
-            "type": "u64"
-          },
-          {
-            "name": "highest_bidder",
-            "type": "pubkey"
-          },
-          {
-            "name": "baxus_fee",
-            "type": "bool"
-          },
-          {
-            "name": "is_token2022",
-            "type": "bool"
-          },
-          {
-            "name": "is_pnft",
-            "type": "bool"
-          },
-          {
-            "name": "royalty_basis_points",
-            "type": "u16"
-          },
-          {
-            "name": "creator_address",
-            "type": "pubkey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+            ]
+          }
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "nft_metadata",
+          "writable": true
+        },
+        {
+          "name": "nft_edition"
+        },
+        {
+          "name": "escrow_authority",
+          "docs": [
+            "Escrow authority PDA"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nft_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow_nft_token",
+          "writable": true
+        },
+        {
+          "name": "escrow_token_record",
+          "writable": true
+        },
+        {
+          "name": "destination_owner"
+        },
+        {
+          "name": "destination_nft_token",
+          "writable": true
+        },
+        {
+          "name": "destination_token_record",
+          "writable": true
+        },
+        {
+          "name": "payment_mint",
+          "docs": [
+            "Payment mint — must match the listing's payment mint"
+          ]
+        },
+        {
+          "name": "bid_escrow",
+          "writable": true
+        },
+        {
+          "name": "seller_payment_account",
+          "docs": [
+            "Seller's payment token account — must be owned by listing.seller"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "docs": [
+            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+          ],
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required for English auctions with bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "winner_sealed_bid",
+          "docs": [
+            "Winner's sealed bid record — required for sealed auctions with a winner"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for the destination token account / token record if missing"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_metadata_program"
+        },
+        {
+          "name": "token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "payment_token_program",
+          "docs": [
+            "Token program for the payment legs (SPL Token or Token-2022)"
+          ]
+        },
+        {
+          "name": "ata_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sysvar_instructions"
+        },
+        {
+          "name": "authorization_rules_program",
+          "optional": true
+        },
+        {
+          "name": "authorization_rules",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settle_auction_sol",
+      "docs": [
+        "Settle an ended native-SOL auction. Same outcomes as `settle_auction`, but",
+        "proceeds are paid in lamports from `sol_escrow` to the seller, treasury and",
+        "creator wallets.",
+        "",
+        "WNS NFTs: on a sale `payer` must sign; it pays WNS's royalty when approving",
+        "the move for the clearing price and is reimbursed from `sol_escrow`.",
+        "Otherwise `escrow_nft` approves it for 0.",
+        "remaining_accounts: same layout as list_item"
+      ],
+      "discriminator": [
+        161,
+        102,
+        218,
+        55,
+        232,
+        160,
+        236,
+        20
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "nft_mint"
+        },
+        {
+          "name": "sol_escrow",
+          "docs": [
+            "Lamport bid escrow — absent when the auction drew no bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "escrow_nft",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "ListingCancelled",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nft_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "seller",
-            "type": "pubkey"
+        },
+        {
+          "name": "creator",
+          "writable": true
+        },
+        {
+          "name": "buyer_nft_account",
+          "docs": [
+            "Buyer NFT account — must be owned by highest bidder"
+          ],
+          "writable": true
+        },
+        {
+          "name": "seller_nft_account",
+          "docs": [
+            "Seller NFT account — must be owned by listing.seller (for no-bid return)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "highest_bidder_refund",
+          "docs": [
+            "High bidder's refund record — required when there are bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "docs": [
+            "Treasury config PDA — if present, overrides hardcoded treasury address"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Approves a WNS sale, funded from sol_escrow — required for WNS NFTs sold"
+          ],
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "nft_token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "ListingCreated",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nft_mint",
-            "type": "pubkey"
-          },
-          {
-            "name": "seller",
-            "type": "pubkey"
-          },
-          {
-            "name": "listing_type",
-            "type": {
-              "defined": {
-                "name": "ListingType"
-              }
-            }
-          },
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "category",
-            "type": {
-              "defined": {
-                "name": "ItemCategory"
+      "name": "settle_bundle",
+      "docs": [
+        "Settle a bundle auction after it ends (permissionless). With a winning",
+        "bid every item goes to the winner and the bid is split per item;",
+        "without bids, or if a Core item can no longer be delivered, the items",
+        "return to the seller (and the winner's bid is credited back in full).",
+        "remaining_accounts: see the section comment above."
+      ],
+      "discriminator": [
+        194,
+        20,
+        148,
+        194,
+        6,
+        70,
+        117,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may settle; pays any Core CPI storage fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.seller",
+                "account": "Bundle"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.bundle_id",
+                "account": "Bundle"
               }
-            }
-          },
-          {
-            "name": "end_time",
-            "type": "i64"
-          },
-          {
-            "name": "payment_mint",
-            "type": "pubkey"
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "winner"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — absent when the auction drew no bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "writable": true
+        },
+        {
+          "name": "winner_bid_refund",
+          "docs": [
+            "Winner's refund record — required only if the bundle can't be delivered"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "collection",
+          "writable": true,
+          "optional": true,
+          "address": "jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS"
+        },
+        {
+          "name": "nft_token_program",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "nft_token_2022_program",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "ListingStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Active"
-          },
-          {
-            "name": "Settled"
-          },
-          {
-            "name": "Cancelled"
+      "name": "settle_core_auction",
+      "docs": [
+        "Settle an ended Core auction. Permissionless.",
+        "",
+        "With a winning bid the asset is thawed and transferred to the winner by",
+        "`core_authority`, and the escrow is split like `buy_now_core` (Artifacte",
+        "collection fee waived); the seller bond goes back to the seller. If the",
+        "seller no longer holds the asset or the delegate was revoked, the winner's",
+        "bid plus the seller bond is credited to the winner instead. With no bids",
+        "the bond is returned and the listing closes; the seller can re-list or",
+        "revoke delegates later."
+      ],
+      "discriminator": [
+        42,
+        130,
+        42,
+        240,
+        230,
+        123,
+        202,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may settle; pays any Core CPI storage fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "collection",
+          "writable": true
+        },
+        {
+          "name": "core_listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "ListingType",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "FixedPrice"
-          },
-          {
-            "name": "Auction"
+        },
+        {
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "winner"
+        },
+        {
+          "name": "core_authority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "TreasuryConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "treasury",
-            "type": "pubkey"
-          },
-          {
-            "name": "authority",
-            "type": "pubkey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+        },
+        {
+          "name": "payment_mint"
+        },
+        {
+          "name": "bid_escrow",
+          "docs": [
+            "Bid escrow PDA — absent when the auction drew no bids"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "core_bond",
+          "docs": [
+            "Seller bond escrow — absent only for listings that posted no bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  114,
+                  101,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "TreasuryUpdated",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "old_treasury",
-            "type": "pubkey"
-          },
-          {
-            "name": "new_treasury",
-            "type": "pubkey"
-          },
-          {
-            "name": "timestamp",
-            "type": "i64"
+        },
+        {
+          "name": "seller_payment_account",
+          "writable": true
+        },
+        {
+          "name": "treasury_payment_account",
+          "writable": true
+        },
+        {
+          "name": "creator_payment_account",
+          "writable": true
+        },
+        {
+          "name": "winner_bid_refund",
+          "docs": [
+            "Winner's refund record — required only if the asset can't be delivered"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasury_config",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
-        ]
-      }
-    }
-  ]
-};
-
-export const IDL: Auction = {
-  "address": "81s1tEx4MPdVvqS6X84Mok5K4N5fMbRLzcsT5eo2K8J3",
-  "metadata": {
-    "name": "auction",
-    "version": "0.1.0",
-    "spec": "0.1.0"
-  },
-  "instructions": [
+        },
+        {
+          "name": "mpl_core_program",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
     {
-      "name": "buy_now",
+      "name": "update_core_listing",
       "docs": [
-        "Buy a fixed-price listing immediately",
-        "",
-        "For WNS/Token-2022 NFTs: client MUST include WNS `approve_transfer` IX",
-        "(amount=0) BEFORE this instruction in the same transaction.",
-        "remaining_accounts: same layout as list_item"
+        "Reprice an active Core listing in place (seller only), keeping the",
+        "delegate approvals. Core auctions can only be repriced before the first",
+        "bid. `end_time` (fixed price only) sets a new expiry, 0 for none.",
+        "`None` leaves a field unchanged."
       ],
       "discriminator": [
-        242,
-        42,
-        184,
-        77,
-        133,
-        152,
-        118,
-        204
+        223,
+        19,
+        233,
+        154,
+        61,
+        87,
+        244,
+        114
       ],
       "accounts": [
         {
-          "name": "listing",
-          "writable": true
-        },
-        {
-          "name": "nft_mint"
+          "name": "seller",
+          "signer": true
         },
         {
-          "name": "escrow_nft",
+          "name": "core_listing",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  111,
+                  114,
                   101,
+                  95,
+                  108,
+                  105,
                   115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "core_listing.asset",
+                "account": "CoreListing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payment_mint_config",
+          "docs": [
+            "Registry entry for the listing's payment mint — validated in handler"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
                   99,
-                  114,
                   111,
-                  119,
-                  95,
                   110,
                   102,
-                  116
+                  105,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
+                "path": "core_listing.payment_mint",
+                "account": "CoreListing"
               }
             ]
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "buyer_payment_account",
-          "writable": true
+          "name": "price_usdc",
+          "type": {
+            "option": "u64"
+          }
         },
         {
-          "name": "seller_payment_account",
-          "docs": [
-            "Seller payment account — must be owned by listing.seller"
-          ],
-          "writable": true
+          "name": "end_time",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "update_listing",
+      "docs": [
+        "Reprice an active listing in place (seller only), leaving the NFT in escrow.",
+        "`price` is the fixed, Dutch starting or auction opening price; auctions",
+        "can only be repriced before the first bid. `end_time` (fixed price only)",
+        "sets a new expiry, 0 for none. `None` leaves a field unchanged."
+      ],
+      "discriminator": [
+        192,
+        174,
+        210,
+        68,
+        116,
+        40,
+        242,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.nft_mint",
+                "account": "Listing"
+              }
+            ]
+          }
         },
         {
-          "name": "treasury_payment_account",
+          "name": "payment_mint_config",
           "docs": [
-            "Treasury payment account — validated in instruction body against treasury_config or fallback"
+            "Registry entry for the listing's payment mint — validated in handler"
           ],
-          "writable": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.payment_mint",
+                "account": "Listing"
+              }
+            ]
+          }
         },
         {
-          "name": "creator_payment_account",
-          "writable": true
-        },
+          "name": "seller",
+          "signer": true
+        }
+      ],
+      "args": [
         {
-          "name": "buyer_nft_account",
-          "writable": true
+          "name": "price",
+          "type": {
+            "option": "u64"
+          }
         },
         {
-          "name": "buyer",
+          "name": "end_time",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "update_payment_mint_config",
+      "docs": [
+        "Enable, retire or retune a payment mint (deploy authority only)"
+      ],
+      "discriminator": [
+        175,
+        140,
+        188,
+        89,
+        121,
+        180,
+        177,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "payment_mint_config",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payment_mint_config.mint",
+                "account": "PaymentMintConfig"
+              }
+            ]
+          }
         },
         {
-          "name": "treasury",
-          "writable": true
-        },
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "settings",
+          "type": {
+            "defined": {
+              "name": "PaymentMintSettings"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "update_treasury",
+      "docs": [
+        "Update treasury address (deploy authority only)"
+      ],
+      "discriminator": [
+        60,
+        16,
+        243,
+        66,
+        96,
+        59,
+        254,
+        131
+      ],
+      "accounts": [
         {
           "name": "treasury_config",
-          "docs": [
-            "Treasury config PDA — if present, overrides hardcoded treasury address"
-          ],
-          "optional": true,
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2449,1746 +22658,3121 @@ export const IDL: Auction = {
           }
         },
         {
-          "name": "nft_token_program"
-        },
-        {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
+          "name": "authority",
+          "signer": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "new_treasury",
+          "type": "pubkey"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "BidRefund",
+      "discriminator": [
+        247,
+        16,
+        39,
+        213,
+        53,
+        112,
+        130,
+        7
+      ]
+    },
+    {
+      "name": "Bundle",
+      "discriminator": [
+        15,
+        82,
+        167,
+        230,
+        37,
+        214,
+        82,
+        80
+      ]
+    },
+    {
+      "name": "CollectionOffer",
+      "discriminator": [
+        164,
+        66,
+        143,
+        81,
+        85,
+        0,
+        239,
+        213
+      ]
+    },
+    {
+      "name": "CompressedListing",
+      "discriminator": [
+        164,
+        96,
+        88,
+        33,
+        255,
+        76,
+        15,
+        149
+      ]
+    },
+    {
+      "name": "CoreListing",
+      "discriminator": [
+        205,
+        178,
+        162,
+        169,
+        199,
+        166,
+        133,
+        157
+      ]
+    },
+    {
+      "name": "DelegateListing",
+      "discriminator": [
+        244,
+        216,
+        109,
+        161,
+        18,
+        187,
+        54,
+        14
+      ]
+    },
+    {
+      "name": "Listing",
+      "discriminator": [
+        218,
+        32,
+        50,
+        73,
+        43,
+        134,
+        26,
+        58
+      ]
+    },
+    {
+      "name": "Offer",
+      "discriminator": [
+        215,
+        88,
+        60,
+        71,
+        170,
+        162,
+        73,
+        229
+      ]
+    },
+    {
+      "name": "PaymentMintConfig",
+      "discriminator": [
+        94,
+        123,
+        251,
+        40,
+        76,
+        70,
+        180,
+        31
+      ]
+    },
+    {
+      "name": "SealedBid",
+      "discriminator": [
+        199,
+        9,
+        212,
+        151,
+        48,
+        136,
+        163,
+        226
+      ]
+    },
+    {
+      "name": "SolEscrow",
+      "discriminator": [
+        75,
+        199,
+        250,
+        63,
+        244,
+        209,
+        235,
+        120
+      ]
+    },
+    {
+      "name": "Swap",
+      "discriminator": [
+        53,
+        206,
+        146,
+        152,
+        44,
+        97,
+        120,
+        177
+      ]
+    },
+    {
+      "name": "TreasuryConfig",
+      "discriminator": [
+        124,
+        54,
+        212,
+        227,
+        213,
+        189,
+        168,
+        41
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "AuctionCancelled",
+      "discriminator": [
+        22,
+        32,
+        51,
+        83,
+        215,
+        194,
+        171,
+        209
+      ]
+    },
+    {
+      "name": "AuctionSettled",
+      "discriminator": [
+        61,
+        151,
+        131,
+        170,
+        95,
+        203,
+        219,
+        147
+      ]
+    },
+    {
+      "name": "BidPlaced",
+      "discriminator": [
+        135,
+        53,
+        176,
+        83,
+        193,
+        69,
+        108,
+        61
+      ]
+    },
+    {
+      "name": "BidRefundCredited",
+      "discriminator": [
+        87,
+        240,
+        146,
+        63,
+        191,
+        163,
+        59,
+        238
+      ]
+    },
+    {
+      "name": "BundleCancelled",
+      "discriminator": [
+        59,
+        27,
+        168,
+        222,
+        187,
+        229,
+        238,
+        0
+      ]
+    },
+    {
+      "name": "BundleCreated",
+      "discriminator": [
+        248,
+        105,
+        64,
+        162,
+        34,
+        23,
+        35,
+        139
+      ]
+    },
+    {
+      "name": "BundleItemAdded",
+      "discriminator": [
+        202,
+        149,
+        207,
+        86,
+        211,
+        97,
+        22,
+        57
+      ]
+    },
+    {
+      "name": "BundleOpened",
+      "discriminator": [
+        101,
+        110,
+        89,
+        61,
+        9,
+        38,
+        160,
+        49
+      ]
+    },
+    {
+      "name": "BundleSold",
+      "discriminator": [
+        142,
+        217,
+        207,
+        169,
+        2,
+        28,
+        171,
+        103
+      ]
+    },
+    {
+      "name": "CollectionOfferCancelled",
+      "discriminator": [
+        119,
+        119,
+        71,
+        140,
+        61,
+        127,
+        161,
+        166
+      ]
+    },
+    {
+      "name": "CollectionOfferFilled",
+      "discriminator": [
+        147,
+        203,
+        225,
+        183,
+        51,
+        101,
+        190,
+        235
+      ]
     },
     {
-      "name": "buy_now_core",
-      "docs": [
-        "Public buy of a Core listing. Artifacte collection buys waive the",
-        "platform fee and only split USDC between creator royalty and seller.",
-        "Then CPI TransferV1 moves the asset to the buyer."
-      ],
+      "name": "CollectionOfferMade",
       "discriminator": [
-        107,
+        76,
+        4,
+        212,
+        144,
+        22,
+        173,
+        236,
+        152
+      ]
+    },
+    {
+      "name": "CoreListingCancelled",
+      "discriminator": [
+        165,
+        96,
+        88,
+        242,
+        37,
+        224,
+        205,
+        57
+      ]
+    },
+    {
+      "name": "CoreListingCreated",
+      "discriminator": [
+        184,
+        108,
+        121,
+        191,
+        52,
+        251,
+        30,
+        84
+      ]
+    },
+    {
+      "name": "CorePurchased",
+      "discriminator": [
+        221,
+        82,
+        110,
+        196,
         235,
-        190,
-        104,
-        232,
-        171,
-        241,
-        145
-      ],
-      "accounts": [
-        {
-          "name": "buyer",
-          "docs": [
-            "Public buyer."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "asset",
-          "writable": true
-        },
-        {
-          "name": "collection",
-          "writable": true
-        },
-        {
-          "name": "core_listing",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "seller",
-          "writable": true
-        },
-        {
-          "name": "core_authority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "payment_mint",
-          "docs": [
-            "USDC mint (validated in handler)."
-          ]
-        },
-        {
-          "name": "buyer_payment_account",
-          "writable": true
-        },
-        {
-          "name": "seller_payment_account",
-          "writable": true
-        },
-        {
-          "name": "treasury_payment_account",
-          "writable": true
-        },
-        {
-          "name": "creator_payment_account",
-          "writable": true
-        },
-        {
-          "name": "treasury",
-          "writable": true
-        },
-        {
-          "name": "treasury_config",
-          "optional": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  116,
-                  114,
-                  101,
-                  97,
-                  115,
-                  117,
-                  114,
-                  121,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "mpl_core_program",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
-        },
-        {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
+        58,
+        114,
+        17
+      ]
+    },
+    {
+      "name": "ItemPurchased",
+      "discriminator": [
+        33,
+        219,
+        12,
+        58,
+        205,
+        48,
+        63,
+        143
+      ]
+    },
+    {
+      "name": "ListingCancelled",
+      "discriminator": [
+        11,
+        46,
+        163,
+        10,
+        103,
+        80,
+        139,
+        194
+      ]
+    },
+    {
+      "name": "ListingCreated",
+      "discriminator": [
+        94,
+        164,
+        167,
+        255,
+        246,
+        186,
+        12,
+        96
+      ]
+    },
+    {
+      "name": "ListingExpired",
+      "discriminator": [
+        86,
+        77,
+        98,
+        166,
+        213,
+        159,
+        72,
+        61
+      ]
     },
     {
-      "name": "cancel_core_listing",
-      "docs": [
-        "Cancel an active Core listing while the seller still holds the asset."
-      ],
+      "name": "ListingUpdated",
+      "discriminator": [
+        190,
+        215,
+        199,
+        138,
+        255,
+        248,
+        98,
+        62
+      ]
+    },
+    {
+      "name": "OfferAccepted",
       "discriminator": [
-        58,
-        30,
         81,
-        251,
-        75,
-        37,
+        238,
+        238,
+        115,
+        140,
+        18,
+        8,
+        20
+      ]
+    },
+    {
+      "name": "OfferCancelled",
+      "discriminator": [
+        45,
+        42,
+        175,
+        214,
+        51,
+        192,
+        154,
+        9
+      ]
+    },
+    {
+      "name": "OfferMade",
+      "discriminator": [
+        11,
+        5,
+        86,
+        210,
+        3,
+        4,
+        25,
+        154
+      ]
+    },
+    {
+      "name": "PaymentMintConfigUpdated",
+      "discriminator": [
+        240,
+        22,
+        24,
+        218,
+        0,
+        194,
+        141,
+        250
+      ]
+    },
+    {
+      "name": "RefundClaimed",
+      "discriminator": [
+        136,
+        64,
+        242,
+        99,
+        4,
+        244,
+        208,
+        130
+      ]
+    },
+    {
+      "name": "ReserveRevealed",
+      "discriminator": [
+        231,
+        30,
+        8,
+        215,
         173,
-        211
-      ],
-      "accounts": [
-        {
-          "name": "seller",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "asset",
-          "writable": true
-        },
-        {
-          "name": "collection",
-          "writable": true
-        },
-        {
-          "name": "core_listing",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "core_authority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "mpl_core_program",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
+        191,
+        233,
+        251
+      ]
+    },
+    {
+      "name": "SealedBidClaimed",
+      "discriminator": [
+        54,
+        148,
+        88,
+        192,
+        108,
+        253,
+        121,
+        48
+      ]
+    },
+    {
+      "name": "SealedBidCommitted",
+      "discriminator": [
+        71,
+        9,
+        215,
+        14,
+        106,
+        238,
+        98,
+        181
+      ]
+    },
+    {
+      "name": "SealedBidRevealed",
+      "discriminator": [
+        206,
+        13,
+        74,
+        50,
+        124,
+        191,
+        46,
+        166
+      ]
+    },
+    {
+      "name": "SellerBondForfeited",
+      "discriminator": [
+        253,
+        113,
+        119,
+        98,
+        16,
+        157,
+        27,
+        203
+      ]
+    },
+    {
+      "name": "SwapCancelled",
+      "discriminator": [
+        210,
+        232,
+        53,
+        121,
+        126,
+        236,
+        66,
+        142
+      ]
+    },
+    {
+      "name": "SwapCreated",
+      "discriminator": [
+        95,
+        3,
+        86,
+        52,
+        73,
+        22,
+        116,
+        203
+      ]
+    },
+    {
+      "name": "SwapFilled",
+      "discriminator": [
+        26,
+        35,
+        81,
+        151,
+        223,
+        241,
+        23,
+        44
+      ]
+    },
+    {
+      "name": "TreasuryUpdated",
+      "discriminator": [
+        80,
+        239,
+        54,
+        168,
+        43,
+        38,
+        85,
+        145
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ListingNotActive",
+      "msg": "Listing is not active"
+    },
+    {
+      "code": 6001,
+      "name": "AuctionEnded",
+      "msg": "Auction has already ended"
+    },
+    {
+      "code": 6002,
+      "name": "AuctionNotEnded",
+      "msg": "Auction has not ended yet"
+    },
+    {
+      "code": 6003,
+      "name": "BidTooLow",
+      "msg": "Bid is below the required minimum"
+    },
+    {
+      "code": 6004,
+      "name": "CalculationError",
+      "msg": "Calculation error"
+    },
+    {
+      "code": 6005,
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6006,
+      "name": "CannotCancelWithBids",
+      "msg": "Cannot cancel auction with existing bids"
+    },
+    {
+      "code": 6007,
+      "name": "NotAnAuction",
+      "msg": "Invalid listing type for this operation"
+    },
+    {
+      "code": 6008,
+      "name": "NotFixedPrice",
+      "msg": "Invalid listing type for this operation"
     },
     {
-      "name": "cancel_listing",
-      "docs": [
-        "Cancel a listing (seller only, auctions only if no bids)",
-        "",
-        "For WNS/Token-2022: client MUST include WNS `approve_transfer` (amount=0)",
-        "remaining_accounts: same layout as list_item"
-      ],
-      "discriminator": [
-        41,
-        183,
-        50,
-        232,
-        230,
-        233,
-        157,
-        70
-      ],
-      "accounts": [
-        {
-          "name": "listing",
-          "writable": true
-        },
-        {
-          "name": "nft_mint"
-        },
-        {
-          "name": "escrow_nft",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  110,
-                  102,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
-              }
-            ]
-          }
-        },
-        {
-          "name": "seller_nft_account",
-          "writable": true
-        },
-        {
-          "name": "seller",
-          "signer": true
-        },
-        {
-          "name": "nft_token_program"
-        }
-      ],
-      "args": []
+      "code": 6009,
+      "name": "InvalidDuration",
+      "msg": "Invalid duration for auction"
     },
     {
-      "name": "cancel_listing_pnft",
-      "docs": [
-        "Cancel a pNFT listing — return NFT to seller."
-      ],
-      "discriminator": [
-        41,
-        48,
-        179,
-        6,
-        129,
-        16,
-        120,
-        65
-      ],
-      "accounts": [
-        {
-          "name": "listing",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nft_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "nft_mint"
-        },
-        {
-          "name": "nft_metadata",
-          "writable": true
-        },
-        {
-          "name": "nft_edition"
-        },
-        {
-          "name": "escrow_authority",
-          "docs": [
-            "Escrow authority PDA"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nft_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "escrow_nft_token",
-          "writable": true
-        },
-        {
-          "name": "escrow_token_record",
-          "writable": true
-        },
-        {
-          "name": "seller_nft_token",
-          "writable": true
-        },
-        {
-          "name": "seller_token_record",
-          "writable": true
-        },
-        {
-          "name": "seller",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "token_metadata_program"
-        },
-        {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "ata_program"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "sysvar_instructions"
-        },
-        {
-          "name": "authorization_rules_program",
-          "optional": true
-        },
-        {
-          "name": "authorization_rules",
-          "optional": true
-        }
-      ],
-      "args": []
+      "code": 6010,
+      "name": "InvalidPaymentMint",
+      "msg": "Invalid payment mint for this category"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidPrice",
+      "msg": "Price must be greater than zero"
+    },
+    {
+      "code": 6012,
+      "name": "TransferFailed",
+      "msg": "Token-2022 transfer with hook failed"
+    },
+    {
+      "code": 6013,
+      "name": "SellerCannotBid",
+      "msg": "Seller cannot bid on their own auction"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidRefundAccount",
+      "msg": "Invalid refund account — must be previous bidder's ATA"
+    },
+    {
+      "code": 6015,
+      "name": "RoyaltyTooHigh",
+      "msg": "Royalty basis points too high (max 1000 = 10%)"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidBuyerAccount",
+      "msg": "Invalid buyer account — must be owned by highest bidder"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidCreatorAccount",
+      "msg": "Invalid creator account — must be creator's ATA for payment mint"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidTokenProgram",
+      "msg": "Invalid token program — must be SPL Token or Token-2022"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRoyaltyBps",
+      "msg": "Invalid royalty basis points — must be 0 or >= 100 (1%)"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidCorePluginState",
+      "msg": "Invalid Metaplex Core plugin state"
+    },
+    {
+      "code": 6021,
+      "name": "StaleCoreListing",
+      "msg": "Core listing is stale because the seller no longer owns the asset"
     },
     {
-      "name": "close_stale_core_listing",
-      "docs": [
-        "Close a stale Core listing after ownership changed outside the program.",
-        "Allows the current holder to clear old state and re-list the asset."
-      ],
-      "discriminator": [
-        108,
-        204,
-        165,
-        119,
-        83,
-        128,
-        227,
-        162
-      ],
-      "accounts": [
-        {
-          "name": "holder",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "asset",
-          "writable": true
-        },
-        {
-          "name": "collection",
-          "writable": true
-        },
-        {
-          "name": "core_listing",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "core_authority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "mpl_core_program",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
+      "code": 6022,
+      "name": "CoreListingNotStale",
+      "msg": "Core listing is not stale"
     },
     {
-      "name": "close_stale_listing",
-      "docs": [
-        "Close a stale listing where escrow is empty (NFT already returned)",
-        "This allows re-listing the same NFT after a cancelled listing"
-      ],
-      "discriminator": [
-        120,
-        38,
-        229,
-        87,
-        16,
-        1,
-        54,
-        10
-      ],
-      "accounts": [
-        {
-          "name": "listing",
-          "writable": true
-        },
-        {
-          "name": "nft_mint"
-        },
-        {
-          "name": "escrow_nft",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  110,
-                  102,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
-              }
-            ]
-          }
-        },
-        {
-          "name": "seller",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "nft_token_program"
-        }
-      ],
-      "args": []
+      "code": 6023,
+      "name": "InvalidSoftClose",
+      "msg": "Invalid soft-close settings — window and extension must be positive"
     },
     {
-      "name": "initialize_treasury",
-      "docs": [
-        "Initialize the treasury config PDA (one-time setup by deploy authority)"
-      ],
-      "discriminator": [
-        124,
-        186,
-        211,
-        195,
-        85,
-        165,
-        129,
-        166
-      ],
-      "accounts": [
-        {
-          "name": "treasury_config",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  116,
-                  114,
-                  101,
-                  97,
-                  115,
-                  117,
-                  114,
-                  121,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
+      "code": 6024,
+      "name": "InvalidReservePrice",
+      "msg": "Invalid reserve price — must exceed the opening price"
+    },
+    {
+      "code": 6025,
+      "name": "NoHiddenReserve",
+      "msg": "Listing has no hidden reserve to reveal"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidReserveReveal",
+      "msg": "Reserve reveal does not match the committed hash"
+    },
+    {
+      "code": 6027,
+      "name": "ReserveNotRevealed",
+      "msg": "Hidden reserve has not been revealed yet"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidBuyNowPrice",
+      "msg": "Invalid buy-now price — must exceed the opening price and reserve"
+    },
+    {
+      "code": 6029,
+      "name": "BuyNowUnavailable",
+      "msg": "Buy-now is not available on this auction"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidBidIncrement",
+      "msg": "Invalid bid increment — step must be positive and percentages at most 100%"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidStartTime",
+      "msg": "Scheduled start time must not be in the past"
+    },
+    {
+      "code": 6032,
+      "name": "ListingNotStarted",
+      "msg": "Listing has not started yet"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidDutchConfig",
+      "msg": "Invalid Dutch auction settings — floor must be positive and below the start price"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMaxBid",
+      "msg": "Invalid maximum bid — must be at least the bid and above any existing maximum"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidSealedConfig",
+      "msg": "Invalid sealed auction settings — reveal window must be positive"
+    },
+    {
+      "code": 6036,
+      "name": "NotSealedAuction",
+      "msg": "Listing is not a sealed-bid auction"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidSealedBid",
+      "msg": "Sealed bid does not match its commitment or was already revealed"
+    },
+    {
+      "code": 6038,
+      "name": "NotInRevealWindow",
+      "msg": "Outside the sealed-bid reveal window"
+    },
+    {
+      "code": 6039,
+      "name": "WinningBidLocked",
+      "msg": "Winning sealed bid is locked until the auction is settled"
+    },
+    {
+      "code": 6040,
+      "name": "PriceBelowMinimum",
+      "msg": "Price is below the payment mint's minimum"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidOfferExpiry",
+      "msg": "Offer expiry must be in the future"
+    },
+    {
+      "code": 6042,
+      "name": "OfferExpired",
+      "msg": "Offer has expired"
+    },
+    {
+      "code": 6043,
+      "name": "OfferNotExpired",
+      "msg": "Offer has not expired yet"
     },
     {
-      "name": "list_core_item",
-      "docs": [
-        "List a Metaplex Core asset for fixed-price USDC sale."
-      ],
-      "discriminator": [
-        249,
-        213,
-        18,
-        100,
-        230,
-        110,
-        232,
-        59
-      ],
-      "accounts": [
-        {
-          "name": "seller",
-          "docs": [
-            "Current holder-signed."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "asset",
-          "writable": true
-        },
-        {
-          "name": "collection",
-          "writable": true
-        },
-        {
-          "name": "payment_mint",
-          "docs": [
-            "USDC mint (validated in handler)."
-          ]
-        },
-        {
-          "name": "core_listing",
-          "docs": [
-            "CoreListing PDA — created on list, closed on cancel/buy."
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "core_authority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  114,
-                  101,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "asset"
-              }
-            ]
-          }
-        },
-        {
-          "name": "mpl_core_program",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "price_usdc",
-          "type": "u64"
-        }
-      ]
+      "code": 6044,
+      "name": "InvalidOfferAsset",
+      "msg": "Offers can only be made on SPL / Token-2022 mints or Core assets, and accepted with the matching accounts"
     },
     {
-      "name": "list_item",
-      "docs": [
-        "List an item for sale (either fixed price or auction)",
-        "",
-        "For WNS/Token-2022 NFTs: client MUST include a WNS `approve_transfer` IX",
-        "(amount=0) BEFORE this instruction in the same transaction.",
-        "remaining_accounts for Token-2022:",
-        "[0] extra_metas_account PDA (readonly) - seeds: [\"extra-account-metas\", nft_mint]",
-        "[1] approve_account PDA (writable) - seeds: [\"approve-account\", nft_mint]",
-        "[2] wns_program (readonly)"
-      ],
-      "discriminator": [
-        174,
-        245,
-        22,
-        211,
-        228,
-        103,
-        121,
-        13
-      ],
-      "accounts": [
-        {
-          "name": "listing",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nft_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "nft_mint"
-        },
-        {
-          "name": "payment_mint"
-        },
-        {
-          "name": "escrow_nft",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  110,
-                  102,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nft_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "seller_nft_account",
-          "writable": true
-        },
-        {
-          "name": "seller",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "nft_token_program"
-        },
-        {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
+      "code": 6045,
+      "name": "InvalidOfferQuantity",
+      "msg": "Collection offer quantity must be positive"
+    },
+    {
+      "code": 6046,
+      "name": "OfferFilled",
+      "msg": "Collection offer is fully filled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidAttributePredicate",
+      "msg": "Attribute predicates must be unique, non-empty and within size limits"
+    },
+    {
+      "code": 6048,
+      "name": "AttributeMismatch",
+      "msg": "Asset attributes do not match the offer"
+    },
+    {
+      "code": 6049,
+      "name": "CannotUpdateWithBids",
+      "msg": "Cannot reprice an auction with bids"
+    },
+    {
+      "code": 6050,
+      "name": "ListingExpired",
+      "msg": "Listing has expired"
+    },
+    {
+      "code": 6051,
+      "name": "ListingNotExpired",
+      "msg": "Listing has not expired"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidBundle",
+      "msg": "Bundle is already open, full, empty, or already holds this item"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidBundleWeights",
+      "msg": "Bundle weights must be all zero or all positive"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidBundleAccounts",
+      "msg": "Bundle item accounts do not match the bundle"
+    },
+    {
+      "code": 6055,
+      "name": "CoreAssetCommitted",
+      "msg": "Core asset is already listed, bundled or offered in a swap"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidSwapTerms",
+      "msg": "Swap must want another asset or collection, with attributes only for collections and a future expiry"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidSwapAccounts",
+      "msg": "Swap accounts do not match the swap"
+    },
+    {
+      "code": 6058,
+      "name": "SwapMismatch",
+      "msg": "Asset does not satisfy the swap"
+    },
+    {
+      "code": 6059,
+      "name": "StaleListing",
+      "msg": "Listing is stale because the seller's token account no longer holds the NFT under its delegation"
+    },
+    {
+      "code": 6060,
+      "name": "ListingNotStale",
+      "msg": "Listing is not stale"
+    },
+    {
+      "code": 6061,
+      "name": "InvalidCompressedProof",
+      "msg": "Merkle proof does not verify the compressed NFT leaf"
+    },
+    {
+      "code": 6062,
+      "name": "MissingTransferHookProgram",
+      "msg": "Transfer-hook program missing from remaining_accounts"
+    },
+    {
+      "code": 6063,
+      "name": "MissingExtraMetasAccount",
+      "msg": "Transfer-hook extra-account-metas PDA missing from remaining_accounts"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidTransferHookAccounts",
+      "msg": "remaining_accounts lack accounts listed by the transfer hook's extra-account-metas"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidWnsAccountLayout",
+      "msg": "WNS remaining_accounts must be: wns_program, extra_metas, approve_account, distribution_program, distribution_account, distribution_token_account, system_program, member, group"
+    },
+    {
+      "code": 6066,
+      "name": "WnsApproveFailed",
+      "msg": "WNS approve_transfer failed"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidSellerBond",
+      "msg": "Seller bond accounts are missing or invalid"
+    },
+    {
+      "code": 6068,
+      "name": "NotLegacyListing",
+      "msg": "Account is not a listing in the legacy layout"
+    },
+    {
+      "code": 6069,
+      "name": "ReserveRevealClosed",
+      "msg": "Hidden reserve reveal window has closed"
+    },
+    {
+      "code": 6070,
+      "name": "PayoutBelowRent",
+      "msg": "Lamport payout would leave its wallet below the rent-exempt minimum"
+    }
+  ],
+  "types": [
+    {
+      "name": "AttributePredicate",
+      "docs": [
+        "Exact-match requirement on a Core `Attributes` plugin entry."
       ],
-      "args": [
-        {
-          "name": "listing_type",
-          "type": {
-            "defined": {
-              "name": "ListingType"
-            }
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "string"
+          },
+          {
+            "name": "value",
+            "type": "string"
           }
-        },
-        {
-          "name": "price",
-          "type": "u64"
-        },
-        {
-          "name": "duration_seconds",
-          "type": {
-            "option": "i64"
+        ]
+      }
+    },
+    {
+      "name": "AuctionCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": "string"
           }
-        },
-        {
-          "name": "category",
-          "type": {
-            "defined": {
-              "name": "ItemCategory"
-            }
+        ]
+      }
+    },
+    {
+      "name": "AuctionSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
           }
-        },
-        {
-          "name": "royalty_basis_points",
-          "type": "u16"
-        },
-        {
-          "name": "creator_address",
-          "type": "pubkey"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "list_item_pnft",
+      "name": "BidIncrement",
       "docs": [
-        "List a pNFT (Metaplex programmable NFT) for sale.",
-        "Uses Token Metadata TransferV1 CPI with delegate + token_record."
-      ],
-      "discriminator": [
-        236,
-        179,
-        101,
-        29,
-        212,
-        149,
-        190,
-        159
-      ],
-      "accounts": [
-        {
-          "name": "listing",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  108,
-                  105,
-                  115,
-                  116,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nft_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "nft_mint"
-        },
-        {
-          "name": "nft_metadata",
-          "writable": true
-        },
-        {
-          "name": "nft_edition"
-        },
-        {
-          "name": "seller_nft_token",
-          "writable": true
-        },
-        {
-          "name": "seller_token_record",
-          "writable": true
-        },
-        {
-          "name": "escrow_authority",
-          "docs": [
-            "Escrow authority PDA — owns the escrow token account"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nft_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "escrow_nft_token",
-          "docs": [
-            "Escrow token account — created by Token Metadata via ATA"
-          ],
-          "writable": true
-        },
-        {
-          "name": "escrow_token_record",
-          "docs": [
-            "Escrow token record (pNFT programmable config)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "payment_mint"
-        },
-        {
-          "name": "seller",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "token_metadata_program"
-        },
-        {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "ata_program"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "sysvar_instructions"
-        },
-        {
-          "name": "authorization_rules_program",
-          "optional": true
-        },
-        {
-          "name": "authorization_rules",
-          "optional": true
-        }
+        "Minimum raise rule for English auctions."
       ],
-      "args": [
-        {
-          "name": "listing_type",
-          "type": {
-            "defined": {
-              "name": "ListingType"
-            }
-          }
-        },
-        {
-          "name": "price",
-          "type": "u64"
-        },
-        {
-          "name": "duration_seconds",
-          "type": {
-            "option": "i64"
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Standard"
+          },
+          {
+            "name": "Absolute",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "Percentage",
+            "fields": [
+              "u16"
+            ]
+          },
+          {
+            "name": "Tiered"
           }
-        },
-        {
-          "name": "category",
-          "type": {
-            "defined": {
-              "name": "ItemCategory"
-            }
+        ]
+      }
+    },
+    {
+      "name": "BidPlaced",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auction end time after any soft-close extension"
+            ],
+            "type": "i64"
           }
-        },
-        {
-          "name": "royalty_basis_points",
-          "type": "u16"
-        },
-        {
-          "name": "creator_address",
-          "type": "pubkey"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "place_bid",
+      "name": "BidRefund",
       "docs": [
-        "Place a bid on an active auction (payment tokens only, no NFT transfer)"
+        "Funds owed back to a bidder on one auction (SPL or Core), held in that",
+        "auction's `bid_escrow` until claimed. Outlives the listing."
       ],
-      "discriminator": [
-        238,
-        77,
-        148,
-        91,
-        200,
-        151,
-        92,
-        146
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "nft_mint",
+            "docs": [
+              "NFT mint, or Core asset, the auction is keyed by"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "native",
+            "docs": [
+              "Held as lamports in `sol_escrow` rather than tokens in `bid_escrow`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "listing_created_at",
+            "docs": [
+              "`created_at` of the listing instance whose escrow holds the funds"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidRefundCredited",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Bundle",
+      "docs": [
+        "Several NFTs sold as one lot, at a fixed price or by English auction."
       ],
-      "accounts": [
-        {
-          "name": "listing",
-          "writable": true
-        },
-        {
-          "name": "payment_mint",
-          "docs": [
-            "Payment mint — must match the listing's payment mint"
-          ]
-        },
-        {
-          "name": "bid_escrow",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  105,
-                  100,
-                  95,
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundle_id",
+            "type": "u64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Fixed price, or auction starting price"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "duration_seconds",
+            "docs": [
+              "Applied when the bundle opens (0 = fixed price without expiry)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "open",
+            "docs": [
+              "Set by `open_bundle`; items can only be added before"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "current_bid",
+            "type": "u64"
+          },
+          {
+            "name": "highest_bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "bid_increment",
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "BundleItem"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "created_at",
+            "docs": [
+              "Identifies this bundle instance; scopes its bid escrow and refund",
+              "records, since a closed bundle's PDA can be created again"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "Unwound after expiry rather than cancelled by the seller"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundle_id",
+            "type": "u64"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
               }
-            ]
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
           }
-        },
-        {
-          "name": "bidder_token_account",
-          "writable": true
-        },
-        {
-          "name": "previous_bidder_account",
-          "writable": true
-        },
-        {
-          "name": "bidder",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "settle_auction",
-      "docs": [
-        "Settle an auction after end time",
-        "",
-        "For WNS/Token-2022: client MUST include WNS `approve_transfer` (amount=0)",
-        "remaining_accounts: same layout as list_item"
-      ],
-      "discriminator": [
-        246,
-        196,
-        183,
-        98,
-        222,
-        139,
-        46,
-        133
-      ],
-      "accounts": [
-        {
-          "name": "listing",
-          "writable": true
-        },
-        {
-          "name": "nft_mint"
-        },
-        {
-          "name": "bid_escrow",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  105,
-                  100,
-                  95,
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
+      "name": "BundleItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "docs": [
+              "NFT mint or Core asset"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "BundleItemKind"
               }
-            ]
+            }
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Share of the bundle price (0 on every item = even split)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "royalty_basis_points",
+            "docs": [
+              "Seller-declared royalty (SPL / Token-2022 only; Core reads the plugin)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "`bundle_escrow` bump, or `core_authority` bump for Core items"
+            ],
+            "type": "u8"
           }
-        },
-        {
-          "name": "escrow_nft",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  110,
-                  102,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.nft_mint",
-                "account": "Listing"
-              }
-            ]
+        ]
+      }
+    },
+    {
+      "name": "BundleItemAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
           }
-        },
-        {
-          "name": "seller_payment_account",
-          "docs": [
-            "Seller's payment token account — must be owned by listing.seller"
-          ],
-          "writable": true
-        },
-        {
-          "name": "treasury_payment_account",
-          "docs": [
-            "Treasury payment account — validated in instruction body against treasury_config or fallback"
-          ],
-          "writable": true
-        },
-        {
-          "name": "creator_payment_account",
-          "writable": true
-        },
-        {
-          "name": "buyer_nft_account",
-          "docs": [
-            "Buyer NFT account — must be owned by highest bidder (or seller if no bids for return)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "seller_nft_account",
-          "docs": [
-            "Seller NFT account — must be owned by listing.seller (for no-bid return)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "seller",
-          "writable": true
-        },
-        {
-          "name": "treasury",
-          "writable": true
-        },
-        {
-          "name": "treasury_config",
-          "docs": [
-            "Treasury config PDA — if present, overrides hardcoded treasury address"
-          ],
-          "optional": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  116,
-                  114,
-                  101,
-                  97,
-                  115,
-                  117,
-                  114,
-                  121,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
+        ]
+      }
+    },
+    {
+      "name": "BundleItemKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Spl"
+          },
+          {
+            "name": "Token2022"
+          },
+          {
+            "name": "Core"
           }
-        },
-        {
-          "name": "nft_token_program"
-        },
-        {
-          "name": "token_program",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "system_program",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
+        ]
+      }
     },
     {
-      "name": "update_treasury",
+      "name": "BundleOpened",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "item_count",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleSold",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundle",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          },
+          {
+            "name": "item_count",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyNowConfig",
       "docs": [
-        "Update treasury address (deploy authority only)"
+        "Buy-it-now settings for auctions, supplied at listing time."
       ],
-      "discriminator": [
-        60,
-        16,
-        243,
-        66,
-        96,
-        59,
-        254,
-        131
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "cutoff_bps",
+            "docs": [
+              "Share of `price` (in bps) the high bid may reach before buy-now is withdrawn"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionOffer",
+      "docs": [
+        "Standing bid for up to `quantity_remaining` assets of a Core collection at",
+        "`price` each, escrowed in `collection_offer_escrow`."
       ],
-      "accounts": [
-        {
-          "name": "treasury_config",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  116,
-                  114,
-                  101,
-                  97,
-                  115,
-                  117,
-                  114,
-                  121,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity_remaining",
+            "type": "u32"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "attributes",
+            "docs": [
+              "Asset must match all of these (empty = any asset in the collection)"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
               }
-            ]
+            }
+          },
+          {
+            "name": "attributes_hash",
+            "docs": [
+              "`attribute_predicates_hash(attributes)`, part of the PDA seeds"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrow_bump",
+            "type": "u8"
           }
-        },
-        {
-          "name": "authority",
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_treasury",
-          "type": "pubkey"
-        }
-      ]
-    }
-  ],
-  "accounts": [
+        ]
+      }
+    },
     {
-      "name": "CoreListing",
-      "discriminator": [
-        205,
-        178,
-        162,
-        169,
-        199,
-        166,
-        133,
-        157
-      ]
+      "name": "CollectionOfferCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "quantity_remaining",
+            "type": "u32"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "Closed after expiry rather than withdrawn by the buyer"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionOfferFilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          },
+          {
+            "name": "quantity_remaining",
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "name": "Listing",
-      "discriminator": [
-        218,
-        32,
-        50,
-        73,
-        43,
-        134,
-        26,
-        58
-      ]
+      "name": "CollectionOfferMade",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u32"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "attributes",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "TreasuryConfig",
-      "discriminator": [
-        124,
-        54,
-        212,
-        227,
-        213,
-        189,
-        168,
-        41
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "AuctionCancelled",
-      "discriminator": [
-        22,
-        32,
-        51,
-        83,
-        215,
-        194,
-        171,
-        209
-      ]
+      "name": "CompressedLeaf",
+      "docs": [
+        "A Bubblegum leaf: its hashes and position, plus the root its proof is against."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "data_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creator_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "name": "AuctionSettled",
-      "discriminator": [
-        61,
-        151,
-        131,
-        170,
-        95,
-        203,
-        219,
-        147
-      ]
+      "name": "CompressedListing",
+      "docs": [
+        "Fixed-price listing of a cNFT; the PDA is the leaf delegate."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset_id",
+            "docs": [
+              "Bubblegum asset id, derived from tree and nonce"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "merkle_tree",
+            "type": "pubkey"
+          },
+          {
+            "name": "leaf_nonce",
+            "type": "u64"
+          },
+          {
+            "name": "leaf_index",
+            "type": "u32"
+          },
+          {
+            "name": "data_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creator_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "0 = no expiry"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "royalty_basis_points",
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "name": "BidPlaced",
-      "discriminator": [
-        135,
-        53,
-        176,
-        83,
-        193,
-        69,
-        108,
-        61
-      ]
+      "name": "CoreListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "collection",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "start_time",
+            "docs": [
+              "Purchases are rejected before this time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "listing_type",
+            "docs": [
+              "FixedPrice or Auction — other listing types are not offered for Core"
+            ],
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auctions: bidding closes at this time. Fixed price: expiry (0 = none)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "current_bid",
+            "type": "u64"
+          },
+          {
+            "name": "highest_bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "bid_increment",
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "seller_bond",
+            "docs": [
+              "Auctions: bond held in `core_bond` until settlement or cancellation"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
       "name": "CoreListingCancelled",
-      "discriminator": [
-        165,
-        96,
-        88,
-        242,
-        37,
-        224,
-        205,
-        57
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
       "name": "CoreListingCreated",
-      "discriminator": [
-        184,
-        108,
-        121,
-        191,
-        52,
-        251,
-        30,
-        84
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "price_usdc",
+            "type": "u64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auction close, or fixed-price expiry (0 = none)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
       "name": "CorePurchased",
-      "discriminator": [
-        221,
-        82,
-        110,
-        196,
-        235,
-        58,
-        114,
-        17
-      ]
-    },
-    {
-      "name": "ItemPurchased",
-      "discriminator": [
-        33,
-        219,
-        12,
-        58,
-        205,
-        48,
-        63,
-        143
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price_usdc",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "ListingCancelled",
-      "discriminator": [
-        11,
-        46,
-        163,
-        10,
-        103,
-        80,
-        139,
-        194
-      ]
+      "name": "DelegateListing",
+      "docs": [
+        "Escrowless fixed-price listing of an SPL / Token-2022 NFT or pNFT; the PDA",
+        "is the token delegate of `seller_nft_account`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller_nft_account",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "0 = no expiry"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "is_token2022",
+            "type": "bool"
+          },
+          {
+            "name": "is_pnft",
+            "docs": [
+              "Sale-delegated pNFT (see `list_item_pnft_delegated`)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "royalty_basis_points",
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "name": "ListingCreated",
-      "discriminator": [
-        94,
-        164,
-        167,
-        255,
-        246,
-        186,
-        12,
-        96
-      ]
+      "name": "DutchConfig",
+      "docs": [
+        "Dutch auction settings, supplied at listing time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "floor_price",
+            "type": "u64"
+          },
+          {
+            "name": "curve",
+            "type": {
+              "defined": {
+                "name": "DutchCurve"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "TreasuryUpdated",
-      "discriminator": [
-        80,
-        239,
-        54,
-        168,
-        43,
-        38,
-        85,
-        145
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "ListingNotActive",
-      "msg": "Listing is not active"
+      "name": "DutchCurve",
+      "docs": [
+        "How a Dutch listing's price decays between start and end time."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Stepwise",
+            "fields": [
+              {
+                "name": "step_seconds",
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "AuctionEnded",
-      "msg": "Auction has already ended"
+      "name": "ItemCategory",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "DigitalArt"
+          },
+          {
+            "name": "Spirits"
+          },
+          {
+            "name": "TCGCards"
+          },
+          {
+            "name": "SportsCards"
+          },
+          {
+            "name": "Watches"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "AuctionNotEnded",
-      "msg": "Auction has not ended yet"
+      "name": "ItemPurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "BidTooLow",
-      "msg": "Bid is too low"
+      "name": "Listing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "ListingStatus"
+              }
+            }
+          },
+          {
+            "name": "escrow_nft_account",
+            "type": "pubkey"
+          },
+          {
+            "name": "current_bid",
+            "type": "u64"
+          },
+          {
+            "name": "highest_bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "baxus_fee",
+            "type": "bool"
+          },
+          {
+            "name": "is_token2022",
+            "type": "bool"
+          },
+          {
+            "name": "is_pnft",
+            "type": "bool"
+          },
+          {
+            "name": "royalty_basis_points",
+            "type": "u16"
+          },
+          {
+            "name": "creator_address",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "soft_close_window",
+            "docs": [
+              "Seconds before `end_time` in which a bid extends the auction (0 = off)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "soft_close_extension",
+            "docs": [
+              "Seconds added past the bid time when soft-close triggers"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "max_end_time",
+            "docs": [
+              "Hard cap on `end_time` after extensions (0 = uncapped)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "reserve_price",
+            "docs": [
+              "Minimum winning bid; 0 = no reserve beyond the opening `price`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserve_commitment",
+            "docs": [
+              "sha256 commitment of a hidden reserve, zeroed once revealed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buy_now_price",
+            "docs": [
+              "Price at which a buyer can end the auction early (0 = not offered)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "buy_now_cutoff_bps",
+            "docs": [
+              "Buy-now is withdrawn once the high bid passes this share of `buy_now_price`"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bid_increment",
+            "docs": [
+              "How far each new bid must exceed `current_bid`"
+            ],
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "dutch_floor_price",
+            "docs": [
+              "Dutch listings: price reached at `end_time` (`price` is the start price)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dutch_curve",
+            "type": {
+              "defined": {
+                "name": "DutchCurve"
+              }
+            }
+          },
+          {
+            "name": "highest_bidder_max",
+            "docs": [
+              "Leader's proxy maximum — the amount actually held in `bid_escrow`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sealed_pricing",
+            "docs": [
+              "Sealed auctions: pricing rule, reveal deadline and bid bookkeeping"
+            ],
+            "type": {
+              "defined": {
+                "name": "SealedPricing"
+              }
+            }
+          },
+          {
+            "name": "reveal_end_time",
+            "type": "i64"
+          },
+          {
+            "name": "second_bid",
+            "type": "u64"
+          },
+          {
+            "name": "sealed_bid_count",
+            "type": "u32"
+          },
+          {
+            "name": "forfeit_unrevealed",
+            "type": "bool"
+          },
+          {
+            "name": "native_sol",
+            "docs": [
+              "SOL English auctions escrow bids as lamports in `sol_escrow` and must use",
+              "the `_sol` instructions"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "created_at",
+            "docs": [
+              "Identifies this listing instance; scopes its bid escrows and refund",
+              "records. Zero for listings migrated from the legacy layout."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "CalculationError",
-      "msg": "Calculation error"
+      "name": "ListingCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "Unauthorized",
-      "msg": "Unauthorized"
+      "name": "ListingCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing_type",
+            "type": {
+              "defined": {
+                "name": "ListingType"
+              }
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "ItemCategory"
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "type": "i64"
+          },
+          {
+            "name": "end_time",
+            "type": "i64"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "CannotCancelWithBids",
-      "msg": "Cannot cancel auction with existing bids"
+      "name": "ListingExpired",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "docs": [
+              "NFT mint, or Core asset for `close_expired_core_listing`"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "NotAnAuction",
-      "msg": "Invalid listing type for this operation"
+      "name": "ListingOptions",
+      "docs": [
+        "Optional settings for `list_item` / `list_item_pnft`; `None` leaves a",
+        "setting off."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "soft_close",
+            "docs": [
+              "Auctions only: extends `end_time` when bids land near the close."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "SoftCloseConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "reserve",
+            "docs": [
+              "Auctions only: a sale floor above the opening `price`, either public",
+              "or hidden behind a sha256 commitment."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "ReservePrice"
+                }
+              }
+            }
+          },
+          {
+            "name": "buy_now",
+            "docs": [
+              "Auctions only: lets a buyer end the auction early at a fixed price."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "BuyNowConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "bid_increment",
+            "docs": [
+              "Auctions only: defaults to the payment mint's configured increment."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "BidIncrement"
+                }
+              }
+            }
+          },
+          {
+            "name": "start_time",
+            "docs": [
+              "Schedules the listing to open later; the NFT is escrowed now."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "dutch",
+            "docs": [
+              "Dutch listings only: the floor and decay curve; `price` is the starting",
+              "price and `duration_seconds` the time taken to decay to the floor."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "DutchConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "sealed",
+            "docs": [
+              "Sealed-bid listings only: the pricing rule and reveal window;",
+              "`duration_seconds` is then the commit phase."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "SealedConfig"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "NotFixedPrice",
-      "msg": "Invalid listing type for this operation"
+      "name": "ListingStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Settled"
+          },
+          {
+            "name": "Cancelled"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "InvalidDuration",
-      "msg": "Invalid duration for auction"
+      "name": "ListingType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FixedPrice"
+          },
+          {
+            "name": "Auction"
+          },
+          {
+            "name": "Dutch"
+          },
+          {
+            "name": "Sealed"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "InvalidPaymentMint",
-      "msg": "Invalid payment mint for this category"
+      "name": "ListingUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "docs": [
+              "NFT mint, or Core asset for `update_core_listing`"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "end_time",
+            "docs": [
+              "Auction close, or fixed-price expiry (0 = none)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "InvalidPrice",
-      "msg": "Price must be greater than zero"
+      "name": "Offer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "docs": [
+              "NFT mint or Metaplex Core asset"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Escrowed in `offer_escrow`; the full sale price on acceptance"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrow_bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "TransferFailed",
-      "msg": "Token-2022 transfer with hook failed"
+      "name": "OfferAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "platform_fee",
+            "type": "u64"
+          },
+          {
+            "name": "seller_amount",
+            "docs": [
+              "Amounts each party received, net of any payment-mint transfer fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury_amount",
+            "type": "u64"
+          },
+          {
+            "name": "creator_royalty",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "SellerCannotBid",
-      "msg": "Seller cannot bid on their own auction"
+      "name": "OfferCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "expired",
+            "docs": [
+              "Closed after expiry rather than withdrawn by the buyer"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "InvalidRefundAccount",
-      "msg": "Invalid refund account — must be previous bidder's ATA"
+      "name": "OfferMade",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "expires_at",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "RoyaltyTooHigh",
-      "msg": "Royalty basis points too high (max 1000 = 10%)"
+      "name": "PaymentMintConfig",
+      "docs": [
+        "Accepted payment mint. Listings may only use a mint whose config is",
+        "enabled and allows the item's category (or Core, for Core listings)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "allowed_categories",
+            "docs": [
+              "Bitmask of allowed `ItemCategory` values, one bit per variant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "allow_core",
+            "type": "bool"
+          },
+          {
+            "name": "min_price",
+            "docs": [
+              "Lowest price a listing may sell for, in payment-token base units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bid_increment",
+            "docs": [
+              "Used by auctions that don't choose their own increment"
+            ],
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "InvalidBuyerAccount",
-      "msg": "Invalid buyer account — must be owned by highest bidder"
+      "name": "PaymentMintConfigUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "allowed_categories",
+            "type": "u8"
+          },
+          {
+            "name": "allow_core",
+            "type": "bool"
+          },
+          {
+            "name": "min_price",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "InvalidCreatorAccount",
-      "msg": "Invalid creator account — must be creator's ATA for payment mint"
+      "name": "PaymentMintSettings",
+      "docs": [
+        "Admin-supplied settings for a `PaymentMintConfig`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "categories",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "ItemCategory"
+                }
+              }
+            }
+          },
+          {
+            "name": "allow_core",
+            "type": "bool"
+          },
+          {
+            "name": "min_price",
+            "type": "u64"
+          },
+          {
+            "name": "bid_increment",
+            "type": {
+              "defined": {
+                "name": "BidIncrement"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "InvalidTokenProgram",
-      "msg": "Invalid token program — must be SPL Token or Token-2022"
+      "name": "RefundClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "InvalidRoyaltyBps",
-      "msg": "Invalid royalty basis points — must be 0 or >= 100 (1%)"
+      "name": "ReservePrice",
+      "docs": [
+        "Auction reserve, supplied at listing time."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Public",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "Hidden",
+            "fields": [
+              {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "InvalidCorePluginState",
-      "msg": "Invalid Metaplex Core plugin state"
+      "name": "ReserveRevealed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reserve_price",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "StaleCoreListing",
-      "msg": "Core listing is stale because the seller no longer owns the asset"
+      "name": "SealedBid",
+      "docs": [
+        "One bidder's sealed bid on one listing instance. Outlives the listing so",
+        "losing and unrevealed bids can be reclaimed after settlement."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "payment_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "escrowed",
+            "docs": [
+              "Payment tokens held in `bid_escrow` for this bid"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "revealed",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reveal_end_time",
+            "type": "i64"
+          },
+          {
+            "name": "forfeit_unrevealed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "listing_created_at",
+            "docs": [
+              "`created_at` of the listing instance the bid was made on; scopes this",
+              "record and the escrow holding its deposit"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "CoreListingNotStale",
-      "msg": "Core listing is not stale"
-    }
-  ],
-  "types": [
-    {
-      "name": "AuctionCancelled",
+      "name": "SealedBidClaimed",
       "type": {
         "kind": "struct",
         "fields": [
@@ -4197,14 +25781,22 @@ export const IDL: Auction = {
             "type": "pubkey"
           },
           {
-            "name": "reason",
-            "type": "string"
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "forfeited",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "AuctionSettled",
+      "name": "SealedBidCommitted",
       "type": {
         "kind": "struct",
         "fields": [
@@ -4213,386 +25805,492 @@ export const IDL: Auction = {
             "type": "pubkey"
           },
           {
-            "name": "winner",
+            "name": "bidder",
             "type": "pubkey"
           },
           {
-            "name": "price",
+            "name": "deposit",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidRevealed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nft_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
           },
           {
-            "name": "platform_fee",
+            "name": "amount",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "BidPlaced",
+      "name": "SealedConfig",
+      "docs": [
+        "Sealed-bid auction settings, supplied at listing time."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "nft_mint",
-            "type": "pubkey"
+            "name": "pricing",
+            "type": {
+              "defined": {
+                "name": "SealedPricing"
+              }
+            }
           },
           {
-            "name": "bidder",
-            "type": "pubkey"
+            "name": "reveal_seconds",
+            "docs": [
+              "Length of the reveal window after the commit phase ends"
+            ],
+            "type": "i64"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "forfeit_unrevealed",
+            "docs": [
+              "Unrevealed deposits go to the seller instead of back to the bidder"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedPricing",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstPrice"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "SecondPrice"
           }
         ]
       }
     },
     {
-      "name": "CoreListing",
+      "name": "SellerBondForfeited",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "seller",
-            "type": "pubkey"
-          },
           {
             "name": "asset",
             "type": "pubkey"
           },
           {
-            "name": "collection",
+            "name": "seller",
             "type": "pubkey"
           },
           {
-            "name": "payment_mint",
+            "name": "winner",
             "type": "pubkey"
           },
           {
-            "name": "price",
+            "name": "amount",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SoftCloseConfig",
+      "docs": [
+        "Anti-sniping settings for English auctions, supplied at listing time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "window_seconds",
+            "type": "i64"
           },
           {
-            "name": "created_at",
+            "name": "extension_seconds",
             "type": "i64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "max_extension_seconds",
+            "docs": [
+              "Maximum total extension past the original end time (None = uncapped)"
+            ],
+            "type": {
+              "option": "i64"
+            }
           }
         ]
       }
     },
     {
-      "name": "CoreListingCancelled",
+      "name": "SolEscrow",
+      "docs": [
+        "Lamport escrow for native-SOL auction bids. Program-owned so the program",
+        "can debit it directly; its rent-exempt reserve is never paid out as bids."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "asset",
+            "name": "nft_mint",
             "type": "pubkey"
           },
           {
-            "name": "seller",
-            "type": "pubkey"
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "CoreListingCreated",
+      "name": "Swap",
+      "docs": [
+        "One escrowed asset offered for another, keyed by the offered asset."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "asset",
+            "name": "maker",
             "type": "pubkey"
           },
           {
-            "name": "seller",
+            "name": "offered_asset",
             "type": "pubkey"
           },
           {
-            "name": "price_usdc",
-            "type": "u64"
+            "name": "offered_kind",
+            "type": {
+              "defined": {
+                "name": "SwapAssetKind"
+              }
+            }
           },
           {
-            "name": "payment_mint",
-            "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "CorePurchased",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "want",
+            "type": {
+              "defined": {
+                "name": "SwapWant"
+              }
+            }
+          },
           {
-            "name": "asset",
+            "name": "wanted",
             "type": "pubkey"
           },
           {
-            "name": "seller",
-            "type": "pubkey"
+            "name": "attributes",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
+              }
+            }
           },
           {
-            "name": "buyer",
+            "name": "payment_mint",
+            "docs": [
+              "Default pubkey when there is no top-up"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "price_usdc",
+            "name": "top_up",
             "type": "u64"
           },
           {
-            "name": "platform_fee",
-            "type": "u64"
+            "name": "top_up_payer",
+            "type": {
+              "defined": {
+                "name": "TopUpPayer"
+              }
+            }
           },
           {
-            "name": "creator_royalty",
-            "type": "u64"
+            "name": "expires_at",
+            "type": "i64"
+          },
+          {
+            "name": "created_at",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrow_bump",
+            "docs": [
+              "Bump of the escrow PDA for `offered_kind` (see `swap_escrow_seed`)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "payment_escrow_bump",
+            "docs": [
+              "`swap_payment_escrow` bump when the maker pays the top-up"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "ItemCategory",
+      "name": "SwapAssetKind",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "DigitalArt"
-          },
-          {
-            "name": "Spirits"
+            "name": "Spl"
           },
           {
-            "name": "TCGCards"
+            "name": "Token2022"
           },
           {
-            "name": "SportsCards"
+            "name": "Pnft"
           },
           {
-            "name": "Watches"
+            "name": "Core"
           }
         ]
       }
     },
     {
-      "name": "ItemPurchased",
+      "name": "SwapCancelled",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "nft_mint",
+            "name": "swap",
             "type": "pubkey"
           },
           {
-            "name": "seller",
+            "name": "maker",
             "type": "pubkey"
           },
           {
-            "name": "buyer",
+            "name": "offered_asset",
             "type": "pubkey"
           },
           {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "platform_fee",
-            "type": "u64"
-          },
-          {
-            "name": "creator_royalty",
-            "type": "u64"
+            "name": "expired",
+            "docs": [
+              "Unwound after expiry rather than cancelled by the maker"
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "Listing",
+      "name": "SwapCreated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seller",
+            "name": "swap",
             "type": "pubkey"
           },
           {
-            "name": "nft_mint",
+            "name": "maker",
             "type": "pubkey"
           },
           {
-            "name": "payment_mint",
+            "name": "offered_asset",
             "type": "pubkey"
           },
           {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "listing_type",
+            "name": "offered_kind",
             "type": {
               "defined": {
-                "name": "ListingType"
+                "name": "SwapAssetKind"
               }
             }
           },
           {
-            "name": "category",
+            "name": "want",
             "type": {
               "defined": {
-                "name": "ItemCategory"
+                "name": "SwapWant"
               }
             }
           },
           {
-            "name": "start_time",
-            "type": "i64"
-          },
-          {
-            "name": "end_time",
-            "type": "i64"
+            "name": "wanted",
+            "type": "pubkey"
           },
           {
-            "name": "status",
+            "name": "attributes",
             "type": {
-              "defined": {
-                "name": "ListingStatus"
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
               }
             }
           },
           {
-            "name": "escrow_nft_account",
+            "name": "payment_mint",
             "type": "pubkey"
           },
           {
-            "name": "current_bid",
+            "name": "top_up",
             "type": "u64"
           },
           {
-            "name": "highest_bidder",
-            "type": "pubkey"
+            "name": "top_up_payer",
+            "type": {
+              "defined": {
+                "name": "TopUpPayer"
+              }
+            }
           },
           {
-            "name": "baxus_fee",
-            "type": "bool"
-          },
+            "name": "expires_at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapFilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "is_token2022",
-            "type": "bool"
+            "name": "swap",
+            "type": "pubkey"
           },
           {
-            "name": "is_pnft",
-            "type": "bool"
+            "name": "maker",
+            "type": "pubkey"
           },
           {
-            "name": "royalty_basis_points",
-            "type": "u16"
+            "name": "taker",
+            "type": "pubkey"
           },
           {
-            "name": "creator_address",
+            "name": "offered_asset",
             "type": "pubkey"
           },
           {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ListingCancelled",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nft_mint",
+            "name": "received_asset",
             "type": "pubkey"
           },
           {
-            "name": "seller",
+            "name": "payment_mint",
             "type": "pubkey"
+          },
+          {
+            "name": "top_up",
+            "type": "u64"
+          },
+          {
+            "name": "top_up_payer",
+            "type": {
+              "defined": {
+                "name": "TopUpPayer"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "ListingCreated",
+      "name": "SwapTerms",
+      "docs": [
+        "What a swap maker wants back, and the optional cash leg."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "nft_mint",
-            "type": "pubkey"
+            "name": "want",
+            "type": {
+              "defined": {
+                "name": "SwapWant"
+              }
+            }
           },
           {
-            "name": "seller",
+            "name": "wanted",
             "type": "pubkey"
           },
           {
-            "name": "listing_type",
+            "name": "attributes",
+            "docs": [
+              "Core attributes the taker's asset must carry (collection swaps only)"
+            ],
             "type": {
-              "defined": {
-                "name": "ListingType"
+              "vec": {
+                "defined": {
+                  "name": "AttributePredicate"
+                }
               }
             }
           },
           {
-            "name": "price",
+            "name": "top_up",
+            "docs": [
+              "Payment-mint amount that changes hands with the assets (0 = none)"
+            ],
             "type": "u64"
           },
           {
-            "name": "category",
+            "name": "top_up_payer",
             "type": {
               "defined": {
-                "name": "ItemCategory"
+                "name": "TopUpPayer"
               }
             }
           },
           {
-            "name": "end_time",
+            "name": "expires_at",
+            "docs": [
+              "0 = never expires"
+            ],
             "type": "i64"
-          },
-          {
-            "name": "payment_mint",
-            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "ListingStatus",
+      "name": "SwapWant",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Active"
-          },
-          {
-            "name": "Settled"
+            "name": "Asset"
           },
           {
-            "name": "Cancelled"
+            "name": "Collection"
           }
         ]
       }
     },
     {
-      "name": "ListingType",
+      "name": "TopUpPayer",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "FixedPrice"
+            "name": "Taker"
           },
           {
-            "name": "Auction"
+            "name": "Maker"
           }
         ]
       }
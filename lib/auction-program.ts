@@ -24,6 +24,17 @@ const WNS_DISTRIBUTION_PROGRAM_ID = new PublicKey("diste3nXmK7ddDTs1zb6uday6j4et
 export enum ListingType {
   FixedPrice = 0,
   Auction = 1,
+  Dutch = 2,
+  Sealed = 3,
+}
+
+function listingTypeArg(listingType: ListingType) {
+  switch (listingType) {
+    case ListingType.FixedPrice: return { fixedPrice: {} };
+    case ListingType.Auction: return { auction: {} };
+    case ListingType.Dutch: return { dutch: {} };
+    case ListingType.Sealed: return { sealed: {} };
+  }
 }
 
 export enum ItemCategory {
@@ -91,62 +102,25 @@ async function isWNSNft(connection: Connection, mintAddress: PublicKey): Promise
 }
 
 /**
- * Build WNS approve_transfer instruction (amount=0, just to set slot for hook)
+ * Read the 32-byte key WNS stores after the discriminator and the account's own
+ * mint: a member account's group, or a group account's mint.
  */
-function buildWNSApproveInstruction(
-  payer: PublicKey,
-  authority: PublicKey,
-  mint: PublicKey,
-  groupMint: PublicKey,
-  amount: number = 0
-): TransactionInstruction {
-  const [approveAccount] = PublicKey.findProgramAddressSync(
-    [Buffer.from("approve-account"), mint.toBuffer()],
-    WNS_PROGRAM_ID
-  );
-
-  // Distribution account PDA: seeds = [group_mint, payment_mint]
-  // payment_mint = SystemProgram (SOL) for amount=0 transfers
-  const [distributionAccount] = PublicKey.findProgramAddressSync(
-    [groupMint.toBuffer(), SystemProgram.programId.toBuffer()],
-    WNS_DISTRIBUTION_PROGRAM_ID
-  );
-
-  // Anchor discriminator for "approve_transfer": sha256("global:approve_transfer")[..8]
-  // Hardcoded to avoid require("crypto") which doesn't work in browser
-  const discriminator = Buffer.from([198, 217, 247, 150, 208, 60, 169, 244]);
-
-  // Instruction data: discriminator + amount (u64 LE)
-  const data = Buffer.alloc(16);
-  discriminator.copy(data, 0);
-  data.writeBigUInt64LE(BigInt(amount), 8);
-
-  const accounts = [
-    { pubkey: payer, isSigner: true, isWritable: true },             // payer
-    { pubkey: authority, isSigner: true, isWritable: false },         // authority
-    { pubkey: mint, isSigner: false, isWritable: false },             // mint
-    { pubkey: approveAccount, isSigner: false, isWritable: true },    // approve_account
-    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // payment_mint (dummy for amount=0)
-    { pubkey: WNS_PROGRAM_ID, isSigner: false, isWritable: false },   // distribution_token_account = None
-    { pubkey: WNS_PROGRAM_ID, isSigner: false, isWritable: false },   // authority_token_account = None
-    { pubkey: distributionAccount, isSigner: false, isWritable: true }, // distribution_account (real PDA)
-    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system_program
-    { pubkey: WNS_DISTRIBUTION_PROGRAM_ID, isSigner: false, isWritable: false }, // distribution_program
-    { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false }, // token_program
-    { pubkey: WNS_PROGRAM_ID, isSigner: false, isWritable: false },   // payment_token_program = None
-  ];
-
-  return new TransactionInstruction({
-    keys: accounts,
-    programId: WNS_PROGRAM_ID,
-    data,
-  });
+async function readWNSGroupLink(connection: Connection, account: PublicKey): Promise<PublicKey> {
+  const info = await connection.getAccountInfo(account);
+  if (!info || info.data.length < 72) throw new Error(`WNS account not found: ${account.toBase58()}`);
+  return new PublicKey(info.data.subarray(40, 72));
 }
 
 /**
- * Get WNS remaining accounts for transfer_checked hook
+ * WNS remaining accounts, in the order the program validates them.
+ * The program makes the WNS `approve_transfer` CPI itself; pass `payment` on
+ * sales so the distribution accounts match the listing's payment mint.
  */
-function getWNSRemainingAccounts(nftMint: PublicKey): { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[] {
+async function getWNSRemainingAccounts(
+  connection: Connection,
+  nftMint: PublicKey,
+  payment?: { mint: PublicKey; tokenProgram: PublicKey }
+): Promise<{ pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[]> {
   const [extraMetasPda] = PublicKey.findProgramAddressSync(
     [Buffer.from("extra-account-metas"), nftMint.toBuffer()],
     WNS_PROGRAM_ID
@@ -155,46 +129,82 @@ function getWNSRemainingAccounts(nftMint: PublicKey): { pubkey: PublicKey; isSig
     [Buffer.from("approve-account"), nftMint.toBuffer()],
     WNS_PROGRAM_ID
   );
+  const [memberAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from("member"), nftMint.toBuffer()],
+    WNS_PROGRAM_ID
+  );
+  const groupAccount = await readWNSGroupLink(connection, memberAccount);
+  const groupMint = await readWNSGroupLink(connection, groupAccount);
+
+  // Distribution PDA seeds: [group_mint, payment_mint]; SOL is the system program id
+  const [distributionAccount] = PublicKey.findProgramAddressSync(
+    [groupMint.toBuffer(), (payment?.mint ?? SystemProgram.programId).toBuffer()],
+    WNS_DISTRIBUTION_PROGRAM_ID
+  );
+  const distributionTokenAccount = payment
+    ? await getAssociatedTokenAddress(payment.mint, distributionAccount, true, payment.tokenProgram)
+    : WNS_PROGRAM_ID;
 
-  // Order must match Token-2022 CPI hook resolution:
-  // hook_program, extra_account_metas_pda, then resolved metas (approve_account)
   return [
-    { pubkey: WNS_PROGRAM_ID, isSigner: false, isWritable: false },   // [0] hook program
-    { pubkey: extraMetasPda, isSigner: false, isWritable: false },    // [1] extra_account_metas PDA
-    { pubkey: approveAccount, isSigner: false, isWritable: true },    // [2] approve_account (resolved from meta list)
+    { pubkey: WNS_PROGRAM_ID, isSigner: false, isWritable: false },              // [0] wns_program
+    { pubkey: extraMetasPda, isSigner: false, isWritable: false },               // [1] extra_metas_account
+    { pubkey: approveAccount, isSigner: false, isWritable: true },               // [2] approve_account
+    { pubkey: WNS_DISTRIBUTION_PROGRAM_ID, isSigner: false, isWritable: false }, // [3] distribution_program
+    { pubkey: distributionAccount, isSigner: false, isWritable: true },          // [4] distribution_account
+    { pubkey: distributionTokenAccount, isSigner: false, isWritable: !!payment }, // [5] distribution_token_account
+    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },     // [6] system_program
+    { pubkey: memberAccount, isSigner: false, isWritable: false },               // [7] member account
+    { pubkey: groupAccount, isSigner: false, isWritable: false },                // [8] group account
   ];
 }
 
-// WNS authority → group mint mapping for distribution PDA derivation
-// Distribution PDA seeds: [group_mint, payment_mint] under distribution program
-// The group_mint is the Token-2022 collection NFT mint, NOT the authority address
-const WNS_GROUP_MINT_MAP: Record<string, string> = {
-  // Quekz WNS: authority → group mint
-  "2hwTMM3uWRvNny8YxSEKQkHZ8NHB5BRv7f35ccMWg1ay": "98AmC3VCiJvrntZqR4Uv8fzoESdsSGrrshZ3e2WqiYgf",
-};
-
 /**
- * Get the WNS group mint for a Token-2022 NFT.
- * Uses the authority from Helius DAS to look up the group mint.
+ * Listing-instance seed of the bid escrow / refund PDAs: empty for listings
+ * created before instance keying (`created_at` 0), else `created_at` as i64 LE.
  */
-async function getWNSGroupMint(nftMint: PublicKey): Promise<PublicKey> {
-  try {
-    const resp = await fetch(`/api/nft?mint=${nftMint.toBase58()}`);
-    const data = await resp.json();
-    const asset = data.nft || data;
-    const authority = asset.authorities?.[0]?.address;
-    if (authority && WNS_GROUP_MINT_MAP[authority]) {
-      return new PublicKey(WNS_GROUP_MINT_MAP[authority]);
-    }
-    // If not in map, try authority as group mint (may work for some collections)
-    if (authority) {
-      return new PublicKey(authority);
-    }
-  } catch (err) {
-    console.error("Failed to fetch WNS group mint:", err);
-  }
-  // Fallback: Quekz group mint
-  return new PublicKey("98AmC3VCiJvrntZqR4Uv8fzoESdsSGrrshZ3e2WqiYgf");
+function listingInstanceSeed(createdAt: anchor.BN | number): Buffer {
+  const value = BigInt(createdAt.toString());
+  if (value === BigInt(0)) return Buffer.alloc(0);
+  const seed = Buffer.alloc(8);
+  seed.writeBigInt64LE(value);
+  return seed;
+}
+
+function paymentMintConfigPda(paymentMint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("payment_mint_config"), paymentMint.toBuffer()],
+    AUCTION_PROGRAM_ID
+  )[0];
+}
+
+function bidRefundPda(nftMint: PublicKey, createdAt: anchor.BN | number, bidder: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bid_refund"), nftMint.toBuffer(), listingInstanceSeed(createdAt), bidder.toBuffer()],
+    AUCTION_PROGRAM_ID
+  )[0];
+}
+
+/** Optional auction settings for `listItem` / `listItemPnft`; omitted ones stay off. */
+export interface ListingOptions {
+  softClose?: { windowSeconds: anchor.BN; extensionSeconds: anchor.BN; maxExtensionSeconds: anchor.BN | null };
+  reserve?: { public: [anchor.BN] } | { hidden: [number[]] };
+  buyNow?: { price: anchor.BN; cutoffBps: number };
+  bidIncrement?: { standard: {} } | { absolute: [anchor.BN] } | { percentage: [number] } | { tiered: {} };
+  startTime?: number;
+  dutch?: { floorPrice: anchor.BN; curve: { linear: {} } | { stepwise: { stepSeconds: anchor.BN } } };
+  sealed?: { pricing: { firstPrice: {} } | { secondPrice: {} }; revealSeconds: anchor.BN; forfeitUnrevealed: boolean };
+}
+
+function toListingOptionsArg(options: ListingOptions) {
+  return {
+    softClose: options.softClose ?? null,
+    reserve: options.reserve ?? null,
+    buyNow: options.buyNow ?? null,
+    bidIncrement: options.bidIncrement ?? null,
+    startTime: options.startTime !== undefined ? new anchor.BN(options.startTime) : null,
+    dutch: options.dutch ?? null,
+    sealed: options.sealed ?? null,
+  };
 }
 
 export class AuctionProgram {
@@ -258,6 +268,19 @@ export class AuctionProgram {
     return sig;
   }
 
+  /**
+   * Resolve the treasury wallet from the config PDA if initialized, else the fallback
+   * constant. `treasuryConfig` is null when the PDA doesn't exist yet.
+   */
+  private async resolveTreasury(): Promise<{ treasury: PublicKey; treasuryConfig: PublicKey | null }> {
+    try {
+      const cfg: any = await this.program.account.treasuryConfig.fetch(TREASURY_CONFIG_PDA);
+      return { treasury: new PublicKey(cfg.treasury), treasuryConfig: TREASURY_CONFIG_PDA };
+    } catch {
+      return { treasury: TREASURY_WALLET, treasuryConfig: null };
+    }
+  }
+
   /**
    * Fetch royalty info from NFT metadata via Helius DAS API.
    * For WNS/Token-2022: reads from mint_extensions.metadata.additional_metadata
@@ -362,7 +385,8 @@ export class AuctionProgram {
     listingType: ListingType,
     price: number,
     durationSeconds?: number,
-    category: ItemCategory = ItemCategory.DigitalArt
+    category: ItemCategory = ItemCategory.DigitalArt,
+    options: ListingOptions = {}
   ): Promise<string> {
     const nftTokenProgram = await detectTokenProgram(this.connection, nftMint);
     const isT22 = nftTokenProgram.equals(TOKEN_2022_PROGRAM_ID);
@@ -383,7 +407,7 @@ export class AuctionProgram {
 
     let builder = this.program.methods
       .listItem(
-        listingType === ListingType.FixedPrice ? { fixedPrice: {} } : { auction: {} },
+        listingTypeArg(listingType),
         new anchor.BN(price),
         durationSeconds ? new anchor.BN(durationSeconds) : null,
         category === ItemCategory.DigitalArt ? { digitalArt: {} } :
@@ -392,12 +416,14 @@ export class AuctionProgram {
         category === ItemCategory.SportsCards ? { sportsCards: {} } :
         { watches: {} },
         royaltyBps,
-        creatorAddress
+        creatorAddress,
+        toListingOptionsArg(options)
       )
       .accounts({
         listing,
         nftMint,
         paymentMint,
+        paymentMintConfig: paymentMintConfigPda(paymentMint),
         escrowNft,
         sellerNftAccount,
         seller: this.wallet.publicKey,
@@ -407,25 +433,14 @@ export class AuctionProgram {
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       });
 
-    // Add WNS remaining accounts if Token-2022 with hook
-    if (isWNS) {
-      builder = builder.remainingAccounts(getWNSRemainingAccounts(nftMint));
-    }
-
+    // Add WNS remaining accounts if Token-2022 with hook; the program approves the move itself
     if (isWNS) {
-      const wnsGroupMint = await getWNSGroupMint(nftMint);
-      const approveIx = buildWNSApproveInstruction(
-        this.wallet.publicKey,
-        this.wallet.publicKey, // seller is authority
-        nftMint,
-        wnsGroupMint,
-        0
-      );
+      builder = builder.remainingAccounts(await getWNSRemainingAccounts(this.connection, nftMint));
       const listIx = await builder.instruction();
       const tx = new Transaction()
         .add(ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
         .add(ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 50_000 }))
-        .add(approveIx).add(listIx);
+        .add(listIx);
       return await this.sendAndConfirm(tx);
     } else {
       const listIx = await builder.instruction();
@@ -468,9 +483,10 @@ export class AuctionProgram {
       AUCTION_PROGRAM_ID
     )[0];
 
+    const { treasury, treasuryConfig } = await this.resolveTreasury();
     const treasuryPaymentAccount = await getAssociatedTokenAddress(
       paymentMint,
-      TREASURY_WALLET
+      treasury
     );
 
     // Fetch listing to get creator_address for royalty payment
@@ -485,6 +501,7 @@ export class AuctionProgram {
       .accounts({
         listing,
         nftMint,
+        paymentMint,
         escrowNft,
         buyerPaymentAccount,
         sellerPaymentAccount,
@@ -492,14 +509,18 @@ export class AuctionProgram {
         creatorPaymentAccount,
         buyerNftAccount,
         buyer: this.wallet.publicKey,
-        treasury: TREASURY_WALLET,
+        treasury,
+        treasuryConfig,
         nftTokenProgram,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       });
 
+    // The buyer approves the WNS move (and pays WNS's royalty) inside the program
     if (isWNS) {
-      builder = builder.remainingAccounts(getWNSRemainingAccounts(nftMint));
+      builder = builder.remainingAccounts(
+        await getWNSRemainingAccounts(this.connection, nftMint, { mint: paymentMint, tokenProgram: TOKEN_PROGRAM_ID })
+      );
     }
 
     // Build pre-instructions for wSOL wrapping + missing ATAs
@@ -554,7 +575,7 @@ export class AuctionProgram {
         createAssociatedTokenAccountInstruction(
           this.wallet.publicKey,
           treasuryPaymentAccount,
-          TREASURY_WALLET,
+          treasury,
           paymentMint
         )
       );
@@ -595,42 +616,39 @@ export class AuctionProgram {
       .add(ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
       .add(ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 50_000 }));
 
-    if (isWNS) {
-      const wnsGroupMint = await getWNSGroupMint(nftMint);
-      const approveIx = buildWNSApproveInstruction(
-        this.wallet.publicKey,
-        this.wallet.publicKey,
-        nftMint,
-        wnsGroupMint,
-        0
-      );
-      tx.add(...preInstructions, approveIx, buyIx);
-    } else {
-      tx.add(...preInstructions, buyIx);
-    }
+    tx.add(...preInstructions, buyIx);
 
     return await this.sendAndConfirm(tx);
   }
 
   /**
-   * Place a bid on an active auction (payment only, no NFT transfer)
+   * Place a bid on an active auction (payment only, no NFT transfer).
+   * `maxAmount` makes it a proxy bid: up to `maxAmount` is escrowed and the program
+   * raises the visible bid only as far as needed. Native-SOL auctions use `placeBidSol`.
    */
   async placeBid(
     nftMint: PublicKey,
     bidAmount: number,
     bidderTokenAccount: PublicKey,
     paymentMint: PublicKey,
-    previousBidderAccount: PublicKey
+    maxAmount?: number
   ): Promise<string> {
     const listing = PublicKey.findProgramAddressSync(
       [Buffer.from("listing"), nftMint.toBuffer()],
       AUCTION_PROGRAM_ID
     )[0];
 
+    // Bid escrow and refund PDAs are keyed by the listing instance
+    const listingData = await this.program.account.listing.fetch(listing);
+    const instance = listingInstanceSeed(listingData.createdAt);
     const bidEscrow = PublicKey.findProgramAddressSync(
-      [Buffer.from("bid_escrow"), nftMint.toBuffer()],
+      [Buffer.from("bid_escrow"), nftMint.toBuffer(), instance],
       AUCTION_PROGRAM_ID
     )[0];
+    const highestBidder = listingData.highestBidder as PublicKey;
+    const previousBidderRefund = highestBidder.equals(PublicKey.default)
+      ? null
+      : bidRefundPda(nftMint, listingData.createdAt, highestBidder);
 
     // For wSOL bids: create ATA + wrap SOL if needed
     const preInstructions: TransactionInstruction[] = [];
@@ -649,12 +667,12 @@ export class AuctionProgram {
           )
         );
       }
-      // Transfer native SOL into wSOL ATA
+      // Transfer native SOL into wSOL ATA (up to the proxy maximum)
       preInstructions.push(
         SystemProgram.transfer({
           fromPubkey: this.wallet.publicKey,
           toPubkey: bidderTokenAccount,
-          lamports: bidAmount,
+          lamports: maxAmount ?? bidAmount,
         })
       );
       // Sync native balance
@@ -663,13 +681,14 @@ export class AuctionProgram {
     }
 
     const bidBuilder = this.program.methods
-      .placeBid(new anchor.BN(bidAmount))
+      .placeBid(new anchor.BN(bidAmount), maxAmount !== undefined ? new anchor.BN(maxAmount) : null)
       .accounts({
         listing,
         paymentMint,
         bidEscrow,
         bidderTokenAccount,
-        previousBidderAccount,
+        bidderRefund: bidRefundPda(nftMint, listingData.createdAt, this.wallet.publicKey),
+        previousBidderRefund,
         bidder: this.wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -682,6 +701,40 @@ export class AuctionProgram {
     return tx;
   }
 
+  /**
+   * Place a bid on a native-SOL auction. Lamports are escrowed in the `sol_escrow`
+   * PDA, so no wSOL account is needed. Same proxy rules as `placeBid`.
+   */
+  async placeBidSol(nftMint: PublicKey, bidLamports: number, maxLamports?: number): Promise<string> {
+    const listing = PublicKey.findProgramAddressSync(
+      [Buffer.from("listing"), nftMint.toBuffer()],
+      AUCTION_PROGRAM_ID
+    )[0];
+
+    const listingData = await this.program.account.listing.fetch(listing);
+    const solEscrow = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_escrow"), nftMint.toBuffer(), listingInstanceSeed(listingData.createdAt)],
+      AUCTION_PROGRAM_ID
+    )[0];
+    const highestBidder = listingData.highestBidder as PublicKey;
+    const previousBidderRefund = highestBidder.equals(PublicKey.default)
+      ? null
+      : bidRefundPda(nftMint, listingData.createdAt, highestBidder);
+
+    const bidIx = await this.program.methods
+      .placeBidSol(new anchor.BN(bidLamports), maxLamports !== undefined ? new anchor.BN(maxLamports) : null)
+      .accounts({
+        listing,
+        solEscrow,
+        bidderRefund: bidRefundPda(nftMint, listingData.createdAt, this.wallet.publicKey),
+        previousBidderRefund,
+        bidder: this.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    return await this.sendAndConfirm(new Transaction().add(bidIx));
+  }
+
   /**
    * Cancel a listing (seller only)
    * Supports both standard SPL Token and Token-2022/WNS NFTs
@@ -716,27 +769,13 @@ export class AuctionProgram {
       });
 
     if (isWNS) {
-      builder = builder.remainingAccounts(getWNSRemainingAccounts(nftMint));
+      builder = builder.remainingAccounts(await getWNSRemainingAccounts(this.connection, nftMint));
     }
 
-    if (isWNS) {
-      const wnsGroupMint = await getWNSGroupMint(nftMint);
-      const approveIx = buildWNSApproveInstruction(
-        this.wallet.publicKey,
-        this.wallet.publicKey,
-        nftMint,
-        wnsGroupMint,
-        0
-      );
-      const cancelIx = await builder.instruction();
-      const tx = new Transaction().add(approveIx).add(cancelIx);
-      return await this.sendAndConfirm(tx);
-    } else {
-      const cancelIx2 = await builder.instruction();
-      const cancelTx = new Transaction().add(cancelIx2);
-      const cancelSig = await this.sendAndConfirm(cancelTx);
-      return cancelSig;
-    }
+    const cancelIx = await builder.instruction();
+    const cancelTx = new Transaction().add(cancelIx);
+    const cancelSig = await this.sendAndConfirm(cancelTx);
+    return cancelSig;
   }
 
   /**
@@ -831,7 +870,8 @@ export class AuctionProgram {
 
   /**
    * Settle an auction after the end time
-   * Supports both standard SPL Token and Token-2022/WNS NFTs
+   * Supports both standard SPL Token and Token-2022/WNS NFTs.
+   * Auto-routes native-SOL auctions to `settle_auction_sol`.
    */
   async settleAuction(
     nftMint: PublicKey,
@@ -849,92 +889,142 @@ export class AuctionProgram {
       AUCTION_PROGRAM_ID
     )[0];
 
-    const bidEscrow = PublicKey.findProgramAddressSync(
-      [Buffer.from("bid_escrow"), nftMint.toBuffer()],
-      AUCTION_PROGRAM_ID
-    )[0];
-
     const escrowNft = PublicKey.findProgramAddressSync(
       [Buffer.from("escrow_nft"), nftMint.toBuffer()],
       AUCTION_PROGRAM_ID
     )[0];
 
-    const treasuryPaymentAccount = await getAssociatedTokenAddress(
-      paymentMint,
-      TREASURY_WALLET
-    );
+    const { treasury, treasuryConfig } = await this.resolveTreasury();
 
-    // Fetch listing to get creator_address for royalty payment
     const listingData = await this.program.account.listing.fetch(listing);
     const creatorAddr = listingData.creatorAddress as PublicKey;
-    // Always derive the correct ATA — program now always validates it
-    const creatorPaymentAccount = await getAssociatedTokenAddress(paymentMint, creatorAddr, true);
-
     const sellerAddress = listingData.seller as PublicKey;
     const highestBidder = listingData.highestBidder as PublicKey;
-
-    let builder = this.program.methods
-      .settleAuction()
-      .accounts({
-        listing,
-        nftMint,
-        bidEscrow,
-        escrowNft,
-        sellerPaymentAccount,
-        treasuryPaymentAccount,
-        creatorPaymentAccount,
-        buyerNftAccount,
-        sellerNftAccount,
-        seller: sellerAddress,
-        treasury: TREASURY_WALLET,
-        nftTokenProgram,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      });
-
-    if (isWNS) {
-      builder = builder.remainingAccounts(getWNSRemainingAccounts(nftMint));
-    }
+    const instance = listingInstanceSeed(listingData.createdAt);
+    const hasBidder = !highestBidder.equals(PublicKey.default);
+    const highestBidderRefund = hasBidder ? bidRefundPda(nftMint, listingData.createdAt, highestBidder) : null;
+    // Mirrors on-chain `is_reserve_met`: an unrevealed hidden reserve lapses once the reveal window closes
+    const hiddenReserve = (listingData.reserveCommitment as number[]).some((byte) => byte !== 0);
+    const isSale = listingData.currentBid.gtn(0) && (hiddenReserve || listingData.currentBid.gte(listingData.reservePrice));
 
     // Build pre-instructions to create missing ATAs
     const preIxs: TransactionInstruction[] = [];
 
-    // Seller wSOL ATA (to receive payment)
-    const sellerPaymentInfo = await this.connection.getAccountInfo(sellerPaymentAccount);
-    if (!sellerPaymentInfo) {
+    // Buyer NFT ATA (to receive the NFT)
+    const buyerNftInfo = await this.connection.getAccountInfo(buyerNftAccount);
+    if (!buyerNftInfo) {
       preIxs.push(createAssociatedTokenAccountInstruction(
-        this.wallet.publicKey, sellerPaymentAccount, sellerAddress, paymentMint
+        this.wallet.publicKey, buyerNftAccount, highestBidder, nftMint, nftTokenProgram, ASSOCIATED_TOKEN_PROGRAM_ID
       ));
     }
 
-    // Treasury wSOL ATA (for platform fee)
-    const treasuryInfo = await this.connection.getAccountInfo(treasuryPaymentAccount);
-    if (!treasuryInfo) {
-      preIxs.push(createAssociatedTokenAccountInstruction(
-        this.wallet.publicKey, treasuryPaymentAccount, TREASURY_WALLET, paymentMint
-      ));
-    }
+    let builder: any;
+    if (listingData.nativeSol) {
+      const solEscrow = PublicKey.findProgramAddressSync(
+        [Buffer.from("sol_escrow"), nftMint.toBuffer(), instance],
+        AUCTION_PROGRAM_ID
+      )[0];
+      const solEscrowInfo = await this.connection.getAccountInfo(solEscrow);
+
+      // Proceeds are paid in lamports straight to the seller, treasury and creator wallets.
+      // WNS sales need a system-owned `payer` to approve the move and pay WNS's royalty.
+      builder = this.program.methods
+        .settleAuctionSol()
+        .accounts({
+          listing,
+          nftMint,
+          solEscrow: solEscrowInfo ? solEscrow : null,
+          escrowNft,
+          creator: creatorAddr,
+          buyerNftAccount,
+          sellerNftAccount,
+          highestBidderRefund,
+          seller: sellerAddress,
+          treasury,
+          treasuryConfig,
+          payer: isWNS && isSale ? this.wallet.publicKey : null,
+          nftTokenProgram,
+          systemProgram: SystemProgram.programId,
+        });
+      if (isWNS) {
+        builder = builder.remainingAccounts(await getWNSRemainingAccounts(this.connection, nftMint));
+      }
+    } else {
+      const bidEscrow = PublicKey.findProgramAddressSync(
+        [Buffer.from("bid_escrow"), nftMint.toBuffer(), instance],
+        AUCTION_PROGRAM_ID
+      )[0];
+      const treasuryPaymentAccount = await getAssociatedTokenAddress(paymentMint, treasury);
+      // Always derive the correct ATA — program now always validates it
+      const creatorPaymentAccount = await getAssociatedTokenAddress(paymentMint, creatorAddr, true);
+      const winnerSealedBid = hasBidder && listingData.listingType.sealed
+        ? PublicKey.findProgramAddressSync(
+            [Buffer.from("sealed_bid"), nftMint.toBuffer(), instance, highestBidder.toBuffer()],
+            AUCTION_PROGRAM_ID
+          )[0]
+        : null;
+
+      builder = this.program.methods
+        .settleAuction()
+        .accounts({
+          listing,
+          nftMint,
+          paymentMint,
+          bidEscrow,
+          escrowNft,
+          sellerPaymentAccount,
+          treasuryPaymentAccount,
+          creatorPaymentAccount,
+          buyerNftAccount,
+          sellerNftAccount,
+          highestBidderRefund,
+          winnerSealedBid,
+          seller: sellerAddress,
+          treasury,
+          treasuryConfig,
+          nftTokenProgram,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        });
+
+      // Sales approve the WNS move for the clearing price, paid in the listing's mint
+      if (isWNS) {
+        builder = builder.remainingAccounts(
+          await getWNSRemainingAccounts(
+            this.connection,
+            nftMint,
+            isSale ? { mint: paymentMint, tokenProgram: TOKEN_PROGRAM_ID } : undefined
+          )
+        );
+      }
 
-    // Creator payment ATA (for royalties) — skip if no royalties
-    // Create creator ATA if missing and royalty > 0
-    if (listingData.royaltyBasisPoints > 0) {
-      const creatorInfo = await this.connection.getAccountInfo(creatorPaymentAccount);
-      if (!creatorInfo) {
+      // Seller payment ATA (to receive payment)
+      const sellerPaymentInfo = await this.connection.getAccountInfo(sellerPaymentAccount);
+      if (!sellerPaymentInfo) {
         preIxs.push(createAssociatedTokenAccountInstruction(
-          this.wallet.publicKey, creatorPaymentAccount, creatorAddr, paymentMint
+          this.wallet.publicKey, sellerPaymentAccount, sellerAddress, paymentMint
         ));
       }
-    }
 
-    // Buyer NFT ATA (to receive the NFT)
-    const buyerNftInfo = await this.connection.getAccountInfo(buyerNftAccount);
-    if (!buyerNftInfo) {
-      preIxs.push(createAssociatedTokenAccountInstruction(
-        this.wallet.publicKey, buyerNftAccount, highestBidder, nftMint, nftTokenProgram, ASSOCIATED_TOKEN_PROGRAM_ID
-      ));
+      // Treasury payment ATA (for platform fee)
+      const treasuryInfo = await this.connection.getAccountInfo(treasuryPaymentAccount);
+      if (!treasuryInfo) {
+        preIxs.push(createAssociatedTokenAccountInstruction(
+          this.wallet.publicKey, treasuryPaymentAccount, treasury, paymentMint
+        ));
+      }
+
+      // Creator payment ATA (for royalties) — skip if no royalties
+      if (listingData.royaltyBasisPoints > 0) {
+        const creatorInfo = await this.connection.getAccountInfo(creatorPaymentAccount);
+        if (!creatorInfo) {
+          preIxs.push(createAssociatedTokenAccountInstruction(
+            this.wallet.publicKey, creatorPaymentAccount, creatorAddr, paymentMint
+          ));
+        }
+      }
     }
 
-    // Always use Transaction path to include pre-instructions
     const settleIx = await builder.instruction();
     const tx = new Transaction();
 
@@ -943,19 +1033,6 @@ export class AuctionProgram {
       tx.add(ix);
     }
 
-    // Add WNS approve if needed
-    if (isWNS) {
-      const wnsGroupMint = await getWNSGroupMint(nftMint);
-      const approveIx = buildWNSApproveInstruction(
-        this.wallet.publicKey,
-        this.wallet.publicKey,
-        nftMint,
-        wnsGroupMint,
-        0
-      );
-      tx.add(approveIx);
-    }
-
     tx.add(settleIx);
     return await this.sendAndConfirm(tx);
   }
@@ -991,6 +1068,7 @@ export class AuctionProgram {
     royaltyBps: number,
     creatorAddress: PublicKey,
     ruleSet: PublicKey | null = null,
+    options: ListingOptions = {},
   ): Promise<string> {
     console.log('[listItemPnft] nftMint:', nftMint.toBase58());
     console.log('[listItemPnft] paymentMint:', paymentMint.toBase58());
@@ -1066,7 +1144,7 @@ export class AuctionProgram {
 
     const ix = await this.program.methods
       .listItemPnft(
-        listingType === ListingType.FixedPrice ? { fixedPrice: {} } : { auction: {} },
+        listingTypeArg(listingType),
         new anchor.BN(price),
         durationSeconds ? new anchor.BN(durationSeconds) : null,
         category === ItemCategory.DigitalArt ? { digitalArt: {} } :
@@ -1076,6 +1154,7 @@ export class AuctionProgram {
         { watches: {} },
         validatedRoyaltyBps,
         creatorAddress,
+        toListingOptionsArg(options),
       )
       .accounts({
         listing,
@@ -1088,6 +1167,7 @@ export class AuctionProgram {
         escrowNftToken,
         escrowTokenRecord,
         paymentMint,
+        paymentMintConfig: paymentMintConfigPda(paymentMint),
         seller: this.wallet.publicKey,
         tokenMetadataProgram: MPL_TOKEN_METADATA_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -1207,21 +1287,56 @@ export class AuctionProgram {
     return { basisPoints: 0, creator: TREASURY_WALLET };
   }
 
+  private coreBondPda(asset: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("core_bond"), asset.toBuffer()],
+      AUCTION_PROGRAM_ID
+    )[0];
+  }
+
+  /**
+   * Bond accounts for cancelling or closing a Core listing: the seller's bond is
+   * returned from `core_bond` for auctions, fixed-price listings pass none.
+   */
+  private async coreBondAccounts(asset: PublicKey, listingAcc: any) {
+    if (new anchor.BN(listingAcc.sellerBond ?? 0).isZero()) {
+      return { paymentMint: null, coreBond: null, sellerPaymentAccount: null, tokenProgram: null };
+    }
+    const paymentMint: PublicKey = listingAcc.paymentMint;
+    return {
+      paymentMint,
+      coreBond: this.coreBondPda(asset),
+      sellerPaymentAccount: await getAssociatedTokenAddress(paymentMint, listingAcc.seller),
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+  }
+
   /**
    * List a Metaplex Core asset for fixed-price USDC sale.
    * Current holder-only; the asset stays in the seller wallet (TransferDelegate model).
+   * `startTime` schedules the listing; `durationSeconds` expires it that long after it opens.
    */
-  async listCoreItem(asset: PublicKey, priceUsdc: number | bigint): Promise<string> {
+  async listCoreItem(
+    asset: PublicKey,
+    priceUsdc: number | bigint,
+    startTime?: number,
+    durationSeconds?: number
+  ): Promise<string> {
     const coreListing = this.coreListingPda(asset);
     const coreAuthority = this.coreAuthorityPda(asset);
 
     const ix = await this.program.methods
-      .listCoreItem(new anchor.BN(priceUsdc.toString()))
+      .listCoreItem(
+        new anchor.BN(priceUsdc.toString()),
+        startTime !== undefined ? new anchor.BN(startTime) : null,
+        durationSeconds !== undefined ? new anchor.BN(durationSeconds) : null
+      )
       .accounts({
         seller: this.wallet.publicKey,
         asset,
         collection: ARTIFACTE_COLLECTION,
         paymentMint: USDC_MINT,
+        paymentMintConfig: paymentMintConfigPda(USDC_MINT),
         coreListing,
         coreAuthority,
         mplCoreProgram: MPL_CORE_PROGRAM_ID,
@@ -1235,6 +1350,50 @@ export class AuctionProgram {
     return await this.sendAndConfirm(tx);
   }
 
+  /**
+   * List a Metaplex Core asset for an English auction in USDC. The seller posts a
+   * bond (a share of the start price) into `core_bond`, returned when the auction ends.
+   */
+  async listCoreAuction(
+    asset: PublicKey,
+    startPriceUsdc: number | bigint,
+    durationSeconds: number,
+    startTime?: number,
+    bidIncrement?: ListingOptions["bidIncrement"]
+  ): Promise<string> {
+    const coreListing = this.coreListingPda(asset);
+    const coreAuthority = this.coreAuthorityPda(asset);
+    const sellerPaymentAccount = await getAssociatedTokenAddress(USDC_MINT, this.wallet.publicKey);
+
+    const ix = await this.program.methods
+      .listCoreAuction(
+        new anchor.BN(startPriceUsdc.toString()),
+        new anchor.BN(durationSeconds),
+        startTime !== undefined ? new anchor.BN(startTime) : null,
+        bidIncrement ?? null
+      )
+      .accounts({
+        seller: this.wallet.publicKey,
+        asset,
+        collection: ARTIFACTE_COLLECTION,
+        paymentMint: USDC_MINT,
+        paymentMintConfig: paymentMintConfigPda(USDC_MINT),
+        coreListing,
+        coreBond: this.coreBondPda(asset),
+        sellerPaymentAccount,
+        coreAuthority,
+        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    const tx = new Transaction()
+      .add(ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }))
+      .add(ix);
+    return await this.sendAndConfirm(tx);
+  }
+
   /** Cancel an active Core listing while the seller still holds the asset. */
   async cancelCoreListing(asset: PublicKey): Promise<string> {
     const coreListing = this.coreListingPda(asset);
@@ -1253,6 +1412,7 @@ export class AuctionProgram {
         coreListing,
         coreAuthority,
         mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        ...(await this.coreBondAccounts(asset, listingAcc)),
         systemProgram: SystemProgram.programId,
       })
       .instruction();
@@ -1279,6 +1439,7 @@ export class AuctionProgram {
         coreListing,
         coreAuthority,
         mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        ...(await this.coreBondAccounts(asset, listingAcc)),
         systemProgram: SystemProgram.programId,
       })
       .instruction();
@@ -1302,14 +1463,7 @@ export class AuctionProgram {
     const collection: PublicKey = listingAcc.collection;
     const paymentMint: PublicKey = listingAcc.paymentMint;
 
-    // Resolve treasury (from PDA if initialized, fallback constant otherwise)
-    let treasury = TREASURY_WALLET;
-    try {
-      const cfg: any = await this.program.account.treasuryConfig.fetch(TREASURY_CONFIG_PDA);
-      if (cfg?.treasury) treasury = new PublicKey(cfg.treasury);
-    } catch {
-      // not initialized → fallback
-    }
+    const { treasury, treasuryConfig } = await this.resolveTreasury();
 
     const royalty = await this.fetchCoreRoyalties(asset, collection);
 
@@ -1348,7 +1502,7 @@ export class AuctionProgram {
         treasuryPaymentAccount: treasuryAta,
         creatorPaymentAccount: creatorAta,
         treasury,
-        treasuryConfig: TREASURY_CONFIG_PDA,
+        treasuryConfig,
         mplCoreProgram: MPL_CORE_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
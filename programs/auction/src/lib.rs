@@ -2,17 +2,25 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::solana_program::program_error::ProgramError;
 use anchor_lang::solana_program::pubkey;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use anchor_spl::token::{self, Token, Transfer};
 use anchor_spl::token_interface::{
     Mint as IfaceMint,
     TokenAccount as IfaceTokenAccount,
     TokenInterface,
 };
+use anchor_spl::token_interface;
+use anchor_spl::token_2022::Token2022;
+use spl_token_2022::extension::transfer_fee::{TransferFee, TransferFeeConfig};
 use spl_token_2022::extension::BaseStateWithExtensions;
+use spl_transfer_hook_interface::get_extra_account_metas_address;
 use spl_transfer_hook_interface::onchain::add_extra_accounts_for_execute_cpi;
 use anchor_spl::metadata::mpl_token_metadata::{
-    instructions::{TransferV1, TransferV1InstructionArgs},
+    instructions::{
+        DelegateSaleV1, DelegateSaleV1InstructionArgs, RevokeSaleV1, TransferV1,
+        TransferV1InstructionArgs,
+    },
 };
+use sha2::{Digest, Sha256};
 
 /// Transfer pNFT via Token Metadata TransferV1 CPI using Kinobi-generated builder.
 /// Uses mpl_token_metadata::instructions::TransferV1 for correct serialization.
@@ -90,6 +98,88 @@ fn transfer_pnft<'info>(
     }
     Ok(())
 }
+
+/// Approve (`delegated = true`) or revoke a Token Metadata Sale delegate on a
+/// pNFT. Delegating puts the token record in the `Listed` state: the pNFT stays
+/// frozen in the owner's wallet and only the delegate can transfer it until
+/// the owner revokes. `authority` is the token owner in both directions.
+#[allow(clippy::too_many_arguments)]
+fn set_pnft_sale_delegate<'info>(
+    delegated: bool,
+    token_metadata_program: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    metadata: &AccountInfo<'info>,
+    edition: &AccountInfo<'info>,
+    token_record: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    sysvar_instructions: &AccountInfo<'info>,
+    spl_token_program: &AccountInfo<'info>,
+    auth_rules_program: Option<&AccountInfo<'info>>,
+    auth_rules: Option<&AccountInfo<'info>>,
+) -> Result<()> {
+    require_keys_eq!(
+        *token_metadata_program.key,
+        anchor_spl::metadata::mpl_token_metadata::ID,
+        AuctionError::Unauthorized
+    );
+
+    let ix = if delegated {
+        DelegateSaleV1 {
+            delegate_record: None,
+            delegate: *delegate.key,
+            metadata: *metadata.key,
+            master_edition: Some(*edition.key),
+            token_record: Some(*token_record.key),
+            mint: *mint.key,
+            token: *token.key,
+            authority: *authority.key,
+            payer: *authority.key,
+            system_program: *system_program.key,
+            sysvar_instructions: *sysvar_instructions.key,
+            spl_token_program: Some(*spl_token_program.key),
+            authorization_rules_program: auth_rules_program.map(|a| *a.key),
+            authorization_rules: auth_rules.map(|a| *a.key),
+        }
+        .instruction(DelegateSaleV1InstructionArgs {
+            amount: 1,
+            authorization_data: None,
+        })
+    } else {
+        RevokeSaleV1 {
+            delegate_record: None,
+            delegate: *delegate.key,
+            metadata: *metadata.key,
+            master_edition: Some(*edition.key),
+            token_record: Some(*token_record.key),
+            mint: *mint.key,
+            token: *token.key,
+            authority: *authority.key,
+            payer: *authority.key,
+            system_program: *system_program.key,
+            sysvar_instructions: *sysvar_instructions.key,
+            spl_token_program: Some(*spl_token_program.key),
+            authorization_rules_program: auth_rules_program.map(|a| *a.key),
+            authorization_rules: auth_rules.map(|a| *a.key),
+        }
+        .instruction()
+    };
+
+    let mut account_infos = vec![
+        delegate.clone(), metadata.clone(), edition.clone(), token_record.clone(),
+        mint.clone(), token.clone(), authority.clone(), system_program.clone(),
+        sysvar_instructions.clone(), spl_token_program.clone(),
+    ];
+    if let Some(p) = auth_rules_program { account_infos.push(p.clone()); }
+    if let Some(r) = auth_rules { account_infos.push(r.clone()); }
+    account_infos.push(token_metadata_program.clone());
+
+    anchor_lang::solana_program::program::invoke(&ix, &account_infos)
+        .map_err(|_| error!(AuctionError::TransferFailed))?;
+    Ok(())
+}
 declare_id!("81s1tEx4MPdVvqS6X84Mok5K4N5fMbRLzcsT5eo2K8J3");
 
 /// Close a token account using the correct token program (works for both Token and Token-2022)
@@ -125,14 +215,51 @@ const DEPLOY_AUTHORITY_PUBKEY: Pubkey = pubkey!("H3s3zhbcDNrLgPbUQFZYvRd9xy58nVN
 const TREASURY_FALLBACK: &str = "82v8xATLqdvq3cS1CXwpygVUH926QKdAd4NVxD91r4a6";
 
 // Standard token mints
-const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
-const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
-const USDC_MINT_PUBKEY: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+const SOL_MINT_PUBKEY: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 const PLATFORM_FEE_BPS: u64 = 200;
 
+// How long a seller has after the auction ends to reveal a hidden reserve.
+// Once it lapses the reserve counts as met, so staying silent can't void a sale.
+const RESERVE_REVEAL_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+// Account sizes of Listing / CoreListing created before fields were appended
+// to their layouts; `migrate_listing` / `migrate_core_listing` grow them.
+const LEGACY_LISTING_LEN: usize = 241;
+const LEGACY_CORE_LISTING_LEN: usize = 153;
+// Legacy auctions raised bids by a flat 0.1 SOL in base units
+const LEGACY_BID_INCREMENT: u64 = 100_000_000;
+
 // Artifacte v2 (Metaplex Core) constants
-const ARTIFACTE_COLLECTION_ID: &str = "jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS";
 const ARTIFACTE_COLLECTION_PUBKEY: Pubkey = pubkey!("jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS");
+// Core auctions leave the asset in the seller's wallet, so the seller posts a
+// bond (bps of the start price) that is paid to the winner on non-delivery
+const CORE_AUCTION_BOND_BPS: u64 = 1000;
+
+// Attribute predicates a collection offer can carry (e.g. grader, grade, set, number)
+const MAX_OFFER_ATTRIBUTES: usize = 4;
+const MAX_ATTRIBUTE_LEN: usize = 32;
+
+// Items a single bundle can hold (bounded by the sale's compute and account budget)
+const MAX_BUNDLE_ITEMS: usize = 8;
+
+// Compressed NFT (Bubblegum) programs and the instruction discriminators we invoke
+const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+const SPL_ACCOUNT_COMPRESSION_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+const SPL_NOOP_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+const BUBBLEGUM_TRANSFER_DISCRIMINATOR: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+const BUBBLEGUM_DELEGATE_DISCRIMINATOR: [u8; 8] = [90, 147, 75, 178, 85, 88, 4, 137];
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+// WNS (Wen New Standard) transfer hook and royalty distribution programs
+const WNS_PROGRAM_ID: Pubkey = pubkey!("wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM");
+const WNS_DISTRIBUTION_PROGRAM_ID: Pubkey = pubkey!("diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay");
+const WNS_APPROVE_TRANSFER_DISCRIMINATOR: [u8; 8] = [198, 217, 247, 150, 208, 60, 169, 244];
+const WNS_ROYALTY_BASIS_POINTS_KEY: &str = "royalty_basis_points";
+// Accounts per NFT in the WNS remaining_accounts layout (see `list_item`)
+const WNS_ACCOUNT_COUNT: usize = 9;
+// WNS group / member accounts: 8-byte discriminator, then the group's
+// update_authority or the member's mint, then the group mint / member's group
+const WNS_GROUP_LINK_OFFSET: usize = 8 + 32;
 
 fn core_platform_fee_bps(collection: Pubkey) -> u64 {
     if collection == ARTIFACTE_COLLECTION_PUBKEY {
@@ -173,6 +300,466 @@ fn calculate_core_sale_split(price: u64, collection: Pubkey, royalty_bps: u16) -
     })
 }
 
+/// Seller bond a Core auction starting at `start_price` must post.
+fn core_auction_bond(start_price: u64) -> Result<u64> {
+    start_price
+        .checked_mul(CORE_AUCTION_BOND_BPS)
+        .and_then(|value| value.checked_div(10000))
+        .ok_or(error!(AuctionError::CalculationError))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SaleSplit {
+    platform_fee: u64,
+    baxus_fee: u64,
+    creator_royalty: u64,
+    seller_amount: u64,
+}
+
+/// Royalty a listing can be created with: at most 10% (1000 bps), and the
+/// same floor as `calculate_sale_split`, so the sale can always be split.
+fn validate_royalty_bps(royalty_bps: u16) -> Result<()> {
+    require!(royalty_bps <= 1000, AuctionError::RoyaltyTooHigh);
+    require!(
+        royalty_bps == 0 || royalty_bps >= 100,
+        AuctionError::InvalidRoyaltyBps
+    );
+    Ok(())
+}
+
+fn calculate_sale_split(price: u64, baxus_fee: bool, royalty_bps: u16) -> Result<SaleSplit> {
+    // Royalty floor: must be 0 or at least 1% (100 bps) to prevent rounding-to-zero bypass
+    require!(
+        royalty_bps == 0 || royalty_bps >= 100,
+        AuctionError::InvalidRoyaltyBps
+    );
+
+    // Checked arithmetic to prevent overflow
+    let platform_fee = price
+        .checked_mul(PLATFORM_FEE_BPS)
+        .and_then(|value| value.checked_div(10000))
+        .ok_or(AuctionError::CalculationError)?; // 2%
+    let baxus_fee = if baxus_fee {
+        price
+            .checked_mul(1000)
+            .and_then(|value| value.checked_div(10000))
+            .ok_or(AuctionError::CalculationError)? // 10%
+    } else {
+        0
+    };
+    let creator_royalty = price
+        .checked_mul(royalty_bps as u64)
+        .and_then(|value| value.checked_div(10000))
+        .ok_or(AuctionError::CalculationError)?;
+    let seller_amount = price
+        .checked_sub(platform_fee)
+        .ok_or(AuctionError::CalculationError)?
+        .checked_sub(baxus_fee)
+        .ok_or(AuctionError::CalculationError)?
+        .checked_sub(creator_royalty)
+        .ok_or(AuctionError::CalculationError)?;
+
+    Ok(SaleSplit {
+        platform_fee,
+        baxus_fee,
+        creator_royalty,
+        seller_amount,
+    })
+}
+
+/// What the seller, treasury and creator actually received for a sale, net of
+/// any Token-2022 transfer fee withheld on the way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SalePayout {
+    seller_amount: u64,
+    treasury_amount: u64,
+    creator_royalty: u64,
+}
+
+impl SaleSplit {
+    fn treasury_amount(&self) -> Result<u64> {
+        Ok(self
+            .platform_fee
+            .checked_add(self.baxus_fee)
+            .ok_or(AuctionError::CalculationError)?)
+    }
+
+    /// Payout when the legs arrive in full (native SOL, fee-less mints).
+    fn payout(&self) -> Result<SalePayout> {
+        Ok(SalePayout {
+            seller_amount: self.seller_amount,
+            treasury_amount: self.treasury_amount()?,
+            creator_royalty: self.creator_royalty,
+        })
+    }
+
+    /// Legs paid directly by `pay_sale_split`. WNS collects the creator royalty
+    /// itself during `approve_transfer`, so that leg is dropped for WNS sales.
+    fn direct_legs(&self, is_wns: bool) -> SaleSplit {
+        if is_wns {
+            SaleSplit { creator_royalty: 0, ..*self }
+        } else {
+            *self
+        }
+    }
+}
+
+/// Split a bundle `price` across its items by `weights`. All-zero weights mean
+/// an even split; otherwise every weight must be positive. Rounding dust goes
+/// to the last item so the shares always sum to `price`.
+fn bundle_item_prices(price: u64, weights: &[u16]) -> Result<Vec<u64>> {
+    require!(!weights.is_empty(), AuctionError::InvalidBundleWeights);
+    let even = weights.iter().all(|weight| *weight == 0);
+    require!(
+        even || weights.iter().all(|weight| *weight > 0),
+        AuctionError::InvalidBundleWeights
+    );
+    let total: u128 = if even {
+        weights.len() as u128
+    } else {
+        weights.iter().map(|weight| *weight as u128).sum()
+    };
+
+    let mut prices = Vec::with_capacity(weights.len());
+    let mut allocated = 0u64;
+    for weight in &weights[..weights.len() - 1] {
+        let weight = if even { 1 } else { *weight as u128 };
+        let share = u64::try_from(price as u128 * weight / total).map_err(|_| AuctionError::CalculationError)?;
+        allocated = allocated.checked_add(share).ok_or(AuctionError::CalculationError)?;
+        prices.push(share);
+    }
+    prices.push(price.checked_sub(allocated).ok_or(AuctionError::CalculationError)?);
+    Ok(prices)
+}
+
+/// Amount to send so the recipient nets `net_amount` after `fee` is withheld.
+fn amount_with_transfer_fee(fee: &TransferFee, net_amount: u64) -> Result<u64> {
+    Ok(fee
+        .calculate_pre_fee_amount(net_amount)
+        .ok_or(AuctionError::CalculationError)?)
+}
+
+/// Buy-now on an auction stays available until the high bid passes
+/// `cutoff_bps` of the buy-now price. A zero price means buy-now is off.
+fn buy_now_available(current_bid: u64, buy_now_price: u64, cutoff_bps: u16) -> Result<bool> {
+    if buy_now_price == 0 {
+        return Ok(false);
+    }
+    let cutoff = buy_now_price
+        .checked_mul(cutoff_bps as u64)
+        .and_then(|value| value.checked_div(10000))
+        .ok_or(AuctionError::CalculationError)?;
+
+    Ok(current_bid <= cutoff)
+}
+
+// Auction-house increment ladder: (bids below this many whole tokens, step in whole tokens)
+const TIERED_BID_INCREMENTS: [(u64, u64); 6] = [
+    (50, 1),
+    (200, 5),
+    (1_000, 25),
+    (5_000, 100),
+    (20_000, 250),
+    (100_000, 1_000),
+];
+const TIERED_BID_INCREMENT_TOP: u64 = 5_000;
+
+/// Minimum raise over `current_bid`, in payment-token base units.
+fn bid_increment_step(current_bid: u64, increment: BidIncrement, decimals: u8) -> Result<u64> {
+    let one_token = 10u64
+        .checked_pow(decimals as u32)
+        .ok_or(AuctionError::CalculationError)?;
+
+    let step = match increment {
+        // 0.1 whole payment tokens — the historical 0.1 SOL step
+        BidIncrement::Standard => one_token / 10,
+        BidIncrement::Absolute(step) => step,
+        BidIncrement::Percentage(bps) => current_bid
+            .checked_mul(bps as u64)
+            .and_then(|value| value.checked_div(10000))
+            .ok_or(AuctionError::CalculationError)?,
+        BidIncrement::Tiered => {
+            let whole_tokens = current_bid / one_token;
+            let step_tokens = TIERED_BID_INCREMENTS
+                .iter()
+                .find(|(below, _)| whole_tokens < *below)
+                .map_or(TIERED_BID_INCREMENT_TOP, |(_, step)| *step);
+            step_tokens
+                .checked_mul(one_token)
+                .ok_or(AuctionError::CalculationError)?
+        }
+    };
+
+    // Always require bids to actually move up
+    Ok(step.max(1))
+}
+
+/// Lowest acceptable bid: the opening price, or the current bid plus one step.
+fn min_next_bid(current_bid: u64, price: u64, increment: BidIncrement, decimals: u8) -> Result<u64> {
+    if current_bid == 0 {
+        return Ok(price);
+    }
+    current_bid
+        .checked_add(bid_increment_step(current_bid, increment, decimals)?)
+        .ok_or(error!(AuctionError::CalculationError))
+}
+
+/// Current price of a Dutch listing: decays from `start_price` at `start_time`
+/// to `floor_price` at `end_time`, then holds at the floor.
+fn dutch_price(
+    start_price: u64,
+    floor_price: u64,
+    start_time: i64,
+    end_time: i64,
+    curve: DutchCurve,
+    now: i64,
+) -> Result<u64> {
+    if now <= start_time {
+        return Ok(start_price);
+    }
+    if now >= end_time {
+        return Ok(floor_price);
+    }
+
+    let duration = end_time
+        .checked_sub(start_time)
+        .ok_or(AuctionError::CalculationError)? as u128;
+    let mut elapsed = now
+        .checked_sub(start_time)
+        .ok_or(AuctionError::CalculationError)? as u128;
+    if let DutchCurve::Stepwise { step_seconds } = curve {
+        // Price only drops at whole step boundaries
+        let step = step_seconds as u128;
+        elapsed = elapsed / step * step;
+    }
+
+    let drop = (start_price as u128)
+        .checked_sub(floor_price as u128)
+        .and_then(|range| range.checked_mul(elapsed))
+        .and_then(|value| value.checked_div(duration))
+        .ok_or(AuctionError::CalculationError)?;
+
+    Ok(start_price - drop as u64)
+}
+
+/// Outcome of a new bid against the standing (possibly proxy) high bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProxyBidOutcome {
+    challenger_wins: bool,
+    /// Visible `current_bid` after auto-bidding
+    current_bid: u64,
+}
+
+/// Resolve a challenger's bid (`bid`, up to `max_bid`) against the leader's
+/// escrowed maximum. Ties go to the earlier bidder; the winner's visible bid
+/// is one increment over the loser's maximum, capped at the winner's own.
+fn resolve_proxy_bid(
+    current_bid: u64,
+    leader_max: u64,
+    bid: u64,
+    max_bid: u64,
+    increment: BidIncrement,
+    decimals: u8,
+) -> Result<ProxyBidOutcome> {
+    if current_bid == 0 {
+        return Ok(ProxyBidOutcome { challenger_wins: true, current_bid: bid });
+    }
+
+    if max_bid > leader_max {
+        let outbid = leader_max
+            .checked_add(bid_increment_step(leader_max, increment, decimals)?)
+            .ok_or(AuctionError::CalculationError)?;
+        Ok(ProxyBidOutcome {
+            challenger_wins: true,
+            current_bid: outbid.max(bid).min(max_bid),
+        })
+    } else {
+        let defence = max_bid
+            .checked_add(bid_increment_step(max_bid, increment, decimals)?)
+            .ok_or(AuctionError::CalculationError)?;
+        Ok(ProxyBidOutcome {
+            challenger_wins: false,
+            current_bid: defence.min(leader_max).max(current_bid),
+        })
+    }
+}
+
+/// Compute the auction end time after a bid placed at `now`.
+///
+/// Bids landing inside the soft-close window push `end_time` out to
+/// `now + extension_seconds`, never past `max_end_time` (0 = uncapped).
+/// A zero window disables soft-close entirely.
+fn soft_close_end_time(
+    end_time: i64,
+    now: i64,
+    window_seconds: i64,
+    extension_seconds: i64,
+    max_end_time: i64,
+) -> Result<i64> {
+    if window_seconds == 0 {
+        return Ok(end_time);
+    }
+    let window_start = end_time
+        .checked_sub(window_seconds)
+        .ok_or(AuctionError::CalculationError)?;
+    if now < window_start {
+        return Ok(end_time);
+    }
+
+    let extended = now
+        .checked_add(extension_seconds)
+        .ok_or(AuctionError::CalculationError)?
+        .max(end_time);
+    if max_end_time > 0 {
+        Ok(extended.min(max_end_time.max(end_time)))
+    } else {
+        Ok(extended)
+    }
+}
+
+/// Extra seed scoping a listing's bid escrow and refund records to one listing
+/// instance (its `created_at`), so relisting the same mint or asset never shares
+/// them with an earlier auction. Listings migrated from the legacy layout have
+/// `created_at == 0` and keep the unscoped, pre-instance addresses.
+fn listing_instance_seed(created_at: i64) -> Vec<u8> {
+    if created_at == 0 {
+        Vec::new()
+    } else {
+        created_at.to_le_bytes().to_vec()
+    }
+}
+
+/// Commitment for a hidden reserve: sha256(reserve_price_le || salt).
+fn reserve_commitment(reserve_price: u64, salt: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(reserve_price.to_le_bytes());
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+/// Commitment for a sealed bid: sha256(amount_le || salt || bidder).
+/// Binding the bidder stops others from copying a commitment they saw on-chain.
+fn sealed_bid_commitment(amount: u64, salt: &[u8; 32], bidder: &Pubkey) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(amount.to_le_bytes());
+    hasher.update(salt);
+    hasher.update(bidder.as_ref());
+    hasher.finalize().into()
+}
+
+/// Seed for a collection offer's attribute predicates: sha256 over each
+/// length-prefixed key and value, in order. Plain floor bids hash no predicates.
+fn attribute_predicates_hash(attributes: &[AttributePredicate]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for attribute in attributes {
+        hasher.update((attribute.key.len() as u32).to_le_bytes());
+        hasher.update(attribute.key.as_bytes());
+        hasher.update((attribute.value.len() as u32).to_le_bytes());
+        hasher.update(attribute.value.as_bytes());
+    }
+    hasher.finalize().into()
+}
+
+fn validate_attribute_predicates(attributes: &[AttributePredicate]) -> Result<()> {
+    require!(
+        attributes.len() <= MAX_OFFER_ATTRIBUTES,
+        AuctionError::InvalidAttributePredicate
+    );
+    for (i, attribute) in attributes.iter().enumerate() {
+        require!(
+            !attribute.key.is_empty()
+                && attribute.key.len() <= MAX_ATTRIBUTE_LEN
+                && !attribute.value.is_empty()
+                && attribute.value.len() <= MAX_ATTRIBUTE_LEN,
+            AuctionError::InvalidAttributePredicate
+        );
+        require!(
+            attributes[..i].iter().all(|other| other.key != attribute.key),
+            AuctionError::InvalidAttributePredicate
+        );
+    }
+    Ok(())
+}
+
+/// Swap expiry: 0 means the swap never expires.
+fn swap_expired(expires_at: i64, now: i64) -> bool {
+    expires_at != 0 && now >= expires_at
+}
+
+fn validate_swap_terms(terms: &SwapTerms, offered_asset: Pubkey, now: i64) -> Result<()> {
+    require!(
+        terms.wanted != Pubkey::default() && terms.wanted != offered_asset,
+        AuctionError::InvalidSwapTerms
+    );
+    // Attribute predicates only narrow a collection swap
+    require!(
+        terms.attributes.is_empty() || terms.want == SwapWant::Collection,
+        AuctionError::InvalidSwapTerms
+    );
+    validate_attribute_predicates(&terms.attributes)?;
+    require!(
+        terms.expires_at == 0 || terms.expires_at > now,
+        AuctionError::InvalidSwapTerms
+    );
+    Ok(())
+}
+
+/// Every predicate must equal an attribute on the asset (exact key and value).
+fn attributes_match(asset_attributes: &[mpl_core::types::Attribute], predicates: &[AttributePredicate]) -> bool {
+    predicates.iter().all(|predicate| {
+        asset_attributes
+            .iter()
+            .any(|attribute| attribute.key == predicate.key && attribute.value == predicate.value)
+    })
+}
+
+/// Price the sealed-auction winner pays. Second-price auctions charge the
+/// runner-up's bid, but never less than `floor` (opening price or reserve).
+fn sealed_clearing_price(pricing: SealedPricing, highest_bid: u64, second_bid: u64, floor: u64) -> u64 {
+    match pricing {
+        SealedPricing::FirstPrice => highest_bid,
+        SealedPricing::SecondPrice => second_bid.max(floor).min(highest_bid),
+    }
+}
+
+/// WNS keeps the royalty as `royalty_basis_points` in the mint's additional
+/// metadata; absent means none.
+fn wns_royalty_bps(additional_metadata: &[(String, String)]) -> Result<u16> {
+    match additional_metadata
+        .iter()
+        .find(|(key, _)| key == WNS_ROYALTY_BASIS_POINTS_KEY)
+    {
+        Some((_, value)) => value
+            .parse::<u16>()
+            .ok()
+            .filter(|bps| *bps <= 10_000)
+            .ok_or_else(|| error!(AuctionError::InvalidRoyaltyBps)),
+        None => Ok(0),
+    }
+}
+
+/// Bubblegum V1 leaf hash: keccak(version || id || owner || delegate ||
+/// nonce_le || data_hash || creator_hash).
+fn compressed_leaf_hash(
+    asset_id: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    solana_keccak_hasher::hashv(&[
+        &[1u8],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        data_hash,
+        creator_hash,
+    ])
+    .to_bytes()
+}
+
 #[cfg(test)]
 fn is_missing_mpl_core_plugin_error(error: &ProgramError) -> bool {
     matches!(
@@ -186,14 +773,60 @@ fn is_missing_mpl_core_plugin_error(error: &ProgramError) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
+        amount_with_transfer_fee,
+        apply_legacy_listing_defaults,
+        attribute_predicates_hash,
+        attributes_match,
+        bundle_item_prices,
+        listing_expired,
+        listing_instance_seed,
+        resolve_listing_expiry,
+        bid_increment_step,
+        buy_now_available,
         calculate_core_sale_split,
+        calculate_sale_split,
+        core_auction_bond,
+        category_mask,
+        compressed_leaf_hash,
+        dutch_price,
         core_platform_fee_bps,
         is_missing_mpl_core_plugin_error,
+        min_next_bid,
+        reserve_commitment,
+        resolve_proxy_bid,
+        sealed_bid_commitment,
+        sealed_clearing_price,
+        soft_close_end_time,
+        swap_expired,
+        validate_attribute_predicates,
+        validate_duration,
+        validate_payment_mint_config,
+        validate_royalty_bps,
+        validate_swap_terms,
+        wns_royalty_bps,
         ARTIFACTE_COLLECTION_PUBKEY,
+        AttributePredicate,
+        BidIncrement,
+        CoreListing,
+        DutchCurve,
+        ItemCategory,
+        Listing,
+        ListingStatus,
+        ListingType,
+        PaymentMintConfig,
+        SealedPricing,
+        SwapTerms,
+        SwapWant,
+        TopUpPayer,
+        TransferFee,
+        LEGACY_BID_INCREMENT,
+        LEGACY_CORE_LISTING_LEN,
+        LEGACY_LISTING_LEN,
         PLATFORM_FEE_BPS,
     };
     use anchor_lang::solana_program::program_error::ProgramError;
     use anchor_lang::prelude::Pubkey;
+    use anchor_lang::{AccountDeserialize, AnchorSerialize, Discriminator, Space};
 
     #[test]
     fn identifies_only_missing_mpl_core_plugin_errors() {
@@ -237,962 +870,1477 @@ mod tests {
     fn core_sale_split_rejects_excessive_royalties() {
         assert!(calculate_core_sale_split(25_000_000, ARTIFACTE_COLLECTION_PUBKEY, 1001).is_err());
     }
-}
 
-/// Perform a Token-2022 transfer_checked CPI that properly supports transfer hooks.
-/// remaining_accounts must include the hook-related accounts (extra_metas, approve_account, hook_program).
-fn transfer_checked_with_hook<'info>(
-    token_program: &AccountInfo<'info>,
-    source: &AccountInfo<'info>,
-    mint: &AccountInfo<'info>,
-    destination: &AccountInfo<'info>,
-    authority: &AccountInfo<'info>,
-    remaining_accounts: &[AccountInfo<'info>],
-    amount: u64,
-    decimals: u8,
-    signer_seeds: &[&[&[u8]]],
-) -> Result<()> {
-    // Build the base transfer_checked instruction
-    let mut ix = spl_token_2022::instruction::transfer_checked(
-        token_program.key,
-        source.key,
-        mint.key,
-        destination.key,
-        authority.key,
-        &[],
-        amount,
-        decimals,
-    ).map_err(|_| AuctionError::TransferFailed)?;
+    #[test]
+    fn sale_split_applies_platform_baxus_and_royalty_fees() {
+        let split = calculate_sale_split(10_000_000, true, 500).unwrap();
 
-    // Collect all account infos for the CPI
-    let mut account_infos = vec![
-        source.clone(),
-        mint.clone(),
-        destination.clone(),
-        authority.clone(),
-    ];
+        assert_eq!(split.platform_fee, 200_000);
+        assert_eq!(split.baxus_fee, 1_000_000);
+        assert_eq!(split.creator_royalty, 500_000);
+        assert_eq!(split.seller_amount, 8_300_000);
+    }
 
-    // Find the transfer hook program ID from mint extensions
-    let mint_data = mint.try_borrow_data()?;
-    let mint_state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
-    let hook_program_id = if let Ok(hook) = mint_state.get_extension::<spl_token_2022::extension::transfer_hook::TransferHook>() {
-        let id: Option<Pubkey> = hook.program_id.into();
-        id
-    } else {
-        None
-    };
-    drop(mint_data);
+    #[test]
+    fn sale_split_rejects_sub_one_percent_royalties() {
+        assert!(calculate_sale_split(10_000_000, false, 50).is_err());
+        assert!(calculate_sale_split(10_000_000, false, 0).is_ok());
+    }
 
-    if let Some(hook_id) = hook_program_id {
-        // Add extra accounts for transfer hook execution
-        add_extra_accounts_for_execute_cpi(
-            &mut ix,
-            &mut account_infos,
-            &hook_id,
-            source.clone(),
-            mint.clone(),
-            destination.clone(),
-            authority.clone(),
-            amount,
-            remaining_accounts,
-        ).map_err(|_| AuctionError::TransferFailed)?;
+    #[test]
+    fn buy_now_closes_once_bids_pass_cutoff() {
+        assert!(buy_now_available(0, 1_000, 0).unwrap());
+        assert!(!buy_now_available(1, 1_000, 0).unwrap());
+        assert!(buy_now_available(750, 1_000, 7_500).unwrap());
+        assert!(!buy_now_available(751, 1_000, 7_500).unwrap());
+        assert!(!buy_now_available(0, 0, 10_000).unwrap());
     }
 
-    // Invoke with or without signer seeds
-    if signer_seeds.is_empty() {
-        anchor_lang::solana_program::program::invoke(&ix, &account_infos)?;
-    } else {
-        anchor_lang::solana_program::program::invoke_signed(&ix, &account_infos, signer_seeds)?;
+    #[test]
+    fn standard_increment_scales_with_mint_decimals() {
+        // wSOL (9 decimals): 0.1 SOL
+        assert_eq!(bid_increment_step(5_000_000_000, BidIncrement::Standard, 9).unwrap(), 100_000_000);
+        // USDC (6 decimals): 0.1 USDC, not 100 USDC
+        assert_eq!(bid_increment_step(5_000_000, BidIncrement::Standard, 6).unwrap(), 100_000);
     }
-    Ok(())
-}
 
-#[program]
-pub mod auction {
-    use super::*;
+    #[test]
+    fn absolute_and_percentage_increments() {
+        assert_eq!(bid_increment_step(5_000_000, BidIncrement::Absolute(250_000), 6).unwrap(), 250_000);
+        assert_eq!(bid_increment_step(5_000_000, BidIncrement::Percentage(500), 6).unwrap(), 250_000);
+        // Never a zero step
+        assert_eq!(bid_increment_step(10, BidIncrement::Percentage(1), 6).unwrap(), 1);
+    }
 
-    /// Initialize the treasury config PDA (one-time setup by deploy authority)
-    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
-        require!(
-            ctx.accounts.authority.key() == DEPLOY_AUTHORITY_PUBKEY,
-            AuctionError::Unauthorized
-        );
-        let config = &mut ctx.accounts.treasury_config;
-        config.treasury = ctx.accounts.authority.key(); // set initial treasury = deploy authority, update after
-        config.authority = ctx.accounts.authority.key();
-        config.bump = ctx.bumps.treasury_config;
-        Ok(())
+    #[test]
+    fn tiered_increment_follows_the_ladder() {
+        assert_eq!(bid_increment_step(49_000_000, BidIncrement::Tiered, 6).unwrap(), 1_000_000);
+        assert_eq!(bid_increment_step(150_000_000, BidIncrement::Tiered, 6).unwrap(), 5_000_000);
+        assert_eq!(bid_increment_step(1_000_000_000, BidIncrement::Tiered, 6).unwrap(), 100_000_000);
+        assert_eq!(bid_increment_step(250_000_000_000, BidIncrement::Tiered, 6).unwrap(), 5_000_000_000);
     }
 
-    /// Update treasury address (deploy authority only)
-    pub fn update_treasury(ctx: Context<UpdateTreasury>, new_treasury: Pubkey) -> Result<()> {
-        require!(
-            ctx.accounts.authority.key() == DEPLOY_AUTHORITY_PUBKEY,
-            AuctionError::Unauthorized
-        );
-        ctx.accounts.treasury_config.treasury = new_treasury;
-        emit!(TreasuryUpdated {
-            old_treasury: ctx.accounts.treasury_config.treasury,
-            new_treasury,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        Ok(())
+    #[test]
+    fn first_bid_only_needs_the_opening_price() {
+        assert_eq!(min_next_bid(0, 7_000_000, BidIncrement::Tiered, 6).unwrap(), 7_000_000);
+        assert_eq!(min_next_bid(7_000_000, 7_000_000, BidIncrement::Tiered, 6).unwrap(), 8_000_000);
     }
 
-    /// List an item for sale (either fixed price or auction)
-    ///
-    /// For WNS/Token-2022 NFTs: client MUST include a WNS `approve_transfer` IX
-    /// (amount=0) BEFORE this instruction in the same transaction.
-    /// remaining_accounts for Token-2022:
-    ///   [0] extra_metas_account PDA (readonly) - seeds: ["extra-account-metas", nft_mint]
-    ///   [1] approve_account PDA (writable) - seeds: ["approve-account", nft_mint]
-    ///   [2] wns_program (readonly)
-    pub fn list_item<'info>(
-        ctx: Context<'_, '_, '_, 'info, ListItem<'info>>,
-        listing_type: ListingType,
-        price: u64,
-        duration_seconds: Option<i64>,
-        category: ItemCategory,
-        royalty_basis_points: u16,
-        creator_address: Pubkey,
-    ) -> Result<()> {
-        let clock = Clock::get()?;
-        let listing = &mut ctx.accounts.listing;
+    #[test]
+    fn linear_dutch_price_interpolates_to_floor() {
+        let curve = DutchCurve::Linear;
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 50).unwrap(), 1_000);
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 125).unwrap(), 800);
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 150).unwrap(), 600);
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 500).unwrap(), 200);
+    }
 
-        // Validate price
-        require!(price > 0, AuctionError::InvalidPrice);
-        require!(price <= 1_000_000_000_000_000_000, AuctionError::InvalidPrice);
+    #[test]
+    fn stepwise_dutch_price_drops_on_step_boundaries() {
+        let curve = DutchCurve::Stepwise { step_seconds: 25 };
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 124).unwrap(), 1_000);
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 125).unwrap(), 800);
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 149).unwrap(), 800);
+        assert_eq!(dutch_price(1_000, 200, 100, 200, curve, 199).unwrap(), 400);
+    }
 
-        // Cap royalty basis points at 10% (1000 bps) to prevent fee manipulation
-        require!(royalty_basis_points <= 1000, AuctionError::RoyaltyTooHigh);
+    #[test]
+    fn proxy_bid_outbids_leader_by_one_increment() {
+        let outcome = resolve_proxy_bid(10, 50, 11, 100, BidIncrement::Absolute(5), 6).unwrap();
+        assert!(outcome.challenger_wins);
+        assert_eq!(outcome.current_bid, 55);
+
+        // Capped at the challenger's own maximum
+        let outcome = resolve_proxy_bid(10, 50, 11, 52, BidIncrement::Absolute(5), 6).unwrap();
+        assert!(outcome.challenger_wins);
+        assert_eq!(outcome.current_bid, 52);
+    }
 
-        // Validate category matches allowed payments
-        validate_category_and_payment(&category, ctx.accounts.payment_mint.key())?;
+    #[test]
+    fn standing_proxy_defends_against_lower_maximum() {
+        let outcome = resolve_proxy_bid(10, 50, 20, 30, BidIncrement::Absolute(5), 6).unwrap();
+        assert!(!outcome.challenger_wins);
+        assert_eq!(outcome.current_bid, 35);
+
+        // Ties go to the standing bid
+        let outcome = resolve_proxy_bid(10, 50, 20, 50, BidIncrement::Absolute(5), 6).unwrap();
+        assert!(!outcome.challenger_wins);
+        assert_eq!(outcome.current_bid, 50);
+    }
 
-        // Validate duration for auctions
-        if matches!(listing_type, ListingType::Auction) {
-            require!(
-                duration_seconds.is_some() && duration_seconds.unwrap() > 0,
-                AuctionError::InvalidDuration
-            );
-        }
+    #[test]
+    fn sealed_bid_commitment_binds_bidder() {
+        let salt = [3u8; 32];
+        let bidder = Pubkey::new_unique();
 
-        // Validate token program is SPL Token or Token-2022
-        require!(
-            ctx.accounts.nft_token_program.key() == Token::id() || 
-            ctx.accounts.nft_token_program.key() == spl_token_2022::id(),
-            AuctionError::InvalidTokenProgram
+        assert_eq!(
+            sealed_bid_commitment(1_000, &salt, &bidder),
+            sealed_bid_commitment(1_000, &salt, &bidder)
         );
+        assert_ne!(
+            sealed_bid_commitment(1_000, &salt, &bidder),
+            sealed_bid_commitment(1_000, &salt, &Pubkey::new_unique())
+        );
+    }
 
-        // Detect Token-2022
-        let is_token2022 = ctx.accounts.nft_token_program.key() != Token::id();
+    #[test]
+    fn sealed_clearing_price_by_pricing_rule() {
+        assert_eq!(sealed_clearing_price(SealedPricing::FirstPrice, 900, 700, 500), 900);
+        assert_eq!(sealed_clearing_price(SealedPricing::SecondPrice, 900, 700, 500), 700);
+        // Lone bidder pays the floor, never more than their own bid
+        assert_eq!(sealed_clearing_price(SealedPricing::SecondPrice, 900, 0, 500), 500);
+        assert_eq!(sealed_clearing_price(SealedPricing::SecondPrice, 900, 0, 1_000), 900);
+    }
 
-        listing.seller = ctx.accounts.seller.key();
-        listing.nft_mint = ctx.accounts.nft_mint.key();
-        listing.payment_mint = ctx.accounts.payment_mint.key();
-        listing.price = price;
-        listing.listing_type = listing_type;
-        listing.category = category;
-        listing.start_time = clock.unix_timestamp;
-        listing.end_time = if let Some(duration) = duration_seconds {
-            clock.unix_timestamp + duration
-        } else {
-            0
-        };
-        listing.status = ListingStatus::Active;
-        listing.escrow_nft_account = ctx.accounts.escrow_nft.key();
-        listing.current_bid = 0;
-        listing.highest_bidder = Pubkey::default();
-        listing.baxus_fee = false;
-        listing.is_token2022 = is_token2022;
-        listing.is_pnft = false;
-        listing.royalty_basis_points = royalty_basis_points;
-        listing.creator_address = creator_address;
-        listing.bump = ctx.bumps.listing;
+    #[test]
+    fn soft_close_ignores_bids_outside_the_window() {
+        assert_eq!(soft_close_end_time(1_000, 800, 120, 300, 0).unwrap(), 1_000);
+        assert_eq!(soft_close_end_time(1_000, 950, 0, 300, 0).unwrap(), 1_000);
+    }
 
-        // Transfer NFT from seller to escrow
-        if is_token2022 {
-            // Token-2022 with transfer hook: use proper hook-aware CPI
-            transfer_checked_with_hook(
-                &ctx.accounts.nft_token_program.to_account_info(),
-                &ctx.accounts.seller_nft_account.to_account_info(),
-                &ctx.accounts.nft_mint.to_account_info(),
-                &ctx.accounts.escrow_nft.to_account_info(),
-                &ctx.accounts.seller.to_account_info(),
-                ctx.remaining_accounts,
-                1,
-                0,
-                &[],
-            )?;
-        } else {
-            // Standard SPL Token
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.nft_token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.seller_nft_account.to_account_info(),
-                        to: ctx.accounts.escrow_nft.to_account_info(),
-                        authority: ctx.accounts.seller.to_account_info(),
-                    },
-                ),
-                1,
-            )?;
-        }
+    #[test]
+    fn soft_close_extends_bids_inside_the_window() {
+        assert_eq!(soft_close_end_time(1_000, 900, 120, 300, 0).unwrap(), 1_200);
+        // Never shortens an auction when the extension is smaller than the time left
+        assert_eq!(soft_close_end_time(1_000, 900, 120, 60, 0).unwrap(), 1_000);
+    }
 
-        emit!(ListingCreated {
-            nft_mint: listing.nft_mint,
-            seller: listing.seller,
-            listing_type: listing_type.clone(),
-            price,
-            category: category.clone(),
-            end_time: listing.end_time,
-            payment_mint: listing.payment_mint,
-        });
+    #[test]
+    fn soft_close_respects_hard_cap() {
+        assert_eq!(soft_close_end_time(1_000, 990, 120, 300, 1_100).unwrap(), 1_100);
+        assert_eq!(soft_close_end_time(1_100, 1_090, 120, 300, 1_100).unwrap(), 1_100);
+    }
 
-        Ok(())
+    #[test]
+    fn transfer_fee_gross_up_nets_the_requested_amount() {
+        let fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: 5_000_000.into(),
+            transfer_fee_basis_points: 150.into(),
+        };
+        for net in [1, 999, 1_000_000, 123_456_789] {
+            let gross = amount_with_transfer_fee(&fee, net).unwrap();
+            assert_eq!(gross - fee.calculate_fee(gross).unwrap(), net);
+        }
+        // Past the fee cap only the maximum fee is added
+        let net = 1_000_000_000;
+        assert_eq!(amount_with_transfer_fee(&fee, net).unwrap(), net + 5_000_000);
     }
 
-    /// Place a bid on an active auction (payment tokens only, no NFT transfer)
-    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
-        let listing = &mut ctx.accounts.listing;
-        let clock = Clock::get()?;
+    #[test]
+    fn payment_mint_config_gates_category_core_and_minimum() {
+        let config = PaymentMintConfig {
+            mint: Pubkey::new_unique(),
+            enabled: true,
+            allowed_categories: category_mask(&[ItemCategory::Spirits, ItemCategory::Watches]),
+            allow_core: false,
+            min_price: 1_000_000,
+            bid_increment: BidIncrement::Standard,
+            bump: 255,
+        };
 
-        require!(
-            matches!(listing.listing_type, ListingType::Auction),
-            AuctionError::NotAnAuction
-        );
-        require!(
-            listing.status == ListingStatus::Active,
-            AuctionError::ListingNotActive
-        );
-        require!(
-            clock.unix_timestamp < listing.end_time,
-            AuctionError::AuctionEnded
-        );
+        assert!(validate_payment_mint_config(&config, Some(ItemCategory::Watches), 1_000_000).is_ok());
+        assert!(validate_payment_mint_config(&config, Some(ItemCategory::DigitalArt), 1_000_000).is_err());
+        assert!(validate_payment_mint_config(&config, Some(ItemCategory::Spirits), 999_999).is_err());
+        assert!(validate_payment_mint_config(&config, None, 1_000_000).is_err());
 
-        // Prevent shill bidding — seller cannot bid on own auction
-        require!(
-            ctx.accounts.bidder.key() != listing.seller,
-            AuctionError::SellerCannotBid
-        );
+        let retired = PaymentMintConfig { enabled: false, ..config };
+        assert!(validate_payment_mint_config(&retired, Some(ItemCategory::Watches), 1_000_000).is_err());
+    }
 
-        // Minimum 0.1 SOL increment
-        let min_increment: u64 = 100_000_000;
-        let min_bid = if listing.current_bid > 0 {
-            listing.current_bid + min_increment
-        } else {
-            listing.price
+    #[test]
+    fn core_auction_bond_is_a_tenth_of_the_start_price() {
+        assert_eq!(core_auction_bond(1_000_000_000).unwrap(), 100_000_000);
+        assert_eq!(core_auction_bond(9).unwrap(), 0);
+        assert!(core_auction_bond(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn listing_instance_seed_scopes_new_listings_only() {
+        let mint = Pubkey::new_unique();
+        let legacy = Pubkey::find_program_address(&[b"bid_escrow", mint.as_ref()], &crate::ID).0;
+        let pda = |created_at: i64| {
+            Pubkey::find_program_address(
+                &[b"bid_escrow", mint.as_ref(), &listing_instance_seed(created_at)],
+                &crate::ID,
+            )
+            .0
         };
-        require!(amount >= min_bid, AuctionError::BidTooLow);
 
-        // Refund previous bidder
-        if listing.current_bid > 0 && listing.highest_bidder != Pubkey::default() {
-            // Validate previous_bidder_account belongs to actual previous highest bidder
-            require!(
-                ctx.accounts.previous_bidder_account.key() != Pubkey::default(),
-                AuctionError::InvalidRefundAccount
-            );
-            // The token account address must match the expected ATA
-            let expected_ata = anchor_spl::associated_token::get_associated_token_address(
-                &listing.highest_bidder,
-                &listing.payment_mint,
-            );
-            require!(
-                ctx.accounts.previous_bidder_account.key() == expected_ata,
-                AuctionError::InvalidRefundAccount
-            );
-            // Also validate the token account is owned by the SPL token program (not tampered)
-            require!(
-                ctx.accounts.previous_bidder_account.owner == &spl_token::ID,
-                AuctionError::InvalidRefundAccount
-            );
+        assert_eq!(pda(0), legacy);
+        assert_ne!(pda(1_700_000_000), legacy);
+        assert_ne!(pda(1_700_000_000), pda(1_700_000_001));
+    }
 
-            let bid_escrow_bump = ctx.bumps.bid_escrow;
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.bid_escrow.to_account_info(),
-                        to: ctx.accounts.previous_bidder_account.to_account_info(),
-                        authority: ctx.accounts.bid_escrow.to_account_info(),
-                    },
-                    &[&[
-                        b"bid_escrow",
-                        listing.nft_mint.as_ref(),
-                        &[bid_escrow_bump],
-                    ]],
-                ),
-                listing.current_bid,
-            )?;
-        }
+    #[test]
+    fn legacy_listing_grows_into_current_layout() {
+        let (seller, creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = Listing::DISCRIMINATOR.to_vec();
+        (seller, Pubkey::new_unique(), Pubkey::new_unique(), 5_000u64)
+            .serialize(&mut data)
+            .unwrap();
+        (ListingType::Auction, ItemCategory::Spirits, 10i64, 20i64, ListingStatus::Active)
+            .serialize(&mut data)
+            .unwrap();
+        (Pubkey::new_unique(), 7_000u64, Pubkey::new_unique(), false, true, false, 500u16, creator, 254u8)
+            .serialize(&mut data)
+            .unwrap();
+        assert_eq!(data.len(), LEGACY_LISTING_LEN);
+
+        data.resize(8 + Listing::INIT_SPACE, 0);
+        let mut listing = Listing::try_deserialize(&mut &data[..]).unwrap();
+        apply_legacy_listing_defaults(&mut listing);
+
+        assert_eq!(listing.seller, seller);
+        assert_eq!(listing.creator_address, creator);
+        assert_eq!(listing.current_bid, 7_000);
+        assert_eq!(listing.bump, 254);
+        assert!(listing.bid_increment == BidIncrement::Absolute(LEGACY_BID_INCREMENT));
+        assert_eq!(listing.highest_bidder_max, 7_000);
+        assert_eq!((listing.reserve_price, listing.buy_now_price, listing.soft_close_window), (0, 0, 0));
+        assert!(!listing.native_sol);
+        assert_eq!(listing.created_at, 0);
+    }
 
-        // Transfer new bid to escrow
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.bidder_token_account.to_account_info(),
-                    to: ctx.accounts.bid_escrow.to_account_info(),
-                    authority: ctx.accounts.bidder.to_account_info(),
-                },
-            ),
-            amount,
-        )?;
+    #[test]
+    fn legacy_core_listing_grows_into_fixed_price_listing() {
+        let seller = Pubkey::new_unique();
+        let mut data = CoreListing::DISCRIMINATOR.to_vec();
+        let (asset, collection, payment_mint) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        (seller, asset, collection, payment_mint, 9_000u64, 1_700_000_000i64, 253u8)
+            .serialize(&mut data)
+            .unwrap();
+        assert_eq!(data.len(), LEGACY_CORE_LISTING_LEN);
 
-        listing.current_bid = amount;
-        listing.highest_bidder = ctx.accounts.bidder.key();
+        data.resize(8 + CoreListing::INIT_SPACE, 0);
+        let listing = CoreListing::try_deserialize(&mut &data[..]).unwrap();
 
-        emit!(BidPlaced {
-            nft_mint: listing.nft_mint,
-            bidder: ctx.accounts.bidder.key(),
-            amount,
-            timestamp: clock.unix_timestamp,
-        });
+        assert_eq!(listing.seller, seller);
+        assert_eq!((listing.price, listing.created_at, listing.bump), (9_000, 1_700_000_000, 253));
+        assert!(listing.listing_type == ListingType::FixedPrice);
+        assert_eq!((listing.start_time, listing.end_time, listing.seller_bond), (0, 0, 0));
+    }
 
-        Ok(())
+    #[test]
+    fn reserve_commitment_binds_price_and_salt() {
+        let salt = [7u8; 32];
+        let commitment = reserve_commitment(5_000_000, &salt);
+
+        assert_eq!(commitment, reserve_commitment(5_000_000, &salt));
+        assert_ne!(commitment, reserve_commitment(5_000_001, &salt));
+        assert_ne!(commitment, reserve_commitment(5_000_000, &[8u8; 32]));
     }
 
-    /// Buy a fixed-price listing immediately
-    ///
-    /// For WNS/Token-2022 NFTs: client MUST include WNS `approve_transfer` IX
-    /// (amount=0) BEFORE this instruction in the same transaction.
-    /// remaining_accounts: same layout as list_item
-    pub fn buy_now<'info>(ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>) -> Result<()> {
-        let listing = &mut ctx.accounts.listing;
+    #[test]
+    fn listing_expiry_is_zero_or_in_the_future() {
+        assert_eq!(resolve_listing_expiry(0, 1_000, 500).unwrap(), 0);
+        assert_eq!(resolve_listing_expiry(2_000, 1_000, 500).unwrap(), 2_000);
+        // Must be after both now and a scheduled start
+        assert!(resolve_listing_expiry(500, 100, 500).is_err());
+        assert!(resolve_listing_expiry(900, 1_000, 500).is_err());
+    }
 
-        require!(
-            matches!(listing.listing_type, ListingType::FixedPrice),
-            AuctionError::NotFixedPrice
-        );
-        require!(
-            listing.status == ListingStatus::Active,
-            AuctionError::ListingNotActive
-        );
+    #[test]
+    fn only_fixed_price_listings_expire() {
+        assert!(!listing_expired(&ListingType::FixedPrice, 0, i64::MAX));
+        assert!(!listing_expired(&ListingType::FixedPrice, 1_000, 999));
+        assert!(listing_expired(&ListingType::FixedPrice, 1_000, 1_000));
+        // Auction close and Dutch decay end are not expiries
+        assert!(!listing_expired(&ListingType::Auction, 1_000, 2_000));
+        assert!(!listing_expired(&ListingType::Dutch, 1_000, 2_000));
+
+        assert!(validate_duration(&ListingType::FixedPrice, None).is_ok());
+        assert!(validate_duration(&ListingType::FixedPrice, Some(0)).is_err());
+        assert!(validate_duration(&ListingType::Auction, None).is_err());
+        assert!(validate_duration(&ListingType::Sealed, Some(3_600)).is_ok());
+    }
 
-        // Resolve treasury address: use config PDA if initialized, else fallback
-        let treasury_address = if let Some(ref config) = ctx.accounts.treasury_config {
-            config.treasury
-        } else {
-            TREASURY_FALLBACK.parse::<anchor_lang::prelude::Pubkey>().unwrap()
-        };
-        require!(
-            ctx.accounts.treasury_payment_account.owner == treasury_address,
-            AuctionError::Unauthorized
+    fn predicate(key: &str, value: &str) -> AttributePredicate {
+        AttributePredicate { key: key.to_string(), value: value.to_string() }
+    }
+
+    #[test]
+    fn attribute_predicates_match_exact_key_and_value() {
+        let asset = [
+            mpl_core::types::Attribute { key: "Grader".to_string(), value: "PSA".to_string() },
+            mpl_core::types::Attribute { key: "Grade".to_string(), value: "10".to_string() },
+            mpl_core::types::Attribute { key: "Set".to_string(), value: "Base Set".to_string() },
+        ];
+
+        assert!(attributes_match(&asset, &[]));
+        assert!(attributes_match(&asset, &[predicate("Grade", "10"), predicate("Grader", "PSA")]));
+        assert!(!attributes_match(&asset, &[predicate("Grade", "9")]));
+        assert!(!attributes_match(&asset, &[predicate("grade", "10")]));
+        assert!(!attributes_match(&asset, &[predicate("Grader", "PSA"), predicate("Number", "4")]));
+    }
+
+    #[test]
+    fn attribute_predicates_hash_and_validation() {
+        // Length prefixes keep key/value boundaries distinct
+        assert_ne!(
+            attribute_predicates_hash(&[predicate("ab", "c")]),
+            attribute_predicates_hash(&[predicate("a", "bc")])
         );
-        require!(
-            ctx.accounts.treasury.key() == treasury_address,
-            AuctionError::Unauthorized
+        assert_ne!(
+            attribute_predicates_hash(&[]),
+            attribute_predicates_hash(&[predicate("Grade", "10")])
         );
 
-        // Calculate fees (checked arithmetic to prevent overflow)
-        let platform_fee = listing.price
-            .checked_mul(200)
-            .and_then(|x| x.checked_div(10000))
-            .ok_or(AuctionError::CalculationError)?; // 2%
-        let baxus_fee = if listing.baxus_fee {
-            listing.price
-                .checked_mul(1000)
-                .and_then(|x| x.checked_div(10000))
-                .ok_or(AuctionError::CalculationError)? // 10%
-        } else {
-            0u64
+        assert!(validate_attribute_predicates(&[predicate("Grader", "PSA"), predicate("Grade", "10")]).is_ok());
+        assert!(validate_attribute_predicates(&[predicate("Grade", "10"), predicate("Grade", "9")]).is_err());
+        assert!(validate_attribute_predicates(&[predicate("", "10")]).is_err());
+        assert!(validate_attribute_predicates(&[predicate("Grade", &"9".repeat(33))]).is_err());
+        let too_many: Vec<_> = (0..5).map(|i| predicate(&i.to_string(), "x")).collect();
+        assert!(validate_attribute_predicates(&too_many).is_err());
+    }
+
+    #[test]
+    fn bundle_item_prices_split_by_weight() {
+        assert_eq!(bundle_item_prices(100, &[0, 0, 0]).unwrap(), vec![33, 33, 34]);
+        assert_eq!(bundle_item_prices(1_000, &[1, 3]).unwrap(), vec![250, 750]);
+        assert_eq!(bundle_item_prices(10, &[1, 1, 1]).unwrap(), vec![3, 3, 4]);
+        assert_eq!(bundle_item_prices(u64::MAX, &[u16::MAX, u16::MAX]).unwrap().iter().sum::<u64>(), u64::MAX);
+
+        // Mixed zero / non-zero weights and empty bundles are rejected
+        assert!(bundle_item_prices(100, &[0, 1]).is_err());
+        assert!(bundle_item_prices(100, &[]).is_err());
+    }
+
+    #[test]
+    fn swap_terms_validation_and_expiry() {
+        let offered = Pubkey::new_unique();
+        let terms = |want, wanted, attributes: Vec<AttributePredicate>, expires_at| SwapTerms {
+            want,
+            wanted,
+            attributes,
+            top_up: 0,
+            top_up_payer: TopUpPayer::Taker,
+            expires_at,
         };
-        let creator_royalty = listing.price
-            .checked_mul(listing.royalty_basis_points as u64)
-            .and_then(|x| x.checked_div(10000))
-            .ok_or(AuctionError::CalculationError)?;
+        let collection = Pubkey::new_unique();
 
-        // Royalty floor: must be 0 or at least 1% (100 bps) to prevent rounding-to-zero bypass
-        require!(
-            listing.royalty_basis_points == 0 || listing.royalty_basis_points >= 100,
-            AuctionError::InvalidRoyaltyBps
-        );
+        assert!(validate_swap_terms(&terms(SwapWant::Asset, Pubkey::new_unique(), vec![], 0), offered, 100).is_ok());
+        assert!(validate_swap_terms(&terms(SwapWant::Collection, collection, vec![predicate("Grade", "10")], 200), offered, 100).is_ok());
 
-        // Validate creator_payment_account when royalty > 0 (prevents royalty redirection)
-        if creator_royalty > 0 {
-            let expected_creator_ata = anchor_spl::associated_token::get_associated_token_address(
-                &listing.creator_address,
-                &listing.payment_mint,
-            );
-            require!(
-                ctx.accounts.creator_payment_account.key() == expected_creator_ata,
-                AuctionError::InvalidCreatorAccount
-            );
-        }
+        // Must want something other than the offered asset
+        assert!(validate_swap_terms(&terms(SwapWant::Asset, offered, vec![], 0), offered, 100).is_err());
+        assert!(validate_swap_terms(&terms(SwapWant::Asset, Pubkey::default(), vec![], 0), offered, 100).is_err());
+        // Attributes only narrow collection swaps
+        assert!(validate_swap_terms(&terms(SwapWant::Asset, collection, vec![predicate("Grade", "10")], 0), offered, 100).is_err());
+        assert!(validate_swap_terms(&terms(SwapWant::Collection, collection, vec![], 100), offered, 100).is_err());
 
-        // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
-        listing.status = ListingStatus::Settled;
+        assert!(!swap_expired(0, i64::MAX));
+        assert!(!swap_expired(200, 199));
+        assert!(swap_expired(200, 200));
+    }
 
-        let seller_amount = listing
-            .price
-            .checked_sub(platform_fee)
-            .ok_or(AuctionError::CalculationError)?
-            .checked_sub(baxus_fee)
-            .ok_or(AuctionError::CalculationError)?
-            .checked_sub(creator_royalty)
-            .ok_or(AuctionError::CalculationError)?;
+    #[test]
+    fn compressed_leaf_hash_tracks_owner_and_delegate() {
+        let asset = Pubkey::new_unique();
+        let seller = Pubkey::new_unique();
+        let listing = Pubkey::new_unique();
+        let data_hash = [1u8; 32];
+        let creator_hash = [2u8; 32];
+        let listed = compressed_leaf_hash(&asset, &seller, &listing, 7, &data_hash, &creator_hash);
+
+        assert_eq!(listed, compressed_leaf_hash(&asset, &seller, &listing, 7, &data_hash, &creator_hash));
+        // Any change to the leaf — new owner, revoked delegate, other nonce — makes the listing stale
+        assert_ne!(listed, compressed_leaf_hash(&asset, &Pubkey::new_unique(), &listing, 7, &data_hash, &creator_hash));
+        assert_ne!(listed, compressed_leaf_hash(&asset, &seller, &seller, 7, &data_hash, &creator_hash));
+        assert_ne!(listed, compressed_leaf_hash(&asset, &seller, &listing, 8, &data_hash, &creator_hash));
+    }
 
-        // Payment: buyer → seller
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.buyer_payment_account.to_account_info(),
-                    to: ctx.accounts.seller_payment_account.to_account_info(),
-                    authority: ctx.accounts.buyer.to_account_info(),
-                },
-            ),
-            seller_amount,
-        )?;
+    #[test]
+    fn listed_royalty_must_be_splittable() {
+        for royalty_bps in [0, 100, 500, 1000] {
+            assert!(validate_royalty_bps(royalty_bps).is_ok());
+        }
+        // Below the sale split's 1% floor, or above the 10% cap
+        for royalty_bps in [1, 99, 1001, 10_000] {
+            assert!(validate_royalty_bps(royalty_bps).is_err());
+        }
+    }
 
-        // Payment: buyer → treasury (platform fee + BAXUS fee)
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.buyer_payment_account.to_account_info(),
-                    to: ctx.accounts.treasury_payment_account.to_account_info(),
-                    authority: ctx.accounts.buyer.to_account_info(),
-                },
-            ),
-            platform_fee + baxus_fee,
-        )?;
-
-        // Creator royalty — always enforced
-        if creator_royalty > 0 {
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.buyer_payment_account.to_account_info(),
-                        to: ctx.accounts.creator_payment_account.to_account_info(),
-                        authority: ctx.accounts.buyer.to_account_info(),
-                    },
-                ),
-                creator_royalty,
-            )?;
-        }
-
-        // Transfer NFT: escrow → buyer
-        let escrow_bump = ctx.bumps.escrow_nft;
-        let nft_mint_key = listing.nft_mint;
-        let escrow_seeds: &[&[u8]] = &[
-            b"escrow_nft",
-            nft_mint_key.as_ref(),
-            &[escrow_bump],
-        ];
-
-        if listing.is_token2022 {
-            // Token-2022 with transfer hook: escrow → buyer
-            transfer_checked_with_hook(
-                &ctx.accounts.nft_token_program.to_account_info(),
-                &ctx.accounts.escrow_nft.to_account_info(),
-                &ctx.accounts.nft_mint.to_account_info(),
-                &ctx.accounts.buyer_nft_account.to_account_info(),
-                &ctx.accounts.escrow_nft.to_account_info(),
-                ctx.remaining_accounts,
-                1,
-                0,
-                &[escrow_seeds],
-            )?;
-        } else {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.nft_token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.escrow_nft.to_account_info(),
-                        to: ctx.accounts.buyer_nft_account.to_account_info(),
-                        authority: ctx.accounts.escrow_nft.to_account_info(),
-                    },
-                    &[escrow_seeds],
-                ),
-                1,
-            )?;
-        }
-
-        emit!(ItemPurchased {
-            nft_mint: listing.nft_mint,
-            seller: listing.seller,
-            buyer: ctx.accounts.buyer.key(),
-            price: listing.price,
-            platform_fee,
-            creator_royalty,
-        });
-
-        // Close escrow_nft token account via CPI — rent to treasury (revenue)
-        close_token_account_cpi(
-            &ctx.accounts.nft_token_program.to_account_info(),
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &ctx.accounts.treasury.to_account_info(),
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &[escrow_seeds],
-        )?;
+    #[test]
+    fn wns_royalty_bps_reads_additional_metadata() {
+        let metadata = |entries: &[(&str, &str)]| {
+            entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<Vec<_>>()
+        };
 
-        // Close listing account (owned by our program) — rent to treasury (revenue)
-        let listing_info = ctx.accounts.listing.to_account_info();
-        let treasury_info = ctx.accounts.treasury.to_account_info();
-        let dest_starting_lamports = treasury_info.lamports();
-        **treasury_info.lamports.borrow_mut() = dest_starting_lamports
-            .checked_add(listing_info.lamports())
-            .unwrap();
-        **listing_info.lamports.borrow_mut() = 0;
-        listing_info.assign(&anchor_lang::solana_program::system_program::ID);
-        listing_info.realloc(0, false)?;
+        assert_eq!(wns_royalty_bps(&metadata(&[("royalty_basis_points", "500")])).unwrap(), 500);
+        // Creator shares sit alongside the royalty entry
+        assert_eq!(
+            wns_royalty_bps(&metadata(&[("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin", "100"), ("royalty_basis_points", "250")])).unwrap(),
+            250
+        );
+        assert_eq!(wns_royalty_bps(&metadata(&[])).unwrap(), 0);
 
-        Ok(())
+        assert!(wns_royalty_bps(&metadata(&[("royalty_basis_points", "abc")])).is_err());
+        assert!(wns_royalty_bps(&metadata(&[("royalty_basis_points", "10001")])).is_err());
     }
+}
 
-    /// Cancel a listing (seller only, auctions only if no bids)
-    ///
-    /// For WNS/Token-2022: client MUST include WNS `approve_transfer` (amount=0)
-    /// remaining_accounts: same layout as list_item
-    pub fn cancel_listing<'info>(ctx: Context<'_, '_, '_, 'info, CancelListing<'info>>) -> Result<()> {
-        let listing = &mut ctx.accounts.listing;
+/// Perform a Token-2022 transfer_checked CPI that properly supports transfer hooks.
+/// remaining_accounts must include the hook program, its extra_metas PDA and the
+/// accounts that PDA lists (e.g. the WNS approve_account), in any order.
+fn transfer_checked_with_hook<'info>(
+    token_program: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    // Build the base transfer_checked instruction
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        source.key,
+        mint.key,
+        destination.key,
+        authority.key,
+        &[],
+        amount,
+        decimals,
+    ).map_err(|_| AuctionError::TransferFailed)?;
+
+    // Collect all account infos for the CPI
+    let mut account_infos = vec![
+        source.clone(),
+        mint.clone(),
+        destination.clone(),
+        authority.clone(),
+    ];
 
+    if let Some(hook_id) = transfer_hook_program_id(mint)? {
+        let passed = |key: Pubkey| remaining_accounts.iter().any(|account| account.key() == key);
+        require!(passed(hook_id), AuctionError::MissingTransferHookProgram);
         require!(
-            ctx.accounts.seller.key() == listing.seller,
-            AuctionError::Unauthorized
+            passed(get_extra_account_metas_address(mint.key, &hook_id)),
+            AuctionError::MissingExtraMetasAccount
         );
 
-        if matches!(listing.listing_type, ListingType::Auction) {
-            require!(
-                listing.current_bid == 0,
-                AuctionError::CannotCancelWithBids
-            );
-        }
+        // Add extra accounts for transfer hook execution
+        add_extra_accounts_for_execute_cpi(
+            &mut ix,
+            &mut account_infos,
+            &hook_id,
+            source.clone(),
+            mint.clone(),
+            destination.clone(),
+            authority.clone(),
+            amount,
+            remaining_accounts,
+        ).map_err(|_| AuctionError::InvalidTransferHookAccounts)?;
+    }
 
-        // Return NFT: escrow → seller
-        let escrow_bump = ctx.bumps.escrow_nft;
-        let nft_mint_key = listing.nft_mint;
-        let escrow_seeds: &[&[u8]] = &[
-            b"escrow_nft",
-            nft_mint_key.as_ref(),
-            &[escrow_bump],
-        ];
+    // Invoke with or without signer seeds
+    if signer_seeds.is_empty() {
+        anchor_lang::solana_program::program::invoke(&ix, &account_infos)?;
+    } else {
+        anchor_lang::solana_program::program::invoke_signed(&ix, &account_infos, signer_seeds)?;
+    }
+    Ok(())
+}
 
-        if listing.is_token2022 {
-            // Token-2022 with transfer hook: escrow → seller (cancel)
-            transfer_checked_with_hook(
-                &ctx.accounts.nft_token_program.to_account_info(),
-                &ctx.accounts.escrow_nft.to_account_info(),
-                &ctx.accounts.nft_mint.to_account_info(),
-                &ctx.accounts.seller_nft_account.to_account_info(),
-                &ctx.accounts.escrow_nft.to_account_info(),
-                ctx.remaining_accounts,
-                1,
-                0,
-                &[escrow_seeds],
-            )?;
-        } else {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.nft_token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.escrow_nft.to_account_info(),
-                        to: ctx.accounts.seller_nft_account.to_account_info(),
-                        authority: ctx.accounts.escrow_nft.to_account_info(),
-                    },
-                    &[escrow_seeds],
-                ),
-                1,
-            )?;
-        }
+/// Transfer-hook program of a Token-2022 mint, if it has one.
+fn transfer_hook_program_id(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    Ok(mint_state
+        .get_extension::<spl_token_2022::extension::transfer_hook::TransferHook>()
+        .ok()
+        .and_then(|hook| Option::<Pubkey>::from(hook.program_id)))
+}
 
-        emit!(ListingCancelled {
-            nft_mint: listing.nft_mint,
-            seller: listing.seller,
-        });
+/// WNS accounts at the front of `remaining_accounts` (layout on `list_item`).
+struct WnsAccounts<'a, 'info> {
+    wns_program: &'a AccountInfo<'info>,
+    approve_account: &'a AccountInfo<'info>,
+    distribution_program: &'a AccountInfo<'info>,
+    distribution_account: &'a AccountInfo<'info>,
+    distribution_token_account: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    /// Mint of the NFT's WNS collection group; seeds the distribution PDA
+    group_mint: Pubkey,
+}
 
-        // Close escrow_nft token account via CPI, return rent to seller
-        close_token_account_cpi(
-            &ctx.accounts.nft_token_program.to_account_info(),
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &ctx.accounts.seller.to_account_info(),
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &[escrow_seeds],
-        )?;
+/// Payment side of a WNS sale: royalty is taken from `authority_token_account`.
+#[derive(Clone, Copy)]
+struct WnsPayment<'a, 'info> {
+    payment_mint: &'a AccountInfo<'info>,
+    payment_token_program: &'a AccountInfo<'info>,
+    authority_token_account: &'a AccountInfo<'info>,
+}
 
-        // Close listing account (owned by our program), return rent to seller
-        let listing_info = ctx.accounts.listing.to_account_info();
-        let seller_info = ctx.accounts.seller.to_account_info();
-        let dest_starting_lamports = seller_info.lamports();
-        **seller_info.lamports.borrow_mut() = dest_starting_lamports
-            .checked_add(listing_info.lamports())
-            .unwrap();
-        **listing_info.lamports.borrow_mut() = 0;
-        listing_info.assign(&anchor_lang::solana_program::system_program::ID);
-        listing_info.realloc(0, false)?;
+/// Whether `nft_mint` is a Token-2022 mint with the WNS transfer hook.
+fn is_wns_mint(nft_mint: &AccountInfo) -> Result<bool> {
+    Ok(*nft_mint.owner == spl_token_2022::id() && transfer_hook_program_id(nft_mint)? == Some(WNS_PROGRAM_ID))
+}
 
-        Ok(())
+/// Validate the WNS `remaining_accounts` layout for `nft_mint`. Returns `None`
+/// for mints whose transfer hook is not WNS (or that have none).
+fn wns_accounts<'a, 'info>(
+    nft_mint: &AccountInfo<'info>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Option<WnsAccounts<'a, 'info>>> {
+    if !is_wns_mint(nft_mint)? {
+        return Ok(None);
     }
+    let [wns_program, extra_metas, approve_account, distribution_program, distribution_account, distribution_token_account, system_program, member_account, group_account, ..] =
+        remaining_accounts
+    else {
+        return err!(AuctionError::InvalidWnsAccountLayout);
+    };
 
-    /// Settle an auction after end time
-    ///
-    /// For WNS/Token-2022: client MUST include WNS `approve_transfer` (amount=0)
-    /// remaining_accounts: same layout as list_item
-    pub fn settle_auction<'info>(ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>) -> Result<()> {
-        // Resolve treasury address: use config PDA if initialized, else fallback
-        let treasury_address = if let Some(ref config) = ctx.accounts.treasury_config {
-            config.treasury
-        } else {
-            TREASURY_FALLBACK.parse::<anchor_lang::prelude::Pubkey>().unwrap()
-        };
-        require!(
-            ctx.accounts.treasury_payment_account.owner == treasury_address,
-            AuctionError::Unauthorized
-        );
-        require!(
-            ctx.accounts.treasury.key() == treasury_address,
-            AuctionError::Unauthorized
-        );
-
-        let listing = &mut ctx.accounts.listing;
-        let clock = Clock::get()?;
+    let (expected_approve_account, _) =
+        Pubkey::find_program_address(&[b"approve-account", nft_mint.key.as_ref()], &WNS_PROGRAM_ID);
+    require!(
+        wns_program.key() == WNS_PROGRAM_ID
+            && extra_metas.key() == get_extra_account_metas_address(nft_mint.key, &WNS_PROGRAM_ID)
+            && approve_account.key() == expected_approve_account
+            && distribution_program.key() == WNS_DISTRIBUTION_PROGRAM_ID
+            && system_program.key() == anchor_lang::system_program::ID,
+        AuctionError::InvalidWnsAccountLayout
+    );
 
-        require!(
-            matches!(listing.listing_type, ListingType::Auction),
-            AuctionError::NotAnAuction
-        );
-        require!(
-            listing.status == ListingStatus::Active,
-            AuctionError::ListingNotActive
-        );
-        require!(
-            clock.unix_timestamp >= listing.end_time,
-            AuctionError::AuctionNotEnded
-        );
+    // The collection is read from WNS's own member and group accounts, so the
+    // royalty cannot be routed to another collection's distribution account
+    let (expected_member_account, _) =
+        Pubkey::find_program_address(&[b"member", nft_mint.key.as_ref()], &WNS_PROGRAM_ID);
+    require!(
+        member_account.key() == expected_member_account
+            && *member_account.owner == WNS_PROGRAM_ID
+            && *group_account.owner == WNS_PROGRAM_ID
+            && group_account.key() == wns_group_link(member_account)?,
+        AuctionError::InvalidWnsAccountLayout
+    );
+    Ok(Some(WnsAccounts {
+        wns_program,
+        approve_account,
+        distribution_program,
+        distribution_account,
+        distribution_token_account,
+        system_program,
+        group_mint: wns_group_link(group_account)?,
+    }))
+}
 
-        let bid_escrow_bump = ctx.bumps.bid_escrow;
-        let nft_mint_key = listing.nft_mint;
-        let escrow_bump = ctx.bumps.escrow_nft;
+/// The member's group account, or the group's mint.
+fn wns_group_link(account: &AccountInfo) -> Result<Pubkey> {
+    let data = account.try_borrow_data()?;
+    data.get(WNS_GROUP_LINK_OFFSET..WNS_GROUP_LINK_OFFSET + 32)
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(Pubkey::new_from_array)
+        .ok_or_else(|| error!(AuctionError::InvalidWnsAccountLayout))
+}
 
-        if listing.current_bid > 0 {
-            // Validate buyer_nft_account is owned by the highest bidder
-            // (prevents redirecting the NFT to an attacker's account)
-            let buyer_nft_owner = ctx.accounts.buyer_nft_account.owner;
-            require!(
-                buyer_nft_owner == listing.highest_bidder,
-                AuctionError::InvalidBuyerAccount
-            );
+/// WNS layout of each WNS NFT among `nft_mints`, in order, consumed from the
+/// front of `remaining_accounts`. Entries are `None` for other mints.
+fn wns_accounts_each<'a, 'info>(
+    nft_mints: &[&AccountInfo<'info>],
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Vec<Option<WnsAccounts<'a, 'info>>>> {
+    let mut rest = remaining_accounts;
+    let mut layouts = Vec::with_capacity(nft_mints.len());
+    for nft_mint in nft_mints {
+        let wns = wns_accounts(nft_mint, rest)?;
+        if wns.is_some() {
+            rest = &rest[WNS_ACCOUNT_COUNT..];
+        }
+        layouts.push(wns);
+    }
+    Ok(layouts)
+}
 
-            // Auction has bids: distribute payments + transfer NFT to winner
-            // Checked arithmetic to prevent overflow
-            let platform_fee = listing.current_bid
-                .checked_mul(200)
-                .and_then(|x| x.checked_div(10000))
-                .ok_or(AuctionError::CalculationError)?; // 2%
-            let baxus_fee = if listing.baxus_fee {
-                listing.current_bid
-                    .checked_mul(1000)
-                    .and_then(|x| x.checked_div(10000))
-                    .ok_or(AuctionError::CalculationError)? // 10%
-            } else {
-                0u64
-            };
-            let creator_royalty = listing.current_bid
-                .checked_mul(listing.royalty_basis_points as u64)
-                .and_then(|x| x.checked_div(10000))
-                .ok_or(AuctionError::CalculationError)?;
+/// WNS `approve_transfer` CPI, made before the NFT moves so the WNS hook sees
+/// an approval in the same slot. WNS takes its royalty on `amount` from
+/// `authority` into the collection's distribution account. Moves that are not
+/// sales pass `amount` 0 and no `payment`, which WNS treats as a SOL payment.
+#[allow(clippy::too_many_arguments)]
+fn approve_wns_transfer<'info>(
+    wns: &WnsAccounts<'_, 'info>,
+    payer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    nft_mint: &AccountInfo<'info>,
+    nft_token_program: &AccountInfo<'info>,
+    amount: u64,
+    payment: Option<WnsPayment<'_, 'info>>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+    // Distribution PDA of the NFT's collection for this payment mint (SOL: the
+    // system program id, i.e. the default key)
+    let distribution_payment_mint = payment
+        .as_ref()
+        .map_or(Pubkey::default(), |payment| payment.payment_mint.key());
+    let (expected_distribution_account, _) = Pubkey::find_program_address(
+        &[wns.group_mint.as_ref(), distribution_payment_mint.as_ref()],
+        &WNS_DISTRIBUTION_PROGRAM_ID,
+    );
+    require_keys_eq!(
+        wns.distribution_account.key(),
+        expected_distribution_account,
+        AuctionError::InvalidWnsAccountLayout
+    );
 
-            // Royalty floor: must be 0 or at least 1% (100 bps) to prevent rounding-to-zero bypass
-            require!(
-                listing.royalty_basis_points == 0 || listing.royalty_basis_points >= 100,
-                AuctionError::InvalidRoyaltyBps
+    // Optional WNS accounts are passed as the WNS program id when unused
+    let (payment_mint, distribution_token_account, authority_token_account, payment_token_program) = match &payment {
+        Some(payment) => {
+            require_keys_eq!(
+                wns.distribution_token_account.key(),
+                anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                    wns.distribution_account.key,
+                    payment.payment_mint.key,
+                    payment.payment_token_program.key,
+                ),
+                AuctionError::InvalidWnsAccountLayout
             );
+            (
+                payment.payment_mint,
+                wns.distribution_token_account,
+                payment.authority_token_account,
+                payment.payment_token_program,
+            )
+        }
+        None => (wns.system_program, wns.wns_program, wns.wns_program, wns.wns_program),
+    };
+    let optional = |account: &AccountInfo| {
+        if account.key() == WNS_PROGRAM_ID {
+            AccountMeta::new_readonly(WNS_PROGRAM_ID, false)
+        } else {
+            AccountMeta::new(account.key(), false)
+        }
+    };
 
-            // Validate creator_payment_account when royalty > 0 (prevents royalty redirection)
-            if creator_royalty > 0 {
-                let expected_creator_ata = anchor_spl::associated_token::get_associated_token_address(
-                    &listing.creator_address,
-                    &listing.payment_mint,
-                );
-                require!(
-                    ctx.accounts.creator_payment_account.key() == expected_creator_ata,
-                    AuctionError::InvalidCreatorAccount
-                );
-            }
+    let mut data = WNS_APPROVE_TRANSFER_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    let ix = Instruction {
+        program_id: WNS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new_readonly(authority.key(), true),
+            AccountMeta::new_readonly(nft_mint.key(), false),
+            AccountMeta::new(wns.approve_account.key(), false),
+            AccountMeta::new_readonly(payment_mint.key(), false),
+            optional(distribution_token_account),
+            optional(authority_token_account),
+            AccountMeta::new(wns.distribution_account.key(), false),
+            AccountMeta::new_readonly(wns.system_program.key(), false),
+            AccountMeta::new_readonly(wns.distribution_program.key(), false),
+            AccountMeta::new_readonly(nft_token_program.key(), false),
+            AccountMeta::new_readonly(payment_token_program.key(), false),
+        ],
+        data,
+    };
+    let account_infos = [
+        payer.clone(),
+        authority.clone(),
+        nft_mint.clone(),
+        wns.approve_account.clone(),
+        payment_mint.clone(),
+        distribution_token_account.clone(),
+        authority_token_account.clone(),
+        wns.distribution_account.clone(),
+        wns.system_program.clone(),
+        wns.distribution_program.clone(),
+        nft_token_program.clone(),
+        payment_token_program.clone(),
+        wns.wns_program.clone(),
+    ];
+    invoke_signed(&ix, &account_infos, signer_seeds).map_err(|_| error!(AuctionError::WnsApproveFailed))?;
+    Ok(())
+}
 
-            // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
-            listing.status = ListingStatus::Settled;
-
-            let seller_amount = listing
-                .current_bid
-                .checked_sub(platform_fee)
-                .ok_or(AuctionError::CalculationError)?
-                .checked_sub(baxus_fee)
-                .ok_or(AuctionError::CalculationError)?
-                .checked_sub(creator_royalty)
-                .ok_or(AuctionError::CalculationError)?;
+/// Royalty WNS charges on a sale, from the mint's token metadata.
+fn read_wns_royalty_bps(nft_mint: &AccountInfo) -> Result<u16> {
+    let mint_data = nft_mint.try_borrow_data()?;
+    let mint_state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let metadata = mint_state
+        .get_variable_len_extension::<spl_token_metadata_interface::state::TokenMetadata>()
+        .map_err(|_| error!(AuctionError::InvalidWnsAccountLayout))?;
+    wns_royalty_bps(&metadata.additional_metadata)
+}
 
-            let bid_escrow_seeds: &[&[&[u8]]] = &[&[
-                b"bid_escrow",
-                nft_mint_key.as_ref(),
-                &[bid_escrow_bump],
-            ]];
-
-            // Payment: bid_escrow → seller
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.bid_escrow.to_account_info(),
-                        to: ctx.accounts.seller_payment_account.to_account_info(),
-                        authority: ctx.accounts.bid_escrow.to_account_info(),
-                    },
-                    bid_escrow_seeds,
-                ),
-                seller_amount,
-            )?;
+/// Reject a WNS NFT whose royalty no sale could split. The royalty is read
+/// again at sale time, so checking it at listing time keeps a listing from
+/// being created that no buyer could complete.
+fn validate_wns_royalty(nft_mint: &AccountInfo) -> Result<()> {
+    if !is_wns_mint(nft_mint)? {
+        return Ok(());
+    }
+    validate_royalty_bps(read_wns_royalty_bps(nft_mint)?)
+}
 
-            // Payment: bid_escrow → treasury
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.bid_escrow.to_account_info(),
-                        to: ctx.accounts.treasury_payment_account.to_account_info(),
-                        authority: ctx.accounts.bid_escrow.to_account_info(),
-                    },
-                    bid_escrow_seeds,
-                ),
-                platform_fee + baxus_fee,
-            )?;
+/// Move the escrowed NFT out of the `escrow_nft` PDA to `destination`.
+fn transfer_nft_from_escrow<'info>(
+    is_token2022: bool,
+    nft_token_program: &AccountInfo<'info>,
+    escrow_nft: &AccountInfo<'info>,
+    nft_mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    escrow_seeds: &[&[u8]],
+) -> Result<()> {
+    if is_token2022 {
+        // Token-2022 with transfer hook: use proper hook-aware CPI
+        transfer_checked_with_hook(
+            nft_token_program,
+            escrow_nft,
+            nft_mint,
+            destination,
+            escrow_nft,
+            remaining_accounts,
+            1,
+            0,
+            &[escrow_seeds],
+        )
+    } else {
+        token::transfer(
+            CpiContext::new_with_signer(
+                nft_token_program.clone(),
+                Transfer {
+                    from: escrow_nft.clone(),
+                    to: destination.clone(),
+                    authority: escrow_nft.clone(),
+                },
+                &[escrow_seeds],
+            ),
+            1,
+        )
+    }
+}
 
-            // Creator royalty — always enforced
-            if creator_royalty > 0 {
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
-                        Transfer {
-                            from: ctx.accounts.bid_escrow.to_account_info(),
-                            to: ctx.accounts.creator_payment_account.to_account_info(),
-                            authority: ctx.accounts.bid_escrow.to_account_info(),
-                        },
-                        bid_escrow_seeds,
-                    ),
-                    creator_royalty,
-                )?;
-            }
+/// Move an NFT out of its owner's token account, signed by the listing PDA
+/// the owner approved as delegate.
+#[allow(clippy::too_many_arguments)]
+fn transfer_nft_as_delegate<'info>(
+    is_token2022: bool,
+    nft_token_program: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    nft_mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    delegate_seeds: &[&[u8]],
+) -> Result<()> {
+    if is_token2022 {
+        transfer_checked_with_hook(
+            nft_token_program,
+            source,
+            nft_mint,
+            destination,
+            delegate,
+            remaining_accounts,
+            1,
+            0,
+            &[delegate_seeds],
+        )
+    } else {
+        token::transfer(
+            CpiContext::new_with_signer(
+                nft_token_program.clone(),
+                Transfer {
+                    from: source.clone(),
+                    to: destination.clone(),
+                    authority: delegate.clone(),
+                },
+                &[delegate_seeds],
+            ),
+            1,
+        )
+    }
+}
 
-            // Transfer NFT: escrow → winner
-            let nft_escrow_seeds: &[&[u8]] = &[
-                b"escrow_nft",
-                nft_mint_key.as_ref(),
-                &[escrow_bump],
-            ];
+/// Decimals and current-epoch transfer fee of a payment mint. SPL Token mints
+/// and Token-2022 mints without `TransferFeeConfig` have no fee.
+fn read_payment_mint(payment_mint: &AccountInfo) -> Result<(u8, Option<TransferFee>)> {
+    let mint_data = payment_mint.try_borrow_data()?;
+    let mint_state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let fee = if *payment_mint.owner == spl_token_2022::ID {
+        match mint_state.get_extension::<TransferFeeConfig>() {
+            Ok(config) => Some(*config.get_epoch_fee(Clock::get()?.epoch)),
+            Err(_) => None,
+        }
+    } else {
+        None
+    };
+    Ok((mint_state.base.decimals, fee))
+}
 
-            if listing.is_token2022 {
-                // Token-2022 with transfer hook: escrow → winner
-                transfer_checked_with_hook(
-                    &ctx.accounts.nft_token_program.to_account_info(),
-                    &ctx.accounts.escrow_nft.to_account_info(),
-                    &ctx.accounts.nft_mint.to_account_info(),
-                    &ctx.accounts.buyer_nft_account.to_account_info(),
-                    &ctx.accounts.escrow_nft.to_account_info(),
-                    ctx.remaining_accounts,
-                    1,
-                    0,
-                    &[nft_escrow_seeds],
-                )?;
-            } else {
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.nft_token_program.to_account_info(),
-                        Transfer {
-                            from: ctx.accounts.escrow_nft.to_account_info(),
-                            to: ctx.accounts.buyer_nft_account.to_account_info(),
-                            authority: ctx.accounts.escrow_nft.to_account_info(),
-                        },
-                        &[nft_escrow_seeds],
-                    ),
-                    1,
-                )?;
-            }
+/// Payment mints must not run a transfer hook: payment legs carry no extra
+/// hook accounts. A hook extension with no program set (as on PYUSD) is fine.
+fn validate_payment_mint_extensions(payment_mint: &AccountInfo) -> Result<()> {
+    if *payment_mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+    let mint_data = payment_mint.try_borrow_data()?;
+    let mint_state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    if let Ok(hook) = mint_state.get_extension::<spl_token_2022::extension::transfer_hook::TransferHook>() {
+        let hook_program: Option<Pubkey> = hook.program_id.into();
+        require!(hook_program.is_none(), AuctionError::InvalidPaymentMint);
+    }
+    Ok(())
+}
 
-            emit!(AuctionSettled {
-                nft_mint: listing.nft_mint,
-                winner: listing.highest_bidder,
-                price: listing.current_bid,
-                platform_fee,
-            });
-        } else {
-            // No bids: return NFT to seller
-            // Validate seller_nft_account is owned by the seller
-            let seller_nft_owner = ctx.accounts.seller_nft_account.owner;
-            require!(
-                seller_nft_owner == listing.seller,
-                AuctionError::Unauthorized
-            );
+/// Move payment tokens with `transfer_checked`, which works for both SPL Token
+/// and Token-2022 mints. Returns what `to` actually received after any
+/// transfer fee.
+fn transfer_payment<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+    let (decimals, fee) = read_payment_mint(payment_mint)?;
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::TransferChecked {
+                from: from.clone(),
+                mint: payment_mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )?;
+    let withheld = match fee {
+        Some(fee) => fee.calculate_fee(amount).ok_or(AuctionError::CalculationError)?,
+        None => 0,
+    };
+    Ok(amount.checked_sub(withheld).ok_or(AuctionError::CalculationError)?)
+}
 
-            let nft_escrow_seeds: &[&[u8]] = &[
-                b"escrow_nft",
-                nft_mint_key.as_ref(),
-                &[escrow_bump],
-            ];
+/// Deposit exactly `amount` into an escrow, with the depositor covering any
+/// transfer fee on top.
+fn deposit_payment<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let gross = match read_payment_mint(payment_mint)?.1 {
+        Some(fee) => amount_with_transfer_fee(&fee, amount)?,
+        None => amount,
+    };
+    let received = transfer_payment(token_program, payment_mint, from, escrow, authority, gross, &[])?;
+    require!(received >= amount, AuctionError::CalculationError);
+    Ok(())
+}
 
-            if listing.is_token2022 {
-                // Token-2022 with transfer hook: escrow → seller (no bids)
-                transfer_checked_with_hook(
-                    &ctx.accounts.nft_token_program.to_account_info(),
-                    &ctx.accounts.escrow_nft.to_account_info(),
-                    &ctx.accounts.nft_mint.to_account_info(),
-                    &ctx.accounts.seller_nft_account.to_account_info(),
-                    &ctx.accounts.escrow_nft.to_account_info(),
-                    ctx.remaining_accounts,
-                    1,
-                    0,
-                    &[nft_escrow_seeds],
-                )?;
-            } else {
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.nft_token_program.to_account_info(),
-                        Transfer {
-                            from: ctx.accounts.escrow_nft.to_account_info(),
-                            to: ctx.accounts.seller_nft_account.to_account_info(),
-                            authority: ctx.accounts.escrow_nft.to_account_info(),
-                        },
-                        &[nft_escrow_seeds],
-                    ),
-                    1,
-                )?;
-            }
+/// Move an NFT the `owner` holds in their own wallet to `destination`.
+fn transfer_nft_from_owner<'info>(
+    is_token2022: bool,
+    nft_token_program: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    nft_mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if is_token2022 {
+        // Token-2022 with transfer hook: use proper hook-aware CPI
+        transfer_checked_with_hook(
+            nft_token_program,
+            source,
+            nft_mint,
+            destination,
+            owner,
+            remaining_accounts,
+            1,
+            0,
+            &[],
+        )
+    } else {
+        // Standard SPL Token
+        token::transfer(
+            CpiContext::new(
+                nft_token_program.clone(),
+                Transfer {
+                    from: source.clone(),
+                    to: destination.clone(),
+                    authority: owner.clone(),
+                },
+            ),
+            1,
+        )
+    }
+}
 
-            listing.status = ListingStatus::Cancelled;
+/// Pay a sale out of `source` to seller, treasury (platform + BAXUS fee) and
+/// creator. Zero legs are skipped.
+#[allow(clippy::too_many_arguments)]
+fn pay_sale_split<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    seller_payment_account: &AccountInfo<'info>,
+    treasury_payment_account: &AccountInfo<'info>,
+    creator_payment_account: &AccountInfo<'info>,
+    split: &SaleSplit,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<SalePayout> {
+    let pay = |to: &AccountInfo<'info>, amount: u64| {
+        transfer_payment(token_program, payment_mint, source, to, authority, amount, signer_seeds)
+    };
 
-            emit!(AuctionCancelled {
-                nft_mint: listing.nft_mint,
-                reason: "No bids received".to_string(),
-            });
-        }
+    Ok(SalePayout {
+        seller_amount: pay(seller_payment_account, split.seller_amount)?,
+        treasury_amount: pay(treasury_payment_account, split.treasury_amount()?)?,
+        // Creator royalty — always enforced
+        creator_royalty: pay(creator_payment_account, split.creator_royalty)?,
+    })
+}
 
-        // Rent destination: treasury on sale, seller on no-bid cancel
-        let rent_dest = if listing.status == ListingStatus::Settled {
-            ctx.accounts.treasury.to_account_info()
-        } else {
-            ctx.accounts.seller.to_account_info()
-        };
+/// Transfer payment tokens out of the `bid_escrow` PDA for `nft_mint`'s
+/// listing instance (see `listing_instance_seed`).
+#[allow(clippy::too_many_arguments)]
+fn transfer_from_bid_escrow<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    bid_escrow: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    nft_mint: Pubkey,
+    listing_instance: &[u8],
+    bid_escrow_bump: u8,
+    amount: u64,
+) -> Result<u64> {
+    transfer_payment(
+        token_program,
+        payment_mint,
+        bid_escrow,
+        destination,
+        bid_escrow,
+        amount,
+        &[&[b"bid_escrow", nft_mint.as_ref(), listing_instance, &[bid_escrow_bump]]],
+    )
+}
 
-        // Close escrow_nft token account via CPI
-        let close_escrow_seeds: &[&[u8]] = &[
-            b"escrow_nft",
-            nft_mint_key.as_ref(),
-            &[escrow_bump],
-        ];
-        close_token_account_cpi(
-            &ctx.accounts.nft_token_program.to_account_info(),
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &rent_dest,
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &[close_escrow_seeds],
+/// Close an empty, self-owned payment escrow PDA. Transfer fees withheld on
+/// deposits are first harvested to the mint, since Token-2022 refuses to close
+/// an account that still holds them.
+fn close_payment_escrow<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    escrow_seeds: &[&[u8]],
+) -> Result<()> {
+    if read_payment_mint(payment_mint)?.1.is_some() {
+        let ix = spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+            token_program.key,
+            payment_mint.key,
+            &[escrow.key],
         )?;
+        anchor_lang::solana_program::program::invoke(&ix, &[payment_mint.clone(), escrow.clone()])?;
+    }
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        token_interface::CloseAccount {
+            account: escrow.clone(),
+            destination: destination.clone(),
+            authority: escrow.clone(),
+        },
+        &[escrow_seeds],
+    ))
+}
 
-        // Close bid_escrow token account if it exists and is empty
-        if ctx.accounts.bid_escrow.amount == 0 {
-            let bid_escrow_close_seeds: &[&[&[u8]]] = &[&[
-                b"bid_escrow",
-                nft_mint_key.as_ref(),
-                &[bid_escrow_bump],
-            ]];
-            token::close_account(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    CloseAccount {
-                        account: ctx.accounts.bid_escrow.to_account_info(),
-                        destination: rent_dest.clone(),
-                        authority: ctx.accounts.bid_escrow.to_account_info(),
-                    },
-                    bid_escrow_close_seeds,
-                ),
-            )?;
-        }
+/// Close the empty `bid_escrow` PDA for `nft_mint`'s listing instance.
+fn close_bid_escrow<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    bid_escrow: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    nft_mint: Pubkey,
+    listing_instance: &[u8],
+    bid_escrow_bump: u8,
+) -> Result<()> {
+    close_payment_escrow(
+        token_program,
+        payment_mint,
+        bid_escrow,
+        destination,
+        &[b"bid_escrow", nft_mint.as_ref(), listing_instance, &[bid_escrow_bump]],
+    )
+}
 
-        // Close listing account (owned by our program)
-        let listing_info = ctx.accounts.listing.to_account_info();
-        let dest_starting_lamports = rent_dest.lamports();
-        **rent_dest.lamports.borrow_mut() = dest_starting_lamports
-            .checked_add(listing_info.lamports())
-            .unwrap();
-        **listing_info.lamports.borrow_mut() = 0;
-        listing_info.assign(&anchor_lang::solana_program::system_program::ID);
-        listing_info.realloc(0, false)?;
+/// Close a program-owned account by draining its lamports to `destination`.
+fn close_listing_account<'info>(
+    listing_info: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let dest_starting_lamports = destination.lamports();
+    **destination.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(listing_info.lamports())
+        .ok_or(AuctionError::CalculationError)?;
+    **listing_info.lamports.borrow_mut() = 0;
+    listing_info.assign(&anchor_lang::solana_program::system_program::ID);
+    listing_info.realloc(0, false)?;
+    Ok(())
+}
 
-        Ok(())
+/// Grow a program account of the legacy length `legacy_len` to `new_len`,
+/// zero-filling the appended fields; `payer` tops up the rent reserve.
+fn grow_legacy_account<'info>(
+    account: &AccountInfo<'info>,
+    discriminator: &[u8],
+    legacy_len: usize,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(
+        account.owner == &crate::ID
+            && account.data_len() == legacy_len
+            && account.try_borrow_data()?.starts_with(discriminator),
+        AuctionError::NotLegacyListing
+    );
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer_lamports(system_program, payer, account, shortfall)?;
     }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
 
-    /// List a pNFT (Metaplex programmable NFT) for sale.
-    /// Uses Token Metadata TransferV1 CPI with delegate + token_record.
-    pub fn list_item_pnft<'info>(
-        ctx: Context<'_, '_, '_, 'info, ListItemPnft<'info>>,
-        listing_type: ListingType,
-        price: u64,
-        duration_seconds: Option<i64>,
-        category: ItemCategory,
-        royalty_basis_points: u16,
-        creator_address: Pubkey,
-    ) -> Result<()> {
-        let clock = Clock::get()?;
-        let listing = &mut ctx.accounts.listing;
+/// Values for the fields appended to `Listing` since the legacy layout, which
+/// a migrated listing reads as zero: keep it bidding as it did before.
+fn apply_legacy_listing_defaults(listing: &mut Listing) {
+    listing.bid_increment = BidIncrement::Absolute(LEGACY_BID_INCREMENT);
+    // Legacy bids were escrowed in full
+    listing.highest_bidder_max = listing.current_bid;
+    // Instance 0 keeps the unscoped bid escrow and refund PDAs
+    listing.created_at = 0;
+}
 
-        require!(price > 0, AuctionError::InvalidPrice);
-        require!(royalty_basis_points <= 1000, AuctionError::RoyaltyTooHigh);
-        validate_category_and_payment(&category, ctx.accounts.payment_mint.key())?;
+/// Create the `BidRefund` of a migrated listing's standing high bidder. Legacy
+/// bids were refunded straight to the outbid bidder's token account, so no
+/// record exists yet, and outbidding or settling credits the leader through it.
+fn create_legacy_leader_refund<'info>(
+    listing: &Listing,
+    leader_refund: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let instance_seed = listing_instance_seed(listing.created_at);
+    let (expected, bump) = Pubkey::find_program_address(
+        &[
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &instance_seed,
+            listing.highest_bidder.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(leader_refund.key(), expected, AuctionError::InvalidRefundAccount);
+    let signer_seeds: &[&[u8]] = &[
+        b"bid_refund",
+        listing.nft_mint.as_ref(),
+        &instance_seed,
+        listing.highest_bidder.as_ref(),
+        &[bump],
+    ];
+
+    // Fund, allocate and assign rather than `create_account`, which fails if
+    // lamports were already sent to the address
+    let space = 8 + BidRefund::INIT_SPACE;
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(leader_refund.lamports());
+    if shortfall > 0 {
+        transfer_lamports(system_program, payer, leader_refund, shortfall)?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: leader_refund.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: leader_refund.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    let bid_refund = BidRefund {
+        bidder: listing.highest_bidder,
+        nft_mint: listing.nft_mint,
+        payment_mint: listing.payment_mint,
+        amount: 0,
+        bump,
+        native: listing.native_sol,
+        listing_created_at: listing.created_at,
+    };
+    bid_refund.try_serialize(&mut &mut leader_refund.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
 
-        if matches!(listing_type, ListingType::Auction) {
+/// Pay a sale split in lamports. `pay` moves lamports from the buyer or
+/// escrow to the given destination; zero amounts are skipped.
+///
+/// A lamport transfer that leaves its destination below the rent-exempt
+/// minimum fails, so a leg too small to fund an empty wallet fails the sale
+/// with `PayoutBelowRent` rather than being paid to anyone else. Funding the
+/// wallet lets the sale go through.
+fn pay_sale_split_lamports<'info>(
+    seller: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    creator: &AccountInfo<'info>,
+    split: &SaleSplit,
+    mut pay: impl FnMut(&AccountInfo<'info>, u64) -> Result<()>,
+) -> Result<SalePayout> {
+    let payout = split.payout()?;
+    let rent = Rent::get()?;
+    for (destination, amount) in [
+        (seller, payout.seller_amount),
+        (treasury, payout.treasury_amount),
+        (creator, payout.creator_royalty),
+    ] {
+        if amount > 0 {
+            // Checked per leg so an earlier leg to the same wallet counts
+            let balance = destination.lamports().saturating_add(amount);
             require!(
-                duration_seconds.is_some() && duration_seconds.unwrap() > 0,
-                AuctionError::InvalidDuration
+                balance >= rent.minimum_balance(destination.data_len()),
+                AuctionError::PayoutBelowRent
             );
+            pay(destination, amount)?;
         }
+    }
+    Ok(payout)
+}
 
-        listing.seller = ctx.accounts.seller.key();
-        listing.nft_mint = ctx.accounts.nft_mint.key();
-        listing.payment_mint = ctx.accounts.payment_mint.key();
-        listing.price = price;
-        listing.listing_type = listing_type;
-        listing.category = category;
-        listing.start_time = clock.unix_timestamp;
-        listing.end_time = if let Some(d) = duration_seconds { clock.unix_timestamp + d } else { 0 };
-        listing.status = ListingStatus::Active;
-        listing.escrow_nft_account = ctx.accounts.escrow_nft_token.key();
-        listing.current_bid = 0;
-        listing.highest_bidder = Pubkey::default();
-        listing.baxus_fee = false;
-        listing.is_token2022 = false;
-        listing.is_pnft = true;
-        listing.royalty_basis_points = royalty_basis_points;
-        listing.creator_address = creator_address;
-        listing.bump = ctx.bumps.listing;
+/// System-program transfer from a signing wallet.
+fn transfer_lamports<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        ),
+        amount,
+    )
+}
 
-        // Transfer pNFT from seller to escrow via Token Metadata TransferV1 raw CPI
-        transfer_pnft(
-            &ctx.accounts.token_metadata_program.to_account_info(),
-            &ctx.accounts.seller_nft_token.to_account_info(),
-            &ctx.accounts.seller.to_account_info(),
-            &ctx.accounts.escrow_nft_token.to_account_info(),
-            &ctx.accounts.escrow_authority.to_account_info(),
-            &ctx.accounts.nft_mint.to_account_info(),
-            &ctx.accounts.nft_metadata.to_account_info(),
-            &ctx.accounts.nft_edition.to_account_info(),
-            &ctx.accounts.seller_token_record.to_account_info(),
-            &ctx.accounts.escrow_token_record.to_account_info(),
-            &ctx.accounts.seller.to_account_info(),
-            &ctx.accounts.seller.to_account_info(),
+/// Debit the program-owned `sol_escrow`, never dipping into its rent reserve.
+fn transfer_from_sol_escrow<'info>(
+    sol_escrow: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let rent_reserve = Rent::get()?.minimum_balance(sol_escrow.data_len());
+    let available = sol_escrow
+        .lamports()
+        .checked_sub(rent_reserve)
+        .ok_or(AuctionError::CalculationError)?;
+    require!(amount <= available, AuctionError::CalculationError);
+
+    **sol_escrow.try_borrow_mut_lamports()? -= amount;
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctionError::CalculationError)?;
+    Ok(())
+}
+
+/// Close `sol_escrow` once only its rent reserve remains.
+fn close_sol_escrow_if_empty<'info>(
+    sol_escrow: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_reserve = Rent::get()?.minimum_balance(sol_escrow.data_len());
+    if sol_escrow.lamports() <= rent_reserve {
+        close_listing_account(sol_escrow, destination)?;
+    }
+    Ok(())
+}
+
+#[program]
+pub mod auction {
+    use super::*;
+
+    /// Initialize the treasury config PDA (one-time setup by deploy authority)
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == DEPLOY_AUTHORITY_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        let config = &mut ctx.accounts.treasury_config;
+        config.treasury = ctx.accounts.authority.key(); // set initial treasury = deploy authority, update after
+        config.authority = ctx.accounts.authority.key();
+        config.bump = ctx.bumps.treasury_config;
+        Ok(())
+    }
+
+    /// Update treasury address (deploy authority only)
+    pub fn update_treasury(ctx: Context<UpdateTreasury>, new_treasury: Pubkey) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == DEPLOY_AUTHORITY_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        ctx.accounts.treasury_config.treasury = new_treasury;
+        emit!(TreasuryUpdated {
+            old_treasury: ctx.accounts.treasury_config.treasury,
+            new_treasury,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Register a payment mint (deploy authority only)
+    pub fn initialize_payment_mint_config(
+        ctx: Context<InitializePaymentMintConfig>,
+        settings: PaymentMintSettings,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == DEPLOY_AUTHORITY_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+        let config = &mut ctx.accounts.payment_mint_config;
+        config.mint = ctx.accounts.payment_mint.key();
+        config.bump = ctx.bumps.payment_mint_config;
+        apply_payment_mint_settings(config, settings)
+    }
+
+    /// Enable, retire or retune a payment mint (deploy authority only)
+    pub fn update_payment_mint_config(
+        ctx: Context<UpdatePaymentMintConfig>,
+        settings: PaymentMintSettings,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == DEPLOY_AUTHORITY_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        apply_payment_mint_settings(&mut ctx.accounts.payment_mint_config, settings)
+    }
+
+    /// Grow a `Listing` created under the legacy layout to the current one
+    /// (permissionless; `payer` funds the extra rent). The appended fields
+    /// keep it behaving as before: no soft close, reserve, buy-now or sealed
+    /// bidding, the legacy 0.1 SOL bid step, and its existing bid escrow and
+    /// refunds (instance 0). A listing with bids also needs `leader_refund`,
+    /// which is created for the standing high bidder.
+    pub fn migrate_listing(ctx: Context<MigrateListing>) -> Result<()> {
+        let listing_info = ctx.accounts.listing.to_account_info();
+        grow_legacy_account(
+            &listing_info,
+            Listing::DISCRIMINATOR,
+            LEGACY_LISTING_LEN,
+            8 + Listing::INIT_SPACE,
+            &ctx.accounts.payer.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
-            &ctx.accounts.sysvar_instructions.to_account_info(),
-            &ctx.accounts.token_program.to_account_info(),
-            &ctx.accounts.ata_program.to_account_info(),
-            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
-            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
-            &[],
+        )?;
+        let mut listing = Listing::try_deserialize(&mut &listing_info.try_borrow_data()?[..])?;
+        apply_legacy_listing_defaults(&mut listing);
+        listing.try_serialize(&mut &mut listing_info.try_borrow_mut_data()?[..])?;
+
+        if listing.current_bid > 0 && listing.highest_bidder != Pubkey::default() {
+            let leader_refund = ctx
+                .accounts
+                .leader_refund
+                .as_ref()
+                .ok_or(AuctionError::InvalidRefundAccount)?;
+            create_legacy_leader_refund(
+                &listing,
+                &leader_refund.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Grow a `CoreListing` created under the legacy layout to the current one
+    /// (permissionless; `payer` funds the extra rent). The zeroed fields read
+    /// as an open-ended fixed-price listing without a bond, as before.
+    pub fn migrate_core_listing(ctx: Context<MigrateListing>) -> Result<()> {
+        grow_legacy_account(
+            &ctx.accounts.listing.to_account_info(),
+            CoreListing::DISCRIMINATOR,
+            LEGACY_CORE_LISTING_LEN,
+            8 + CoreListing::INIT_SPACE,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )
+    }
+
+    /// List an item for sale (fixed price, English, Dutch or sealed-bid auction)
+    ///
+    /// For WNS/Token-2022 NFTs the program makes the WNS `approve_transfer` CPI
+    /// itself (amount 0 here; the sale price in `buy_now` / `settle_auction`).
+    /// remaining_accounts for WNS NFTs, validated in this order:
+    ///   [0] wns_program (readonly)
+    ///   [1] extra_metas_account PDA (readonly) - seeds: ["extra-account-metas", nft_mint]
+    ///   [2] approve_account PDA (writable) - seeds: ["approve-account", nft_mint]
+    ///   [3] wns_distribution_program (readonly)
+    ///   [4] distribution_account PDA (writable) - seeds: [group_mint, payment_mint]
+    ///       under the distribution program, where payment_mint is the system
+    ///       program except on sales, which use the listing's payment mint
+    ///   [5] distribution_token_account (writable) - distribution_account's ATA for
+    ///       the payment mint on sales, else wns_program
+    ///   [6] system_program (readonly)
+    ///   [7] member account PDA (readonly) - seeds: ["member", nft_mint] under WNS
+    ///   [8] group account (readonly) - the member's group; its mint is the
+    ///       group_mint of [4]
+    /// Other Token-2022 transfer hooks: the hook program, its extra_metas_account
+    /// PDA and the accounts that PDA lists, in any order.
+    ///
+    /// `options` carries the optional auction settings; see `ListingOptions`.
+    /// Fixed-price listings expire after `duration_seconds` if given; anyone can
+    /// then return the NFT to the seller with `close_expired_listing`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn list_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListItem<'info>>,
+        listing_type: ListingType,
+        price: u64,
+        duration_seconds: Option<i64>,
+        category: ItemCategory,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+        options: ListingOptions,
+    ) -> Result<()> {
+        let ListingOptions { soft_close, reserve, buy_now, bid_increment, start_time, dutch, sealed } = options;
+        let clock = Clock::get()?;
+        let listing = &mut ctx.accounts.listing;
+
+        // Validate price
+        require!(price > 0, AuctionError::InvalidPrice);
+        require!(price <= 1_000_000_000_000_000_000, AuctionError::InvalidPrice);
+
+        // Cap royalty basis points at 10% (1000 bps) to prevent fee manipulation
+        require!(royalty_basis_points <= 1000, AuctionError::RoyaltyTooHigh);
+
+        // Validate the payment mint is registered for this category and price
+        let lowest_price = dutch.map_or(price, |config| config.floor_price.min(price));
+        validate_payment_mint_config(&ctx.accounts.payment_mint_config, Some(category), lowest_price)?;
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+
+        // Validate duration for auctions (and the Dutch price decay), or the
+        // optional fixed-price expiry
+        validate_duration(&listing_type, duration_seconds)?;
+        validate_soft_close(&listing_type, &soft_close)?;
+        validate_reserve(&listing_type, price, &reserve)?;
+        validate_buy_now(&listing_type, price, &reserve, &buy_now)?;
+        validate_bid_increment(&listing_type, &bid_increment)?;
+        validate_dutch(&listing_type, price, &dutch)?;
+        validate_sealed(&listing_type, &sealed)?;
+
+        // Validate token program is SPL Token or Token-2022
+        require!(
+            ctx.accounts.nft_token_program.key() == Token::id() || 
+            ctx.accounts.nft_token_program.key() == spl_token_2022::id(),
+            AuctionError::InvalidTokenProgram
+        );
+
+        // Detect Token-2022
+        let is_token2022 = ctx.accounts.nft_token_program.key() != Token::id();
+
+        listing.seller = ctx.accounts.seller.key();
+        listing.nft_mint = ctx.accounts.nft_mint.key();
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.price = price;
+        listing.listing_type = listing_type;
+        listing.category = category;
+        // Auctions run for `duration_seconds` from the (possibly scheduled) start;
+        // fixed-price listings given a duration expire after it
+        listing.start_time = resolve_start_time(start_time, clock.unix_timestamp)?;
+        listing.end_time = if let Some(duration) = duration_seconds {
+            listing.start_time + duration
+        } else {
+            0
+        };
+        listing.status = ListingStatus::Active;
+        listing.escrow_nft_account = ctx.accounts.escrow_nft.key();
+        listing.current_bid = 0;
+        listing.highest_bidder = Pubkey::default();
+        listing.highest_bidder_max = 0;
+        listing.baxus_fee = false;
+        listing.is_token2022 = is_token2022;
+        listing.is_pnft = false;
+        listing.native_sol =
+            listing.payment_mint == SOL_MINT_PUBKEY && matches!(listing_type, ListingType::Auction);
+        listing.royalty_basis_points = royalty_basis_points;
+        listing.creator_address = creator_address;
+        listing.bump = ctx.bumps.listing;
+        listing.created_at = clock.unix_timestamp;
+        apply_soft_close(listing, soft_close)?;
+        apply_reserve(listing, reserve);
+        listing.buy_now_price = buy_now.map_or(0, |config| config.price);
+        listing.buy_now_cutoff_bps = buy_now.map_or(0, |config| config.cutoff_bps);
+        listing.bid_increment = bid_increment.unwrap_or(ctx.accounts.payment_mint_config.bid_increment);
+        listing.dutch_floor_price = dutch.map_or(0, |config| config.floor_price);
+        listing.dutch_curve = dutch.map_or(DutchCurve::Linear, |config| config.curve);
+        apply_sealed(listing, sealed)?;
+
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        validate_wns_royalty(&nft_mint_info)?;
+        if let Some(wns) = wns_accounts(&nft_mint_info, ctx.remaining_accounts)? {
+            approve_wns_transfer(
+                &wns,
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.seller.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                0,
+                None,
+                &[],
+            )?;
+        }
+
+        // Transfer NFT from seller to escrow
+        transfer_nft_from_owner(
+            is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.seller_nft_account.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            ctx.remaining_accounts,
         )?;
 
         emit!(ListingCreated {
             nft_mint: listing.nft_mint,
             seller: listing.seller,
-            listing_type,
+            listing_type: listing_type.clone(),
             price,
-            category,
+            category: category.clone(),
+            start_time: listing.start_time,
             end_time: listing.end_time,
             payment_mint: listing.payment_mint,
         });
@@ -1200,528 +2348,10355 @@ pub mod auction {
         Ok(())
     }
 
-    /// Cancel a pNFT listing — return NFT to seller.
-    pub fn cancel_listing_pnft<'info>(
-        ctx: Context<'_, '_, '_, 'info, CancelListingPnft<'info>>
-    ) -> Result<()> {
+    /// Place a bid on an active auction (payment tokens only, no NFT transfer)
+    ///
+    /// `max_amount` turns the bid into a proxy bid: the bidder escrows up to
+    /// `max_amount` and the program raises the visible `current_bid` only as far
+    /// as needed to stay on top of competing bids. A current leader may call this
+    /// again to raise their maximum without moving the visible price.
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64, max_amount: Option<u64>) -> Result<()> {
         let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
 
-        require!(ctx.accounts.seller.key() == listing.seller, AuctionError::Unauthorized);
-        require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
-        if matches!(listing.listing_type, ListingType::Auction) {
-            require!(listing.current_bid == 0, AuctionError::CannotCancelWithBids);
+        require!(!listing.native_sol, AuctionError::InvalidPaymentMint);
+
+        let bidder_key = ctx.accounts.bidder.key();
+        init_bid_refund(
+            &mut ctx.accounts.bidder_refund,
+            bidder_key,
+            listing.nft_mint,
+            listing.payment_mint,
+            false,
+            listing.created_at,
+            ctx.bumps.bidder_refund,
+        )?;
+
+        // Increment is in payment-token base units, scaled by the mint's decimals
+        let escrow_amount = apply_english_bid(
+            listing,
+            bidder_key,
+            amount,
+            max_amount,
+            ctx.accounts.payment_mint.decimals,
+            ctx.accounts.previous_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+            clock.unix_timestamp,
+        )?;
+
+        if escrow_amount > 0 {
+            deposit_payment(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.bidder_token_account.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &ctx.accounts.bidder.to_account_info(),
+                escrow_amount,
+            )?;
         }
 
-        let nft_mint_key = listing.nft_mint;
-        let escrow_auth_bump = ctx.bumps.escrow_authority;
-        let escrow_auth_seeds: &[&[u8]] = &[b"escrow_authority", nft_mint_key.as_ref(), &[escrow_auth_bump]];
+        Ok(())
+    }
 
-        // Transfer pNFT escrow → seller via Token Metadata TransferV1 raw CPI
-        transfer_pnft(
-            &ctx.accounts.token_metadata_program.to_account_info(),
-            &ctx.accounts.escrow_nft_token.to_account_info(),
-            &ctx.accounts.escrow_authority.to_account_info(),
-            &ctx.accounts.seller_nft_token.to_account_info(),
-            &ctx.accounts.seller.to_account_info(),
-            &ctx.accounts.nft_mint.to_account_info(),
-            &ctx.accounts.nft_metadata.to_account_info(),
-            &ctx.accounts.nft_edition.to_account_info(),
-            &ctx.accounts.escrow_token_record.to_account_info(),
-            &ctx.accounts.seller_token_record.to_account_info(),
-            &ctx.accounts.escrow_authority.to_account_info(),
-            &ctx.accounts.seller.to_account_info(),
-            &ctx.accounts.system_program.to_account_info(),
-            &ctx.accounts.sysvar_instructions.to_account_info(),
-            &ctx.accounts.token_program.to_account_info(),
-            &ctx.accounts.ata_program.to_account_info(),
-            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
-            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
-            &[escrow_auth_seeds],
+    /// Native-SOL `place_bid`: the bid is escrowed as lamports in `sol_escrow`,
+    /// so bidders need no wSOL account. Same proxy and refund rules.
+    pub fn place_bid_sol(ctx: Context<PlaceBidSol>, amount: u64, max_amount: Option<u64>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(listing.native_sol, AuctionError::InvalidPaymentMint);
+
+        let bidder_key = ctx.accounts.bidder.key();
+        ctx.accounts.sol_escrow.nft_mint = listing.nft_mint;
+        ctx.accounts.sol_escrow.bump = ctx.bumps.sol_escrow;
+        init_bid_refund(
+            &mut ctx.accounts.bidder_refund,
+            bidder_key,
+            listing.nft_mint,
+            listing.payment_mint,
+            true,
+            listing.created_at,
+            ctx.bumps.bidder_refund,
         )?;
 
-        emit!(ListingCancelled { nft_mint: listing.nft_mint, seller: listing.seller });
+        let escrow_amount = apply_english_bid(
+            listing,
+            bidder_key,
+            amount,
+            max_amount,
+            anchor_spl::token::spl_token::native_mint::DECIMALS,
+            ctx.accounts.previous_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+            clock.unix_timestamp,
+        )?;
 
-        // Close listing — rent to seller
-        let listing_info = ctx.accounts.listing.to_account_info();
-        let seller_info = ctx.accounts.seller.to_account_info();
-        **seller_info.lamports.borrow_mut() = seller_info.lamports().checked_add(listing_info.lamports()).unwrap();
-        **listing_info.lamports.borrow_mut() = 0;
-        listing_info.assign(&anchor_lang::solana_program::system_program::ID);
-        listing_info.realloc(0, false)?;
+        if escrow_amount > 0 {
+            transfer_lamports(
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.bidder.to_account_info(),
+                &ctx.accounts.sol_escrow.to_account_info(),
+                escrow_amount,
+            )?;
+        }
 
         Ok(())
     }
 
-    /// Close a stale listing where escrow is empty (NFT already returned)
-    /// This allows re-listing the same NFT after a cancelled listing
-    pub fn close_stale_listing(ctx: Context<CloseStaleListing>) -> Result<()> {
-        let listing = &ctx.accounts.listing;
+    /// Buy a fixed-price listing immediately, or a Dutch listing at its current price
+    ///
+    /// WNS NFTs are approved by CPI for the sale price: WNS collects the royalty
+    /// in the NFT's metadata from the buyer's payment account, in place of the
+    /// listing's royalty.
+    /// remaining_accounts: same layout as list_item
+    pub fn buy_now<'info>(ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+
+        let clock = Clock::get()?;
 
-        // Only the original seller can close their stale listing
         require!(
-            ctx.accounts.seller.key() == listing.seller,
-            AuctionError::Unauthorized
+            matches!(listing.listing_type, ListingType::FixedPrice | ListingType::Dutch),
+            AuctionError::NotFixedPrice
         );
-
-        // Verify escrow is empty (NFT already returned)
         require!(
-            ctx.accounts.escrow_nft.amount == 0,
-            AuctionError::ListingNotActive // reuse: listing still has NFT
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            !listing_expired(&listing.listing_type, listing.end_time, clock.unix_timestamp),
+            AuctionError::ListingExpired
         );
+        require!(!listing.is_pnft, AuctionError::InvalidTokenProgram);
 
-        emit!(ListingCancelled {
-            nft_mint: listing.nft_mint,
-            seller: listing.seller,
-        });
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
 
-        // Close escrow_nft token account via CPI, return rent to seller
-        let nft_mint_key = listing.nft_mint;
-        let escrow_bump = ctx.bumps.escrow_nft;
-        let close_escrow_seeds: &[&[u8]] = &[
-            b"escrow_nft",
-            nft_mint_key.as_ref(),
-            &[escrow_bump],
-        ];
-        close_token_account_cpi(
-            &ctx.accounts.nft_token_program.to_account_info(),
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &ctx.accounts.seller.to_account_info(),
-            &ctx.accounts.escrow_nft.to_account_info(),
-            &[close_escrow_seeds],
+        // Dutch listings clear at the interpolated price at purchase time
+        let price = current_listing_price(listing, clock.unix_timestamp)?;
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+        let split = escrow_sale_split(
+            listing,
+            price,
+            &nft_mint_info,
+            wns.is_some(),
+            ctx.accounts.creator_payment_account.key(),
         )?;
 
-        // Close listing account (owned by our program)
-        let listing_info = ctx.accounts.listing.to_account_info();
-        let seller_info = ctx.accounts.seller.to_account_info();
-        let dest_starting_lamports = seller_info.lamports();
-        **seller_info.lamports.borrow_mut() = dest_starting_lamports
-            .checked_add(listing_info.lamports())
-            .unwrap();
-        **listing_info.lamports.borrow_mut() = 0;
-        listing_info.assign(&anchor_lang::solana_program::system_program::ID);
-        listing_info.realloc(0, false)?;
+        // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+        listing.status = ListingStatus::Settled;
 
-        Ok(())
+        // WNS takes its royalty from the buyer here; the split skips the creator leg
+        if let Some(wns) = &wns {
+            approve_wns_transfer(
+                wns,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.buyer.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                price,
+                Some(WnsPayment {
+                    payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                    payment_token_program: &ctx.accounts.token_program.to_account_info(),
+                    authority_token_account: &ctx.accounts.buyer_payment_account.to_account_info(),
+                }),
+                &[],
+            )?;
+        }
+
+        // Payment: buyer → seller / treasury / creator
+        let payout = pay_sale_split(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &split.direct_legs(wns.is_some()),
+            &[],
+        )?;
+
+        // Transfer NFT: escrow → buyer
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let nft_mint_key = listing.nft_mint;
+        let escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+        transfer_nft_from_escrow(
+            listing.is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.buyer_nft_account.to_account_info(),
+            ctx.remaining_accounts,
+            escrow_seeds,
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: if wns.is_some() { split.creator_royalty } else { payout.creator_royalty },
+        });
+
+        // Close escrow_nft token account via CPI — rent to treasury (revenue)
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[escrow_seeds],
+        )?;
+
+        // Close listing account (owned by our program) — rent to treasury (revenue)
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+        )
     }
 
-    // ========================================================================
-    // Metaplex Core listing flow (Artifacte v2)
-    //
-    // Non-custodial: the asset stays in the seller's wallet during listing.
-    // The program is approved as a TransferDelegate plugin authority via
-    // mpl-core CPI; on buy, the program signs the TransferV1 CPI as that
-    // delegate, transferring the asset directly from seller to buyer.
-    //
-    // Constraints:
-    //   - Current asset holder may list.
-    //   - Asset must belong to ARTIFACTE_COLLECTION.
-    //   - Payment mint must be USDC.
-    //   - Artifacte collection buys waive the platform fee.
-    //   - Creator royalty (from the on-chain Royalties plugin) is still
-    //     routed to the royalty creator; remainder goes to the seller.
-    // ========================================================================
+    /// Native-SOL `buy_now` for SOL-priced listings: the buyer pays seller,
+    /// treasury and creator wallets with system transfers — no wSOL account needed.
+    /// remaining_accounts: same layout as list_item
+    pub fn buy_now_sol<'info>(ctx: Context<'_, '_, '_, 'info, BuyNowSol<'info>>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
 
-    /// List a Metaplex Core asset for fixed-price USDC sale.
-    pub fn list_core_item(ctx: Context<ListCoreItem>, price_usdc: u64) -> Result<()> {
-        // Artifacte collection only
-        require_keys_eq!(
-            ctx.accounts.collection.key(),
-            ARTIFACTE_COLLECTION_PUBKEY,
-            AuctionError::Unauthorized
+        require!(
+            matches!(listing.listing_type, ListingType::FixedPrice | ListingType::Dutch),
+            AuctionError::NotFixedPrice
         );
-        // USDC only
-        require_keys_eq!(
-            ctx.accounts.payment_mint.key(),
-            USDC_MINT_PUBKEY,
-            AuctionError::InvalidPaymentMint
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
         );
-        require!(price_usdc > 0, AuctionError::InvalidPrice);
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            !listing_expired(&listing.listing_type, listing.end_time, clock.unix_timestamp),
+            AuctionError::ListingExpired
+        );
+        require!(!listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require_keys_eq!(listing.payment_mint, SOL_MINT_PUBKEY, AuctionError::InvalidPaymentMint);
 
-        // Verify the seller owns the Core asset, and the asset belongs to the collection.
-        verify_core_asset_ownership(
-            &ctx.accounts.asset.to_account_info(),
-            ctx.accounts.seller.key(),
-            ctx.accounts.collection.key(),
-        )?;
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
 
-        let clock = Clock::get()?;
-        let listing = &mut ctx.accounts.core_listing;
-        listing.seller = ctx.accounts.seller.key();
-        listing.asset = ctx.accounts.asset.key();
-        listing.collection = ctx.accounts.collection.key();
-        listing.payment_mint = ctx.accounts.payment_mint.key();
-        listing.price = price_usdc;
-        listing.created_at = clock.unix_timestamp;
-        listing.bump = ctx.bumps.core_listing;
+        let price = current_listing_price(listing, clock.unix_timestamp)?;
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+        let split = native_sale_split(
+            listing,
+            price,
+            &nft_mint_info,
+            wns.is_some(),
+            ctx.accounts.creator.key(),
+        )?;
 
-        let expected_transfer_delegate_authority = mpl_core::types::PluginAuthority::Address {
-            address: ctx.accounts.core_authority.key(),
-        };
+        // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+        listing.status = ListingStatus::Settled;
 
-        match read_core_transfer_delegate_state(&ctx.accounts.asset.to_account_info())? {
-            CoreTransferDelegateState::Missing => {
-                mpl_core::instructions::AddPluginV1Cpi {
-                    __program: &ctx.accounts.mpl_core_program.to_account_info(),
-                    asset: &ctx.accounts.asset.to_account_info(),
-                    collection: Some(&ctx.accounts.collection.to_account_info()),
-                    payer: &ctx.accounts.seller.to_account_info(),
-                    authority: Some(&ctx.accounts.seller.to_account_info()),
-                    system_program: &ctx.accounts.system_program.to_account_info(),
-                    log_wrapper: None,
-                    __args: mpl_core::instructions::AddPluginV1InstructionArgs {
-                        plugin: mpl_core::types::Plugin::TransferDelegate(
-                            mpl_core::types::TransferDelegate {},
-                        ),
-                        init_authority: Some(expected_transfer_delegate_authority.clone()),
-                    },
-                }
-                .invoke()?;
-            }
-            CoreTransferDelegateState::Address(address)
-                if address == ctx.accounts.core_authority.key() => {}
-            CoreTransferDelegateState::Address(_) | CoreTransferDelegateState::Other => {
-                mpl_core::instructions::ApprovePluginAuthorityV1Cpi {
-                    __program: &ctx.accounts.mpl_core_program.to_account_info(),
-                    asset: &ctx.accounts.asset.to_account_info(),
-                    collection: Some(&ctx.accounts.collection.to_account_info()),
-                    payer: &ctx.accounts.seller.to_account_info(),
-                    authority: Some(&ctx.accounts.seller.to_account_info()),
-                    system_program: &ctx.accounts.system_program.to_account_info(),
-                    log_wrapper: None,
-                    __args: mpl_core::instructions::ApprovePluginAuthorityV1InstructionArgs {
-                        plugin_type: mpl_core::types::PluginType::TransferDelegate,
-                        new_authority: expected_transfer_delegate_authority,
-                    },
-                }
-                .invoke()?;
-            }
+        // WNS takes its royalty from the buyer's lamports; the split skips the creator leg
+        if let Some(wns) = &wns {
+            approve_wns_transfer(
+                wns,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.buyer.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                price,
+                None,
+                &[],
+            )?;
         }
 
-        emit!(CoreListingCreated {
-            asset: listing.asset,
+        // Payment: buyer lamports → seller / treasury / creator
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let buyer = ctx.accounts.buyer.to_account_info();
+        let payout = pay_sale_split_lamports(
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &split.direct_legs(wns.is_some()),
+            |to, amount| transfer_lamports(&system_program, &buyer, to, amount),
+        )?;
+
+        // Transfer NFT: escrow → buyer
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let nft_mint_key = listing.nft_mint;
+        let escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+        transfer_nft_from_escrow(
+            listing.is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.buyer_nft_account.to_account_info(),
+            ctx.remaining_accounts,
+            escrow_seeds,
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.nft_mint,
             seller: listing.seller,
-            price_usdc,
-            payment_mint: listing.payment_mint,
+            buyer: ctx.accounts.buyer.key(),
+            price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: if wns.is_some() { split.creator_royalty } else { payout.creator_royalty },
         });
-        Ok(())
+
+        // Close escrow_nft token account via CPI — rent to treasury (revenue)
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[escrow_seeds],
+        )?;
+
+        // Close listing account (owned by our program) — rent to treasury (revenue)
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+        )
     }
 
-    /// Cancel an active Core listing while the seller still holds the asset.
-    pub fn cancel_core_listing(ctx: Context<CancelCoreListing>) -> Result<()> {
-        // Only the original seller (= owner) may cancel
+    /// Buy a running auction outright at its buy-now price, ending it early.
+    ///
+    /// Only offered while the high bid has not passed `buy_now_cutoff_bps` of the
+    /// buy-now price. Any standing high bid is credited to its `BidRefund`.
+    /// Native-SOL auctions use `buy_now_auction_sol`.
+    /// remaining_accounts: same layout as list_item
+    pub fn buy_now_auction<'info>(ctx: Context<'_, '_, '_, 'info, BuyNowAuction<'info>>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(!listing.native_sol, AuctionError::InvalidPaymentMint);
+        ensure_buy_now_auction_open(listing, ctx.accounts.buyer.key(), clock.unix_timestamp)?;
+        let listing_instance = listing_instance_seed(listing.created_at);
+
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
         require!(
-            ctx.accounts.seller.key() == ctx.accounts.core_listing.seller,
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
             AuctionError::Unauthorized
         );
-        verify_active_core_listing_owner(
-            &ctx.accounts.asset.to_account_info(),
-            ctx.accounts.core_listing.seller,
-            ctx.accounts.collection.key(),
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let price = listing.buy_now_price;
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+        let split = escrow_sale_split(
+            listing,
+            price,
+            &nft_mint_info,
+            wns.is_some(),
+            ctx.accounts.creator_payment_account.key(),
         )?;
 
-        mpl_core::instructions::RevokePluginAuthorityV1Cpi {
-            __program: &ctx.accounts.mpl_core_program.to_account_info(),
-            asset: &ctx.accounts.asset.to_account_info(),
-            collection: Some(&ctx.accounts.collection.to_account_info()),
-            payer: &ctx.accounts.seller.to_account_info(),
-            authority: Some(&ctx.accounts.seller.to_account_info()),
-            system_program: &ctx.accounts.system_program.to_account_info(),
-            log_wrapper: None,
-            __args: mpl_core::instructions::RevokePluginAuthorityV1InstructionArgs {
-                plugin_type: mpl_core::types::PluginType::TransferDelegate,
-            },
+        // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+        listing.status = ListingStatus::Settled;
+
+        let nft_mint_key = listing.nft_mint;
+
+        // Credit the standing high bid's full escrowed maximum, claimable later
+        if listing.current_bid > 0 {
+            let highest_bidder_refund = ctx
+                .accounts
+                .highest_bidder_refund
+                .as_deref_mut()
+                .ok_or(AuctionError::InvalidRefundAccount)?;
+            credit_bid_refund(highest_bidder_refund, listing.highest_bidder_max)?;
         }
-        .invoke()?;
 
-        emit!(CoreListingCancelled {
-            asset: ctx.accounts.core_listing.asset,
-            seller: ctx.accounts.core_listing.seller,
+        // WNS takes its royalty from the buyer here; the split skips the creator leg
+        if let Some(wns) = &wns {
+            approve_wns_transfer(
+                wns,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.buyer.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                price,
+                Some(WnsPayment {
+                    payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                    payment_token_program: &ctx.accounts.token_program.to_account_info(),
+                    authority_token_account: &ctx.accounts.buyer_payment_account.to_account_info(),
+                }),
+                &[],
+            )?;
+        }
+
+        // Payment: buyer → seller / treasury / creator
+        let payout = pay_sale_split(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &split.direct_legs(wns.is_some()),
+            &[],
+        )?;
+
+        // Transfer NFT: escrow → buyer
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+        transfer_nft_from_escrow(
+            listing.is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.buyer_nft_account.to_account_info(),
+            ctx.remaining_accounts,
+            escrow_seeds,
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: if wns.is_some() { split.creator_royalty } else { payout.creator_royalty },
         });
-        // CoreListing PDA closed via `close = seller` constraint.
-        Ok(())
+
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[escrow_seeds],
+        )?;
+
+        if let (Some(bid_escrow), Some(bid_escrow_bump)) =
+            (ctx.accounts.bid_escrow.as_ref(), ctx.bumps.bid_escrow)
+        {
+            if bid_escrow.amount == 0 {
+                close_bid_escrow(
+                    &ctx.accounts.token_program.to_account_info(),
+                    &ctx.accounts.payment_mint.to_account_info(),
+                    &bid_escrow.to_account_info(),
+                    &ctx.accounts.treasury.to_account_info(),
+                    nft_mint_key,
+                    &listing_instance,
+                    bid_escrow_bump,
+                )?;
+            }
+        }
+
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+        )
     }
 
-    /// Close a stale Core listing after ownership changed outside the program.
-    /// Allows the current holder to clear old state and re-list the asset.
-    pub fn close_stale_core_listing(ctx: Context<CloseStaleCoreListing>) -> Result<()> {
-        let listing = &ctx.accounts.core_listing;
-        let (asset_owner, asset_collection) =
-            read_core_asset_owner_and_collection(&ctx.accounts.asset.to_account_info())?;
+    /// Native-SOL `buy_now_auction`: the buyer pays seller, treasury and creator
+    /// wallets in lamports. A standing high bid stays in `sol_escrow`, credited
+    /// to its `BidRefund` for `claim_refund`.
+    /// remaining_accounts: same layout as list_item
+    pub fn buy_now_auction_sol<'info>(ctx: Context<'_, '_, '_, 'info, BuyNowAuctionSol<'info>>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(listing.native_sol, AuctionError::InvalidPaymentMint);
+        ensure_buy_now_auction_open(listing, ctx.accounts.buyer.key(), clock.unix_timestamp)?;
+
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let price = listing.buy_now_price;
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+        let split = native_sale_split(
+            listing,
+            price,
+            &nft_mint_info,
+            wns.is_some(),
+            ctx.accounts.creator.key(),
+        )?;
+
+        // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+        listing.status = ListingStatus::Settled;
+
+        // WNS takes its royalty from the buyer's lamports; the split skips the creator leg
+        if let Some(wns) = &wns {
+            approve_wns_transfer(
+                wns,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.buyer.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                price,
+                None,
+                &[],
+            )?;
+        }
+
+        // Credit the standing high bid's full escrowed maximum, claimable later
+        if listing.current_bid > 0 {
+            let highest_bidder_refund = ctx
+                .accounts
+                .highest_bidder_refund
+                .as_deref_mut()
+                .ok_or(AuctionError::InvalidRefundAccount)?;
+            credit_bid_refund(highest_bidder_refund, listing.highest_bidder_max)?;
+        }
+
+        // Payment: buyer lamports → seller / treasury / creator
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let buyer = ctx.accounts.buyer.to_account_info();
+        let payout = pay_sale_split_lamports(
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &split.direct_legs(wns.is_some()),
+            |to, amount| transfer_lamports(&system_program, &buyer, to, amount),
+        )?;
+
+        // Transfer NFT: escrow → buyer
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let nft_mint_key = listing.nft_mint;
+        let escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+        transfer_nft_from_escrow(
+            listing.is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.buyer_nft_account.to_account_info(),
+            ctx.remaining_accounts,
+            escrow_seeds,
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: if wns.is_some() { split.creator_royalty } else { payout.creator_royalty },
+        });
+
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[escrow_seeds],
+        )?;
+
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+        )
+    }
+
+    /// Cancel a listing (seller only, auctions only if no bids)
+    ///
+    /// WNS NFTs are approved by CPI (amount 0, seller as authority).
+    /// remaining_accounts: same layout as list_item
+    pub fn cancel_listing<'info>(ctx: Context<'_, '_, '_, 'info, CancelListing<'info>>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+
+        require!(
+            ctx.accounts.seller.key() == listing.seller,
+            AuctionError::Unauthorized
+        );
+
+        if matches!(listing.listing_type, ListingType::Auction | ListingType::Sealed) {
+            require!(
+                listing.current_bid == 0 && listing.sealed_bid_count == 0,
+                AuctionError::CannotCancelWithBids
+            );
+        }
+
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        if let Some(wns) = wns_accounts(&nft_mint_info, ctx.remaining_accounts)? {
+            approve_wns_transfer(
+                &wns,
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.seller.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                0,
+                None,
+                &[],
+            )?;
+        }
+
+        // Return NFT: escrow → seller
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let nft_mint_key = listing.nft_mint;
+        let escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+        transfer_nft_from_escrow(
+            listing.is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.seller_nft_account.to_account_info(),
+            ctx.remaining_accounts,
+            escrow_seeds,
+        )?;
+
+        emit!(ListingCancelled {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+        });
+
+        // Close escrow_nft token account via CPI, return rent to seller
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[escrow_seeds],
+        )?;
+
+        // Close listing account (owned by our program), return rent to seller
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+        )
+    }
+
+    /// Reprice an active listing in place (seller only), leaving the NFT in escrow.
+    /// `price` is the fixed, Dutch starting or auction opening price; auctions
+    /// can only be repriced before the first bid. `end_time` (fixed price only)
+    /// sets a new expiry, 0 for none. `None` leaves a field unchanged.
+    pub fn update_listing(ctx: Context<UpdateListing>, price: Option<u64>, end_time: Option<i64>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+
+        require!(
+            ctx.accounts.seller.key() == listing.seller,
+            AuctionError::Unauthorized
+        );
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+
+        if let Some(price) = price {
+            validate_listing_price_update(listing, price)?;
+            let lowest_price = if matches!(listing.listing_type, ListingType::Dutch) {
+                listing.dutch_floor_price
+            } else {
+                price
+            };
+            validate_payment_mint_config(&ctx.accounts.payment_mint_config, Some(listing.category), lowest_price)?;
+            listing.price = price;
+        }
+        if let Some(end_time) = end_time {
+            require!(
+                matches!(listing.listing_type, ListingType::FixedPrice),
+                AuctionError::NotFixedPrice
+            );
+            listing.end_time = resolve_listing_expiry(end_time, listing.start_time, Clock::get()?.unix_timestamp)?;
+        }
+
+        emit!(ListingUpdated {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            price: listing.price,
+            end_time: listing.end_time,
+        });
+        Ok(())
+    }
+
+    /// Return the NFT of an expired fixed-price listing to its seller and close
+    /// the listing, with rent to the seller (permissionless).
+    ///
+    /// WNS NFTs are approved by CPI (amount 0, `escrow_nft` as authority).
+    /// remaining_accounts: same layout as list_item
+    pub fn close_expired_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredListing<'info>>,
+    ) -> Result<()> {
+        let listing = &ctx.accounts.listing;
+
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(!listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require!(
+            listing_expired(&listing.listing_type, listing.end_time, Clock::get()?.unix_timestamp),
+            AuctionError::ListingNotExpired
+        );
+
+        // Return NFT: escrow → seller
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let nft_mint_key = listing.nft_mint;
+        let escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        if let Some(wns) = wns_accounts(&nft_mint_info, ctx.remaining_accounts)? {
+            let escrow_nft_info = ctx.accounts.escrow_nft.to_account_info();
+            approve_wns_transfer(
+                &wns,
+                &escrow_nft_info,
+                &escrow_nft_info,
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                0,
+                None,
+                &[escrow_seeds],
+            )?;
+        }
+        transfer_nft_from_escrow(
+            listing.is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.seller_nft_account.to_account_info(),
+            ctx.remaining_accounts,
+            escrow_seeds,
+        )?;
+
+        emit!(ListingExpired {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+        });
+
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[escrow_seeds],
+        )?;
+
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+        )
+    }
+
+    /// Settle an auction after end time
+    ///
+    /// WNS NFTs are approved by CPI with `bid_escrow` as authority: for the
+    /// clearing price on a sale (WNS royalty paid from escrow, as in `buy_now`),
+    /// else 0. The layout's payment_mint is always the listing's payment mint.
+    /// remaining_accounts: same layout as list_item
+    pub fn settle_auction<'info>(ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>) -> Result<()> {
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(
+            matches!(listing.listing_type, ListingType::Auction | ListingType::Sealed),
+            AuctionError::NotAnAuction
+        );
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            clock.unix_timestamp >= bidding_closes_at(listing),
+            AuctionError::AuctionNotEnded
+        );
+        require!(!listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require!(!listing.native_sol, AuctionError::InvalidPaymentMint);
+
+        let bid_escrow_bump = ctx.bumps.bid_escrow;
+        let nft_mint_key = listing.nft_mint;
+        let listing_instance = listing_instance_seed(listing.created_at);
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let nft_escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+
+        let reserve_met = listing.current_bid > 0 && is_reserve_met(listing, clock.unix_timestamp)?;
+
+        // WNS NFTs: bid_escrow approves the move — for the clearing price on a
+        // sale (paying WNS's royalty), else 0
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let bid_escrow_info = ctx.accounts.bid_escrow.to_account_info();
+        let bid_escrow_seeds: &[&[u8]] =
+            &[b"bid_escrow", nft_mint_key.as_ref(), &listing_instance, &[bid_escrow_bump]];
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+        if let Some(wns) = &wns {
+            approve_wns_transfer(
+                wns,
+                &bid_escrow_info,
+                &bid_escrow_info,
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                if reserve_met { clearing_price(listing) } else { 0 },
+                Some(WnsPayment {
+                    payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                    payment_token_program: &ctx.accounts.token_program.to_account_info(),
+                    authority_token_account: &bid_escrow_info,
+                }),
+                &[bid_escrow_seeds],
+            )?;
+        }
+
+        if reserve_met {
+            // Validate buyer_nft_account is owned by the highest bidder
+            // (prevents redirecting the NFT to an attacker's account)
+            let buyer_nft_owner = ctx.accounts.buyer_nft_account.owner;
+            require!(
+                buyer_nft_owner == listing.highest_bidder,
+                AuctionError::InvalidBuyerAccount
+            );
+
+            // Auction has bids: distribute payments + transfer NFT to winner
+            let price = clearing_price(listing);
+            let split = escrow_sale_split(
+                listing,
+                price,
+                &nft_mint_info,
+                wns.is_some(),
+                ctx.accounts.creator_payment_account.key(),
+            )?;
+
+            // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+            listing.status = ListingStatus::Settled;
+
+            // Payment: bid_escrow → seller / treasury / creator
+            let payout = pay_sale_split(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &bid_escrow_info,
+                &bid_escrow_info,
+                &ctx.accounts.seller_payment_account.to_account_info(),
+                &ctx.accounts.treasury_payment_account.to_account_info(),
+                &ctx.accounts.creator_payment_account.to_account_info(),
+                &split.direct_legs(wns.is_some()),
+                &[bid_escrow_seeds],
+            )?;
+
+            // Winner only pays the clearing price — unused proxy / sealed headroom
+            // stays in bid_escrow, claimable by the winner
+            let excess = listing
+                .highest_bidder_max
+                .checked_sub(price)
+                .ok_or(AuctionError::CalculationError)?;
+            credit_high_bidder(
+                listing,
+                ctx.accounts.highest_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+                ctx.accounts.winner_sealed_bid.as_deref_mut().map(|sealed_bid| &mut **sealed_bid),
+                excess,
+            )?;
+
+            // Transfer NFT: escrow → winner
+            transfer_nft_from_escrow(
+                listing.is_token2022,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.buyer_nft_account.to_account_info(),
+                ctx.remaining_accounts,
+                nft_escrow_seeds,
+            )?;
+
+            emit!(AuctionSettled {
+                nft_mint: listing.nft_mint,
+                winner: listing.highest_bidder,
+                price,
+                platform_fee: split.platform_fee,
+                seller_amount: payout.seller_amount,
+                treasury_amount: payout.treasury_amount,
+                creator_royalty: if wns.is_some() { split.creator_royalty } else { payout.creator_royalty },
+            });
+        } else {
+            // No bids or reserve not met: the high bid becomes claimable, NFT returns to seller
+            if listing.current_bid > 0 {
+                credit_high_bidder(
+                    listing,
+                    ctx.accounts.highest_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+                    ctx.accounts.winner_sealed_bid.as_deref_mut().map(|sealed_bid| &mut **sealed_bid),
+                    listing.highest_bidder_max,
+                )?;
+            }
+
+            // Validate seller_nft_account is owned by the seller
+            let seller_nft_owner = ctx.accounts.seller_nft_account.owner;
+            require!(
+                seller_nft_owner == listing.seller,
+                AuctionError::Unauthorized
+            );
+
+            transfer_nft_from_escrow(
+                listing.is_token2022,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.seller_nft_account.to_account_info(),
+                ctx.remaining_accounts,
+                nft_escrow_seeds,
+            )?;
+
+            listing.status = ListingStatus::Cancelled;
+
+            let reason = if listing.current_bid > 0 {
+                "Reserve price not met"
+            } else {
+                "No bids received"
+            };
+            emit!(AuctionCancelled {
+                nft_mint: listing.nft_mint,
+                reason: reason.to_string(),
+            });
+        }
+
+        // Rent destination: treasury on sale, seller on no-bid / reserve-not-met cancel
+        let rent_dest = if listing.status == ListingStatus::Settled {
+            ctx.accounts.treasury.to_account_info()
+        } else {
+            ctx.accounts.seller.to_account_info()
+        };
+
+        // Close escrow_nft token account via CPI
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &rent_dest,
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[nft_escrow_seeds],
+        )?;
+
+        // Close bid_escrow token account if it exists and is empty
+        ctx.accounts.bid_escrow.reload()?;
+        if ctx.accounts.bid_escrow.amount == 0 {
+            close_bid_escrow(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &rent_dest,
+                nft_mint_key,
+                &listing_instance,
+                bid_escrow_bump,
+            )?;
+        }
+
+        // Close listing account (owned by our program)
+        close_listing_account(&ctx.accounts.listing.to_account_info(), &rent_dest)
+    }
+
+    /// Settle an ended native-SOL auction. Same outcomes as `settle_auction`, but
+    /// proceeds are paid in lamports from `sol_escrow` to the seller, treasury and
+    /// creator wallets.
+    ///
+    /// WNS NFTs: on a sale `payer` must sign; it pays WNS's royalty when approving
+    /// the move for the clearing price and is reimbursed from `sol_escrow`.
+    /// Otherwise `escrow_nft` approves it for 0.
+    /// remaining_accounts: same layout as list_item
+    pub fn settle_auction_sol<'info>(ctx: Context<'_, '_, '_, 'info, SettleAuctionSol<'info>>) -> Result<()> {
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(listing.native_sol, AuctionError::InvalidPaymentMint);
+        require!(
+            matches!(listing.listing_type, ListingType::Auction),
+            AuctionError::NotAnAuction
+        );
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            clock.unix_timestamp >= listing.end_time,
+            AuctionError::AuctionNotEnded
+        );
+
+        let nft_mint_key = listing.nft_mint;
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let nft_escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+
+        let reserve_met = listing.current_bid > 0 && is_reserve_met(listing, clock.unix_timestamp)?;
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+
+        if reserve_met {
+            let buyer_nft_owner = ctx.accounts.buyer_nft_account.owner;
+            require!(
+                buyer_nft_owner == listing.highest_bidder,
+                AuctionError::InvalidBuyerAccount
+            );
+
+            let price = listing.current_bid;
+            let split = native_sale_split(
+                listing,
+                price,
+                &nft_mint_info,
+                wns.is_some(),
+                ctx.accounts.creator.key(),
+            )?;
+
+            // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+            listing.status = ListingStatus::Settled;
+
+            // WNS pulls its royalty from a system-owned signer, so `payer` fronts
+            // it and sol_escrow reimburses it below; the split skips the creator leg
+            let wns_payer = match &wns {
+                Some(wns) => {
+                    let payer = ctx
+                        .accounts
+                        .payer
+                        .as_ref()
+                        .ok_or(AuctionError::InvalidWnsAccountLayout)?
+                        .to_account_info();
+                    approve_wns_transfer(
+                        wns,
+                        &payer,
+                        &payer,
+                        &nft_mint_info,
+                        &ctx.accounts.nft_token_program.to_account_info(),
+                        price,
+                        None,
+                        &[],
+                    )?;
+                    Some(payer)
+                }
+                None => None,
+            };
+
+            transfer_nft_from_escrow(
+                listing.is_token2022,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.buyer_nft_account.to_account_info(),
+                ctx.remaining_accounts,
+                nft_escrow_seeds,
+            )?;
+            // Close escrow_nft token account via CPI — rent to treasury (revenue)
+            close_token_account_cpi(
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &ctx.accounts.treasury.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &[nft_escrow_seeds],
+            )?;
+
+            // Payment: sol_escrow lamports → seller / treasury / creator. These are
+            // direct lamport edits, so they come after the last CPI: a CPI only
+            // sees the balances of its own accounts and would find the rest unbalanced
+            let sol_escrow = ctx
+                .accounts
+                .sol_escrow
+                .as_ref()
+                .ok_or(AuctionError::InvalidRefundAccount)?
+                .to_account_info();
+            if let Some(payer) = &wns_payer {
+                transfer_from_sol_escrow(&sol_escrow, payer, split.creator_royalty)?;
+            }
+
+            let payout = pay_sale_split_lamports(
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.treasury.to_account_info(),
+                &ctx.accounts.creator.to_account_info(),
+                &split.direct_legs(wns.is_some()),
+                |to, amount| transfer_from_sol_escrow(&sol_escrow, to, amount),
+            )?;
+
+            // Winner only pays the clearing price — unused proxy headroom stays claimable
+            let excess = listing
+                .highest_bidder_max
+                .checked_sub(price)
+                .ok_or(AuctionError::CalculationError)?;
+            credit_high_bidder(
+                listing,
+                ctx.accounts.highest_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+                None,
+                excess,
+            )?;
+
+            emit!(AuctionSettled {
+                nft_mint: listing.nft_mint,
+                winner: listing.highest_bidder,
+                price,
+                platform_fee: split.platform_fee,
+                seller_amount: payout.seller_amount,
+                treasury_amount: payout.treasury_amount,
+                creator_royalty: if wns.is_some() { split.creator_royalty } else { payout.creator_royalty },
+            });
+        } else {
+            // No bids or reserve not met: the high bid becomes claimable, NFT returns to seller
+            if listing.current_bid > 0 {
+                credit_high_bidder(
+                    listing,
+                    ctx.accounts.highest_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+                    None,
+                    listing.highest_bidder_max,
+                )?;
+            }
+
+            require!(
+                ctx.accounts.seller_nft_account.owner == listing.seller,
+                AuctionError::Unauthorized
+            );
+
+            if let Some(wns) = &wns {
+                let escrow_nft_info = ctx.accounts.escrow_nft.to_account_info();
+                approve_wns_transfer(
+                    wns,
+                    &escrow_nft_info,
+                    &escrow_nft_info,
+                    &nft_mint_info,
+                    &ctx.accounts.nft_token_program.to_account_info(),
+                    0,
+                    None,
+                    &[nft_escrow_seeds],
+                )?;
+            }
+
+            transfer_nft_from_escrow(
+                listing.is_token2022,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.seller_nft_account.to_account_info(),
+                ctx.remaining_accounts,
+                nft_escrow_seeds,
+            )?;
+            close_token_account_cpi(
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.escrow_nft.to_account_info(),
+                &[nft_escrow_seeds],
+            )?;
+
+            listing.status = ListingStatus::Cancelled;
+
+            let reason = if listing.current_bid > 0 {
+                "Reserve price not met"
+            } else {
+                "No bids received"
+            };
+            emit!(AuctionCancelled {
+                nft_mint: listing.nft_mint,
+                reason: reason.to_string(),
+            });
+        }
+
+        // Rent destination: treasury on sale, seller on no-bid / reserve-not-met cancel
+        let rent_dest = if listing.status == ListingStatus::Settled {
+            ctx.accounts.treasury.to_account_info()
+        } else {
+            ctx.accounts.seller.to_account_info()
+        };
+
+        if let Some(sol_escrow) = ctx.accounts.sol_escrow.as_ref() {
+            close_sol_escrow_if_empty(&sol_escrow.to_account_info(), &rent_dest)?;
+        }
+
+        close_listing_account(&ctx.accounts.listing.to_account_info(), &rent_dest)
+    }
+
+    /// Commit a sealed bid during the bidding phase.
+    ///
+    /// `commitment` = sha256(amount_le || salt || bidder). The `deposit` (at least
+    /// the opening price) is escrowed in `bid_escrow` now; the rest of the bid is
+    /// topped up at reveal.
+    pub fn commit_sealed_bid(ctx: Context<CommitSealedBid>, commitment: [u8; 32], deposit: u64) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(
+            matches!(listing.listing_type, ListingType::Sealed),
+            AuctionError::NotSealedAuction
+        );
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            clock.unix_timestamp < listing.end_time,
+            AuctionError::AuctionEnded
+        );
+        // Prevent shill bidding — seller cannot bid on own auction
+        require!(
+            ctx.accounts.bidder.key() != listing.seller,
+            AuctionError::SellerCannotBid
+        );
+        require!(commitment != [0u8; 32], AuctionError::InvalidSealedBid);
+        require_gte!(deposit, listing.price, AuctionError::BidTooLow);
+
+        deposit_payment(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.bidder_token_account.to_account_info(),
+            &ctx.accounts.bid_escrow.to_account_info(),
+            &ctx.accounts.bidder.to_account_info(),
+            deposit,
+        )?;
+
+        let sealed_bid = &mut ctx.accounts.sealed_bid;
+        sealed_bid.nft_mint = listing.nft_mint;
+        sealed_bid.bidder = ctx.accounts.bidder.key();
+        sealed_bid.seller = listing.seller;
+        sealed_bid.payment_mint = listing.payment_mint;
+        sealed_bid.commitment = commitment;
+        sealed_bid.escrowed = deposit;
+        sealed_bid.revealed = false;
+        sealed_bid.amount = 0;
+        sealed_bid.reveal_end_time = listing.reveal_end_time;
+        sealed_bid.forfeit_unrevealed = listing.forfeit_unrevealed;
+        sealed_bid.bump = ctx.bumps.sealed_bid;
+        sealed_bid.listing_created_at = listing.created_at;
+
+        listing.sealed_bid_count = listing
+            .sealed_bid_count
+            .checked_add(1)
+            .ok_or(AuctionError::CalculationError)?;
+
+        emit!(SealedBidCommitted {
+            nft_mint: listing.nft_mint,
+            bidder: sealed_bid.bidder,
+            deposit,
+        });
+
+        Ok(())
+    }
+
+    /// Reveal a sealed bid during the reveal window.
+    ///
+    /// The bid must match the commitment. If it exceeds the deposit, the
+    /// difference is escrowed now so the winning bid is always fully funded.
+    /// Bids below the opening price are recorded as revealed but not ranked.
+    pub fn reveal_sealed_bid(ctx: Context<RevealSealedBid>, amount: u64, salt: [u8; 32]) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let sealed_bid = &mut ctx.accounts.sealed_bid;
+        let clock = Clock::get()?;
+
+        require!(
+            matches!(listing.listing_type, ListingType::Sealed),
+            AuctionError::NotSealedAuction
+        );
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            clock.unix_timestamp >= listing.end_time
+                && clock.unix_timestamp < listing.reveal_end_time,
+            AuctionError::NotInRevealWindow
+        );
+        require!(!sealed_bid.revealed, AuctionError::InvalidSealedBid);
+        require!(
+            sealed_bid_commitment(amount, &salt, &sealed_bid.bidder) == sealed_bid.commitment,
+            AuctionError::InvalidSealedBid
+        );
+
+        if amount > sealed_bid.escrowed {
+            deposit_payment(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.bidder_token_account.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &ctx.accounts.bidder.to_account_info(),
+                amount - sealed_bid.escrowed,
+            )?;
+            sealed_bid.escrowed = amount;
+        }
+        sealed_bid.revealed = true;
+        sealed_bid.amount = amount;
+
+        // Rank: ties go to the earlier reveal
+        if amount >= listing.price {
+            if amount > listing.current_bid {
+                listing.second_bid = listing.current_bid;
+                listing.current_bid = amount;
+                listing.highest_bidder = sealed_bid.bidder;
+                listing.highest_bidder_max = sealed_bid.escrowed;
+            } else if amount > listing.second_bid {
+                listing.second_bid = amount;
+            }
+        }
+
+        emit!(SealedBidRevealed {
+            nft_mint: listing.nft_mint,
+            bidder: sealed_bid.bidder,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Reclaim a losing or unrevealed sealed bid once the reveal window closes.
+    ///
+    /// Revealed bids are refunded in full. Unrevealed deposits go to the seller
+    /// when the listing forfeits them, otherwise back to the bidder. The winning
+    /// bid is locked until `settle_auction` runs.
+    pub fn claim_sealed_bid(ctx: Context<ClaimSealedBid>) -> Result<()> {
+        let sealed_bid = &ctx.accounts.sealed_bid;
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= sealed_bid.reveal_end_time,
+            AuctionError::NotInRevealWindow
+        );
+
+        // The listing instance is gone after settlement (a relisting at the same
+        // PDA is another auction); while it is live the current leader's escrow
+        // belongs to the pending sale.
+        let listing_info = ctx.accounts.listing.to_account_info();
+        let leader =
+            live_listing_leader(&listing_info, sealed_bid.nft_mint, sealed_bid.listing_created_at)?;
+        let listing_open = leader.is_some();
+        require!(
+            !(leader == Some(sealed_bid.bidder) && sealed_bid.revealed),
+            AuctionError::WinningBidLocked
+        );
+
+        let forfeited = !sealed_bid.revealed && sealed_bid.forfeit_unrevealed;
+        let amount = sealed_bid.escrowed;
+        let listing_instance = listing_instance_seed(sealed_bid.listing_created_at);
+        let bid_escrow_bump = ctx.bumps.bid_escrow;
+
+        if amount > 0 {
+            let destination = if forfeited {
+                let seller_payment_account = ctx
+                    .accounts
+                    .seller_payment_account
+                    .as_ref()
+                    .ok_or(AuctionError::Unauthorized)?;
+                require!(
+                    seller_payment_account.owner == sealed_bid.seller,
+                    AuctionError::Unauthorized
+                );
+                seller_payment_account.to_account_info()
+            } else {
+                ctx.accounts.bidder_payment_account.to_account_info()
+            };
+            transfer_from_bid_escrow(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &destination,
+                sealed_bid.nft_mint,
+                &listing_instance,
+                bid_escrow_bump,
+                amount,
+            )?;
+        }
+
+        emit!(SealedBidClaimed {
+            nft_mint: sealed_bid.nft_mint,
+            bidder: sealed_bid.bidder,
+            amount,
+            forfeited,
+        });
+
+        // Last claim after settlement: close the emptied bid escrow
+        ctx.accounts.bid_escrow.reload()?;
+        if !listing_open && ctx.accounts.bid_escrow.amount == 0 {
+            close_bid_escrow(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &ctx.accounts.bidder.to_account_info(),
+                sealed_bid.nft_mint,
+                &listing_instance,
+                bid_escrow_bump,
+            )?;
+        }
+
+        // SealedBid PDA closed via `close = bidder` constraint.
+        Ok(())
+    }
+
+    /// Withdraw outbid or unspent funds credited to the caller's `BidRefund`.
+    ///
+    /// Works for SPL, Core and bundle auctions. The record is closed unless the caller is
+    /// the current high bidder, who must keep it so a later outbid can be credited.
+    /// Once the listing instance is gone (settled, cancelled or relisted), the
+    /// last claim also closes its empty bid escrow.
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        let nft_mint_key = ctx.accounts.bid_refund.nft_mint;
+        let bidder_key = ctx.accounts.bidder.key();
+        let amount = ctx.accounts.bid_refund.amount;
+        let listing_created_at = ctx.accounts.bid_refund.listing_created_at;
+        let listing_instance = listing_instance_seed(listing_created_at);
+
+        // A relisting at the same PDA is another instance: this one is closed
+        let listing_info = ctx.accounts.listing.to_account_info();
+        let leader = live_listing_leader(&listing_info, nft_mint_key, listing_created_at)?;
+        let is_leader = leader == Some(bidder_key);
+        let listing_open = leader.is_some();
+
+        ctx.accounts.bid_refund.amount = 0;
+
+        if ctx.accounts.bid_refund.native {
+            let sol_escrow = ctx
+                .accounts
+                .sol_escrow
+                .as_ref()
+                .ok_or(AuctionError::InvalidRefundAccount)?
+                .to_account_info();
+            if amount > 0 {
+                transfer_from_sol_escrow(&sol_escrow, &ctx.accounts.bidder.to_account_info(), amount)?;
+            }
+            if !listing_open {
+                close_sol_escrow_if_empty(&sol_escrow, &ctx.accounts.bidder.to_account_info())?;
+            }
+        } else if let Some(bid_escrow) = ctx.accounts.bid_escrow.as_mut() {
+            let bid_escrow_bump = ctx.bumps.bid_escrow.ok_or(AuctionError::InvalidRefundAccount)?;
+            let payment_mint = ctx
+                .accounts
+                .payment_mint
+                .as_ref()
+                .ok_or(AuctionError::InvalidRefundAccount)?
+                .to_account_info();
+            if amount > 0 {
+                let bidder_payment_account = ctx
+                    .accounts
+                    .bidder_payment_account
+                    .as_ref()
+                    .ok_or(AuctionError::InvalidRefundAccount)?;
+                transfer_from_bid_escrow(
+                    &ctx.accounts.token_program.to_account_info(),
+                    &payment_mint,
+                    &bid_escrow.to_account_info(),
+                    &bidder_payment_account.to_account_info(),
+                    nft_mint_key,
+                    &listing_instance,
+                    bid_escrow_bump,
+                    amount,
+                )?;
+            }
+
+            bid_escrow.reload()?;
+            if !listing_open && bid_escrow.amount == 0 {
+                close_bid_escrow(
+                    &ctx.accounts.token_program.to_account_info(),
+                    &payment_mint,
+                    &bid_escrow.to_account_info(),
+                    &ctx.accounts.bidder.to_account_info(),
+                    nft_mint_key,
+                    &listing_instance,
+                    bid_escrow_bump,
+                )?;
+            }
+        } else {
+            // Escrow already closed — only an empty record can be cleaned up
+            require!(amount == 0, AuctionError::InvalidRefundAccount);
+        }
+
+        emit!(RefundClaimed {
+            nft_mint: nft_mint_key,
+            bidder: bidder_key,
+            amount,
+        });
+
+        if !is_leader {
+            ctx.accounts
+                .bid_refund
+                .close(ctx.accounts.bidder.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    /// Reveal a hidden reserve price (seller only).
+    ///
+    /// Settlement waits for the reveal until `RESERVE_REVEAL_WINDOW_SECONDS`
+    /// after the auction ends; a reserve still hidden by then counts as met and
+    /// the high bid wins, so the reveal is rejected from then on. The revealed
+    /// reserve can't be below the start price.
+    pub fn reveal_reserve(ctx: Context<RevealReserve>, reserve_price: u64, salt: [u8; 32]) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            listing.reserve_commitment != [0u8; 32],
+            AuctionError::NoHiddenReserve
+        );
+        require!(
+            now < reserve_reveal_deadline(listing)?,
+            AuctionError::ReserveRevealClosed
+        );
+        require!(
+            reserve_commitment(reserve_price, &salt) == listing.reserve_commitment,
+            AuctionError::InvalidReserveReveal
+        );
+        require!(reserve_price >= listing.price, AuctionError::InvalidReserveReveal);
+
+        listing.reserve_price = reserve_price;
+        listing.reserve_commitment = [0u8; 32];
+
+        // A standing proxy that covers the revealed reserve bids up to it
+        if reserve_price > listing.current_bid && listing.highest_bidder_max >= reserve_price {
+            listing.current_bid = reserve_price;
+        }
+
+        emit!(ReserveRevealed {
+            nft_mint: listing.nft_mint,
+            reserve_price,
+        });
+
+        Ok(())
+    }
+
+    /// List a pNFT (Metaplex programmable NFT) for sale.
+    /// Uses Token Metadata TransferV1 CPI with delegate + token_record.
+    #[allow(clippy::too_many_arguments)]
+    pub fn list_item_pnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListItemPnft<'info>>,
+        listing_type: ListingType,
+        price: u64,
+        duration_seconds: Option<i64>,
+        category: ItemCategory,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+        options: ListingOptions,
+    ) -> Result<()> {
+        let ListingOptions { soft_close, reserve, buy_now, bid_increment, start_time, dutch, sealed } = options;
+        let clock = Clock::get()?;
+        let listing = &mut ctx.accounts.listing;
+
+        require!(price > 0, AuctionError::InvalidPrice);
+        require!(royalty_basis_points <= 1000, AuctionError::RoyaltyTooHigh);
+        let lowest_price = dutch.map_or(price, |config| config.floor_price.min(price));
+        validate_payment_mint_config(&ctx.accounts.payment_mint_config, Some(category), lowest_price)?;
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+
+        validate_duration(&listing_type, duration_seconds)?;
+        validate_soft_close(&listing_type, &soft_close)?;
+        validate_reserve(&listing_type, price, &reserve)?;
+        validate_buy_now(&listing_type, price, &reserve, &buy_now)?;
+        validate_bid_increment(&listing_type, &bid_increment)?;
+        validate_dutch(&listing_type, price, &dutch)?;
+        validate_sealed(&listing_type, &sealed)?;
+
+        listing.seller = ctx.accounts.seller.key();
+        listing.nft_mint = ctx.accounts.nft_mint.key();
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.price = price;
+        listing.listing_type = listing_type;
+        listing.category = category;
+        listing.start_time = resolve_start_time(start_time, clock.unix_timestamp)?;
+        listing.end_time = if let Some(d) = duration_seconds { listing.start_time + d } else { 0 };
+        listing.status = ListingStatus::Active;
+        listing.escrow_nft_account = ctx.accounts.escrow_nft_token.key();
+        listing.current_bid = 0;
+        listing.highest_bidder = Pubkey::default();
+        listing.highest_bidder_max = 0;
+        listing.baxus_fee = false;
+        listing.is_token2022 = false;
+        listing.is_pnft = true;
+        listing.native_sol = false;
+        listing.royalty_basis_points = royalty_basis_points;
+        listing.creator_address = creator_address;
+        listing.bump = ctx.bumps.listing;
+        listing.created_at = clock.unix_timestamp;
+        apply_soft_close(listing, soft_close)?;
+        apply_reserve(listing, reserve);
+        listing.buy_now_price = buy_now.map_or(0, |config| config.price);
+        listing.buy_now_cutoff_bps = buy_now.map_or(0, |config| config.cutoff_bps);
+        listing.bid_increment = bid_increment.unwrap_or(ctx.accounts.payment_mint_config.bid_increment);
+        listing.dutch_floor_price = dutch.map_or(0, |config| config.floor_price);
+        listing.dutch_curve = dutch.map_or(DutchCurve::Linear, |config| config.curve);
+        apply_sealed(listing, sealed)?;
+
+        // Transfer pNFT from seller to escrow via Token Metadata TransferV1 raw CPI
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.seller_nft_token.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.escrow_nft_token.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.seller_token_record.to_account_info(),
+            &ctx.accounts.escrow_token_record.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            &[],
+        )?;
+
+        emit!(ListingCreated {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            listing_type,
+            price,
+            category,
+            start_time: listing.start_time,
+            end_time: listing.end_time,
+            payment_mint: listing.payment_mint,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a pNFT listing — return NFT to seller.
+    pub fn cancel_listing_pnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelListingPnft<'info>>
+    ) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+
+        require!(ctx.accounts.seller.key() == listing.seller, AuctionError::Unauthorized);
+        require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
+        if matches!(listing.listing_type, ListingType::Auction | ListingType::Sealed) {
+            require!(
+                listing.current_bid == 0 && listing.sealed_bid_count == 0,
+                AuctionError::CannotCancelWithBids
+            );
+        }
+
+        let nft_mint_key = listing.nft_mint;
+        let escrow_auth_bump = ctx.bumps.escrow_authority;
+        let escrow_auth_seeds: &[&[u8]] = &[b"escrow_authority", nft_mint_key.as_ref(), &[escrow_auth_bump]];
+
+        // Transfer pNFT escrow → seller via Token Metadata TransferV1 raw CPI
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.escrow_nft_token.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.seller_nft_token.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.escrow_token_record.to_account_info(),
+            &ctx.accounts.seller_token_record.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            &[escrow_auth_seeds],
+        )?;
+
+        emit!(ListingCancelled { nft_mint: listing.nft_mint, seller: listing.seller });
+
+        // Close listing — rent to seller
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+        )
+    }
+
+    /// Return the pNFT of an expired fixed-price listing to its seller
+    /// (permissionless). `payer` funds the seller's token record if needed;
+    /// listing rent goes to the seller.
+    pub fn close_expired_listing_pnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredListingPnft<'info>>
+    ) -> Result<()> {
+        let listing = &ctx.accounts.listing;
+
+        require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            listing_expired(&listing.listing_type, listing.end_time, Clock::get()?.unix_timestamp),
+            AuctionError::ListingNotExpired
+        );
+
+        let nft_mint_key = listing.nft_mint;
+        let escrow_auth_bump = ctx.bumps.escrow_authority;
+        let escrow_auth_seeds: &[&[u8]] = &[b"escrow_authority", nft_mint_key.as_ref(), &[escrow_auth_bump]];
+
+        // Transfer pNFT escrow → seller via Token Metadata TransferV1 raw CPI
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.escrow_nft_token.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.seller_nft_token.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.escrow_token_record.to_account_info(),
+            &ctx.accounts.seller_token_record.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            &[escrow_auth_seeds],
+        )?;
+
+        emit!(ListingExpired { nft_mint: listing.nft_mint, seller: listing.seller });
+
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+        )
+    }
+
+    /// Buy a fixed-price or Dutch pNFT listing.
+    ///
+    /// Same pricing and fee split as `buy_now`; the pNFT moves escrow → buyer via
+    /// Token Metadata TransferV1 so token records and rule sets are honoured.
+    pub fn buy_now_pnft<'info>(ctx: Context<'_, '_, '_, 'info, BuyNowPnft<'info>>) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require!(
+            matches!(listing.listing_type, ListingType::FixedPrice | ListingType::Dutch),
+            AuctionError::NotFixedPrice
+        );
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            !listing_expired(&listing.listing_type, listing.end_time, clock.unix_timestamp),
+            AuctionError::ListingExpired
+        );
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let price = current_listing_price(listing, clock.unix_timestamp)?;
+        let split = calculate_sale_split(price, listing.baxus_fee, listing.royalty_basis_points)?;
+        validate_creator_payment_account(
+            listing,
+            ctx.accounts.creator_payment_account.key(),
+            split.creator_royalty,
+        )?;
+
+        // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+        listing.status = ListingStatus::Settled;
+
+        // Payment: buyer → seller / treasury / creator
+        let payout = pay_sale_split(
+            &ctx.accounts.payment_token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &split,
+            &[],
+        )?;
+
+        let nft_mint_key = listing.nft_mint;
+        let escrow_auth_bump = ctx.bumps.escrow_authority;
+        let escrow_auth_seeds: &[&[u8]] = &[b"escrow_authority", nft_mint_key.as_ref(), &[escrow_auth_bump]];
+
+        // Transfer pNFT escrow → buyer via Token Metadata TransferV1 raw CPI
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.escrow_nft_token.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.buyer_nft_token.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.escrow_token_record.to_account_info(),
+            &ctx.accounts.buyer_token_record.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            &[escrow_auth_seeds],
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: payout.creator_royalty,
+        });
+
+        // Close listing account (owned by our program) — rent to treasury (revenue)
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+        )
+    }
+
+    /// Settle an ended pNFT auction (English or sealed-bid).
+    ///
+    /// Mirrors `settle_auction`: on a sale the winner receives the pNFT and any
+    /// unused escrow is credited back; otherwise the high bid is credited back and
+    /// the pNFT returns to the seller. `destination_owner` must be the winner or seller accordingly.
+    /// Permissionless — `payer` funds the destination token record if needed.
+    pub fn settle_auction_pnft<'info>(ctx: Context<'_, '_, '_, 'info, SettleAuctionPnft<'info>>) -> Result<()> {
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let listing = &mut ctx.accounts.listing;
+        let clock = Clock::get()?;
+
+        require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require!(
+            matches!(listing.listing_type, ListingType::Auction | ListingType::Sealed),
+            AuctionError::NotAnAuction
+        );
+        require!(
+            listing.status == ListingStatus::Active,
+            AuctionError::ListingNotActive
+        );
+        require!(
+            clock.unix_timestamp >= bidding_closes_at(listing),
+            AuctionError::AuctionNotEnded
+        );
+
+        let bid_escrow_bump = ctx.bumps.bid_escrow;
+        let nft_mint_key = listing.nft_mint;
+        let listing_instance = listing_instance_seed(listing.created_at);
+        let reserve_met = listing.current_bid > 0 && is_reserve_met(listing, clock.unix_timestamp)?;
+
+        if reserve_met {
+            // Prevents redirecting the pNFT to an attacker's wallet
+            require!(
+                ctx.accounts.destination_owner.key() == listing.highest_bidder,
+                AuctionError::InvalidBuyerAccount
+            );
+
+            let price = clearing_price(listing);
+            let split = calculate_sale_split(
+                price,
+                listing.baxus_fee,
+                listing.royalty_basis_points,
+            )?;
+            validate_creator_payment_account(
+                listing,
+                ctx.accounts.creator_payment_account.key(),
+                split.creator_royalty,
+            )?;
+
+            // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+            listing.status = ListingStatus::Settled;
+
+            // Payment: bid_escrow → seller / treasury / creator
+            let payout = pay_sale_split(
+                &ctx.accounts.payment_token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &ctx.accounts.seller_payment_account.to_account_info(),
+                &ctx.accounts.treasury_payment_account.to_account_info(),
+                &ctx.accounts.creator_payment_account.to_account_info(),
+                &split,
+                &[&[b"bid_escrow", nft_mint_key.as_ref(), &listing_instance, &[bid_escrow_bump]]],
+            )?;
+
+            // Winner only pays the clearing price — unused proxy / sealed headroom
+            // stays in bid_escrow, claimable by the winner
+            let excess = listing
+                .highest_bidder_max
+                .checked_sub(price)
+                .ok_or(AuctionError::CalculationError)?;
+            credit_high_bidder(
+                listing,
+                ctx.accounts.highest_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+                ctx.accounts.winner_sealed_bid.as_deref_mut().map(|sealed_bid| &mut **sealed_bid),
+                excess,
+            )?;
+
+            emit!(AuctionSettled {
+                nft_mint: listing.nft_mint,
+                winner: listing.highest_bidder,
+                price,
+                platform_fee: split.platform_fee,
+                seller_amount: payout.seller_amount,
+                treasury_amount: payout.treasury_amount,
+                creator_royalty: payout.creator_royalty,
+            });
+        } else {
+            require!(
+                ctx.accounts.destination_owner.key() == listing.seller,
+                AuctionError::Unauthorized
+            );
+
+            // No bids or reserve not met: the high bid becomes claimable, pNFT returns to seller
+            if listing.current_bid > 0 {
+                credit_high_bidder(
+                    listing,
+                    ctx.accounts.highest_bidder_refund.as_deref_mut().map(|refund| &mut **refund),
+                    ctx.accounts.winner_sealed_bid.as_deref_mut().map(|sealed_bid| &mut **sealed_bid),
+                    listing.highest_bidder_max,
+                )?;
+            }
+
+            listing.status = ListingStatus::Cancelled;
+
+            let reason = if listing.current_bid > 0 {
+                "Reserve price not met"
+            } else {
+                "No bids received"
+            };
+            emit!(AuctionCancelled {
+                nft_mint: listing.nft_mint,
+                reason: reason.to_string(),
+            });
+        }
+
+        let escrow_auth_bump = ctx.bumps.escrow_authority;
+        let escrow_auth_seeds: &[&[u8]] = &[b"escrow_authority", nft_mint_key.as_ref(), &[escrow_auth_bump]];
+
+        // Transfer pNFT escrow → winner (or seller) via Token Metadata TransferV1 raw CPI
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.escrow_nft_token.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.destination_nft_token.to_account_info(),
+            &ctx.accounts.destination_owner.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.escrow_token_record.to_account_info(),
+            &ctx.accounts.destination_token_record.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            &[escrow_auth_seeds],
+        )?;
+
+        // Rent destination: treasury on sale, seller on no-bid / reserve-not-met cancel
+        let rent_dest = if ctx.accounts.listing.status == ListingStatus::Settled {
+            ctx.accounts.treasury.to_account_info()
+        } else {
+            ctx.accounts.seller.to_account_info()
+        };
+
+        // Close bid_escrow token account if it exists and is empty
+        ctx.accounts.bid_escrow.reload()?;
+        if ctx.accounts.bid_escrow.amount == 0 {
+            close_bid_escrow(
+                &ctx.accounts.payment_token_program.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.bid_escrow.to_account_info(),
+                &rent_dest,
+                nft_mint_key,
+                &listing_instance,
+                bid_escrow_bump,
+            )?;
+        }
+
+        // Close listing account (owned by our program)
+        close_listing_account(&ctx.accounts.listing.to_account_info(), &rent_dest)
+    }
+
+    /// Close a stale listing where escrow is empty (NFT already returned)
+    /// This allows re-listing the same NFT after a cancelled listing
+    pub fn close_stale_listing(ctx: Context<CloseStaleListing>) -> Result<()> {
+        let listing = &ctx.accounts.listing;
+
+        // Only the original seller can close their stale listing
+        require!(
+            ctx.accounts.seller.key() == listing.seller,
+            AuctionError::Unauthorized
+        );
+
+        // Verify escrow is empty (NFT already returned)
+        require!(
+            ctx.accounts.escrow_nft.amount == 0,
+            AuctionError::ListingNotActive // reuse: listing still has NFT
+        );
+
+        emit!(ListingCancelled {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+        });
+
+        // Close escrow_nft token account via CPI, return rent to seller
+        let nft_mint_key = listing.nft_mint;
+        let escrow_bump = ctx.bumps.escrow_nft;
+        let close_escrow_seeds: &[&[u8]] = &[
+            b"escrow_nft",
+            nft_mint_key.as_ref(),
+            &[escrow_bump],
+        ];
+        close_token_account_cpi(
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.escrow_nft.to_account_info(),
+            &[close_escrow_seeds],
+        )?;
+
+        // Close listing account (owned by our program)
+        close_listing_account(
+            &ctx.accounts.listing.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+        )
+    }
+
+    // ========================================================================
+    // Delegate (escrowless) listings for SPL / Token-2022 NFTs
+    //
+    // Like Core listings, the NFT stays in the seller's token account: the
+    // `delegate_listing` PDA is approved as token delegate for one unit and
+    // signs the transfer to the buyer. Fixed price only. If the seller moves
+    // the NFT or revokes the approval, the listing goes stale and anyone may
+    // close it with `close_stale_delegated_listing`.
+    // ========================================================================
+
+    /// List an SPL / Token-2022 NFT without escrow by approving the listing PDA
+    /// as delegate. `royalty_basis_points` and `creator_address` are as for
+    /// `list_item`; `start_time` and `duration_seconds` as for `list_core_item`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn list_item_delegated(
+        ctx: Context<ListItemDelegated>,
+        price: u64,
+        category: ItemCategory,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+        start_time: Option<i64>,
+        duration_seconds: Option<i64>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.nft_token_program.key() == Token::id()
+                || ctx.accounts.nft_token_program.key() == spl_token_2022::id(),
+            AuctionError::InvalidTokenProgram
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let (start_time, end_time) = validate_delegate_listing(
+            price,
+            category,
+            royalty_basis_points,
+            &ctx.accounts.payment_mint_config,
+            &ctx.accounts.payment_mint.to_account_info(),
+            start_time,
+            duration_seconds,
+            now,
+        )?;
+        validate_wns_royalty(&ctx.accounts.nft_mint.to_account_info())?;
+
+        let listing = &mut ctx.accounts.delegate_listing;
+        listing.seller = ctx.accounts.seller.key();
+        listing.nft_mint = ctx.accounts.nft_mint.key();
+        listing.seller_nft_account = ctx.accounts.seller_nft_account.key();
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.price = price;
+        listing.category = category;
+        listing.start_time = start_time;
+        listing.end_time = end_time;
+        listing.is_token2022 = ctx.accounts.nft_token_program.key() != Token::id();
+        listing.is_pnft = false;
+        listing.royalty_basis_points = royalty_basis_points;
+        listing.creator_address = creator_address;
+        listing.created_at = now;
+        listing.bump = ctx.bumps.delegate_listing;
+
+        token_interface::approve(
+            CpiContext::new(
+                ctx.accounts.nft_token_program.to_account_info(),
+                token_interface::Approve {
+                    to: ctx.accounts.seller_nft_account.to_account_info(),
+                    delegate: listing.to_account_info(),
+                    authority: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        emit!(ListingCreated {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            listing_type: ListingType::FixedPrice,
+            price,
+            category,
+            start_time: listing.start_time,
+            end_time: listing.end_time,
+            payment_mint: listing.payment_mint,
+        });
+        Ok(())
+    }
+
+    /// Buy a delegate listing: the listing PDA moves the NFT straight from the
+    /// seller's token account to the buyer. remaining_accounts: same layout as
+    /// list_item
+    pub fn buy_now_delegated<'info>(ctx: Context<'_, '_, '_, 'info, BuyNowDelegated<'info>>) -> Result<()> {
+        let listing = &ctx.accounts.delegate_listing;
+        let clock = Clock::get()?;
+
+        require!(!listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            !listing_expired(&ListingType::FixedPrice, listing.end_time, clock.unix_timestamp),
+            AuctionError::ListingExpired
+        );
+        require!(
+            delegated_listing_live(listing, listing.key(), &ctx.accounts.seller_nft_account.to_account_info())?,
+            AuctionError::StaleListing
+        );
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        // WNS NFTs are charged the royalty in their metadata, collected by WNS
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+        let split = if wns.is_some() {
+            calculate_sale_split(listing.price, false, read_wns_royalty_bps(&nft_mint_info)?)?
+        } else {
+            let split = calculate_sale_split(listing.price, false, listing.royalty_basis_points)?;
+            validate_creator_ata(
+                listing.creator_address,
+                listing.payment_mint,
+                ctx.accounts.creator_payment_account.key(),
+                split.creator_royalty,
+            )?;
+            split
+        };
+
+        if let Some(wns) = &wns {
+            approve_wns_transfer(
+                wns,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.buyer.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                listing.price,
+                Some(WnsPayment {
+                    payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                    payment_token_program: &ctx.accounts.token_program.to_account_info(),
+                    authority_token_account: &ctx.accounts.buyer_payment_account.to_account_info(),
+                }),
+                &[],
+            )?;
+        }
+
+        // Payment: buyer → seller / treasury / creator
+        let payout = pay_sale_split(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &split.direct_legs(wns.is_some()),
+            &[],
+        )?;
+
+        // Transfer NFT: seller → buyer, signed by the listing PDA as delegate
+        let nft_mint_key = listing.nft_mint;
+        let listing_seeds: &[&[u8]] = &[b"delegate_listing", nft_mint_key.as_ref(), &[listing.bump]];
+        transfer_nft_as_delegate(
+            listing.is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.seller_nft_account.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.buyer_nft_account.to_account_info(),
+            &listing.to_account_info(),
+            ctx.remaining_accounts,
+            listing_seeds,
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price: listing.price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: if wns.is_some() { split.creator_royalty } else { payout.creator_royalty },
+        });
+        // DelegateListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Cancel a delegate listing (seller only), revoking the approval if the
+    /// listing PDA still holds it.
+    pub fn cancel_delegated_listing(ctx: Context<CancelDelegatedListing>) -> Result<()> {
+        let listing = &ctx.accounts.delegate_listing;
+        let seller_nft_account = ctx.accounts.seller_nft_account.to_account_info();
+
+        require!(!listing.is_pnft, AuctionError::InvalidTokenProgram);
+        if delegated_listing_live(listing, listing.key(), &seller_nft_account)? {
+            token_interface::revoke(CpiContext::new(
+                ctx.accounts.nft_token_program.to_account_info(),
+                token_interface::Revoke {
+                    source: seller_nft_account,
+                    authority: ctx.accounts.seller.to_account_info(),
+                },
+            ))?;
+        }
+
+        emit!(ListingCancelled {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+        });
+        // DelegateListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Close a delegate listing that can no longer sell (permissionless): the
+    /// NFT left the seller's token account, the approval was revoked or
+    /// replaced, or a fixed expiry passed. Rent goes to the seller. An expired
+    /// pNFT listing still holds its Sale delegate, which only the seller can
+    /// revoke, so it is closed with `cancel_delegated_listing_pnft` instead.
+    pub fn close_stale_delegated_listing(ctx: Context<CloseStaleDelegatedListing>) -> Result<()> {
+        let listing = &ctx.accounts.delegate_listing;
+        let live = delegated_listing_live(
+            listing,
+            listing.key(),
+            &ctx.accounts.seller_nft_account.to_account_info(),
+        )?;
+        let expired = listing_expired(&ListingType::FixedPrice, listing.end_time, Clock::get()?.unix_timestamp);
+        require!(!live || (expired && !listing.is_pnft), AuctionError::ListingNotStale);
+
+        if live {
+            emit!(ListingExpired {
+                nft_mint: listing.nft_mint,
+                seller: listing.seller,
+            });
+        } else {
+            emit!(ListingCancelled {
+                nft_mint: listing.nft_mint,
+                seller: listing.seller,
+            });
+        }
+        // DelegateListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// List a pNFT without escrow. The listing PDA becomes the pNFT's Token
+    /// Metadata Sale delegate, which locks it in the seller's wallet (token
+    /// record `Listed`, account frozen) until it is bought or the listing is
+    /// cancelled. Arguments as for `list_item_delegated`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn list_item_pnft_delegated<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListItemPnftDelegated<'info>>,
+        price: u64,
+        category: ItemCategory,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+        start_time: Option<i64>,
+        duration_seconds: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let (start_time, end_time) = validate_delegate_listing(
+            price,
+            category,
+            royalty_basis_points,
+            &ctx.accounts.payment_mint_config,
+            &ctx.accounts.payment_mint.to_account_info(),
+            start_time,
+            duration_seconds,
+            now,
+        )?;
+
+        let listing = &mut ctx.accounts.delegate_listing;
+        listing.seller = ctx.accounts.seller.key();
+        listing.nft_mint = ctx.accounts.nft_mint.key();
+        listing.seller_nft_account = ctx.accounts.seller_nft_token.key();
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.price = price;
+        listing.category = category;
+        listing.start_time = start_time;
+        listing.end_time = end_time;
+        listing.is_token2022 = false;
+        listing.is_pnft = true;
+        listing.royalty_basis_points = royalty_basis_points;
+        listing.creator_address = creator_address;
+        listing.created_at = now;
+        listing.bump = ctx.bumps.delegate_listing;
+
+        set_pnft_sale_delegate(
+            true,
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &listing.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.seller_token_record.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.seller_nft_token.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+        )?;
+
+        emit!(ListingCreated {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            listing_type: ListingType::FixedPrice,
+            price,
+            category,
+            start_time: listing.start_time,
+            end_time: listing.end_time,
+            payment_mint: listing.payment_mint,
+        });
+        Ok(())
+    }
+
+    /// Buy a delegated pNFT listing. The listing PDA, as Sale delegate, moves
+    /// the pNFT seller → buyer via Token Metadata TransferV1, which unlocks it,
+    /// clears the delegate and enforces the collection's rule set.
+    pub fn buy_now_pnft_delegated<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNowPnftDelegated<'info>>,
+    ) -> Result<()> {
+        let listing = &ctx.accounts.delegate_listing;
+        let clock = Clock::get()?;
+
+        require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            !listing_expired(&ListingType::FixedPrice, listing.end_time, clock.unix_timestamp),
+            AuctionError::ListingExpired
+        );
+        require!(
+            delegated_listing_live(listing, listing.key(), &ctx.accounts.seller_nft_token.to_account_info())?,
+            AuctionError::StaleListing
+        );
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let split = calculate_sale_split(listing.price, false, listing.royalty_basis_points)?;
+        validate_creator_ata(
+            listing.creator_address,
+            listing.payment_mint,
+            ctx.accounts.creator_payment_account.key(),
+            split.creator_royalty,
+        )?;
+
+        // Payment: buyer → seller / treasury / creator
+        let payout = pay_sale_split(
+            &ctx.accounts.payment_token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &split,
+            &[],
+        )?;
+
+        let nft_mint_key = listing.nft_mint;
+        let listing_seeds: &[&[u8]] = &[b"delegate_listing", nft_mint_key.as_ref(), &[listing.bump]];
+
+        // Transfer pNFT seller → buyer, signed by the listing PDA as Sale delegate
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.seller_nft_token.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.buyer_nft_token.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.seller_token_record.to_account_info(),
+            &ctx.accounts.buyer_token_record.to_account_info(),
+            &listing.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            &[listing_seeds],
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price: listing.price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: payout.creator_royalty,
+        });
+        // DelegateListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Cancel a delegated pNFT listing (seller only): revokes the Sale delegate,
+    /// which unlocks the pNFT, if the listing PDA still holds it. Also the way
+    /// to close an expired pNFT listing.
+    pub fn cancel_delegated_listing_pnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelDelegatedListingPnft<'info>>,
+    ) -> Result<()> {
+        let listing = &ctx.accounts.delegate_listing;
+
+        require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
+        if delegated_listing_live(listing, listing.key(), &ctx.accounts.seller_nft_token.to_account_info())? {
+            set_pnft_sale_delegate(
+                false,
+                &ctx.accounts.token_metadata_program.to_account_info(),
+                &listing.to_account_info(),
+                &ctx.accounts.nft_metadata.to_account_info(),
+                &ctx.accounts.nft_edition.to_account_info(),
+                &ctx.accounts.seller_token_record.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.seller_nft_token.to_account_info(),
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.sysvar_instructions.to_account_info(),
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+                ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            )?;
+        }
+
+        emit!(ListingCancelled {
+            nft_mint: listing.nft_mint,
+            seller: listing.seller,
+        });
+        // DelegateListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    // ========================================================================
+    // Metaplex Core listing flow (Artifacte v2)
+    //
+    // Non-custodial: the asset stays in the seller's wallet during listing.
+    // The program is approved as a TransferDelegate plugin authority via
+    // mpl-core CPI; on buy, the program signs the TransferV1 CPI as that
+    // delegate, transferring the asset directly from seller to buyer.
+    //
+    // Constraints:
+    //   - Current asset holder may list.
+    //   - Asset must belong to ARTIFACTE_COLLECTION.
+    //   - Payment mint must be registered with `allow_core` (typically USDC).
+    //   - Artifacte collection buys waive the platform fee.
+    //   - Creator royalty (from the on-chain Royalties plugin) is still
+    //     routed to the royalty creator; remainder goes to the seller.
+    // ========================================================================
+
+    /// List a Metaplex Core asset for fixed-price sale in a registered payment mint.
+    /// `start_time` schedules the listing to open later; the delegate is approved now.
+    /// `duration_seconds` optionally expires the listing that long after it opens;
+    /// anyone can then revoke the delegate with `close_expired_core_listing`.
+    pub fn list_core_item(
+        ctx: Context<ListCoreItem>,
+        price_usdc: u64,
+        start_time: Option<i64>,
+        duration_seconds: Option<i64>,
+    ) -> Result<()> {
+        // Artifacte collection only
+        require_keys_eq!(
+            ctx.accounts.collection.key(),
+            ARTIFACTE_COLLECTION_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        require!(price_usdc > 0, AuctionError::InvalidPrice);
+        validate_payment_mint_config(&ctx.accounts.payment_mint_config, None, price_usdc)?;
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+        validate_duration(&ListingType::FixedPrice, duration_seconds)?;
+
+        // Verify the seller owns the Core asset, and the asset belongs to the collection.
+        verify_core_asset_ownership(
+            &ctx.accounts.asset.to_account_info(),
+            ctx.accounts.seller.key(),
+            ctx.accounts.collection.key(),
+        )?;
+
+        let clock = Clock::get()?;
+        let listing = &mut ctx.accounts.core_listing;
+        listing.seller = ctx.accounts.seller.key();
+        listing.asset = ctx.accounts.asset.key();
+        listing.collection = ctx.accounts.collection.key();
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.price = price_usdc;
+        listing.created_at = clock.unix_timestamp;
+        listing.start_time = resolve_start_time(start_time, clock.unix_timestamp)?;
+        listing.bump = ctx.bumps.core_listing;
+
+        listing.listing_type = ListingType::FixedPrice;
+        listing.end_time = match duration_seconds {
+            Some(duration) => listing
+                .start_time
+                .checked_add(duration)
+                .ok_or(AuctionError::CalculationError)?,
+            None => 0,
+        };
+        listing.current_bid = 0;
+        listing.highest_bidder = Pubkey::default();
+        listing.bid_increment = BidIncrement::Standard;
+        listing.seller_bond = 0;
+
+        approve_core_delegate(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &ctx.accounts.asset.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.core_authority.key(),
+            read_core_transfer_delegate_state(&ctx.accounts.asset.to_account_info())?,
+            mpl_core::types::Plugin::TransferDelegate(mpl_core::types::TransferDelegate {}),
+            mpl_core::types::PluginType::TransferDelegate,
+        )?;
+
+        emit!(CoreListingCreated {
+            asset: listing.asset,
+            seller: listing.seller,
+            price_usdc,
+            payment_mint: listing.payment_mint,
+            start_time: listing.start_time,
+            end_time: listing.end_time,
+        });
+        Ok(())
+    }
+
+    /// List a Metaplex Core asset for an English auction in a registered payment mint.
+    ///
+    /// Still non-custodial: besides the TransferDelegate, `core_authority` is made
+    /// FreezeDelegate so the asset can be frozen in the seller's wallet once bids
+    /// exist. Bids are escrowed in the `bid_escrow` PDA keyed by the asset.
+    ///
+    /// Freezing can't stop the seller revoking the TransferDelegate, so they post
+    /// a bond of `CORE_AUCTION_BOND_BPS` of the start price into `core_bond`. It is
+    /// returned when the auction settles, closes without bids or is cancelled, and
+    /// paid to the winner if the asset can't be delivered.
+    pub fn list_core_auction(
+        ctx: Context<ListCoreAuction>,
+        start_price_usdc: u64,
+        duration_seconds: i64,
+        start_time: Option<i64>,
+        bid_increment: Option<BidIncrement>,
+    ) -> Result<()> {
+        // Artifacte collection only
+        require_keys_eq!(
+            ctx.accounts.collection.key(),
+            ARTIFACTE_COLLECTION_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        require!(start_price_usdc > 0, AuctionError::InvalidPrice);
+        validate_payment_mint_config(&ctx.accounts.payment_mint_config, None, start_price_usdc)?;
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+        require!(duration_seconds > 0, AuctionError::InvalidDuration);
+        validate_bid_increment(&ListingType::Auction, &bid_increment)?;
+
+        verify_core_asset_ownership(
+            &ctx.accounts.asset.to_account_info(),
+            ctx.accounts.seller.key(),
+            ctx.accounts.collection.key(),
+        )?;
+
+        let clock = Clock::get()?;
+        let listing = &mut ctx.accounts.core_listing;
+        listing.seller = ctx.accounts.seller.key();
+        listing.asset = ctx.accounts.asset.key();
+        listing.collection = ctx.accounts.collection.key();
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.price = start_price_usdc;
+        listing.created_at = clock.unix_timestamp;
+        listing.start_time = resolve_start_time(start_time, clock.unix_timestamp)?;
+        listing.bump = ctx.bumps.core_listing;
+        listing.listing_type = ListingType::Auction;
+        listing.end_time = listing
+            .start_time
+            .checked_add(duration_seconds)
+            .ok_or(AuctionError::CalculationError)?;
+        listing.current_bid = 0;
+        listing.highest_bidder = Pubkey::default();
+        listing.bid_increment = bid_increment.unwrap_or(ctx.accounts.payment_mint_config.bid_increment);
+        listing.seller_bond = core_auction_bond(start_price_usdc)?;
+
+        deposit_payment(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.core_bond.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            listing.seller_bond,
+        )?;
+
+        let asset = ctx.accounts.asset.to_account_info();
+        approve_core_delegate(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.core_authority.key(),
+            read_core_transfer_delegate_state(&asset)?,
+            mpl_core::types::Plugin::TransferDelegate(mpl_core::types::TransferDelegate {}),
+            mpl_core::types::PluginType::TransferDelegate,
+        )?;
+        approve_core_delegate(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.core_authority.key(),
+            read_core_freeze_delegate_state(&asset)?,
+            mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate { frozen: false }),
+            mpl_core::types::PluginType::FreezeDelegate,
+        )?;
+
+        emit!(CoreListingCreated {
+            asset: listing.asset,
+            seller: listing.seller,
+            price_usdc: start_price_usdc,
+            payment_mint: listing.payment_mint,
+            start_time: listing.start_time,
+            end_time: listing.end_time,
+        });
+        Ok(())
+    }
+
+    /// Bid on a Core auction. The previous high bid is credited to its `BidRefund`;
+    /// the first bid freezes the asset in the seller's wallet until settlement.
+    pub fn place_core_bid(ctx: Context<PlaceCoreBid>, amount: u64) -> Result<()> {
+        let listing = &mut ctx.accounts.core_listing;
+        let clock = Clock::get()?;
+
+        require!(
+            matches!(listing.listing_type, ListingType::Auction),
+            AuctionError::NotAnAuction
+        );
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            clock.unix_timestamp < listing.end_time,
+            AuctionError::AuctionEnded
+        );
+        // Prevent shill bidding — seller cannot bid on own auction
+        require!(
+            ctx.accounts.bidder.key() != listing.seller,
+            AuctionError::SellerCannotBid
+        );
+        verify_active_core_listing_owner(
+            &ctx.accounts.asset.to_account_info(),
+            listing.seller,
+            ctx.accounts.collection.key(),
+        )?;
+
+        let min_bid = min_next_bid(
+            listing.current_bid,
+            listing.price,
+            listing.bid_increment,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+        require_gte!(amount, min_bid, AuctionError::BidTooLow);
+
+        let asset_key = listing.asset;
+
+        init_bid_refund(
+            &mut ctx.accounts.bidder_refund,
+            ctx.accounts.bidder.key(),
+            asset_key,
+            listing.payment_mint,
+            false,
+            listing.created_at,
+            ctx.bumps.bidder_refund,
+        )?;
+
+        if listing.current_bid > 0 {
+            // Credit previous high bidder; claimable via `claim_refund`
+            let previous_bidder_refund = ctx
+                .accounts
+                .previous_bidder_refund
+                .as_deref_mut()
+                .ok_or(AuctionError::InvalidRefundAccount)?;
+            credit_bid_refund(previous_bidder_refund, listing.current_bid)?;
+        } else {
+            // First bid: freeze the asset so the seller cannot move it mid-auction
+            let core_authority_bump = ctx.bumps.core_authority;
+            set_core_asset_frozen(
+                &ctx.accounts.mpl_core_program.to_account_info(),
+                &ctx.accounts.asset.to_account_info(),
+                &ctx.accounts.collection.to_account_info(),
+                &ctx.accounts.bidder.to_account_info(),
+                &ctx.accounts.core_authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                true,
+                &[b"core_authority", asset_key.as_ref(), &[core_authority_bump]],
+            )?;
+        }
+
+        deposit_payment(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.bidder_payment_account.to_account_info(),
+            &ctx.accounts.bid_escrow.to_account_info(),
+            &ctx.accounts.bidder.to_account_info(),
+            amount,
+        )?;
+
+        listing.current_bid = amount;
+        listing.highest_bidder = ctx.accounts.bidder.key();
+
+        emit!(BidPlaced {
+            nft_mint: asset_key,
+            bidder: listing.highest_bidder,
+            amount,
+            timestamp: clock.unix_timestamp,
+            end_time: listing.end_time,
+        });
+
+        Ok(())
+    }
+
+    /// Settle an ended Core auction. Permissionless.
+    ///
+    /// With a winning bid the asset is thawed and transferred to the winner by
+    /// `core_authority`, and the escrow is split like `buy_now_core` (Artifacte
+    /// collection fee waived); the seller bond goes back to the seller. If the
+    /// seller no longer holds the asset or the delegate was revoked, the winner's
+    /// bid plus the seller bond is credited to the winner instead. With no bids
+    /// the bond is returned and the listing closes; the seller can re-list or
+    /// revoke delegates later.
+    pub fn settle_core_auction(ctx: Context<SettleCoreAuction>) -> Result<()> {
+        let listing = &ctx.accounts.core_listing;
+        let clock = Clock::get()?;
+
+        require!(
+            matches!(listing.listing_type, ListingType::Auction),
+            AuctionError::NotAnAuction
+        );
+        require!(
+            clock.unix_timestamp >= listing.end_time,
+            AuctionError::AuctionNotEnded
+        );
+        require!(
+            ctx.accounts.seller_payment_account.owner == listing.seller,
+            AuctionError::Unauthorized
+        );
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let core_bond = match (ctx.accounts.core_bond.as_ref(), ctx.bumps.core_bond) {
+            (Some(core_bond), Some(bump)) => Some((core_bond, bump)),
+            _ => None,
+        };
+        require!(
+            listing.seller_bond == 0 || core_bond.is_some(),
+            AuctionError::InvalidSellerBond
+        );
+
+        if listing.current_bid == 0 {
+            if let Some((core_bond, core_bond_bump)) = core_bond {
+                release_core_bond(
+                    &token_program,
+                    &ctx.accounts.payment_mint.to_account_info(),
+                    core_bond,
+                    &ctx.accounts.seller_payment_account.to_account_info(),
+                    &ctx.accounts.seller.to_account_info(),
+                    listing.asset,
+                    core_bond_bump,
+                )?;
+            }
+            emit!(AuctionCancelled {
+                nft_mint: listing.asset,
+                reason: "No bids received".to_string(),
+            });
+            // CoreListing PDA closed via `close = seller` constraint.
+            return Ok(());
+        }
+
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let bid_escrow = ctx
+            .accounts
+            .bid_escrow
+            .as_mut()
+            .ok_or(AuctionError::InvalidRefundAccount)?;
+        let asset_key = listing.asset;
+        let listing_instance = listing_instance_seed(listing.created_at);
+        let bid_escrow_bump = ctx.bumps.bid_escrow.ok_or(AuctionError::InvalidRefundAccount)?;
+        let core_authority_bump = ctx.bumps.core_authority;
+        let core_authority_seeds: &[&[u8]] = &[
+            b"core_authority",
+            asset_key.as_ref(),
+            &[core_authority_bump],
+        ];
+
+        let asset = ctx.accounts.asset.to_account_info();
+        let (asset_owner, _collection) = read_core_asset_owner_and_collection(&asset)?;
+        let deliverable = asset_owner == listing.seller
+            && matches!(
+                read_core_transfer_delegate_state(&asset)?,
+                CoreDelegateState::Address(address) if address == ctx.accounts.core_authority.key()
+            );
+
+        if deliverable {
+            let (royalty_bps, royalty_creator) = read_core_royalties(
+                &asset,
+                &ctx.accounts.collection.to_account_info(),
+            )?;
+            let split = calculate_core_sale_split(listing.current_bid, listing.collection, royalty_bps)?;
+            if split.creator_royalty > 0 {
+                let expected_creator_ata =
+                    anchor_spl::associated_token::get_associated_token_address(
+                        &royalty_creator,
+                        &listing.payment_mint,
+                    );
+                require!(
+                    ctx.accounts.creator_payment_account.key() == expected_creator_ata,
+                    AuctionError::InvalidCreatorAccount
+                );
+            }
+
+            // Payment: bid_escrow → seller / treasury / creator
+            let payout = pay_sale_split(
+                &token_program,
+                &ctx.accounts.payment_mint.to_account_info(),
+                &bid_escrow.to_account_info(),
+                &bid_escrow.to_account_info(),
+                &ctx.accounts.seller_payment_account.to_account_info(),
+                &ctx.accounts.treasury_payment_account.to_account_info(),
+                &ctx.accounts.creator_payment_account.to_account_info(),
+                &SaleSplit {
+                    platform_fee: split.platform_fee,
+                    baxus_fee: 0,
+                    creator_royalty: split.creator_royalty,
+                    seller_amount: split.seller_amount,
+                },
+                &[&[b"bid_escrow", asset_key.as_ref(), &listing_instance, &[bid_escrow_bump]]],
+            )?;
+
+            // Thaw, then transfer the Core asset (signed by the program's delegate PDA)
+            set_core_asset_frozen(
+                &ctx.accounts.mpl_core_program.to_account_info(),
+                &asset,
+                &ctx.accounts.collection.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.core_authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                false,
+                core_authority_seeds,
+            )?;
+            mpl_core::instructions::TransferV1Cpi {
+                __program: &ctx.accounts.mpl_core_program.to_account_info(),
+                asset: &asset,
+                collection: Some(&ctx.accounts.collection.to_account_info()),
+                payer: &ctx.accounts.payer.to_account_info(),
+                authority: Some(&ctx.accounts.core_authority.to_account_info()),
+                new_owner: &ctx.accounts.winner.to_account_info(),
+                system_program: Some(&ctx.accounts.system_program.to_account_info()),
+                log_wrapper: None,
+                __args: mpl_core::instructions::TransferV1InstructionArgs {
+                    compression_proof: None,
+                },
+            }
+            .invoke_signed(&[core_authority_seeds])?;
+
+            if let Some((core_bond, core_bond_bump)) = core_bond {
+                release_core_bond(
+                    &token_program,
+                    &ctx.accounts.payment_mint.to_account_info(),
+                    core_bond,
+                    &ctx.accounts.seller_payment_account.to_account_info(),
+                    &ctx.accounts.seller.to_account_info(),
+                    asset_key,
+                    core_bond_bump,
+                )?;
+            }
+
+            emit!(CorePurchased {
+                asset: listing.asset,
+                seller: listing.seller,
+                buyer: listing.highest_bidder,
+                price_usdc: listing.current_bid,
+                platform_fee: split.platform_fee,
+                seller_amount: payout.seller_amount,
+                treasury_amount: payout.treasury_amount,
+                creator_royalty: payout.creator_royalty,
+            });
+        } else {
+            // Asset can no longer be delivered — release it and credit the winner in full
+            let holds_freeze_delegate = matches!(
+                read_core_freeze_delegate_state(&asset)?,
+                CoreDelegateState::Address(address) if address == ctx.accounts.core_authority.key()
+            );
+            if asset_owner == listing.seller && holds_freeze_delegate {
+                set_core_asset_frozen(
+                    &ctx.accounts.mpl_core_program.to_account_info(),
+                    &asset,
+                    &ctx.accounts.collection.to_account_info(),
+                    &ctx.accounts.payer.to_account_info(),
+                    &ctx.accounts.core_authority.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    false,
+                    core_authority_seeds,
+                )?;
+            }
+
+            // Non-delivery forfeits the seller bond to the winner, alongside their bid
+            let forfeited_bond = match core_bond {
+                Some((core_bond, core_bond_bump)) => release_core_bond(
+                    &token_program,
+                    &ctx.accounts.payment_mint.to_account_info(),
+                    core_bond,
+                    &bid_escrow.to_account_info(),
+                    &ctx.accounts.seller.to_account_info(),
+                    asset_key,
+                    core_bond_bump,
+                )?,
+                None => 0,
+            };
+            let winner_bid_refund = ctx
+                .accounts
+                .winner_bid_refund
+                .as_deref_mut()
+                .ok_or(AuctionError::InvalidRefundAccount)?;
+            credit_bid_refund(
+                winner_bid_refund,
+                listing
+                    .current_bid
+                    .checked_add(forfeited_bond)
+                    .ok_or(AuctionError::CalculationError)?,
+            )?;
+
+            emit!(SellerBondForfeited {
+                asset: listing.asset,
+                seller: listing.seller,
+                winner: listing.highest_bidder,
+                amount: forfeited_bond,
+            });
+            emit!(AuctionCancelled {
+                nft_mint: listing.asset,
+                reason: "Seller can no longer deliver the asset".to_string(),
+            });
+        }
+
+        // Close the bid escrow once emptied — rent to the treasury (revenue).
+        // Credited refunds keep it open until claimed.
+        bid_escrow.reload()?;
+        if bid_escrow.amount == 0 {
+            close_bid_escrow(
+                &token_program,
+                &ctx.accounts.payment_mint.to_account_info(),
+                &bid_escrow.to_account_info(),
+                &ctx.accounts.treasury.to_account_info(),
+                asset_key,
+                &listing_instance,
+                bid_escrow_bump,
+            )?;
+        }
+
+        // CoreListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Cancel an active Core listing while the seller still holds the asset.
+    /// Core auctions can only be cancelled before the first bid; their seller
+    /// bond is returned.
+    pub fn cancel_core_listing(ctx: Context<CancelCoreListing>) -> Result<()> {
+        // Only the original seller (= owner) may cancel
+        require!(
+            ctx.accounts.seller.key() == ctx.accounts.core_listing.seller,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.core_listing.current_bid == 0,
+            AuctionError::CannotCancelWithBids
+        );
+        verify_active_core_listing_owner(
+            &ctx.accounts.asset.to_account_info(),
+            ctx.accounts.core_listing.seller,
+            ctx.accounts.collection.key(),
+        )?;
+
+        mpl_core::instructions::RevokePluginAuthorityV1Cpi {
+            __program: &ctx.accounts.mpl_core_program.to_account_info(),
+            asset: &ctx.accounts.asset.to_account_info(),
+            collection: Some(&ctx.accounts.collection.to_account_info()),
+            payer: &ctx.accounts.seller.to_account_info(),
+            authority: Some(&ctx.accounts.seller.to_account_info()),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            log_wrapper: None,
+            __args: mpl_core::instructions::RevokePluginAuthorityV1InstructionArgs {
+                plugin_type: mpl_core::types::PluginType::TransferDelegate,
+            },
+        }
+        .invoke()?;
+
+        if matches!(ctx.accounts.core_listing.listing_type, ListingType::Auction) {
+            mpl_core::instructions::RevokePluginAuthorityV1Cpi {
+                __program: &ctx.accounts.mpl_core_program.to_account_info(),
+                asset: &ctx.accounts.asset.to_account_info(),
+                collection: Some(&ctx.accounts.collection.to_account_info()),
+                payer: &ctx.accounts.seller.to_account_info(),
+                authority: Some(&ctx.accounts.seller.to_account_info()),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                log_wrapper: None,
+                __args: mpl_core::instructions::RevokePluginAuthorityV1InstructionArgs {
+                    plugin_type: mpl_core::types::PluginType::FreezeDelegate,
+                },
+            }
+            .invoke()?;
+        }
+
+        return_core_bond(
+            &ctx.accounts.core_listing,
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.payment_mint.as_deref(),
+            ctx.accounts.core_bond.as_deref().zip(ctx.bumps.core_bond),
+            ctx.accounts.seller_payment_account.as_deref(),
+            &ctx.accounts.seller.to_account_info(),
+        )?;
+
+        emit!(CoreListingCancelled {
+            asset: ctx.accounts.core_listing.asset,
+            seller: ctx.accounts.core_listing.seller,
+        });
+        // CoreListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Reprice an active Core listing in place (seller only), keeping the
+    /// delegate approvals. Core auctions can only be repriced before the first
+    /// bid. `end_time` (fixed price only) sets a new expiry, 0 for none.
+    /// `None` leaves a field unchanged.
+    pub fn update_core_listing(
+        ctx: Context<UpdateCoreListing>,
+        price_usdc: Option<u64>,
+        end_time: Option<i64>,
+    ) -> Result<()> {
+        let listing = &mut ctx.accounts.core_listing;
+
+        require!(
+            ctx.accounts.seller.key() == listing.seller,
+            AuctionError::Unauthorized
+        );
+
+        if let Some(price_usdc) = price_usdc {
+            require!(price_usdc > 0, AuctionError::InvalidPrice);
+            require!(listing.current_bid == 0, AuctionError::CannotUpdateWithBids);
+            // The seller bond was sized on the start price, so it can only drop
+            require!(
+                !matches!(listing.listing_type, ListingType::Auction) || price_usdc <= listing.price,
+                AuctionError::InvalidPrice
+            );
+            validate_payment_mint_config(&ctx.accounts.payment_mint_config, None, price_usdc)?;
+            listing.price = price_usdc;
+        }
+        if let Some(end_time) = end_time {
+            require!(
+                matches!(listing.listing_type, ListingType::FixedPrice),
+                AuctionError::NotFixedPrice
+            );
+            listing.end_time = resolve_listing_expiry(end_time, listing.start_time, Clock::get()?.unix_timestamp)?;
+        }
+
+        emit!(ListingUpdated {
+            nft_mint: listing.asset,
+            seller: listing.seller,
+            price: listing.price,
+            end_time: listing.end_time,
+        });
+        Ok(())
+    }
+
+    /// Close an expired fixed-price Core listing (permissionless). The
+    /// TransferDelegate revokes itself if still approved; rent goes to the seller.
+    pub fn close_expired_core_listing(ctx: Context<CloseExpiredCoreListing>) -> Result<()> {
+        let listing = &ctx.accounts.core_listing;
+        require!(
+            listing_expired(&listing.listing_type, listing.end_time, Clock::get()?.unix_timestamp),
+            AuctionError::ListingNotExpired
+        );
+
+        // A transfer outside the program already reset the delegate to the new owner
+        if let CoreDelegateState::Address(address) =
+            read_core_transfer_delegate_state(&ctx.accounts.asset.to_account_info())?
+        {
+            if address == ctx.accounts.core_authority.key() {
+                let asset_key = ctx.accounts.asset.key();
+                let core_authority_bump = ctx.bumps.core_authority;
+                let core_authority_seeds: &[&[u8]] =
+                    &[b"core_authority", asset_key.as_ref(), &[core_authority_bump]];
+                mpl_core::instructions::RevokePluginAuthorityV1Cpi {
+                    __program: &ctx.accounts.mpl_core_program.to_account_info(),
+                    asset: &ctx.accounts.asset.to_account_info(),
+                    collection: Some(&ctx.accounts.collection.to_account_info()),
+                    payer: &ctx.accounts.payer.to_account_info(),
+                    authority: Some(&ctx.accounts.core_authority.to_account_info()),
+                    system_program: &ctx.accounts.system_program.to_account_info(),
+                    log_wrapper: None,
+                    __args: mpl_core::instructions::RevokePluginAuthorityV1InstructionArgs {
+                        plugin_type: mpl_core::types::PluginType::TransferDelegate,
+                    },
+                }
+                .invoke_signed(&[core_authority_seeds])?;
+            }
+        }
+
+        emit!(ListingExpired {
+            nft_mint: listing.asset,
+            seller: listing.seller,
+        });
+        // CoreListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Close a stale Core listing after ownership changed outside the program.
+    /// Allows the current holder to clear old state and re-list the asset.
+    pub fn close_stale_core_listing(ctx: Context<CloseStaleCoreListing>) -> Result<()> {
+        let listing = &ctx.accounts.core_listing;
+        let (asset_owner, asset_collection) =
+            read_core_asset_owner_and_collection(&ctx.accounts.asset.to_account_info())?;
+
+        require_keys_eq!(asset_collection, ctx.accounts.collection.key(), AuctionError::Unauthorized);
+        require_keys_eq!(asset_owner, ctx.accounts.holder.key(), AuctionError::Unauthorized);
+        require!(listing.seller != ctx.accounts.holder.key(), AuctionError::CoreListingNotStale);
+        // A Core auction with bids is settled (winner refunded) instead
+        require!(listing.current_bid == 0, AuctionError::CannotCancelWithBids);
+
+        match read_core_transfer_delegate_state(&ctx.accounts.asset.to_account_info())? {
+            CoreDelegateState::Address(address)
+                if address == ctx.accounts.core_authority.key() =>
+            {
+                mpl_core::instructions::RevokePluginAuthorityV1Cpi {
+                    __program: &ctx.accounts.mpl_core_program.to_account_info(),
+                    asset: &ctx.accounts.asset.to_account_info(),
+                    collection: Some(&ctx.accounts.collection.to_account_info()),
+                    payer: &ctx.accounts.holder.to_account_info(),
+                    authority: Some(&ctx.accounts.holder.to_account_info()),
+                    system_program: &ctx.accounts.system_program.to_account_info(),
+                    log_wrapper: None,
+                    __args: mpl_core::instructions::RevokePluginAuthorityV1InstructionArgs {
+                        plugin_type: mpl_core::types::PluginType::TransferDelegate,
+                    },
+                }
+                .invoke()?;
+            }
+            _ => {}
+        }
+
+        return_core_bond(
+            listing,
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.payment_mint.as_deref(),
+            ctx.accounts.core_bond.as_deref().zip(ctx.bumps.core_bond),
+            ctx.accounts.seller_payment_account.as_deref(),
+            &ctx.accounts.holder.to_account_info(),
+        )?;
+
+        emit!(CoreListingCancelled {
+            asset: listing.asset,
+            seller: listing.seller,
+        });
+
+        Ok(())
+    }
+
+    /// Public buy of a Core listing. Artifacte collection buys waive the
+    /// platform fee and only split the payment between creator royalty and seller.
+    /// Then CPI TransferV1 moves the asset to the buyer.
+    pub fn buy_now_core(ctx: Context<BuyNowCore>) -> Result<()> {
+        let listing = &ctx.accounts.core_listing;
+
+        require!(
+            matches!(listing.listing_type, ListingType::FixedPrice),
+            AuctionError::NotFixedPrice
+        );
+
+        // Re-validate state (defence in depth)
+        require!(
+            ctx.accounts.payment_mint.key() == listing.payment_mint,
+            AuctionError::InvalidPaymentMint
+        );
+        require!(
+            ctx.accounts.asset.key() == listing.asset,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.collection.key() == listing.collection,
+            AuctionError::Unauthorized
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= listing.start_time, AuctionError::ListingNotStarted);
+        require!(
+            !listing_expired(&listing.listing_type, listing.end_time, now),
+            AuctionError::ListingExpired
+        );
+        verify_active_core_listing_owner(
+            &ctx.accounts.asset.to_account_info(),
+            listing.seller,
+            ctx.accounts.collection.key(),
+        )?;
+
+        // Resolve treasury (config PDA if initialized, else fallback constant)
+        let treasury_address = if let Some(ref config) = ctx.accounts.treasury_config {
+            config.treasury
+        } else {
+            TREASURY_FALLBACK.parse::<Pubkey>().unwrap()
+        };
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.seller_payment_account.owner == listing.seller,
+            AuctionError::Unauthorized
+        );
+
+        // Read on-chain Royalties plugin (asset first, fallback to collection)
+        // and validate creator_payment_account.
+        let (royalty_bps, royalty_creator) = read_core_royalties(
+            &ctx.accounts.asset.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+        )?;
+        require!(royalty_bps <= 1000, AuctionError::RoyaltyTooHigh);
+
+        let platform_fee = listing
+            .price
+            .checked_mul(core_platform_fee_bps(listing.collection))
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(AuctionError::CalculationError)?;
+        let creator_royalty = listing
+            .price
+            .checked_mul(royalty_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(AuctionError::CalculationError)?;
+
+        if creator_royalty > 0 {
+            let expected_creator_ata =
+                anchor_spl::associated_token::get_associated_token_address(
+                    &royalty_creator,
+                    &listing.payment_mint,
+                );
+            require!(
+                ctx.accounts.creator_payment_account.key() == expected_creator_ata,
+                AuctionError::InvalidCreatorAccount
+            );
+        }
+
+        let seller_amount = listing
+            .price
+            .checked_sub(platform_fee)
+            .ok_or(AuctionError::CalculationError)?
+            .checked_sub(creator_royalty)
+            .ok_or(AuctionError::CalculationError)?;
+
+        // Payment: buyer → seller / treasury / creator
+        let payout = pay_sale_split(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &SaleSplit {
+                platform_fee,
+                baxus_fee: 0,
+                creator_royalty,
+                seller_amount,
+            },
+            &[],
+        )?;
+
+        // Transfer the Core asset (signed by the program's delegate PDA)
+        let asset_key = ctx.accounts.asset.key();
+        let core_authority_bump = ctx.bumps.core_authority;
+        let core_authority_seeds: &[&[u8]] = &[
+            b"core_authority",
+            asset_key.as_ref(),
+            &[core_authority_bump],
+        ];
+
+        mpl_core::instructions::TransferV1Cpi {
+            __program: &ctx.accounts.mpl_core_program.to_account_info(),
+            asset: &ctx.accounts.asset.to_account_info(),
+            collection: Some(&ctx.accounts.collection.to_account_info()),
+            payer: &ctx.accounts.buyer.to_account_info(),
+            authority: Some(&ctx.accounts.core_authority.to_account_info()),
+            new_owner: &ctx.accounts.buyer.to_account_info(),
+            system_program: Some(&ctx.accounts.system_program.to_account_info()),
+            log_wrapper: None,
+            __args: mpl_core::instructions::TransferV1InstructionArgs {
+                compression_proof: None,
+            },
+        }
+        .invoke_signed(&[core_authority_seeds])?;
+
+        emit!(CorePurchased {
+            asset: listing.asset,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price_usdc: listing.price,
+            platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: payout.creator_royalty,
+        });
+        // CoreListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    // ========================================================================
+    // Offers
+    //
+    //   - A buyer escrows payment in an `offer_escrow` PDA keyed by the item
+    //     (NFT mint or Core asset) and buyer, with an expiry.
+    //   - The owner may accept whether or not the item is listed; a fixed-price
+    //     listing for the item is closed by the sale.
+    //   - Buyers cancel at any time; anyone may close an expired offer.
+    // ========================================================================
+
+    /// Make an offer on an NFT mint or Core asset, escrowing `amount` until
+    /// `expires_at`.
+    pub fn make_offer(ctx: Context<MakeOffer>, amount: u64, expires_at: i64) -> Result<()> {
+        let clock = Clock::get()?;
+        let config = &ctx.accounts.payment_mint_config;
+
+        require!(config.enabled, AuctionError::InvalidPaymentMint);
+        require!(amount > 0, AuctionError::InvalidPrice);
+        require_gte!(amount, config.min_price, AuctionError::PriceBelowMinimum);
+        require!(expires_at > clock.unix_timestamp, AuctionError::InvalidOfferExpiry);
+        let asset_owner = ctx.accounts.asset.owner;
+        require!(
+            *asset_owner == Token::id() || *asset_owner == spl_token_2022::ID || *asset_owner == mpl_core::ID,
+            AuctionError::InvalidOfferAsset
+        );
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+
+        deposit_payment(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.offer_escrow.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            amount,
+        )?;
+        // Fee rounding can leave a unit over; the whole escrow is the offer
+        ctx.accounts.offer_escrow.reload()?;
+
+        let offer = &mut ctx.accounts.offer;
+        offer.buyer = ctx.accounts.buyer.key();
+        offer.asset = ctx.accounts.asset.key();
+        offer.payment_mint = ctx.accounts.payment_mint.key();
+        offer.amount = ctx.accounts.offer_escrow.amount;
+        offer.expires_at = expires_at;
+        offer.created_at = clock.unix_timestamp;
+        offer.bump = ctx.bumps.offer;
+        offer.escrow_bump = ctx.bumps.offer_escrow;
+
+        emit!(OfferMade {
+            asset: offer.asset,
+            buyer: offer.buyer,
+            payment_mint: offer.payment_mint,
+            amount: offer.amount,
+            expires_at,
+        });
+        Ok(())
+    }
+
+    /// Withdraw an offer and reclaim its escrow (buyer only).
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        refund_offer(
+            &ctx.accounts.offer,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.offer_escrow,
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+        )?;
+
+        emit!(OfferCancelled {
+            asset: ctx.accounts.offer.asset,
+            buyer: ctx.accounts.offer.buyer,
+            expired: false,
+        });
+        // Offer PDA closed via `close = buyer` constraint.
+        Ok(())
+    }
+
+    /// Close an expired offer, returning escrow and rent to the buyer. Permissionless.
+    pub fn close_expired_offer(ctx: Context<CloseExpiredOffer>) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= ctx.accounts.offer.expires_at,
+            AuctionError::OfferNotExpired
+        );
+        refund_offer(
+            &ctx.accounts.offer,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.offer_escrow,
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+        )?;
+
+        emit!(OfferCancelled {
+            asset: ctx.accounts.offer.asset,
+            buyer: ctx.accounts.offer.buyer,
+            expired: true,
+        });
+        // Offer PDA closed via `close = buyer` constraint.
+        Ok(())
+    }
+
+    /// Accept an offer on an SPL / Token-2022 NFT (owner only).
+    ///
+    /// Listed (fixed price or Dutch): pass `listing` and `escrow_nft`; the NFT
+    /// leaves escrow, royalties follow the listing and the listing is closed.
+    /// Unlisted: pass `seller_nft_account`; royalties use `royalty_basis_points`
+    /// and `creator_address` as supplied to `list_item`.
+    /// WNS NFTs are charged the royalty in their metadata instead: the offer
+    /// escrow approves the move by CPI for the offer amount and WNS takes its
+    /// royalty from it.
+    /// remaining_accounts: same layout as list_item
+    pub fn accept_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+    ) -> Result<()> {
+        let offer = &ctx.accounts.offer;
+        let seller_key = ctx.accounts.seller.key();
+        validate_offer_acceptance(offer, seller_key)?;
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let nft_mint_key = ctx.accounts.nft_mint.key();
+        let is_token2022 = ctx.accounts.nft_token_program.key() == spl_token_2022::ID;
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let wns = wns_accounts(&nft_mint_info, ctx.remaining_accounts)?;
+        let wns_royalty_bps = wns.as_ref().map(|_| read_wns_royalty_bps(&nft_mint_info)).transpose()?;
+
+        // WNS takes its royalty from the offer escrow here; the split skips the creator leg
+        if let Some(wns) = &wns {
+            let offer_escrow = ctx.accounts.offer_escrow.to_account_info();
+            let escrow_seeds: &[&[u8]] = &[
+                b"offer_escrow",
+                offer.asset.as_ref(),
+                offer.buyer.as_ref(),
+                &[offer.escrow_bump],
+            ];
+            approve_wns_transfer(
+                wns,
+                &ctx.accounts.seller.to_account_info(),
+                &offer_escrow,
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                offer.amount,
+                Some(WnsPayment {
+                    payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                    payment_token_program: &ctx.accounts.token_program.to_account_info(),
+                    authority_token_account: &offer_escrow,
+                }),
+                &[escrow_seeds],
+            )?;
+        }
+
+        let split = if let Some(listing) = ctx.accounts.listing.as_deref_mut() {
+            validate_offer_listing(listing, seller_key)?;
+            require!(!listing.is_pnft, AuctionError::InvalidTokenProgram);
+            let split = calculate_sale_split(
+                offer.amount,
+                listing.baxus_fee,
+                wns_royalty_bps.unwrap_or(listing.royalty_basis_points),
+            )?;
+            if wns.is_none() {
+                validate_creator_ata(
+                    listing.creator_address,
+                    offer.payment_mint,
+                    ctx.accounts.creator_payment_account.key(),
+                    split.creator_royalty,
+                )?;
+            }
+            // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+            listing.status = ListingStatus::Settled;
+
+            let escrow_nft = ctx
+                .accounts
+                .escrow_nft
+                .as_ref()
+                .ok_or(AuctionError::InvalidOfferAsset)?
+                .to_account_info();
+            let escrow_bump = ctx.bumps.escrow_nft.ok_or(AuctionError::InvalidOfferAsset)?;
+            let escrow_seeds: &[&[u8]] = &[b"escrow_nft", nft_mint_key.as_ref(), &[escrow_bump]];
+            transfer_nft_from_escrow(
+                listing.is_token2022,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &escrow_nft,
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.buyer_nft_account.to_account_info(),
+                ctx.remaining_accounts,
+                escrow_seeds,
+            )?;
+            // Close escrow_nft token account via CPI — rent to treasury (revenue)
+            close_token_account_cpi(
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &escrow_nft,
+                &ctx.accounts.treasury.to_account_info(),
+                &escrow_nft,
+                &[escrow_seeds],
+            )?;
+            split
+        } else {
+            require!(royalty_basis_points <= 1000, AuctionError::RoyaltyTooHigh);
+            let split =
+                calculate_sale_split(offer.amount, false, wns_royalty_bps.unwrap_or(royalty_basis_points))?;
+            if wns.is_none() {
+                validate_creator_ata(
+                    creator_address,
+                    offer.payment_mint,
+                    ctx.accounts.creator_payment_account.key(),
+                    split.creator_royalty,
+                )?;
+            }
+            let seller_nft_account = ctx
+                .accounts
+                .seller_nft_account
+                .as_ref()
+                .ok_or(AuctionError::InvalidOfferAsset)?;
+            transfer_nft_from_owner(
+                is_token2022,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                &seller_nft_account.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.buyer_nft_account.to_account_info(),
+                &ctx.accounts.seller.to_account_info(),
+                ctx.remaining_accounts,
+            )?;
+            split
+        };
+
+        let payout = pay_offer(
+            offer,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.offer_escrow.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &split.direct_legs(wns.is_some()),
+        )?;
+        let payout = if wns.is_some() {
+            SalePayout { creator_royalty: split.creator_royalty, ..payout }
+        } else {
+            payout
+        };
+        emit_offer_accepted(offer, seller_key, &split, &payout);
+
+        if let Some(listing) = ctx.accounts.listing.as_ref() {
+            // Close listing account (owned by our program) — rent to treasury (revenue)
+            close_listing_account(&listing.to_account_info(), &ctx.accounts.treasury.to_account_info())?;
+        }
+        // Offer PDA closed via `close = buyer` constraint.
+        Ok(())
+    }
+
+    /// Accept an offer on a pNFT (owner only). Listed pNFTs (fixed price or
+    /// Dutch) leave the `escrow_authority` escrow and the listing is closed;
+    /// unlisted ones move from the seller's wallet. Royalty arguments as in
+    /// `accept_offer`.
+    pub fn accept_offer_pnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptOfferPnft<'info>>,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+    ) -> Result<()> {
+        let offer = &ctx.accounts.offer;
+        let seller_key = ctx.accounts.seller.key();
+        validate_offer_acceptance(offer, seller_key)?;
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let nft_mint_key = ctx.accounts.nft_mint.key();
+        let escrow_auth_bump = ctx.bumps.escrow_authority;
+        let escrow_auth_seeds: &[&[u8]] = &[b"escrow_authority", nft_mint_key.as_ref(), &[escrow_auth_bump]];
+        let (split, source_owner, signer_seeds): (SaleSplit, AccountInfo<'info>, &[&[&[u8]]]) =
+            if let Some(listing) = ctx.accounts.listing.as_deref_mut() {
+                validate_offer_listing(listing, seller_key)?;
+                require!(listing.is_pnft, AuctionError::InvalidTokenProgram);
+                let split = calculate_sale_split(offer.amount, listing.baxus_fee, listing.royalty_basis_points)?;
+                validate_creator_ata(
+                    listing.creator_address,
+                    offer.payment_mint,
+                    ctx.accounts.creator_payment_account.key(),
+                    split.creator_royalty,
+                )?;
+                // Mark settled BEFORE transfers (checks-effects-interactions — prevents reentrancy)
+                listing.status = ListingStatus::Settled;
+                (split, ctx.accounts.escrow_authority.to_account_info(), &[escrow_auth_seeds])
+            } else {
+                require!(royalty_basis_points <= 1000, AuctionError::RoyaltyTooHigh);
+                let split = calculate_sale_split(offer.amount, false, royalty_basis_points)?;
+                validate_creator_ata(
+                    creator_address,
+                    offer.payment_mint,
+                    ctx.accounts.creator_payment_account.key(),
+                    split.creator_royalty,
+                )?;
+                (split, ctx.accounts.seller.to_account_info(), &[])
+            };
+
+        // Transfer pNFT source → buyer via Token Metadata TransferV1 raw CPI
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.source_nft_token.to_account_info(),
+            &source_owner,
+            &ctx.accounts.buyer_nft_token.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.source_token_record.to_account_info(),
+            &ctx.accounts.buyer_token_record.to_account_info(),
+            &source_owner,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            signer_seeds,
+        )?;
+
+        let payout = pay_offer(
+            offer,
+            &ctx.accounts.payment_token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.offer_escrow.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &split,
+        )?;
+        emit_offer_accepted(offer, seller_key, &split, &payout);
+
+        if let Some(listing) = ctx.accounts.listing.as_ref() {
+            // Close listing account (owned by our program) — rent to treasury (revenue)
+            close_listing_account(&listing.to_account_info(), &ctx.accounts.treasury.to_account_info())?;
+        }
+        // Offer PDA closed via `close = buyer` constraint.
+        Ok(())
+    }
+
+    /// Accept an offer on a Metaplex Core asset (owner only). A fixed-price
+    /// `core_listing`, if passed, is closed and the transfer is signed through
+    /// its TransferDelegate; otherwise the owner signs the transfer directly.
+    /// Royalties come from the on-chain Royalties plugin, as in `buy_now_core`.
+    pub fn accept_offer_core(ctx: Context<AcceptOfferCore>) -> Result<()> {
+        let offer = &ctx.accounts.offer;
+        let seller_key = ctx.accounts.seller.key();
+        validate_offer_acceptance(offer, seller_key)?;
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+        // Artifacte collection only
+        require_keys_eq!(
+            ctx.accounts.collection.key(),
+            ARTIFACTE_COLLECTION_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        let asset = ctx.accounts.asset.to_account_info();
+        verify_core_asset_ownership(&asset, seller_key, ctx.accounts.collection.key())?;
+
+        let listed = match ctx.accounts.core_listing.as_ref() {
+            Some(listing) => {
+                require!(
+                    matches!(listing.listing_type, ListingType::FixedPrice),
+                    AuctionError::NotFixedPrice
+                );
+                require_keys_eq!(listing.seller, seller_key, AuctionError::Unauthorized);
+                true
+            }
+            None => false,
+        };
+
+        let split = core_royalty_sale_split(
+            &asset,
+            &ctx.accounts.collection.to_account_info(),
+            offer.amount,
+            offer.payment_mint,
+            ctx.accounts.creator_payment_account.key(),
+        )?;
+
+        let asset_key = asset.key();
+        let core_authority_bump = ctx.bumps.core_authority;
+        let core_authority_seeds: &[&[u8]] = &[b"core_authority", asset_key.as_ref(), &[core_authority_bump]];
+        let (authority, signer_seeds): (AccountInfo, &[&[&[u8]]]) = if listed {
+            (ctx.accounts.core_authority.to_account_info(), &[core_authority_seeds])
+        } else {
+            (ctx.accounts.seller.to_account_info(), &[])
+        };
+        transfer_core_asset(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &authority,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            signer_seeds,
+        )?;
+
+        let payout = pay_offer(
+            offer,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.offer_escrow.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &split,
+        )?;
+        emit_offer_accepted(offer, seller_key, &split, &payout);
+        // Offer and any CoreListing PDA closed via `close` constraints.
+        Ok(())
+    }
+
+    /// Bid on any asset in a Core collection: escrow `price * quantity` for up
+    /// to `quantity` fills at `price` each, open until `expires_at`.
+    ///
+    /// `attributes` narrows the offer to assets whose Attributes plugin carries
+    /// every listed key/value (e.g. grader PSA, grade 10). A buyer holds one
+    /// offer per collection and predicate set.
+    pub fn make_collection_offer(
+        ctx: Context<MakeCollectionOffer>,
+        price: u64,
+        quantity: u32,
+        expires_at: i64,
+        attributes: Vec<AttributePredicate>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        // Artifacte collection only
+        require_keys_eq!(
+            ctx.accounts.collection.key(),
+            ARTIFACTE_COLLECTION_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        require!(price > 0, AuctionError::InvalidPrice);
+        require!(quantity > 0, AuctionError::InvalidOfferQuantity);
+        validate_attribute_predicates(&attributes)?;
+        require!(expires_at > clock.unix_timestamp, AuctionError::InvalidOfferExpiry);
+        validate_payment_mint_config(&ctx.accounts.payment_mint_config, None, price)?;
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+
+        let total = price
+            .checked_mul(quantity as u64)
+            .ok_or(AuctionError::CalculationError)?;
+        deposit_payment(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.collection_offer_escrow.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            total,
+        )?;
+
+        let offer = &mut ctx.accounts.collection_offer;
+        offer.buyer = ctx.accounts.buyer.key();
+        offer.collection = ctx.accounts.collection.key();
+        offer.payment_mint = ctx.accounts.payment_mint.key();
+        offer.price = price;
+        offer.quantity_remaining = quantity;
+        offer.expires_at = expires_at;
+        offer.created_at = clock.unix_timestamp;
+        offer.attributes_hash = attribute_predicates_hash(&attributes);
+        offer.attributes = attributes;
+        offer.bump = ctx.bumps.collection_offer;
+        offer.escrow_bump = ctx.bumps.collection_offer_escrow;
+
+        emit!(CollectionOfferMade {
+            collection: offer.collection,
+            buyer: offer.buyer,
+            payment_mint: offer.payment_mint,
+            price,
+            quantity,
+            expires_at,
+            attributes: offer.attributes.clone(),
+        });
+        Ok(())
+    }
+
+    /// Sell a Core asset into a collection offer (asset owner only). As with
+    /// `accept_offer_core`, a fixed-price `core_listing` for the asset is closed
+    /// and the transfer is signed through its TransferDelegate. The last fill
+    /// closes the offer and its escrow, returning rent to the buyer.
+    pub fn fill_collection_offer(ctx: Context<FillCollectionOffer>) -> Result<()> {
+        let offer = &ctx.accounts.collection_offer;
+        let seller_key = ctx.accounts.seller.key();
+
+        require!(
+            Clock::get()?.unix_timestamp < offer.expires_at,
+            AuctionError::OfferExpired
+        );
+        require!(offer.quantity_remaining > 0, AuctionError::OfferFilled);
+        require!(seller_key != offer.buyer, AuctionError::SellerCannotBid);
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        // Collection membership and ownership from the asset header
+        let asset = ctx.accounts.asset.to_account_info();
+        let (asset_owner, asset_collection) = read_core_asset_owner_and_collection(&asset)?;
+        require_keys_eq!(asset_collection, offer.collection, AuctionError::Unauthorized);
+        require_keys_eq!(asset_owner, seller_key, AuctionError::Unauthorized);
+        if !offer.attributes.is_empty() {
+            require!(
+                attributes_match(&read_core_attributes(&asset)?, &offer.attributes),
+                AuctionError::AttributeMismatch
+            );
+        }
+
+        let listed = match ctx.accounts.core_listing.as_ref() {
+            Some(listing) => {
+                require!(
+                    matches!(listing.listing_type, ListingType::FixedPrice),
+                    AuctionError::NotFixedPrice
+                );
+                require_keys_eq!(listing.seller, seller_key, AuctionError::Unauthorized);
+                true
+            }
+            None => false,
+        };
+
+        let split = core_royalty_sale_split(
+            &asset,
+            &ctx.accounts.collection.to_account_info(),
+            offer.price,
+            offer.payment_mint,
+            ctx.accounts.creator_payment_account.key(),
+        )?;
+
+        let asset_key = asset.key();
+        let core_authority_bump = ctx.bumps.core_authority;
+        let core_authority_seeds: &[&[u8]] = &[b"core_authority", asset_key.as_ref(), &[core_authority_bump]];
+        let (authority, signer_seeds): (AccountInfo, &[&[&[u8]]]) = if listed {
+            (ctx.accounts.core_authority.to_account_info(), &[core_authority_seeds])
+        } else {
+            (ctx.accounts.seller.to_account_info(), &[])
+        };
+        transfer_core_asset(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &authority,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            signer_seeds,
+        )?;
+
+        let collection_key = offer.collection;
+        let buyer_key = offer.buyer;
+        let attributes_hash = offer.attributes_hash;
+        let escrow_seeds: &[&[u8]] = &[
+            b"collection_offer_escrow",
+            collection_key.as_ref(),
+            buyer_key.as_ref(),
+            attributes_hash.as_ref(),
+            &[offer.escrow_bump],
+        ];
+        let escrow = ctx.accounts.collection_offer_escrow.to_account_info();
+        let payout = pay_sale_split(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &escrow,
+            &escrow,
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &split,
+            &[escrow_seeds],
+        )?;
+
+        let offer = &mut ctx.accounts.collection_offer;
+        offer.quantity_remaining -= 1;
+
+        emit!(CollectionOfferFilled {
+            collection: collection_key,
+            asset: asset_key,
+            seller: seller_key,
+            buyer: buyer_key,
+            price: offer.price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: payout.creator_royalty,
+            quantity_remaining: offer.quantity_remaining,
+        });
+
+        // Last fill: close the emptied escrow and the offer. Any fee-rounding
+        // remainder keeps both open for `cancel_collection_offer`.
+        if offer.quantity_remaining == 0 {
+            ctx.accounts.collection_offer_escrow.reload()?;
+            if ctx.accounts.collection_offer_escrow.amount == 0 {
+                close_payment_escrow(
+                    &ctx.accounts.token_program.to_account_info(),
+                    &ctx.accounts.payment_mint.to_account_info(),
+                    &escrow,
+                    &ctx.accounts.buyer.to_account_info(),
+                    escrow_seeds,
+                )?;
+                close_listing_account(
+                    &ctx.accounts.collection_offer.to_account_info(),
+                    &ctx.accounts.buyer.to_account_info(),
+                )?;
+            }
+        }
+        // Any CoreListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Withdraw a collection offer and reclaim the unfilled escrow (buyer only).
+    pub fn cancel_collection_offer(ctx: Context<CancelCollectionOffer>) -> Result<()> {
+        let offer = &ctx.accounts.collection_offer;
+        refund_payment_escrow(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.collection_offer_escrow,
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &[
+                b"collection_offer_escrow",
+                offer.collection.as_ref(),
+                offer.buyer.as_ref(),
+                offer.attributes_hash.as_ref(),
+                &[offer.escrow_bump],
+            ],
+        )?;
+
+        emit!(CollectionOfferCancelled {
+            collection: offer.collection,
+            buyer: offer.buyer,
+            quantity_remaining: offer.quantity_remaining,
+            expired: false,
+        });
+        // CollectionOffer PDA closed via `close = buyer` constraint.
+        Ok(())
+    }
+
+    /// Refund an expired collection offer to its buyer (permissionless).
+    pub fn close_expired_collection_offer(ctx: Context<CloseExpiredCollectionOffer>) -> Result<()> {
+        let offer = &ctx.accounts.collection_offer;
+        require!(
+            Clock::get()?.unix_timestamp >= offer.expires_at,
+            AuctionError::OfferNotExpired
+        );
+        refund_payment_escrow(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.collection_offer_escrow,
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &[
+                b"collection_offer_escrow",
+                offer.collection.as_ref(),
+                offer.buyer.as_ref(),
+                offer.attributes_hash.as_ref(),
+                &[offer.escrow_bump],
+            ],
+        )?;
+
+        emit!(CollectionOfferCancelled {
+            collection: offer.collection,
+            buyer: offer.buyer,
+            quantity_remaining: offer.quantity_remaining,
+            expired: true,
+        });
+        // CollectionOffer PDA closed via `close = buyer` constraint.
+        Ok(())
+    }
+
+    // ========================================================================
+    // Bundles
+    //
+    // A bundle sells several NFTs as one lot, at a fixed price or by English
+    // auction. The seller creates the bundle, adds items one per instruction
+    // (SPL / Token-2022 NFTs are escrowed; Core assets are delegated to their
+    // `core_authority` PDA and frozen), then opens it. Sale, settlement and
+    // cancellation move every item in one instruction.
+    //
+    // The price is pro-rated across items by seller-declared weights (or
+    // evenly), and each item's share pays its own platform fee and royalty.
+    //
+    // remaining_accounts, one group per item in bundle order:
+    //   SPL / Token-2022: [nft_mint, bundle_escrow, destination NFT account, creator payment account]
+    //   Core:             [asset, core_authority, creator payment account]
+    // followed by list_item's WNS layout for each WNS Token-2022 item, in bundle
+    // order, then any other transfer-hook accounts. WNS items are approved by
+    // CPI for their share of the price on a sale, else 0, and their royalty
+    // comes from their metadata. Creator payment accounts are only read on
+    // sale; returns to the seller may pass any account there.
+    // ========================================================================
+
+    /// Create an empty bundle (seller only). `duration_seconds` is the auction
+    /// length, or an optional fixed-price expiry; it starts counting at `open_bundle`.
+    pub fn create_bundle(
+        ctx: Context<CreateBundle>,
+        bundle_id: u64,
+        listing_type: ListingType,
+        price: u64,
+        duration_seconds: Option<i64>,
+        category: ItemCategory,
+        bid_increment: Option<BidIncrement>,
+    ) -> Result<()> {
+        require!(
+            matches!(listing_type, ListingType::FixedPrice | ListingType::Auction),
+            AuctionError::InvalidBundle
+        );
+        require!(price > 0, AuctionError::InvalidPrice);
+        validate_duration(&listing_type, duration_seconds)?;
+        validate_bid_increment(&listing_type, &bid_increment)?;
+        validate_payment_mint_config(&ctx.accounts.payment_mint_config, Some(category), price)?;
+        validate_payment_mint_extensions(&ctx.accounts.payment_mint.to_account_info())?;
+
+        let bundle = &mut ctx.accounts.bundle;
+        bundle.seller = ctx.accounts.seller.key();
+        bundle.bundle_id = bundle_id;
+        bundle.payment_mint = ctx.accounts.payment_mint.key();
+        bundle.price = price;
+        bundle.listing_type = listing_type;
+        bundle.category = category;
+        bundle.duration_seconds = duration_seconds.unwrap_or(0);
+        bundle.start_time = 0;
+        bundle.end_time = 0;
+        bundle.open = false;
+        bundle.current_bid = 0;
+        bundle.highest_bidder = Pubkey::default();
+        bundle.bid_increment = bid_increment.unwrap_or(ctx.accounts.payment_mint_config.bid_increment);
+        bundle.items = Vec::new();
+        bundle.bump = ctx.bumps.bundle;
+        bundle.created_at = Clock::get()?.unix_timestamp;
+
+        emit!(BundleCreated {
+            bundle: bundle.key(),
+            seller: bundle.seller,
+            bundle_id,
+            listing_type,
+            price,
+            payment_mint: bundle.payment_mint,
+        });
+        Ok(())
+    }
+
+    /// Escrow an SPL / Token-2022 NFT into an unopened bundle (seller only).
+    /// `royalty_basis_points` and `creator_address` are as for `list_item`;
+    /// `weight` is the item's share of the price (0 on every item = even split).
+    /// remaining_accounts: same layout as list_item
+    pub fn add_bundle_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddBundleItem<'info>>,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+        weight: u16,
+    ) -> Result<()> {
+        // Checked before the NFT moves
+        validate_royalty_bps(royalty_basis_points)?;
+        ensure_bundle_accepts(&ctx.accounts.bundle, ctx.accounts.nft_mint.key())?;
+        let is_token2022 = ctx.accounts.nft_token_program.key() != Token::id();
+
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        validate_wns_royalty(&nft_mint_info)?;
+        if let Some(wns) = wns_accounts(&nft_mint_info, ctx.remaining_accounts)? {
+            approve_wns_transfer(
+                &wns,
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.seller.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                0,
+                None,
+                &[],
+            )?;
+        }
+
+        transfer_nft_from_owner(
+            is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.seller_nft_account.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.bundle_escrow.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+
+        let bundle = &mut ctx.accounts.bundle;
+        bundle.items.push(BundleItem {
+            asset: ctx.accounts.nft_mint.key(),
+            kind: if is_token2022 { BundleItemKind::Token2022 } else { BundleItemKind::Spl },
+            weight,
+            royalty_basis_points,
+            creator_address,
+            bump: ctx.bumps.bundle_escrow,
+        });
+
+        emit!(BundleItemAdded {
+            bundle: bundle.key(),
+            asset: ctx.accounts.nft_mint.key(),
+            weight,
+        });
+        Ok(())
+    }
+
+    /// Add a Metaplex Core asset to an unopened bundle (seller only). The
+    /// asset stays in the seller's wallet, delegated to its `core_authority`
+    /// PDA and frozen until the bundle sells or is cancelled. Royalties are read
+    /// from the asset at sale time.
+    pub fn add_bundle_core_item(ctx: Context<AddBundleCoreItem>, weight: u16) -> Result<()> {
+        // Artifacte collection only
+        require_keys_eq!(
+            ctx.accounts.collection.key(),
+            ARTIFACTE_COLLECTION_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        verify_core_asset_ownership(
+            &ctx.accounts.asset.to_account_info(),
+            ctx.accounts.seller.key(),
+            ctx.accounts.collection.key(),
+        )?;
+        // A Core listing would revoke the bundle's delegate on cancel
+        require!(
+            ctx.accounts.core_listing.data_is_empty(),
+            AuctionError::CoreAssetCommitted
+        );
+        ensure_core_asset_uncommitted(&ctx.accounts.asset.to_account_info(), ctx.accounts.core_authority.key())?;
+        ensure_bundle_accepts(&ctx.accounts.bundle, ctx.accounts.asset.key())?;
+
+        let asset = ctx.accounts.asset.to_account_info();
+        let collection = ctx.accounts.collection.to_account_info();
+        let core_authority = ctx.accounts.core_authority.key();
+        approve_core_delegate(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &collection,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            core_authority,
+            read_core_transfer_delegate_state(&asset)?,
+            mpl_core::types::Plugin::TransferDelegate(mpl_core::types::TransferDelegate {}),
+            mpl_core::types::PluginType::TransferDelegate,
+        )?;
+        approve_core_delegate(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &collection,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            core_authority,
+            read_core_freeze_delegate_state(&asset)?,
+            mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate { frozen: false }),
+            mpl_core::types::PluginType::FreezeDelegate,
+        )?;
+
+        let asset_key = asset.key();
+        let core_authority_bump = ctx.bumps.core_authority;
+        set_core_asset_frozen(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &collection,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.core_authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            true,
+            &[b"core_authority", asset_key.as_ref(), &[core_authority_bump]],
+        )?;
+
+        let bundle = &mut ctx.accounts.bundle;
+        bundle.items.push(BundleItem {
+            asset: asset_key,
+            kind: BundleItemKind::Core,
+            weight,
+            royalty_basis_points: 0,
+            creator_address: Pubkey::default(),
+            bump: core_authority_bump,
+        });
+
+        emit!(BundleItemAdded {
+            bundle: bundle.key(),
+            asset: asset_key,
+            weight,
+        });
+        Ok(())
+    }
+
+    /// Open a bundle for sale (seller only); no items can be added afterwards.
+    /// `start_time` schedules the sale or auction to begin later.
+    pub fn open_bundle(ctx: Context<OpenBundle>, start_time: Option<i64>) -> Result<()> {
+        let bundle = &mut ctx.accounts.bundle;
+        require!(!bundle.open, AuctionError::InvalidBundle);
+        require!(!bundle.items.is_empty(), AuctionError::InvalidBundle);
+        let weights: Vec<u16> = bundle.items.iter().map(|item| item.weight).collect();
+        bundle_item_prices(bundle.price, &weights)?;
+        if bundle.items.iter().any(|item| item.kind == BundleItemKind::Core) {
+            validate_payment_mint_config(&ctx.accounts.payment_mint_config, None, bundle.price)?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        bundle.start_time = resolve_start_time(start_time, now)?;
+        bundle.end_time = if bundle.duration_seconds > 0 {
+            bundle
+                .start_time
+                .checked_add(bundle.duration_seconds)
+                .ok_or(AuctionError::CalculationError)?
+        } else {
+            0
+        };
+        bundle.open = true;
+
+        emit!(BundleOpened {
+            bundle: bundle.key(),
+            seller: bundle.seller,
+            start_time: bundle.start_time,
+            end_time: bundle.end_time,
+            item_count: bundle.items.len() as u8,
+        });
+        Ok(())
+    }
+
+    /// Buy a fixed-price bundle: every item moves to the buyer and the price is
+    /// split per item. remaining_accounts: see the section comment above.
+    pub fn buy_bundle<'info>(ctx: Context<'_, '_, '_, 'info, BuyBundle<'info>>) -> Result<()> {
+        let bundle = &ctx.accounts.bundle;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(bundle.open, AuctionError::ListingNotActive);
+        require!(
+            matches!(bundle.listing_type, ListingType::FixedPrice),
+            AuctionError::NotFixedPrice
+        );
+        require!(now >= bundle.start_time, AuctionError::ListingNotStarted);
+        require!(
+            !listing_expired(&bundle.listing_type, bundle.end_time, now),
+            AuctionError::ListingExpired
+        );
+        require!(
+            ctx.accounts.buyer.key() != bundle.seller,
+            AuctionError::SellerCannotBid
+        );
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let bundle_key = bundle.key();
+        let (groups, hook_accounts) = bundle_item_accounts(bundle_key, &bundle.items, ctx.remaining_accounts)?;
+        let wns = bundle_wns_accounts(&groups, hook_accounts)?;
+        require!(
+            bundle_items_deliverable(&bundle.items, &groups, bundle.seller)?,
+            AuctionError::StaleCoreListing
+        );
+        let programs = BundleItemPrograms {
+            nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+            nft_token_2022_program: ctx.accounts.nft_token_2022_program.to_account_info(),
+            mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            collection: ctx.accounts.collection.as_ref().map(|c| c.to_account_info()),
+        };
+
+        let split = bundle_sale_split(bundle, bundle.price, &groups, &wns, &programs)?;
+        // WNS items: the buyer pays WNS's royalty on each item's share
+        approve_bundle_wns_sale(
+            bundle,
+            bundle.price,
+            &groups,
+            &wns,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            WnsPayment {
+                payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                payment_token_program: &ctx.accounts.token_program.to_account_info(),
+                authority_token_account: &ctx.accounts.buyer_payment_account.to_account_info(),
+            },
+            &programs,
+            &[],
+        )?;
+        let payout = pay_bundle_split(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &split,
+            &[],
+        )?;
+
+        deliver_bundle_items(
+            bundle_key,
+            &bundle.items,
+            &groups,
+            hook_accounts,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &programs,
+        )?;
+
+        emit!(BundleSold {
+            bundle: bundle_key,
+            seller: bundle.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price: bundle.price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: payout
+                .creator_royalty
+                .checked_add(split.wns_royalty)
+                .ok_or(AuctionError::CalculationError)?,
+            item_count: bundle.items.len() as u8,
+        });
+        // Bundle PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Bid on a bundle auction. Outbid funds are credited to the previous
+    /// leader's `BidRefund` (keyed by the bundle instance) and claimed via
+    /// `claim_refund`.
+    pub fn place_bundle_bid(ctx: Context<PlaceBundleBid>, amount: u64) -> Result<()> {
+        let bundle = &mut ctx.accounts.bundle;
+        let clock = Clock::get()?;
+
+        require!(bundle.open, AuctionError::ListingNotActive);
+        require!(
+            matches!(bundle.listing_type, ListingType::Auction),
+            AuctionError::NotAnAuction
+        );
+        require!(
+            clock.unix_timestamp >= bundle.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            clock.unix_timestamp < bundle.end_time,
+            AuctionError::AuctionEnded
+        );
+        // Prevent shill bidding — seller cannot bid on own auction
+        require!(
+            ctx.accounts.bidder.key() != bundle.seller,
+            AuctionError::SellerCannotBid
+        );
+
+        let min_bid = min_next_bid(
+            bundle.current_bid,
+            bundle.price,
+            bundle.bid_increment,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+        require_gte!(amount, min_bid, AuctionError::BidTooLow);
+
+        let bundle_key = bundle.key();
+        init_bid_refund(
+            &mut ctx.accounts.bidder_refund,
+            ctx.accounts.bidder.key(),
+            bundle_key,
+            bundle.payment_mint,
+            false,
+            bundle.created_at,
+            ctx.bumps.bidder_refund,
+        )?;
+
+        if bundle.current_bid > 0 {
+            // Credit previous high bidder; claimable via `claim_refund`
+            let previous_bidder_refund = ctx
+                .accounts
+                .previous_bidder_refund
+                .as_deref_mut()
+                .ok_or(AuctionError::InvalidRefundAccount)?;
+            credit_bid_refund(previous_bidder_refund, bundle.current_bid)?;
+        }
+
+        deposit_payment(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.bidder_payment_account.to_account_info(),
+            &ctx.accounts.bid_escrow.to_account_info(),
+            &ctx.accounts.bidder.to_account_info(),
+            amount,
+        )?;
+
+        bundle.current_bid = amount;
+        bundle.highest_bidder = ctx.accounts.bidder.key();
+
+        emit!(BidPlaced {
+            nft_mint: bundle_key,
+            bidder: bundle.highest_bidder,
+            amount,
+            timestamp: clock.unix_timestamp,
+            end_time: bundle.end_time,
+        });
+
+        Ok(())
+    }
+
+    /// Settle a bundle auction after it ends (permissionless). With a winning
+    /// bid every item goes to the winner and the bid is split per item;
+    /// without bids, or if a Core item can no longer be delivered, the items
+    /// return to the seller (and the winner's bid is credited back in full).
+    /// remaining_accounts: see the section comment above.
+    pub fn settle_bundle<'info>(ctx: Context<'_, '_, '_, 'info, SettleBundle<'info>>) -> Result<()> {
+        let bundle = &ctx.accounts.bundle;
+
+        require!(bundle.open, AuctionError::ListingNotActive);
+        require!(
+            matches!(bundle.listing_type, ListingType::Auction),
+            AuctionError::NotAnAuction
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= bundle.end_time,
+            AuctionError::AuctionNotEnded
+        );
+
+        let bundle_key = bundle.key();
+        let (groups, hook_accounts) = bundle_item_accounts(bundle_key, &bundle.items, ctx.remaining_accounts)?;
+        let wns = bundle_wns_accounts(&groups, hook_accounts)?;
+        let programs = BundleItemPrograms {
+            nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+            nft_token_2022_program: ctx.accounts.nft_token_2022_program.to_account_info(),
+            mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            collection: ctx.accounts.collection.as_ref().map(|c| c.to_account_info()),
+        };
+
+        if bundle.current_bid == 0 {
+            return_bundle_items(
+                bundle_key,
+                &bundle.items,
+                &groups,
+                hook_accounts,
+                &wns,
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &programs,
+            )?;
+            emit!(AuctionCancelled {
+                nft_mint: bundle_key,
+                reason: "No bids received".to_string(),
+            });
+            // Bundle PDA closed via `close = seller` constraint.
+            return Ok(());
+        }
+
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.seller_payment_account.owner == bundle.seller,
+            AuctionError::Unauthorized
+        );
+
+        let bid_escrow = ctx
+            .accounts
+            .bid_escrow
+            .as_mut()
+            .ok_or(AuctionError::InvalidRefundAccount)?;
+        let bid_escrow_bump = ctx.bumps.bid_escrow.ok_or(AuctionError::InvalidRefundAccount)?;
+        let bundle_instance = listing_instance_seed(bundle.created_at);
+        let bid_escrow_seeds: &[&[u8]] =
+            &[b"bid_escrow", bundle_key.as_ref(), &bundle_instance, &[bid_escrow_bump]];
+        let token_program = ctx.accounts.token_program.to_account_info();
+
+        if bundle_items_deliverable(&bundle.items, &groups, bundle.seller)? {
+            let split = bundle_sale_split(bundle, bundle.current_bid, &groups, &wns, &programs)?;
+            // WNS items: bid_escrow pays WNS's royalty on each item's share
+            approve_bundle_wns_sale(
+                bundle,
+                bundle.current_bid,
+                &groups,
+                &wns,
+                &ctx.accounts.payer.to_account_info(),
+                &bid_escrow.to_account_info(),
+                WnsPayment {
+                    payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                    payment_token_program: &token_program,
+                    authority_token_account: &bid_escrow.to_account_info(),
+                },
+                &programs,
+                &[bid_escrow_seeds],
+            )?;
+            let payout = pay_bundle_split(
+                &token_program,
+                &ctx.accounts.payment_mint.to_account_info(),
+                &bid_escrow.to_account_info(),
+                &bid_escrow.to_account_info(),
+                &ctx.accounts.seller_payment_account.to_account_info(),
+                &ctx.accounts.treasury_payment_account.to_account_info(),
+                &split,
+                &[bid_escrow_seeds],
+            )?;
+            deliver_bundle_items(
+                bundle_key,
+                &bundle.items,
+                &groups,
+                hook_accounts,
+                &ctx.accounts.winner.to_account_info(),
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &programs,
+            )?;
+
+            emit!(BundleSold {
+                bundle: bundle_key,
+                seller: bundle.seller,
+                buyer: bundle.highest_bidder,
+                price: bundle.current_bid,
+                platform_fee: split.platform_fee,
+                seller_amount: payout.seller_amount,
+                treasury_amount: payout.treasury_amount,
+                creator_royalty: payout
+                    .creator_royalty
+                    .checked_add(split.wns_royalty)
+                    .ok_or(AuctionError::CalculationError)?,
+                item_count: bundle.items.len() as u8,
+            });
+        } else {
+            // Lot can no longer be delivered whole — unwind it and credit the winner in full
+            return_bundle_items(
+                bundle_key,
+                &bundle.items,
+                &groups,
+                hook_accounts,
+                &wns,
+                &ctx.accounts.seller.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &programs,
+            )?;
+            let winner_bid_refund = ctx
+                .accounts
+                .winner_bid_refund
+                .as_deref_mut()
+                .ok_or(AuctionError::InvalidRefundAccount)?;
+            credit_bid_refund(winner_bid_refund, bundle.current_bid)?;
+
+            emit!(AuctionCancelled {
+                nft_mint: bundle_key,
+                reason: "Seller can no longer deliver the bundle".to_string(),
+            });
+        }
+
+        // Close the bid escrow once emptied — rent to the treasury (revenue).
+        // Credited refunds keep it open until claimed.
+        bid_escrow.reload()?;
+        if bid_escrow.amount == 0 {
+            close_bid_escrow(
+                &token_program,
+                &ctx.accounts.payment_mint.to_account_info(),
+                &bid_escrow.to_account_info(),
+                &ctx.accounts.treasury.to_account_info(),
+                bundle_key,
+                &bundle_instance,
+                bid_escrow_bump,
+            )?;
+        }
+
+        // Bundle PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Return every item to the seller and close the bundle. The seller may
+    /// cancel before the first bid; anyone may unwind an expired fixed-price
+    /// bundle. remaining_accounts: see the section comment above.
+    pub fn cancel_bundle<'info>(ctx: Context<'_, '_, '_, 'info, CancelBundle<'info>>) -> Result<()> {
+        let bundle = &ctx.accounts.bundle;
+
+        let expired = bundle.open
+            && listing_expired(&bundle.listing_type, bundle.end_time, Clock::get()?.unix_timestamp);
+        require!(
+            expired || ctx.accounts.authority.key() == bundle.seller,
+            AuctionError::Unauthorized
+        );
+        require!(bundle.current_bid == 0, AuctionError::CannotCancelWithBids);
+
+        let bundle_key = bundle.key();
+        let (groups, hook_accounts) = bundle_item_accounts(bundle_key, &bundle.items, ctx.remaining_accounts)?;
+        let wns = bundle_wns_accounts(&groups, hook_accounts)?;
+        return_bundle_items(
+            bundle_key,
+            &bundle.items,
+            &groups,
+            hook_accounts,
+            &wns,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &BundleItemPrograms {
+                nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+                nft_token_2022_program: ctx.accounts.nft_token_2022_program.to_account_info(),
+                mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                collection: ctx.accounts.collection.as_ref().map(|c| c.to_account_info()),
+            },
+        )?;
+
+        emit!(BundleCancelled {
+            bundle: bundle_key,
+            seller: bundle.seller,
+            expired,
+        });
+        // Bundle PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    // ========================================================================
+    // Swaps
+    //
+    // A swap listing escrows the maker's asset (SPL / Token-2022 NFT, pNFT or
+    // Core) and states what the maker wants back: a specific asset, any asset
+    // of a collection, or a Core asset of a collection matching attribute
+    // predicates. An optional payment-mint top-up changes hands alongside, paid
+    // by the taker, or by the maker (escrowed when the swap is made).
+    //
+    // fill_swap / cancel_swap remaining_accounts, one group per asset leg
+    // (maker's leg first; fill_swap then the taker's leg):
+    //   SPL / Token-2022: [mint, source, destination, metadata]
+    //   pNFT:             [mint, source, destination, metadata, edition,
+    //                      source token record, destination token record,
+    //                      authorization rules (this program's ID for none)]
+    //   Core:             [asset, collection]
+    // followed by list_item's WNS layout for each WNS Token-2022 leg, in leg
+    // order, then any other transfer-hook accounts. WNS legs are approved by
+    // CPI for 0. `metadata` is only read to match a wanted collection on the
+    // taker's leg.
+    // ========================================================================
+
+    /// Make a swap offering an escrowed SPL / Token-2022 NFT.
+    /// remaining_accounts: same layout as list_item
+    pub fn make_swap<'info>(ctx: Context<'_, '_, '_, 'info, MakeSwap<'info>>, terms: SwapTerms) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let nft_mint = ctx.accounts.nft_mint.key();
+        validate_swap_terms(&terms, nft_mint, now)?;
+        let is_token2022 = ctx.accounts.nft_token_program.key() != Token::id();
+
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        if let Some(wns) = wns_accounts(&nft_mint_info, ctx.remaining_accounts)? {
+            approve_wns_transfer(
+                &wns,
+                &ctx.accounts.maker.to_account_info(),
+                &ctx.accounts.maker.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.nft_token_program.to_account_info(),
+                0,
+                None,
+                &[],
+            )?;
+        }
+        transfer_nft_from_owner(
+            is_token2022,
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.maker_nft_account.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.swap_escrow.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+        let payment_mint = fund_swap_top_up(
+            &terms,
+            ctx.accounts.payment_mint.as_deref(),
+            ctx.accounts.payment_mint_config.as_deref(),
+            ctx.accounts.maker_payment_account.as_deref(),
+            ctx.accounts.swap_payment_escrow.as_deref(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+
+        let swap = &mut ctx.accounts.swap;
+        init_swap(
+            swap,
+            ctx.accounts.maker.key(),
+            nft_mint,
+            if is_token2022 { SwapAssetKind::Token2022 } else { SwapAssetKind::Spl },
+            terms,
+            payment_mint,
+            now,
+            ctx.bumps.swap,
+            ctx.bumps.swap_escrow,
+            ctx.bumps.swap_payment_escrow.unwrap_or(0),
+        );
+        emit_swap_created(swap);
+        Ok(())
+    }
+
+    /// Make a swap offering a pNFT, escrowed via Token Metadata TransferV1.
+    pub fn make_swap_pnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, MakeSwapPnft<'info>>,
+        terms: SwapTerms,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let nft_mint = ctx.accounts.nft_mint.key();
+        validate_swap_terms(&terms, nft_mint, now)?;
+
+        transfer_pnft(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.maker_nft_token.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.escrow_nft_token.to_account_info(),
+            &ctx.accounts.swap_authority.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_edition.to_account_info(),
+            &ctx.accounts.maker_token_record.to_account_info(),
+            &ctx.accounts.escrow_token_record.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.sysvar_instructions.to_account_info(),
+            &ctx.accounts.nft_token_program.to_account_info(),
+            &ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            ctx.accounts.authorization_rules.as_ref().map(|a| a.as_ref() as &AccountInfo),
+            &[],
+        )?;
+        let payment_mint = fund_swap_top_up(
+            &terms,
+            ctx.accounts.payment_mint.as_deref(),
+            ctx.accounts.payment_mint_config.as_deref(),
+            ctx.accounts.maker_payment_account.as_deref(),
+            ctx.accounts.swap_payment_escrow.as_deref(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+
+        let swap = &mut ctx.accounts.swap;
+        init_swap(
+            swap,
+            ctx.accounts.maker.key(),
+            nft_mint,
+            SwapAssetKind::Pnft,
+            terms,
+            payment_mint,
+            now,
+            ctx.bumps.swap,
+            ctx.bumps.swap_authority,
+            ctx.bumps.swap_payment_escrow.unwrap_or(0),
+        );
+        emit_swap_created(swap);
+        Ok(())
+    }
+
+    /// Make a swap offering a Metaplex Core asset. Like a bundle item, the asset
+    /// stays in the maker's wallet, delegated to its `core_authority` PDA and
+    /// frozen until the swap is filled or cancelled.
+    pub fn make_swap_core(ctx: Context<MakeSwapCore>, terms: SwapTerms) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let asset_key = ctx.accounts.asset.key();
+        validate_swap_terms(&terms, asset_key, now)?;
+        // Artifacte collection only
+        require_keys_eq!(
+            ctx.accounts.collection.key(),
+            ARTIFACTE_COLLECTION_PUBKEY,
+            AuctionError::Unauthorized
+        );
+        let asset = ctx.accounts.asset.to_account_info();
+        let collection = ctx.accounts.collection.to_account_info();
+        let core_authority = ctx.accounts.core_authority.key();
+        verify_core_asset_ownership(&asset, ctx.accounts.maker.key(), collection.key())?;
+        require!(
+            ctx.accounts.core_listing.data_is_empty(),
+            AuctionError::CoreAssetCommitted
+        );
+        ensure_core_asset_uncommitted(&asset, core_authority)?;
+
+        approve_core_delegate(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &collection,
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            core_authority,
+            read_core_transfer_delegate_state(&asset)?,
+            mpl_core::types::Plugin::TransferDelegate(mpl_core::types::TransferDelegate {}),
+            mpl_core::types::PluginType::TransferDelegate,
+        )?;
+        approve_core_delegate(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &collection,
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            core_authority,
+            read_core_freeze_delegate_state(&asset)?,
+            mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate { frozen: false }),
+            mpl_core::types::PluginType::FreezeDelegate,
+        )?;
+        let core_authority_bump = ctx.bumps.core_authority;
+        set_core_asset_frozen(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            &asset,
+            &collection,
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.core_authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            true,
+            &[b"core_authority", asset_key.as_ref(), &[core_authority_bump]],
+        )?;
+        let payment_mint = fund_swap_top_up(
+            &terms,
+            ctx.accounts.payment_mint.as_deref(),
+            ctx.accounts.payment_mint_config.as_deref(),
+            ctx.accounts.maker_payment_account.as_deref(),
+            ctx.accounts.swap_payment_escrow.as_deref(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+
+        let swap = &mut ctx.accounts.swap;
+        init_swap(
+            swap,
+            ctx.accounts.maker.key(),
+            asset_key,
+            SwapAssetKind::Core,
+            terms,
+            payment_mint,
+            now,
+            ctx.bumps.swap,
+            core_authority_bump,
+            ctx.bumps.swap_payment_escrow.unwrap_or(0),
+        );
+        emit_swap_created(swap);
+        Ok(())
+    }
+
+    /// Fill a swap with an asset the swap wants. The taker's asset goes to the
+    /// maker, the escrowed asset to the taker, and the top-up to whoever is
+    /// owed it, all in this instruction. `taker_kind` is the standard of the
+    /// taker's asset. remaining_accounts: see the section comment above.
+    pub fn fill_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillSwap<'info>>,
+        taker_kind: SwapAssetKind,
+    ) -> Result<()> {
+        let swap = &ctx.accounts.swap;
+        require!(
+            !swap_expired(swap.expires_at, Clock::get()?.unix_timestamp),
+            AuctionError::OfferExpired
+        );
+        require!(
+            ctx.accounts.taker.key() != swap.maker,
+            AuctionError::SellerCannotBid
+        );
+        require_keys_eq!(
+            ctx.accounts.escrow_authority.key(),
+            swap_escrow_authority(swap)?,
+            AuctionError::InvalidSwapAccounts
+        );
+
+        let (maker_leg, rest) = swap_leg_accounts(swap.offered_kind, ctx.remaining_accounts)?;
+        let (taker_leg, hook_accounts) = swap_leg_accounts(taker_kind, rest)?;
+        ensure_swap_wants(swap, taker_kind, taker_leg)?;
+        let wns = wns_accounts_each(&[&maker_leg[0], &taker_leg[0]], hook_accounts)?;
+
+        let programs = SwapPrograms {
+            nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+            nft_token_2022_program: ctx.accounts.nft_token_2022_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            ata_program: ctx.accounts.ata_program.to_account_info(),
+            authorization_rules_program: ctx
+                .accounts
+                .authorization_rules_program
+                .as_ref()
+                .map(|a| a.to_account_info()),
+            mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let maker = ctx.accounts.maker.to_account_info();
+        let taker = ctx.accounts.taker.to_account_info();
+
+        transfer_swap_asset(taker_kind, taker_leg, hook_accounts, wns[1].as_ref(), &taker, &maker, &programs)?;
+        release_swap_asset(
+            swap,
+            maker_leg,
+            hook_accounts,
+            wns[0].as_ref(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &taker,
+            &maker,
+            &taker,
+            &programs,
+        )?;
+
+        if swap.top_up > 0 {
+            let payment_mint = ctx
+                .accounts
+                .payment_mint
+                .as_ref()
+                .ok_or(AuctionError::InvalidSwapAccounts)?
+                .to_account_info();
+            let token_program = ctx.accounts.token_program.to_account_info();
+            match swap.top_up_payer {
+                TopUpPayer::Taker => {
+                    let taker_payment_account = ctx
+                        .accounts
+                        .taker_payment_account
+                        .as_ref()
+                        .ok_or(AuctionError::InvalidSwapAccounts)?;
+                    let maker_payment_account = ctx
+                        .accounts
+                        .maker_payment_account
+                        .as_ref()
+                        .ok_or(AuctionError::InvalidSwapAccounts)?;
+                    transfer_payment(
+                        &token_program,
+                        &payment_mint,
+                        &taker_payment_account.to_account_info(),
+                        &maker_payment_account.to_account_info(),
+                        &taker,
+                        swap.top_up,
+                        &[],
+                    )?;
+                }
+                TopUpPayer::Maker => {
+                    let swap_payment_escrow = ctx
+                        .accounts
+                        .swap_payment_escrow
+                        .as_ref()
+                        .ok_or(AuctionError::InvalidSwapAccounts)?;
+                    let taker_payment_account = ctx
+                        .accounts
+                        .taker_payment_account
+                        .as_ref()
+                        .ok_or(AuctionError::InvalidSwapAccounts)?;
+                    let swap_key = swap.key();
+                    // Pays out the whole escrow, rent back to the maker
+                    refund_payment_escrow(
+                        &token_program,
+                        &payment_mint,
+                        swap_payment_escrow,
+                        &taker_payment_account.to_account_info(),
+                        &maker,
+                        &[b"swap_payment_escrow", swap_key.as_ref(), &[swap.payment_escrow_bump]],
+                    )?;
+                }
+            }
+        }
+
+        emit!(SwapFilled {
+            swap: swap.key(),
+            maker: swap.maker,
+            taker: ctx.accounts.taker.key(),
+            offered_asset: swap.offered_asset,
+            received_asset: taker_leg[0].key(),
+            payment_mint: swap.payment_mint,
+            top_up: swap.top_up,
+            top_up_payer: swap.top_up_payer,
+        });
+        // Swap PDA closed via `close = maker` constraint.
+        Ok(())
+    }
+
+    /// Cancel a swap: the escrowed asset and any maker top-up go back to the
+    /// maker. The maker may cancel at any time; anyone may unwind an expired
+    /// swap. remaining_accounts: the maker's leg (see the section comment above).
+    pub fn cancel_swap<'info>(ctx: Context<'_, '_, '_, 'info, CancelSwap<'info>>) -> Result<()> {
+        let swap = &ctx.accounts.swap;
+        let expired = swap_expired(swap.expires_at, Clock::get()?.unix_timestamp);
+        require!(
+            expired || ctx.accounts.authority.key() == swap.maker,
+            AuctionError::Unauthorized
+        );
+        require_keys_eq!(
+            ctx.accounts.escrow_authority.key(),
+            swap_escrow_authority(swap)?,
+            AuctionError::InvalidSwapAccounts
+        );
+
+        let (maker_leg, hook_accounts) = swap_leg_accounts(swap.offered_kind, ctx.remaining_accounts)?;
+        let wns = wns_accounts(&maker_leg[0], hook_accounts)?;
+        let maker = ctx.accounts.maker.to_account_info();
+        release_swap_asset(
+            swap,
+            maker_leg,
+            hook_accounts,
+            wns.as_ref(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &maker,
+            &maker,
+            &ctx.accounts.authority.to_account_info(),
+            &SwapPrograms {
+                nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+                nft_token_2022_program: ctx.accounts.nft_token_2022_program.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                ata_program: ctx.accounts.ata_program.to_account_info(),
+                authorization_rules_program: ctx
+                    .accounts
+                    .authorization_rules_program
+                    .as_ref()
+                    .map(|a| a.to_account_info()),
+                mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        )?;
+
+        if swap.top_up > 0 && swap.top_up_payer == TopUpPayer::Maker {
+            let payment_mint = ctx
+                .accounts
+                .payment_mint
+                .as_ref()
+                .ok_or(AuctionError::InvalidSwapAccounts)?;
+            let swap_payment_escrow = ctx
+                .accounts
+                .swap_payment_escrow
+                .as_ref()
+                .ok_or(AuctionError::InvalidSwapAccounts)?;
+            let maker_payment_account = ctx
+                .accounts
+                .maker_payment_account
+                .as_ref()
+                .ok_or(AuctionError::InvalidSwapAccounts)?;
+            let swap_key = swap.key();
+            refund_payment_escrow(
+                &ctx.accounts.token_program.to_account_info(),
+                &payment_mint.to_account_info(),
+                swap_payment_escrow,
+                &maker_payment_account.to_account_info(),
+                &maker,
+                &[b"swap_payment_escrow", swap_key.as_ref(), &[swap.payment_escrow_bump]],
+            )?;
+        }
+
+        emit!(SwapCancelled {
+            swap: swap.key(),
+            maker: swap.maker,
+            offered_asset: swap.offered_asset,
+            expired,
+        });
+        // Swap PDA closed via `close = maker` constraint.
+        Ok(())
+    }
+
+    // ========================================================================
+    // Compressed NFTs (Bubblegum)
+    //
+    // A cNFT listing is keyed by the leaf's tree and nonce. Listing makes the
+    // `compressed_listing` PDA the leaf delegate via Bubblegum; the purchase
+    // transfers as that delegate. Every Bubblegum call proves the leaf against
+    // a recent root: remaining_accounts carry the merkle proof (sibling nodes,
+    // leaf to root). The sale only succeeds against the exact leaf recorded at
+    // listing time, so a listing goes stale as soon as the leaf changes.
+    // ========================================================================
+
+    /// List a Bubblegum cNFT for a fixed price. `leaf` identifies the seller's
+    /// current leaf; the other arguments are as for `list_item_delegated`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn list_compressed_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListCompressedItem<'info>>,
+        leaf: CompressedLeaf,
+        price: u64,
+        category: ItemCategory,
+        royalty_basis_points: u16,
+        creator_address: Pubkey,
+        start_time: Option<i64>,
+        duration_seconds: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let (start_time, end_time) = validate_delegate_listing(
+            price,
+            category,
+            royalty_basis_points,
+            &ctx.accounts.payment_mint_config,
+            &ctx.accounts.payment_mint.to_account_info(),
+            start_time,
+            duration_seconds,
+            now,
+        )?;
+
+        let merkle_tree = ctx.accounts.merkle_tree.key();
+        let listing = &mut ctx.accounts.compressed_listing;
+        listing.seller = ctx.accounts.seller.key();
+        listing.asset_id = compressed_asset_id(&merkle_tree, leaf.nonce);
+        listing.merkle_tree = merkle_tree;
+        listing.leaf_nonce = leaf.nonce;
+        listing.leaf_index = leaf.index;
+        listing.data_hash = leaf.data_hash;
+        listing.creator_hash = leaf.creator_hash;
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.price = price;
+        listing.category = category;
+        listing.start_time = start_time;
+        listing.end_time = end_time;
+        listing.royalty_basis_points = royalty_basis_points;
+        listing.creator_address = creator_address;
+        listing.created_at = now;
+        listing.bump = ctx.bumps.compressed_listing;
+
+        let tree = BubblegumTree {
+            tree_config: ctx.accounts.tree_config.to_account_info(),
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: ctx.accounts.compression_program.to_account_info(),
+            bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        delegate_compressed_leaf(
+            &tree,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.previous_leaf_delegate.to_account_info(),
+            &listing.to_account_info(),
+            &leaf,
+            ctx.remaining_accounts,
+        )?;
+
+        emit!(ListingCreated {
+            nft_mint: listing.asset_id,
+            seller: listing.seller,
+            listing_type: ListingType::FixedPrice,
+            price,
+            category,
+            start_time: listing.start_time,
+            end_time: listing.end_time,
+            payment_mint: listing.payment_mint,
+        });
+        Ok(())
+    }
+
+    /// Buy a cNFT listing with the same fee and royalty split as `buy_now`.
+    /// `root` is the tree root the proof in remaining_accounts resolves to.
+    pub fn buy_now_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNowCompressed<'info>>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        let listing = &ctx.accounts.compressed_listing;
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= listing.start_time,
+            AuctionError::ListingNotStarted
+        );
+        require!(
+            !listing_expired(&ListingType::FixedPrice, listing.end_time, clock.unix_timestamp),
+            AuctionError::ListingExpired
+        );
+
+        // Resolve treasury address: use config PDA if initialized, else fallback
+        let treasury_address = resolve_treasury_address(ctx.accounts.treasury_config.as_deref());
+        require!(
+            ctx.accounts.treasury_payment_account.owner == treasury_address,
+            AuctionError::Unauthorized
+        );
+        require!(
+            ctx.accounts.treasury.key() == treasury_address,
+            AuctionError::Unauthorized
+        );
+
+        let split = calculate_sale_split(listing.price, false, listing.royalty_basis_points)?;
+        validate_creator_ata(
+            listing.creator_address,
+            listing.payment_mint,
+            ctx.accounts.creator_payment_account.key(),
+            split.creator_royalty,
+        )?;
+
+        // Payment: buyer → seller / treasury / creator
+        let payout = pay_sale_split(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.seller_payment_account.to_account_info(),
+            &ctx.accounts.treasury_payment_account.to_account_info(),
+            &ctx.accounts.creator_payment_account.to_account_info(),
+            &split,
+            &[],
+        )?;
+
+        // Transfer cNFT: seller → buyer, signed by the listing PDA as leaf delegate.
+        // Bubblegum rejects the proof if the leaf changed since listing.
+        let merkle_tree = listing.merkle_tree;
+        let nonce_bytes = listing.leaf_nonce.to_le_bytes();
+        let listing_seeds: &[&[u8]] = &[
+            b"compressed_listing",
+            merkle_tree.as_ref(),
+            nonce_bytes.as_ref(),
+            &[listing.bump],
+        ];
+        transfer_compressed_leaf(
+            &BubblegumTree {
+                tree_config: ctx.accounts.tree_config.to_account_info(),
+                merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+                log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: ctx.accounts.compression_program.to_account_info(),
+                bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            listing,
+            root,
+            &ctx.accounts.seller.to_account_info(),
+            &listing.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            ctx.remaining_accounts,
+            listing_seeds,
+        )?;
+
+        emit!(ItemPurchased {
+            nft_mint: listing.asset_id,
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            price: listing.price,
+            platform_fee: split.platform_fee,
+            seller_amount: payout.seller_amount,
+            treasury_amount: payout.treasury_amount,
+            creator_royalty: payout.creator_royalty,
+        });
+        // CompressedListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Cancel a cNFT listing (seller only), handing leaf delegation back to the
+    /// seller. A listing whose leaf already changed is closed with
+    /// `close_stale_compressed_listing` instead.
+    pub fn cancel_compressed_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelCompressedListing<'info>>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        let listing = &ctx.accounts.compressed_listing;
+        let leaf = CompressedLeaf {
+            root,
+            data_hash: listing.data_hash,
+            creator_hash: listing.creator_hash,
+            nonce: listing.leaf_nonce,
+            index: listing.leaf_index,
+        };
+        delegate_compressed_leaf(
+            &BubblegumTree {
+                tree_config: ctx.accounts.tree_config.to_account_info(),
+                merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+                log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: ctx.accounts.compression_program.to_account_info(),
+                bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &ctx.accounts.seller.to_account_info(),
+            &listing.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &leaf,
+            ctx.remaining_accounts,
+        )?;
+
+        emit!(ListingCancelled {
+            nft_mint: listing.asset_id,
+            seller: listing.seller,
+        });
+        // CompressedListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+
+    /// Close a cNFT listing that can no longer sell (permissionless). Before
+    /// expiry the caller proves, with a merkle proof against `root`, that the
+    /// leaf at the listing's index is now `current_leaf` and not the listed one.
+    /// Rent goes to the seller.
+    pub fn close_stale_compressed_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseStaleCompressedListing<'info>>,
+        root: [u8; 32],
+        current_leaf: [u8; 32],
+    ) -> Result<()> {
+        let listing = &ctx.accounts.compressed_listing;
+        let expired = listing_expired(&ListingType::FixedPrice, listing.end_time, Clock::get()?.unix_timestamp);
+
+        if expired {
+            emit!(ListingExpired {
+                nft_mint: listing.asset_id,
+                seller: listing.seller,
+            });
+        } else {
+            let listed_leaf = compressed_leaf_hash(
+                &listing.asset_id,
+                &listing.seller,
+                &listing.key(),
+                listing.leaf_nonce,
+                &listing.data_hash,
+                &listing.creator_hash,
+            );
+            require!(current_leaf != listed_leaf, AuctionError::ListingNotStale);
+            verify_compressed_leaf(
+                &ctx.accounts.compression_program.to_account_info(),
+                &ctx.accounts.merkle_tree.to_account_info(),
+                root,
+                current_leaf,
+                listing.leaf_index,
+                ctx.remaining_accounts,
+            )?;
+            emit!(ListingCancelled {
+                nft_mint: listing.asset_id,
+                seller: listing.seller,
+            });
+        }
+        // CompressedListing PDA closed via `close = seller` constraint.
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Treasury wallet: config PDA if initialized, else the hardcoded fallback.
+fn resolve_treasury_address(treasury_config: Option<&TreasuryConfig>) -> Pubkey {
+    match treasury_config {
+        Some(config) => config.treasury,
+        None => TREASURY_FALLBACK.parse::<Pubkey>().unwrap(),
+    }
+}
+
+/// Validate creator_payment_account when royalty > 0 (prevents royalty redirection)
+fn validate_creator_payment_account(
+    listing: &Listing,
+    creator_payment_account: Pubkey,
+    creator_royalty: u64,
+) -> Result<()> {
+    validate_creator_ata(
+        listing.creator_address,
+        listing.payment_mint,
+        creator_payment_account,
+        creator_royalty,
+    )
+}
+
+/// Sale split for an escrowed listing. WNS NFTs are charged the royalty in
+/// their metadata, which WNS itself collects on `approve_transfer`; other NFTs
+/// pay the listing's royalty to the creator ATA.
+fn escrow_sale_split(
+    listing: &Listing,
+    price: u64,
+    nft_mint: &AccountInfo,
+    is_wns: bool,
+    creator_payment_account: Pubkey,
+) -> Result<SaleSplit> {
+    if is_wns {
+        return calculate_sale_split(price, listing.baxus_fee, read_wns_royalty_bps(nft_mint)?);
+    }
+    let split = calculate_sale_split(price, listing.baxus_fee, listing.royalty_basis_points)?;
+    validate_creator_payment_account(listing, creator_payment_account, split.creator_royalty)?;
+    Ok(split)
+}
+
+/// `escrow_sale_split` for native-SOL listings, whose non-WNS royalty is paid
+/// to the creator's wallet.
+fn native_sale_split(
+    listing: &Listing,
+    price: u64,
+    nft_mint: &AccountInfo,
+    is_wns: bool,
+    creator_wallet: Pubkey,
+) -> Result<SaleSplit> {
+    if is_wns {
+        return calculate_sale_split(price, listing.baxus_fee, read_wns_royalty_bps(nft_mint)?);
+    }
+    let split = calculate_sale_split(price, listing.baxus_fee, listing.royalty_basis_points)?;
+    validate_creator_wallet(listing, creator_wallet, split.creator_royalty)?;
+    Ok(split)
+}
+
+/// Royalties are paid to the creator's ATA for the payment mint.
+fn validate_creator_ata(
+    creator_address: Pubkey,
+    payment_mint: Pubkey,
+    creator_payment_account: Pubkey,
+    creator_royalty: u64,
+) -> Result<()> {
+    if creator_royalty > 0 {
+        let expected_creator_ata = anchor_spl::associated_token::get_associated_token_address(
+            &creator_address,
+            &payment_mint,
+        );
+        require!(
+            creator_payment_account == expected_creator_ata,
+            AuctionError::InvalidCreatorAccount
+        );
+    }
+    Ok(())
+}
+
+/// An offer can be accepted by anyone but its buyer until it expires.
+fn validate_offer_acceptance(offer: &Offer, seller: Pubkey) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp < offer.expires_at,
+        AuctionError::OfferExpired
+    );
+    require!(seller != offer.buyer, AuctionError::SellerCannotBid);
+    Ok(())
+}
+
+/// Offers can close out the seller's active fixed-price or Dutch listing.
+fn validate_offer_listing(listing: &Listing, seller: Pubkey) -> Result<()> {
+    require_keys_eq!(listing.seller, seller, AuctionError::Unauthorized);
+    require!(
+        listing.status == ListingStatus::Active,
+        AuctionError::ListingNotActive
+    );
+    require!(
+        matches!(listing.listing_type, ListingType::FixedPrice | ListingType::Dutch),
+        AuctionError::NotFixedPrice
+    );
+    Ok(())
+}
+
+/// Pay an accepted offer out of its escrow, then close the emptied escrow
+/// with its rent returned to the buyer.
+#[allow(clippy::too_many_arguments)]
+fn pay_offer<'info>(
+    offer: &Offer,
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    offer_escrow: &AccountInfo<'info>,
+    seller_payment_account: &AccountInfo<'info>,
+    treasury_payment_account: &AccountInfo<'info>,
+    creator_payment_account: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+    split: &SaleSplit,
+) -> Result<SalePayout> {
+    let escrow_seeds: &[&[u8]] = &[
+        b"offer_escrow",
+        offer.asset.as_ref(),
+        offer.buyer.as_ref(),
+        &[offer.escrow_bump],
+    ];
+    let payout = pay_sale_split(
+        token_program,
+        payment_mint,
+        offer_escrow,
+        offer_escrow,
+        seller_payment_account,
+        treasury_payment_account,
+        creator_payment_account,
+        split,
+        &[escrow_seeds],
+    )?;
+    close_payment_escrow(token_program, payment_mint, offer_escrow, buyer, escrow_seeds)?;
+    Ok(payout)
+}
+
+/// Return an offer's escrow to the buyer and close it.
+fn refund_offer<'info>(
+    offer: &Offer,
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    offer_escrow: &InterfaceAccount<'info, IfaceTokenAccount>,
+    buyer_payment_account: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+) -> Result<()> {
+    refund_payment_escrow(
+        token_program,
+        payment_mint,
+        offer_escrow,
+        buyer_payment_account,
+        buyer,
+        &[
+            b"offer_escrow",
+            offer.asset.as_ref(),
+            offer.buyer.as_ref(),
+            &[offer.escrow_bump],
+        ],
+    )
+}
+
+/// Return everything left in a self-owned payment escrow to the buyer and close it.
+fn refund_payment_escrow<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    escrow: &InterfaceAccount<'info, IfaceTokenAccount>,
+    buyer_payment_account: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+    escrow_seeds: &[&[u8]],
+) -> Result<()> {
+    let escrow_info = escrow.to_account_info();
+    transfer_payment(
+        token_program,
+        payment_mint,
+        &escrow_info,
+        buyer_payment_account,
+        &escrow_info,
+        escrow.amount,
+        &[escrow_seeds],
+    )?;
+    close_payment_escrow(token_program, payment_mint, &escrow_info, buyer, escrow_seeds)
+}
+
+fn emit_offer_accepted(offer: &Offer, seller: Pubkey, split: &SaleSplit, payout: &SalePayout) {
+    emit!(OfferAccepted {
+        asset: offer.asset,
+        seller,
+        buyer: offer.buyer,
+        price: offer.amount,
+        platform_fee: split.platform_fee,
+        seller_amount: payout.seller_amount,
+        treasury_amount: payout.treasury_amount,
+        creator_royalty: payout.creator_royalty,
+    });
+}
+
+/// Check a swap's cash leg and, for a maker-paid top-up, escrow it. Returns
+/// the payment mint, or the default pubkey when there is no top-up.
+fn fund_swap_top_up<'info>(
+    terms: &SwapTerms,
+    payment_mint: Option<&InterfaceAccount<'info, IfaceMint>>,
+    payment_mint_config: Option<&Account<'info, PaymentMintConfig>>,
+    maker_payment_account: Option<&InterfaceAccount<'info, IfaceTokenAccount>>,
+    swap_payment_escrow: Option<&InterfaceAccount<'info, IfaceTokenAccount>>,
+    maker: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<Pubkey> {
+    let maker_funded = terms.top_up > 0 && terms.top_up_payer == TopUpPayer::Maker;
+    require!(
+        swap_payment_escrow.is_some() == maker_funded,
+        AuctionError::InvalidSwapAccounts
+    );
+    if terms.top_up == 0 {
+        return Ok(Pubkey::default());
+    }
+
+    let payment_mint = payment_mint.ok_or(AuctionError::InvalidSwapAccounts)?;
+    let config = payment_mint_config.ok_or(AuctionError::InvalidSwapAccounts)?;
+    require!(
+        config.mint == payment_mint.key() && config.enabled,
+        AuctionError::InvalidPaymentMint
+    );
+    validate_payment_mint_extensions(&payment_mint.to_account_info())?;
+
+    if let Some(swap_payment_escrow) = swap_payment_escrow {
+        let maker_payment_account = maker_payment_account.ok_or(AuctionError::InvalidSwapAccounts)?;
+        deposit_payment(
+            token_program,
+            &payment_mint.to_account_info(),
+            &maker_payment_account.to_account_info(),
+            &swap_payment_escrow.to_account_info(),
+            maker,
+            terms.top_up,
+        )?;
+    }
+    Ok(payment_mint.key())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn init_swap(
+    swap: &mut Swap,
+    maker: Pubkey,
+    offered_asset: Pubkey,
+    offered_kind: SwapAssetKind,
+    terms: SwapTerms,
+    payment_mint: Pubkey,
+    now: i64,
+    bump: u8,
+    escrow_bump: u8,
+    payment_escrow_bump: u8,
+) {
+    swap.maker = maker;
+    swap.offered_asset = offered_asset;
+    swap.offered_kind = offered_kind;
+    swap.want = terms.want;
+    swap.wanted = terms.wanted;
+    swap.attributes = terms.attributes;
+    swap.payment_mint = payment_mint;
+    swap.top_up = terms.top_up;
+    swap.top_up_payer = terms.top_up_payer;
+    swap.expires_at = terms.expires_at;
+    swap.created_at = now;
+    swap.bump = bump;
+    swap.escrow_bump = escrow_bump;
+    swap.payment_escrow_bump = payment_escrow_bump;
+}
+
+fn emit_swap_created(swap: &Account<Swap>) {
+    emit!(SwapCreated {
+        swap: swap.key(),
+        maker: swap.maker,
+        offered_asset: swap.offered_asset,
+        offered_kind: swap.offered_kind,
+        want: swap.want,
+        wanted: swap.wanted,
+        attributes: swap.attributes.clone(),
+        payment_mint: swap.payment_mint,
+        top_up: swap.top_up,
+        top_up_payer: swap.top_up_payer,
+        expires_at: swap.expires_at,
+    });
+}
+
+/// Native-SOL counterpart of `validate_creator_payment_account`: royalties go
+/// straight to the creator's wallet.
+fn validate_creator_wallet(listing: &Listing, creator_wallet: Pubkey, creator_royalty: u64) -> Result<()> {
+    if creator_royalty > 0 {
+        require!(
+            creator_wallet == listing.creator_address,
+            AuctionError::InvalidCreatorAccount
+        );
+    }
+    Ok(())
+}
+
+fn apply_payment_mint_settings(config: &mut PaymentMintConfig, settings: PaymentMintSettings) -> Result<()> {
+    validate_bid_increment(&ListingType::Auction, &Some(settings.bid_increment))?;
+    config.enabled = settings.enabled;
+    config.allowed_categories = category_mask(&settings.categories);
+    config.allow_core = settings.allow_core;
+    config.min_price = settings.min_price;
+    config.bid_increment = settings.bid_increment;
+
+    emit!(PaymentMintConfigUpdated {
+        mint: config.mint,
+        enabled: config.enabled,
+        allowed_categories: config.allowed_categories,
+        allow_core: config.allow_core,
+        min_price: config.min_price,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Bitmask of `ItemCategory` values, one bit per variant.
+fn category_mask(categories: &[ItemCategory]) -> u8 {
+    categories
+        .iter()
+        .fold(0, |mask, category| mask | (1 << *category as u8))
+}
+
+/// Check a listing against its payment mint's config. `category` is `None`
+/// for Core listings, which are gated by `allow_core` instead. `lowest_price`
+/// is the least the item can sell for (the Dutch floor, else the list price).
+fn validate_payment_mint_config(
+    config: &PaymentMintConfig,
+    category: Option<ItemCategory>,
+    lowest_price: u64,
+) -> Result<()> {
+    require!(config.enabled, AuctionError::InvalidPaymentMint);
+    let allowed = match category {
+        Some(category) => config.allowed_categories & category_mask(&[category]) != 0,
+        None => config.allow_core,
+    };
+    require!(allowed, AuctionError::InvalidPaymentMint);
+    require_gte!(lowest_price, config.min_price, AuctionError::PriceBelowMinimum);
+    Ok(())
+}
+
+/// New list price must keep the listing's own invariants: above the Dutch floor,
+/// and below any public reserve and buy-now price. Auctions must have no bids.
+fn validate_listing_price_update(listing: &Listing, price: u64) -> Result<()> {
+    require!(price > 0, AuctionError::InvalidPrice);
+    require!(price <= 1_000_000_000_000_000_000, AuctionError::InvalidPrice);
+
+    match listing.listing_type {
+        ListingType::FixedPrice => {}
+        ListingType::Dutch => {
+            require!(listing.dutch_floor_price < price, AuctionError::InvalidDutchConfig);
+        }
+        ListingType::Auction | ListingType::Sealed => {
+            require!(
+                listing.current_bid == 0 && listing.sealed_bid_count == 0,
+                AuctionError::CannotUpdateWithBids
+            );
+            if listing.reserve_price > 0 {
+                require!(listing.reserve_price > price, AuctionError::InvalidReservePrice);
+            }
+            if listing.buy_now_price > 0 {
+                require!(listing.buy_now_price > price, AuctionError::InvalidBuyNowPrice);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Auctions, Dutch and sealed listings need a positive duration; fixed-price
+/// listings may omit it (no expiry).
+fn validate_duration(listing_type: &ListingType, duration_seconds: Option<i64>) -> Result<()> {
+    match duration_seconds {
+        Some(duration) => require!(duration > 0, AuctionError::InvalidDuration),
+        None => require!(
+            matches!(listing_type, ListingType::FixedPrice),
+            AuctionError::InvalidDuration
+        ),
+    }
+    Ok(())
+}
+
+/// Fixed-price listings with a non-zero `end_time` stop selling at it. For
+/// other listing types `end_time` is the auction close or Dutch decay end.
+fn listing_expired(listing_type: &ListingType, end_time: i64, now: i64) -> bool {
+    matches!(listing_type, ListingType::FixedPrice) && end_time != 0 && now >= end_time
+}
+
+/// Shared checks for `list_item_delegated` / `list_item_pnft_delegated`;
+/// returns the listing's `(start_time, end_time)`.
+#[allow(clippy::too_many_arguments)]
+fn validate_delegate_listing(
+    price: u64,
+    category: ItemCategory,
+    royalty_basis_points: u16,
+    payment_mint_config: &PaymentMintConfig,
+    payment_mint: &AccountInfo,
+    start_time: Option<i64>,
+    duration_seconds: Option<i64>,
+    now: i64,
+) -> Result<(i64, i64)> {
+    require!(price > 0, AuctionError::InvalidPrice);
+    validate_royalty_bps(royalty_basis_points)?;
+    validate_payment_mint_config(payment_mint_config, Some(category), price)?;
+    validate_payment_mint_extensions(payment_mint)?;
+    validate_duration(&ListingType::FixedPrice, duration_seconds)?;
+
+    let start_time = resolve_start_time(start_time, now)?;
+    let end_time = match duration_seconds {
+        Some(duration) => start_time
+            .checked_add(duration)
+            .ok_or(AuctionError::CalculationError)?,
+        None => 0,
+    };
+    Ok((start_time, end_time))
+}
+
+/// A delegate listing can sell while the seller's token account still holds
+/// the NFT with the listing PDA approved for it. pNFT accounts are always
+/// frozen by Token Metadata; other accounts must not be.
+fn delegated_listing_live(
+    listing: &DelegateListing,
+    listing_key: Pubkey,
+    seller_nft_account: &AccountInfo,
+) -> Result<bool> {
+    require_keys_eq!(
+        seller_nft_account.key(),
+        listing.seller_nft_account,
+        AuctionError::Unauthorized
+    );
+    if seller_nft_account.data_is_empty()
+        || (*seller_nft_account.owner != Token::id() && *seller_nft_account.owner != spl_token_2022::id())
+    {
+        return Ok(false);
+    }
+
+    let data = seller_nft_account.try_borrow_data()?;
+    let state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    let account = state.base;
+    Ok(account.mint == listing.nft_mint
+        && account.owner == listing.seller
+        && account.amount >= 1
+        && (listing.is_pnft || !account.is_frozen())
+        && account.delegate == Some(listing_key).into()
+        && account.delegated_amount >= 1)
+}
+
+/// Fixed-price expiry: 0 for none, else a time after both `now` and the start.
+fn resolve_listing_expiry(end_time: i64, start_time: i64, now: i64) -> Result<i64> {
+    if end_time != 0 {
+        require!(
+            end_time > now && end_time > start_time,
+            AuctionError::InvalidDuration
+        );
+    }
+    Ok(end_time)
+}
+
+/// Listing start: `now` by default, or a scheduled time that must not be in the past.
+fn resolve_start_time(start_time: Option<i64>, now: i64) -> Result<i64> {
+    match start_time {
+        Some(start_time) => {
+            require!(start_time >= now, AuctionError::InvalidStartTime);
+            Ok(start_time)
+        }
+        None => Ok(now),
+    }
+}
+
+fn validate_soft_close(listing_type: &ListingType, soft_close: &Option<SoftCloseConfig>) -> Result<()> {
+    let Some(config) = soft_close else {
+        return Ok(());
+    };
+
+    require!(
+        matches!(listing_type, ListingType::Auction),
+        AuctionError::NotAnAuction
+    );
+    require!(
+        config.window_seconds > 0 && config.extension_seconds > 0,
+        AuctionError::InvalidSoftClose
+    );
+    if let Some(max_extension) = config.max_extension_seconds {
+        require!(max_extension > 0, AuctionError::InvalidSoftClose);
+    }
+
+    Ok(())
+}
+
+fn validate_reserve(listing_type: &ListingType, price: u64, reserve: &Option<ReservePrice>) -> Result<()> {
+    let Some(reserve) = reserve else {
+        return Ok(());
+    };
+
+    require!(
+        matches!(listing_type, ListingType::Auction | ListingType::Sealed),
+        AuctionError::NotAnAuction
+    );
+    match reserve {
+        ReservePrice::Public(reserve_price) => {
+            require!(*reserve_price > price, AuctionError::InvalidReservePrice);
+        }
+        ReservePrice::Hidden(commitment) => {
+            require!(*commitment != [0u8; 32], AuctionError::InvalidReservePrice);
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_buy_now(
+    listing_type: &ListingType,
+    price: u64,
+    reserve: &Option<ReservePrice>,
+    buy_now: &Option<BuyNowConfig>,
+) -> Result<()> {
+    let Some(config) = buy_now else {
+        return Ok(());
+    };
+
+    require!(
+        matches!(listing_type, ListingType::Auction),
+        AuctionError::NotAnAuction
+    );
+    require!(config.price > price, AuctionError::InvalidBuyNowPrice);
+    if let Some(ReservePrice::Public(reserve_price)) = reserve {
+        require!(config.price >= *reserve_price, AuctionError::InvalidBuyNowPrice);
+    }
+    require!(config.cutoff_bps <= 10000, AuctionError::InvalidBuyNowPrice);
+
+    Ok(())
+}
+
+fn validate_bid_increment(listing_type: &ListingType, bid_increment: &Option<BidIncrement>) -> Result<()> {
+    let Some(increment) = bid_increment else {
+        return Ok(());
+    };
+
+    require!(
+        matches!(listing_type, ListingType::Auction),
+        AuctionError::NotAnAuction
+    );
+    match increment {
+        BidIncrement::Absolute(step) => require!(*step > 0, AuctionError::InvalidBidIncrement),
+        BidIncrement::Percentage(bps) => {
+            require!(*bps > 0 && *bps <= 10000, AuctionError::InvalidBidIncrement)
+        }
+        BidIncrement::Standard | BidIncrement::Tiered => {}
+    }
+
+    Ok(())
+}
+
+fn validate_dutch(listing_type: &ListingType, price: u64, dutch: &Option<DutchConfig>) -> Result<()> {
+    let is_dutch = matches!(listing_type, ListingType::Dutch);
+    require!(is_dutch == dutch.is_some(), AuctionError::InvalidDutchConfig);
+    let Some(config) = dutch else {
+        return Ok(());
+    };
+
+    require!(
+        config.floor_price > 0 && config.floor_price < price,
+        AuctionError::InvalidDutchConfig
+    );
+    if let DutchCurve::Stepwise { step_seconds } = config.curve {
+        require!(step_seconds > 0, AuctionError::InvalidDutchConfig);
+    }
+
+    Ok(())
+}
+
+/// Price a buyer pays right now: the Dutch interpolation, else the list price.
+fn current_listing_price(listing: &Listing, now: i64) -> Result<u64> {
+    match listing.listing_type {
+        ListingType::Dutch => dutch_price(
+            listing.price,
+            listing.dutch_floor_price,
+            listing.start_time,
+            listing.end_time,
+            listing.dutch_curve,
+            now,
+        ),
+        _ => Ok(listing.price),
+    }
+}
+
+fn validate_sealed(listing_type: &ListingType, sealed: &Option<SealedConfig>) -> Result<()> {
+    let is_sealed = matches!(listing_type, ListingType::Sealed);
+    require!(is_sealed == sealed.is_some(), AuctionError::InvalidSealedConfig);
+    if let Some(config) = sealed {
+        require!(config.reveal_seconds > 0, AuctionError::InvalidSealedConfig);
+    }
+
+    Ok(())
+}
+
+/// Copy sealed-bid settings onto a freshly initialized listing.
+/// Must run after `end_time` is set, since the reveal window follows it.
+fn apply_sealed(listing: &mut Listing, sealed: Option<SealedConfig>) -> Result<()> {
+    listing.second_bid = 0;
+    listing.sealed_bid_count = 0;
+    match sealed {
+        Some(config) => {
+            listing.sealed_pricing = config.pricing;
+            listing.forfeit_unrevealed = config.forfeit_unrevealed;
+            listing.reveal_end_time = listing
+                .end_time
+                .checked_add(config.reveal_seconds)
+                .ok_or(AuctionError::CalculationError)?;
+        }
+        None => {
+            listing.sealed_pricing = SealedPricing::FirstPrice;
+            listing.forfeit_unrevealed = false;
+            listing.reveal_end_time = 0;
+        }
+    }
+
+    Ok(())
+}
+
+/// When an auction can be settled: after the reveal window for sealed bids,
+/// else at `end_time`.
+/// An English auction can be bought outright while it runs and buy-now is
+/// still offered; the seller can't buy their own item.
+fn ensure_buy_now_auction_open(listing: &Listing, buyer: Pubkey, now: i64) -> Result<()> {
+    require!(
+        matches!(listing.listing_type, ListingType::Auction),
+        AuctionError::NotAnAuction
+    );
+    require!(
+        listing.status == ListingStatus::Active,
+        AuctionError::ListingNotActive
+    );
+    require!(now >= listing.start_time, AuctionError::ListingNotStarted);
+    require!(now < listing.end_time, AuctionError::AuctionEnded);
+    require!(
+        buy_now_available(listing.current_bid, listing.buy_now_price, listing.buy_now_cutoff_bps)?,
+        AuctionError::BuyNowUnavailable
+    );
+    require!(buyer != listing.seller, AuctionError::SellerCannotBid);
+    Ok(())
+}
+
+fn bidding_closes_at(listing: &Listing) -> i64 {
+    match listing.listing_type {
+        ListingType::Sealed => listing.reveal_end_time,
+        _ => listing.end_time,
+    }
+}
+
+/// What the auction winner pays for a settled auction.
+fn clearing_price(listing: &Listing) -> u64 {
+    match listing.listing_type {
+        ListingType::Sealed => sealed_clearing_price(
+            listing.sealed_pricing,
+            listing.current_bid,
+            listing.second_bid,
+            listing.price.max(listing.reserve_price),
+        ),
+        _ => listing.current_bid,
+    }
+}
+
+/// Fill in a freshly created `BidRefund`. An existing record must already be
+/// in the listing's currency, or credits would be paid out of the wrong escrow.
+fn init_bid_refund(
+    bid_refund: &mut BidRefund,
+    bidder: Pubkey,
+    nft_mint: Pubkey,
+    payment_mint: Pubkey,
+    native: bool,
+    listing_created_at: i64,
+    bump: u8,
+) -> Result<()> {
+    if bid_refund.bidder == Pubkey::default() {
+        bid_refund.bidder = bidder;
+        bid_refund.nft_mint = nft_mint;
+        bid_refund.payment_mint = payment_mint;
+        bid_refund.amount = 0;
+        bid_refund.bump = bump;
+        bid_refund.native = native;
+        bid_refund.listing_created_at = listing_created_at;
+    }
+    require!(
+        bid_refund.payment_mint == payment_mint
+            && bid_refund.native == native
+            && bid_refund.listing_created_at == listing_created_at,
+        AuctionError::InvalidRefundAccount
+    );
+    Ok(())
+}
+
+/// Credit escrowed funds back to a bidder. The tokens stay in `bid_escrow`
+/// until `claim_refund`, so crediting never touches the bidder's token account.
+fn credit_bid_refund(bid_refund: &mut BidRefund, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    bid_refund.amount = bid_refund
+        .amount
+        .checked_add(amount)
+        .ok_or(AuctionError::CalculationError)?;
+
+    emit!(BidRefundCredited {
+        nft_mint: bid_refund.nft_mint,
+        bidder: bid_refund.bidder,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Return `amount` of the high bidder's escrow at settlement: credited to their
+/// `BidRefund` for English auctions, or left on their `SealedBid` (which then
+/// holds exactly `amount`) for sealed auctions.
+fn credit_high_bidder(
+    listing: &Listing,
+    highest_bidder_refund: Option<&mut BidRefund>,
+    winner_sealed_bid: Option<&mut SealedBid>,
+    amount: u64,
+) -> Result<()> {
+    match listing.listing_type {
+        ListingType::Sealed => {
+            let sealed_bid = winner_sealed_bid.ok_or(AuctionError::InvalidSealedBid)?;
+            sealed_bid.escrowed = amount;
+            Ok(())
+        }
+        // A winner who paid their full escrow has nothing to credit
+        _ if amount == 0 => Ok(()),
+        _ => {
+            let bid_refund = highest_bidder_refund.ok_or(AuctionError::InvalidRefundAccount)?;
+            credit_bid_refund(bid_refund, amount)
+        }
+    }
+}
+
+/// The standing high bidder of the listing instance at `listing_info` — an SPL
+/// `Listing`, `CoreListing` or bundle PDA for `nft_mint` — or `None` once that
+/// instance is gone. A relisting at the same PDA is a different instance.
+fn live_listing_leader(
+    listing_info: &AccountInfo,
+    nft_mint: Pubkey,
+    listing_created_at: i64,
+) -> Result<Option<Pubkey>> {
+    let (listing_pda, _) = Pubkey::find_program_address(&[b"listing", nft_mint.as_ref()], &crate::ID);
+    let (core_listing_pda, _) =
+        Pubkey::find_program_address(&[b"core_listing", nft_mint.as_ref()], &crate::ID);
+    // Bundle refunds are keyed by the bundle PDA itself, which is program-owned
+    // while open and system-owned once closed; mints and Core assets never are.
+    let is_bundle = listing_info.key() == nft_mint
+        && (listing_info.owner == &crate::ID
+            || (listing_info.owner == &anchor_lang::system_program::ID && listing_info.data_is_empty()));
+    require!(
+        listing_info.key() == listing_pda || listing_info.key() == core_listing_pda || is_bundle,
+        AuctionError::Unauthorized
+    );
+    if listing_info.owner != &crate::ID || listing_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = listing_info.try_borrow_data()?;
+    if is_bundle {
+        let bundle = Bundle::try_deserialize(&mut &data[..])?;
+        Ok((bundle.created_at == listing_created_at).then_some(bundle.highest_bidder))
+    } else if listing_info.key() == listing_pda {
+        let listing = Listing::try_deserialize(&mut &data[..])?;
+        if listing.created_at != listing_created_at {
+            return Ok(None);
+        }
+        Ok(Some(if listing.status == ListingStatus::Active {
+            listing.highest_bidder
+        } else {
+            Pubkey::default()
+        }))
+    } else {
+        let listing = CoreListing::try_deserialize(&mut &data[..])?;
+        Ok((listing.created_at == listing_created_at).then_some(listing.highest_bidder))
+    }
+}
+
+fn apply_reserve(listing: &mut Listing, reserve: Option<ReservePrice>) {
+    let (reserve_price, commitment) = match reserve {
+        Some(ReservePrice::Public(reserve_price)) => (reserve_price, [0u8; 32]),
+        Some(ReservePrice::Hidden(commitment)) => (0, commitment),
+        None => (0, [0u8; 32]),
+    };
+    listing.reserve_price = reserve_price;
+    listing.reserve_commitment = commitment;
+}
+
+/// End of the window in which the seller may reveal a hidden reserve.
+fn reserve_reveal_deadline(listing: &Listing) -> Result<i64> {
+    bidding_closes_at(listing)
+        .checked_add(RESERVE_REVEAL_WINDOW_SECONDS)
+        .ok_or_else(|| error!(AuctionError::CalculationError))
+}
+
+/// Whether the current high bid clears the reserve. A hidden reserve that was
+/// never revealed counts as met once the reveal window lapses: the seller
+/// committed to it, so withholding the reveal must not void the sale.
+fn is_reserve_met(listing: &Listing, now: i64) -> Result<bool> {
+    if listing.reserve_commitment != [0u8; 32] {
+        require!(
+            now >= reserve_reveal_deadline(listing)?,
+            AuctionError::ReserveNotRevealed
+        );
+        return Ok(true);
+    }
+
+    Ok(listing.current_bid >= listing.reserve_price)
+}
+
+/// Apply an English-auction bid: proxy resolution, reserve bump, soft close
+/// and `BidPlaced` events. An outbid leader's escrow is credited to
+/// `previous_bidder_refund`. Returns what the bidder must add to escrow.
+fn apply_english_bid(
+    listing: &mut Listing,
+    bidder: Pubkey,
+    amount: u64,
+    max_amount: Option<u64>,
+    decimals: u8,
+    previous_bidder_refund: Option<&mut BidRefund>,
+    now: i64,
+) -> Result<u64> {
+    require!(
+        matches!(listing.listing_type, ListingType::Auction),
+        AuctionError::NotAnAuction
+    );
+    require!(
+        listing.status == ListingStatus::Active,
+        AuctionError::ListingNotActive
+    );
+    require!(now >= listing.start_time, AuctionError::ListingNotStarted);
+    require!(now < listing.end_time, AuctionError::AuctionEnded);
+
+    // Prevent shill bidding — seller cannot bid on own auction
+    require!(bidder != listing.seller, AuctionError::SellerCannotBid);
+
+    let max_amount = max_amount.unwrap_or(amount);
+    require!(max_amount >= amount, AuctionError::InvalidMaxBid);
+
+    let has_leader = listing.current_bid > 0 && listing.highest_bidder != Pubkey::default();
+    let mut escrow_amount = 0;
+
+    if has_leader && listing.highest_bidder == bidder {
+        // Leader raising their own maximum: escrow the difference only
+        require!(
+            max_amount > listing.highest_bidder_max,
+            AuctionError::InvalidMaxBid
+        );
+        escrow_amount = max_amount - listing.highest_bidder_max;
+        listing.highest_bidder_max = max_amount;
+    } else {
+        let min_bid = min_next_bid(
+            listing.current_bid,
+            listing.price,
+            listing.bid_increment,
+            decimals,
+        )?;
+        require_gte!(amount, min_bid, AuctionError::BidTooLow);
+
+        let outcome = resolve_proxy_bid(
+            listing.current_bid,
+            listing.highest_bidder_max,
+            amount,
+            max_amount,
+            listing.bid_increment,
+            decimals,
+        )?;
+
+        if outcome.challenger_wins {
+            // Credit the previous bidder's full escrowed maximum; funds stay in
+            // escrow until they `claim_refund`
+            if has_leader {
+                let previous_bidder_refund =
+                    previous_bidder_refund.ok_or(AuctionError::InvalidRefundAccount)?;
+                credit_bid_refund(previous_bidder_refund, listing.highest_bidder_max)?;
+            }
+
+            // Escrow the new leader's maximum
+            escrow_amount = max_amount;
+            listing.highest_bidder = bidder;
+            listing.highest_bidder_max = max_amount;
+        } else {
+            // Standing proxy defends: record the losing bid, nothing is escrowed
+            emit!(BidPlaced {
+                nft_mint: listing.nft_mint,
+                bidder,
+                amount: max_amount,
+                timestamp: now,
+                end_time: listing.end_time,
+            });
+        }
+        listing.current_bid = outcome.current_bid;
+    }
+
+    // A proxy that covers a public reserve bids straight up to it
+    if listing.reserve_price > listing.current_bid
+        && listing.highest_bidder_max >= listing.reserve_price
+    {
+        listing.current_bid = listing.reserve_price;
+    }
+
+    // Anti-sniping: bids inside the soft-close window push the end time out
+    listing.end_time = soft_close_end_time(
+        listing.end_time,
+        now,
+        listing.soft_close_window,
+        listing.soft_close_extension,
+        listing.max_end_time,
+    )?;
+
+    emit!(BidPlaced {
+        nft_mint: listing.nft_mint,
+        bidder: listing.highest_bidder,
+        amount: listing.current_bid,
+        timestamp: now,
+        end_time: listing.end_time,
+    });
+
+    Ok(escrow_amount)
+}
+
+/// Copy soft-close settings onto a freshly initialized listing.
+/// Must run after `end_time` is set, since the hard cap is relative to it.
+fn apply_soft_close(listing: &mut Listing, soft_close: Option<SoftCloseConfig>) -> Result<()> {
+    match soft_close {
+        Some(config) => {
+            listing.soft_close_window = config.window_seconds;
+            listing.soft_close_extension = config.extension_seconds;
+            listing.max_end_time = match config.max_extension_seconds {
+                Some(max_extension) => listing
+                    .end_time
+                    .checked_add(max_extension)
+                    .ok_or(AuctionError::CalculationError)?,
+                None => 0,
+            };
+        }
+        None => {
+            listing.soft_close_window = 0;
+            listing.soft_close_extension = 0;
+            listing.max_end_time = 0;
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Instructions
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(listing_type: ListingType, price: u64, duration_seconds: Option<i64>, category: ItemCategory, royalty_basis_points: u16, creator_address: Pubkey, options: ListingOptions)]
+pub struct ListItem<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Listing::INIT_SPACE,
+        seeds = [b"listing", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    #[account(
+        init_if_needed,
+        payer = seller,
+        token::mint = nft_mint,
+        token::authority = escrow_nft,
+        token::token_program = nft_token_program,
+        seeds = [b"escrow_nft", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub seller_nft_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// ============================================================================
+// pNFT Account Structs
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(listing_type: ListingType, price: u64, duration_seconds: Option<i64>, category: ItemCategory, royalty_basis_points: u16, creator_address: Pubkey, options: ListingOptions)]
+pub struct ListItemPnft<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Listing::INIT_SPACE,
+        seeds = [b"listing", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// CHECK: Mint — verified by Token Metadata CPI program constraints
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Seller NFT token account — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Seller token record PDA — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_token_record: UncheckedAccount<'info>,
+
+    /// Escrow authority PDA — owns the escrow token account
+    #[account(
+        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: SystemAccount<'info>,
+
+    /// Escrow token account — created by Token Metadata via ATA
+    #[account(mut)]
+    pub escrow_nft_token: UncheckedAccount<'info>,
+
+    /// Escrow token record (pNFT programmable config)
+    #[account(mut)]
+    pub escrow_token_record: UncheckedAccount<'info>,
+
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: SPL Associated Token Account program
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional Metaplex authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional Metaplex authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelListingPnft<'info> {
+    #[account(mut, seeds = [b"listing", nft_mint.key().as_ref()], bump = listing.bump)]
+    pub listing: Account<'info, Listing>,
+
+    /// CHECK: Must match listing.nft_mint — enforced by PDA seed constraint above
+    #[account(constraint = nft_mint.key() == listing.nft_mint @ AuctionError::Unauthorized)]
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    /// Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: SystemAccount<'info>,
+
+    /// CHECK: Escrow token account — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Seller NFT token account — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Seller token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_token_record: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: SPL ATA program
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredListingPnft<'info> {
+    #[account(mut, seeds = [b"listing", nft_mint.key().as_ref()], bump = listing.bump)]
+    pub listing: Account<'info, Listing>,
+
+    /// CHECK: Must match listing.nft_mint — enforced by PDA seed constraint above
+    #[account(constraint = nft_mint.key() == listing.nft_mint @ AuctionError::Unauthorized)]
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    /// Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: SystemAccount<'info>,
+
+    /// CHECK: Escrow token account — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Seller NFT token account — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Seller token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Original seller — validated against listing.seller; receives the pNFT and rent.
+    #[account(mut, address = listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Pays for the seller's token record if it must be created
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: SPL ATA program
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowPnft<'info> {
+    #[account(mut, seeds = [b"listing", nft_mint.key().as_ref()], bump = listing.bump)]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// CHECK: Must match listing.nft_mint — enforced by PDA seed constraint above
+    #[account(constraint = nft_mint.key() == listing.nft_mint @ AuctionError::Unauthorized)]
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    /// Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: SystemAccount<'info>,
+
+    /// CHECK: Escrow token account — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer NFT token account — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub buyer_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer token record — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub buyer_token_record: UncheckedAccount<'info>,
+
+    /// Payment mint — must match the listing's payment mint
+    #[account(address = listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(mut)]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — must be owned by listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Treasury wallet for rent collection. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Token program for the payment legs (SPL Token or Token-2022)
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: SPL ATA program
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct SettleAuctionPnft<'info> {
+    #[account(mut, seeds = [b"listing", nft_mint.key().as_ref()], bump = listing.bump)]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// CHECK: Must match listing.nft_mint — enforced by PDA seed constraint above
+    #[account(constraint = nft_mint.key() == listing.nft_mint @ AuctionError::Unauthorized)]
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    /// Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: SystemAccount<'info>,
+
+    /// CHECK: Escrow token account — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub escrow_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Winner on a sale, seller otherwise — validated in instruction body
+    pub destination_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Destination NFT token account — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub destination_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Destination token record — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// Payment mint — must match the listing's payment mint
+    #[account(address = listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [
+            b"bid_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump,
+        token::mint = listing.payment_mint,
+    )]
+    pub bid_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller's payment token account — must be owned by listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body if royalty > 0
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+    /// High bidder's refund record — required for English auctions with bids
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = highest_bidder_refund.bump,
+        constraint = highest_bidder_refund.payment_mint == listing.payment_mint
+            && highest_bidder_refund.native == listing.native_sol
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub highest_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+    /// Winner's sealed bid record — required for sealed auctions with a winner
+    #[account(
+        mut,
+        seeds = [
+            b"sealed_bid",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = winner_sealed_bid.bump,
+    )]
+    pub winner_sealed_bid: Option<Box<Account<'info, SealedBid>>>,
+
+    /// CHECK: The original seller, validated against listing.seller.
+    #[account(mut, constraint = seller.key() == listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: Treasury wallet for rent collection on sales. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// Pays for the destination token account / token record if missing
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Token program for the payment legs (SPL Token or Token-2022)
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: SPL ATA program
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub listing: Account<'info, Listing>,
+    /// Payment mint — must match the listing's payment mint
+    #[account(constraint = payment_mint.key() == listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        token::mint = payment_mint,
+        token::authority = bid_escrow,
+        token::token_program = token_program,
+        seeds = [
+            b"bid_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump,
+    )]
+    pub bid_escrow: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_token_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// Bidder's own refund record — must exist while they lead so an outbid
+    /// can always be credited
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BidRefund::INIT_SPACE,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            bidder.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bidder_refund: Box<Account<'info, BidRefund>>,
+    /// Previous high bidder's refund record — required when outbidding them
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = previous_bidder_refund.bump,
+        constraint = previous_bidder_refund.payment_mint == listing.payment_mint
+            && previous_bidder_refund.native == listing.native_sol
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub previous_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceBidSol<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + SolEscrow::INIT_SPACE,
+        seeds = [
+            b"sol_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump,
+    )]
+    pub sol_escrow: Box<Account<'info, SolEscrow>>,
+    /// Bidder's own refund record (see `PlaceBid`)
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BidRefund::INIT_SPACE,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            bidder.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bidder_refund: Box<Account<'info, BidRefund>>,
+    /// Previous high bidder's refund record — required when outbidding them
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = previous_bidder_refund.bump,
+        constraint = previous_bidder_refund.payment_mint == listing.payment_mint
+            && previous_bidder_refund.native == listing.native_sol
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub previous_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitSealedBid<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    /// Payment mint — must match the listing's payment mint
+    #[account(constraint = payment_mint.key() == listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        token::mint = payment_mint,
+        token::authority = bid_escrow,
+        token::token_program = token_program,
+        seeds = [
+            b"bid_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump,
+    )]
+    pub bid_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + SealedBid::INIT_SPACE,
+        seeds = [
+            b"sealed_bid",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            bidder.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub sealed_bid: Box<Account<'info, SealedBid>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_token_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealSealedBid<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    /// Payment mint — must match the listing's payment mint
+    #[account(address = listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [
+            b"bid_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump,
+        token::mint = listing.payment_mint,
+    )]
+    pub bid_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            b"sealed_bid",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            bidder.key().as_ref(),
+        ],
+        bump = sealed_bid.bump,
+    )]
+    pub sealed_bid: Box<Account<'info, SealedBid>>,
+    #[account(
+        mut,
+        token::mint = listing.payment_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_token_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    pub bidder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSealedBid<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"sealed_bid",
+            sealed_bid.nft_mint.as_ref(),
+            &listing_instance_seed(sealed_bid.listing_created_at),
+            bidder.key().as_ref(),
+        ],
+        bump = sealed_bid.bump,
+        close = bidder,
+    )]
+    pub sealed_bid: Box<Account<'info, SealedBid>>,
+    /// CHECK: Listing PDA for the auction; may already be closed after settlement.
+    #[account(seeds = [b"listing", sealed_bid.nft_mint.as_ref()], bump)]
+    pub listing: UncheckedAccount<'info>,
+    #[account(address = sealed_bid.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [
+            b"bid_escrow",
+            sealed_bid.nft_mint.as_ref(),
+            &listing_instance_seed(sealed_bid.listing_created_at),
+        ],
+        bump,
+        token::mint = sealed_bid.payment_mint,
+    )]
+    pub bid_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(
+        mut,
+        token::mint = sealed_bid.payment_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — required only when an unrevealed deposit is forfeited
+    #[account(mut, token::mint = sealed_bid.payment_mint)]
+    pub seller_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            bid_refund.nft_mint.as_ref(),
+            &listing_instance_seed(bid_refund.listing_created_at),
+            bidder.key().as_ref(),
+        ],
+        bump = bid_refund.bump,
+    )]
+    pub bid_refund: Box<Account<'info, BidRefund>>,
+    /// CHECK: Listing, CoreListing or Bundle PDA for the item; may already be closed.
+    /// Validated in instruction body.
+    pub listing: UncheckedAccount<'info>,
+    /// Payment mint — required for token refunds
+    #[account(address = bid_refund.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
+    /// Bid escrow — required while a token refund holds funds
+    #[account(
+        mut,
+        seeds = [
+            b"bid_escrow",
+            bid_refund.nft_mint.as_ref(),
+            &listing_instance_seed(bid_refund.listing_created_at),
+        ],
+        bump,
+        token::mint = bid_refund.payment_mint,
+    )]
+    pub bid_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    /// Lamport escrow — required for native-SOL refunds
+    #[account(
+        mut,
+        seeds = [
+            b"sol_escrow",
+            bid_refund.nft_mint.as_ref(),
+            &listing_instance_seed(bid_refund.listing_created_at),
+        ],
+        bump = sol_escrow.bump,
+    )]
+    pub sol_escrow: Option<Box<Account<'info, SolEscrow>>>,
+    /// Destination for token refunds; unused for native-SOL refunds
+    #[account(
+        mut,
+        token::mint = bid_refund.payment_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RevealReserve<'info> {
+    #[account(mut, has_one = seller @ AuctionError::Unauthorized)]
+    pub listing: Account<'info, Listing>,
+    pub seller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNow<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
+    /// Payment mint — must match the listing's payment mint
+    #[account(address = listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub buyer_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// Seller payment account — must be owned by listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// CHECK: Creator payment account — validated in instruction body
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub buyer_nft_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Treasury wallet for rent collection. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowSol<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Seller wallet — receives lamports, validated against listing.seller
+    #[account(mut, address = listing.seller @ AuctionError::Unauthorized)]
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: Creator wallet — validated in instruction body
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Treasury wallet — receives fees and rent. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowAuction<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Payment mint — must match the listing's payment mint
+    #[account(address = listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Bid escrow — if present and empty, closed to the treasury
+    #[account(
+        mut,
+        seeds = [
+            b"bid_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump,
+        token::mint = listing.payment_mint,
+    )]
+    pub bid_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    /// High bidder's refund record — required only when there is a standing bid
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = highest_bidder_refund.bump,
+        constraint = highest_bidder_refund.payment_mint == listing.payment_mint
+            && highest_bidder_refund.native == listing.native_sol
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub highest_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+    #[account(mut)]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — must be owned by listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Treasury wallet for rent collection. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowAuctionSol<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// High bidder's refund record — required only when there is a standing bid
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = highest_bidder_refund.bump,
+        constraint = highest_bidder_refund.payment_mint == listing.payment_mint
+            && highest_bidder_refund.native == listing.native_sol
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub highest_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+    /// CHECK: Seller wallet — receives lamports, validated against listing.seller
+    #[account(mut, address = listing.seller @ AuctionError::Unauthorized)]
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: Creator wallet — validated in instruction body
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Treasury wallet — receives fees and rent. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateListing<'info> {
+    #[account(mut, seeds = [b"listing", listing.nft_mint.as_ref()], bump = listing.bump)]
+    pub listing: Box<Account<'info, Listing>>,
+    /// Registry entry for the listing's payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", listing.payment_mint.as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    pub seller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredListing<'info> {
+    #[account(mut, seeds = [b"listing", nft_mint.key().as_ref()], bump = listing.bump)]
+    pub listing: Box<Account<'info, Listing>>,
+    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// Seller's NFT account — receives the returned NFT
+    #[account(
+        mut,
+        token::mint = listing.nft_mint,
+        token::authority = listing.seller,
+        token::token_program = nft_token_program,
+    )]
+    pub seller_nft_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// CHECK: Original seller — validated against listing.seller; receives rent.
+    #[account(mut, address = listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    #[account(mut)]
+    pub listing: Account<'info, Listing>,
+    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub seller_nft_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    pub seller: Signer<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CloseStaleListing<'info> {
+    #[account(mut)]
+    pub listing: Account<'info, Listing>,
+    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Escrowless fixed-price listing of an SPL / Token-2022 NFT or pNFT; the PDA
+/// is the token delegate of `seller_nft_account`.
+#[account]
+#[derive(InitSpace)]
+pub struct DelegateListing {
+    pub seller: Pubkey,
+    pub nft_mint: Pubkey,
+    pub seller_nft_account: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub category: ItemCategory,
+    pub start_time: i64,
+    /// 0 = no expiry
+    pub end_time: i64,
+    pub is_token2022: bool,
+    /// Sale-delegated pNFT (see `list_item_pnft_delegated`)
+    pub is_pnft: bool,
+    pub royalty_basis_points: u16,
+    pub creator_address: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct ListItemDelegated<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + DelegateListing::INIT_SPACE,
+        seeds = [b"delegate_listing", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub delegate_listing: Box<Account<'info, DelegateListing>>,
+    #[account(mint::token_program = nft_token_program)]
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = seller,
+        token::token_program = nft_token_program,
+        constraint = seller_nft_account.amount == 1 @ AuctionError::Unauthorized,
+    )]
+    pub seller_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowDelegated<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegate_listing", delegate_listing.nft_mint.as_ref()],
+        bump = delegate_listing.bump,
+        close = seller,
+    )]
+    pub delegate_listing: Box<Account<'info, DelegateListing>>,
+    /// CHECK: Original seller — receives PDA rent on close.
+    #[account(mut, address = delegate_listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    #[account(address = delegate_listing.nft_mint)]
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// CHECK: Seller's token account — validated in handler (delegation and balance).
+    #[account(mut, address = delegate_listing.seller_nft_account)]
+    pub seller_nft_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::mint = delegate_listing.nft_mint,
+        token::authority = buyer,
+        token::token_program = nft_token_program,
+    )]
+    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(address = delegate_listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(mut)]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — must be owned by delegate_listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == delegate_listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Treasury wallet. Validated in instruction body.
+    pub treasury: UncheckedAccount<'info>,
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CancelDelegatedListing<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegate_listing", delegate_listing.nft_mint.as_ref()],
+        bump = delegate_listing.bump,
+        close = seller,
+    )]
+    pub delegate_listing: Box<Account<'info, DelegateListing>>,
+    /// CHECK: Seller's token account — may already be emptied or closed.
+    #[account(mut, address = delegate_listing.seller_nft_account)]
+    pub seller_nft_account: UncheckedAccount<'info>,
+    #[account(mut, address = delegate_listing.seller @ AuctionError::Unauthorized)]
+    pub seller: Signer<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CloseStaleDelegatedListing<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegate_listing", delegate_listing.nft_mint.as_ref()],
+        bump = delegate_listing.bump,
+        close = seller,
+    )]
+    pub delegate_listing: Box<Account<'info, DelegateListing>>,
+    /// CHECK: Seller's token account — may already be emptied or closed.
+    #[account(address = delegate_listing.seller_nft_account)]
+    pub seller_nft_account: UncheckedAccount<'info>,
+    /// CHECK: Original seller — receives PDA rent on close.
+    #[account(mut, address = delegate_listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ListItemPnftDelegated<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + DelegateListing::INIT_SPACE,
+        seeds = [b"delegate_listing", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub delegate_listing: Box<Account<'info, DelegateListing>>,
+
+    /// CHECK: Mint — verified by Token Metadata CPI program constraints
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = seller,
+        constraint = seller_nft_token.amount == 1 @ AuctionError::Unauthorized,
+    )]
+    pub seller_nft_token: Box<Account<'info, anchor_spl::token::TokenAccount>>,
+
+    /// CHECK: Seller token record PDA — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_token_record: UncheckedAccount<'info>,
+
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional Metaplex authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional Metaplex authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowPnftDelegated<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegate_listing", delegate_listing.nft_mint.as_ref()],
+        bump = delegate_listing.bump,
+        close = seller,
+    )]
+    pub delegate_listing: Box<Account<'info, DelegateListing>>,
+
+    /// CHECK: Original seller — token owner for the transfer; receives PDA rent on close.
+    #[account(mut, address = delegate_listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Must match delegate_listing.nft_mint
+    #[account(address = delegate_listing.nft_mint)]
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Seller NFT token account — validated in handler (delegation and balance).
+    #[account(mut, address = delegate_listing.seller_nft_account)]
+    pub seller_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Seller token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer NFT token account — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub buyer_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer token record — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub buyer_token_record: UncheckedAccount<'info>,
+
+    /// Payment mint — must match the listing's payment mint
+    #[account(address = delegate_listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(mut)]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — must be owned by delegate_listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == delegate_listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Treasury wallet. Validated in instruction body.
+    pub treasury: UncheckedAccount<'info>,
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Token program for the payment legs (SPL Token or Token-2022)
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: SPL ATA program
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelDelegatedListingPnft<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegate_listing", delegate_listing.nft_mint.as_ref()],
+        bump = delegate_listing.bump,
+        close = seller,
+    )]
+    pub delegate_listing: Box<Account<'info, DelegateListing>>,
+
+    /// CHECK: Must match delegate_listing.nft_mint
+    #[account(address = delegate_listing.nft_mint)]
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Seller NFT token account — may already be emptied or closed.
+    #[account(mut, address = delegate_listing.seller_nft_account)]
+    pub seller_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Seller token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub seller_token_record: UncheckedAccount<'info>,
+
+    #[account(mut, address = delegate_listing.seller @ AuctionError::Unauthorized)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Payment mint — must match the listing's payment mint
+    #[account(address = listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        seeds = [
+            b"bid_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump,
+        token::mint = listing.payment_mint,
+    )]
+    pub bid_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller's payment token account — must be owned by listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body if royalty > 0
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+    /// Buyer NFT account — must be owned by highest bidder (or seller if no bids for return)
+    #[account(mut)]
+    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller NFT account — must be owned by listing.seller (for no-bid return)
+    #[account(mut)]
+    pub seller_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// High bidder's refund record — required for English auctions with bids
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = highest_bidder_refund.bump,
+        constraint = highest_bidder_refund.payment_mint == listing.payment_mint
+            && highest_bidder_refund.native == listing.native_sol
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub highest_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+    /// Winner's sealed bid record — required for sealed auctions with a winner
+    #[account(
+        mut,
+        seeds = [
+            b"sealed_bid",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = winner_sealed_bid.bump,
+    )]
+    pub winner_sealed_bid: Option<Box<Account<'info, SealedBid>>>,
+    /// CHECK: The original seller, validated against listing.seller.
+    #[account(mut, constraint = seller.key() == listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: Treasury wallet for rent collection on sales. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleAuctionSol<'info> {
+    #[account(mut)]
+    pub listing: Box<Account<'info, Listing>>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Lamport bid escrow — absent when the auction drew no bids
+    #[account(
+        mut,
+        seeds = [
+            b"sol_escrow",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+        ],
+        bump = sol_escrow.bump,
+    )]
+    pub sol_escrow: Option<Box<Account<'info, SolEscrow>>>,
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
+        bump,
+        token::mint = listing.nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator wallet — validated in instruction body if royalty > 0
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    /// Buyer NFT account — must be owned by highest bidder
+    #[account(mut)]
+    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller NFT account — must be owned by listing.seller (for no-bid return)
+    #[account(mut)]
+    pub seller_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// High bidder's refund record — required when there are bids
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            listing.nft_mint.as_ref(),
+            &listing_instance_seed(listing.created_at),
+            listing.highest_bidder.as_ref(),
+        ],
+        bump = highest_bidder_refund.bump,
+        constraint = highest_bidder_refund.payment_mint == listing.payment_mint
+            && highest_bidder_refund.native == listing.native_sol
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub highest_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+    /// CHECK: The original seller — receives sale proceeds, validated against listing.seller.
+    #[account(mut, constraint = seller.key() == listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: Treasury wallet — receives fees and rent on sales. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    /// Approves a WNS sale, funded from sol_escrow — required for WNS NFTs sold
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// State
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ListingType {
+    FixedPrice,
+    Auction,
+    /// Descending-price auction: first buyer takes it at the current price
+    Dutch,
+    /// Commit-reveal sealed-bid auction
+    Sealed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ItemCategory {
+    DigitalArt,
+    Spirits,
+    TCGCards,
+    SportsCards,
+    Watches,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ListingStatus {
+    Active,
+    Settled,
+    Cancelled,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Listing {
+    pub seller: Pubkey,
+    pub nft_mint: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub listing_type: ListingType,
+    pub category: ItemCategory,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub status: ListingStatus,
+    pub escrow_nft_account: Pubkey,
+    pub current_bid: u64,
+    pub highest_bidder: Pubkey,
+    pub baxus_fee: bool,
+    pub is_token2022: bool,
+    pub is_pnft: bool,
+    pub royalty_basis_points: u16,
+    pub creator_address: Pubkey,
+    pub bump: u8,
+    /// Seconds before `end_time` in which a bid extends the auction (0 = off)
+    pub soft_close_window: i64,
+    /// Seconds added past the bid time when soft-close triggers
+    pub soft_close_extension: i64,
+    /// Hard cap on `end_time` after extensions (0 = uncapped)
+    pub max_end_time: i64,
+    /// Minimum winning bid; 0 = no reserve beyond the opening `price`
+    pub reserve_price: u64,
+    /// sha256 commitment of a hidden reserve, zeroed once revealed
+    pub reserve_commitment: [u8; 32],
+    /// Price at which a buyer can end the auction early (0 = not offered)
+    pub buy_now_price: u64,
+    /// Buy-now is withdrawn once the high bid passes this share of `buy_now_price`
+    pub buy_now_cutoff_bps: u16,
+    /// How far each new bid must exceed `current_bid`
+    pub bid_increment: BidIncrement,
+    /// Dutch listings: price reached at `end_time` (`price` is the start price)
+    pub dutch_floor_price: u64,
+    pub dutch_curve: DutchCurve,
+    /// Leader's proxy maximum — the amount actually held in `bid_escrow`
+    pub highest_bidder_max: u64,
+    /// Sealed auctions: pricing rule, reveal deadline and bid bookkeeping
+    pub sealed_pricing: SealedPricing,
+    pub reveal_end_time: i64,
+    pub second_bid: u64,
+    pub sealed_bid_count: u32,
+    pub forfeit_unrevealed: bool,
+    /// SOL English auctions escrow bids as lamports in `sol_escrow` and must use
+    /// the `_sol` instructions
+    pub native_sol: bool,
+    /// Identifies this listing instance; scopes its bid escrows and refund
+    /// records. Zero for listings migrated from the legacy layout.
+    pub created_at: i64,
+}
+
+/// Optional settings for `list_item` / `list_item_pnft`; `None` leaves a
+/// setting off.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ListingOptions {
+    /// Auctions only: extends `end_time` when bids land near the close.
+    pub soft_close: Option<SoftCloseConfig>,
+    /// Auctions only: a sale floor above the opening `price`, either public
+    /// or hidden behind a sha256 commitment.
+    pub reserve: Option<ReservePrice>,
+    /// Auctions only: lets a buyer end the auction early at a fixed price.
+    pub buy_now: Option<BuyNowConfig>,
+    /// Auctions only: defaults to the payment mint's configured increment.
+    pub bid_increment: Option<BidIncrement>,
+    /// Schedules the listing to open later; the NFT is escrowed now.
+    pub start_time: Option<i64>,
+    /// Dutch listings only: the floor and decay curve; `price` is the starting
+    /// price and `duration_seconds` the time taken to decay to the floor.
+    pub dutch: Option<DutchConfig>,
+    /// Sealed-bid listings only: the pricing rule and reveal window;
+    /// `duration_seconds` is then the commit phase.
+    pub sealed: Option<SealedConfig>,
+}
+
+/// Sealed-bid auction settings, supplied at listing time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct SealedConfig {
+    pub pricing: SealedPricing,
+    /// Length of the reveal window after the commit phase ends
+    pub reveal_seconds: i64,
+    /// Unrevealed deposits go to the seller instead of back to the bidder
+    pub forfeit_unrevealed: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SealedPricing {
+    /// Winner pays their own bid
+    FirstPrice,
+    /// Winner pays the runner-up's bid (Vickrey)
+    SecondPrice,
+}
+
+/// Funds owed back to a bidder on one auction (SPL or Core), held in that
+/// auction's `bid_escrow` until claimed. Outlives the listing.
+#[account]
+#[derive(InitSpace)]
+pub struct BidRefund {
+    pub bidder: Pubkey,
+    /// NFT mint, or Core asset, the auction is keyed by
+    pub nft_mint: Pubkey,
+    pub payment_mint: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+    /// Held as lamports in `sol_escrow` rather than tokens in `bid_escrow`
+    pub native: bool,
+    /// `created_at` of the listing instance whose escrow holds the funds
+    pub listing_created_at: i64,
+}
+
+/// Lamport escrow for native-SOL auction bids. Program-owned so the program
+/// can debit it directly; its rent-exempt reserve is never paid out as bids.
+#[account]
+#[derive(InitSpace)]
+pub struct SolEscrow {
+    pub nft_mint: Pubkey,
+    pub bump: u8,
+}
+
+/// One bidder's sealed bid on one listing instance. Outlives the listing so
+/// losing and unrevealed bids can be reclaimed after settlement.
+#[account]
+#[derive(InitSpace)]
+pub struct SealedBid {
+    pub nft_mint: Pubkey,
+    pub bidder: Pubkey,
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
+    pub commitment: [u8; 32],
+    /// Payment tokens held in `bid_escrow` for this bid
+    pub escrowed: u64,
+    pub revealed: bool,
+    pub amount: u64,
+    pub reveal_end_time: i64,
+    pub forfeit_unrevealed: bool,
+    pub bump: u8,
+    /// `created_at` of the listing instance the bid was made on; scopes this
+    /// record and the escrow holding its deposit
+    pub listing_created_at: i64,
+}
+
+/// Dutch auction settings, supplied at listing time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct DutchConfig {
+    pub floor_price: u64,
+    pub curve: DutchCurve,
+}
+
+/// How a Dutch listing's price decays between start and end time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DutchCurve {
+    /// Continuous straight-line decay
+    Linear,
+    /// Straight-line decay sampled every `step_seconds`
+    Stepwise { step_seconds: i64 },
+}
+
+/// Minimum raise rule for English auctions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BidIncrement {
+    /// 0.1 whole payment tokens, scaled by the mint's decimals
+    Standard,
+    /// Fixed step in payment-token base units
+    Absolute(u64),
+    /// Percentage of the current bid, in basis points
+    Percentage(u16),
+    /// Auction-house ladder that widens the step as the price climbs
+    Tiered,
+}
+
+/// Buy-it-now settings for auctions, supplied at listing time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct BuyNowConfig {
+    pub price: u64,
+    /// Share of `price` (in bps) the high bid may reach before buy-now is withdrawn
+    pub cutoff_bps: u16,
+}
+
+/// Auction reserve, supplied at listing time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ReservePrice {
+    /// Reserve visible on-chain
+    Public(u64),
+    /// sha256(reserve_price_le || salt); revealed later via `reveal_reserve`
+    Hidden([u8; 32]),
+}
+
+/// Anti-sniping settings for English auctions, supplied at listing time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct SoftCloseConfig {
+    pub window_seconds: i64,
+    pub extension_seconds: i64,
+    /// Maximum total extension past the original end time (None = uncapped)
+    pub max_extension_seconds: Option<i64>,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct ListingCreated {
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+    pub listing_type: ListingType,
+    pub price: u64,
+    pub category: ItemCategory,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub payment_mint: Pubkey,
+}
+
+#[event]
+pub struct BidPlaced {
+    pub nft_mint: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    /// Auction end time after any soft-close extension
+    pub end_time: i64,
+}
+
+#[event]
+pub struct ItemPurchased {
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub platform_fee: u64,
+    /// Amounts each party received, net of any payment-mint transfer fee
+    pub seller_amount: u64,
+    pub treasury_amount: u64,
+    pub creator_royalty: u64,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub nft_mint: Pubkey,
+    pub winner: Pubkey,
+    pub price: u64,
+    pub platform_fee: u64,
+    /// Amounts each party received, net of any payment-mint transfer fee
+    pub seller_amount: u64,
+    pub treasury_amount: u64,
+    pub creator_royalty: u64,
+}
+
+#[event]
+pub struct ListingCancelled {
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct ListingExpired {
+    /// NFT mint, or Core asset for `close_expired_core_listing`
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct ListingUpdated {
+    /// NFT mint, or Core asset for `update_core_listing`
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    /// Auction close, or fixed-price expiry (0 = none)
+    pub end_time: i64,
+}
+
+#[event]
+pub struct AuctionCancelled {
+    pub nft_mint: Pubkey,
+    pub reason: String,
+}
+
+#[event]
+pub struct BidRefundCredited {
+    pub nft_mint: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub nft_mint: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SealedBidCommitted {
+    pub nft_mint: Pubkey,
+    pub bidder: Pubkey,
+    pub deposit: u64,
+}
+
+#[event]
+pub struct SealedBidRevealed {
+    pub nft_mint: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SealedBidClaimed {
+    pub nft_mint: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub forfeited: bool,
+}
+
+#[event]
+pub struct ReserveRevealed {
+    pub nft_mint: Pubkey,
+    pub reserve_price: u64,
+}
+
+// ============================================================================
+// Treasury Config
+// ============================================================================
+
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryConfig {
+    pub treasury: Pubkey,
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TreasuryConfig::INIT_SPACE,
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Account<'info, TreasuryConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury_config"],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Account<'info, TreasuryConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Accepted payment mint. Listings may only use a mint whose config is
+/// enabled and allows the item's category (or Core, for Core listings).
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentMintConfig {
+    pub mint: Pubkey,
+    pub enabled: bool,
+    /// Bitmask of allowed `ItemCategory` values, one bit per variant
+    pub allowed_categories: u8,
+    pub allow_core: bool,
+    /// Lowest price a listing may sell for, in payment-token base units
+    pub min_price: u64,
+    /// Used by auctions that don't choose their own increment
+    pub bid_increment: BidIncrement,
+    pub bump: u8,
+}
+
+/// Admin-supplied settings for a `PaymentMintConfig`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct PaymentMintSettings {
+    pub enabled: bool,
+    pub categories: Vec<ItemCategory>,
+    pub allow_core: bool,
+    pub min_price: u64,
+    pub bid_increment: BidIncrement,
+}
+
+#[derive(Accounts)]
+pub struct InitializePaymentMintConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PaymentMintConfig::INIT_SPACE,
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump,
+    )]
+    pub payment_mint_config: Account<'info, PaymentMintConfig>,
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePaymentMintConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"payment_mint_config", payment_mint_config.mint.as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Account<'info, PaymentMintConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateListing<'info> {
+    /// CHECK: Legacy Listing or CoreListing — owner, discriminator and length
+    /// validated in instruction body.
+    #[account(mut)]
+    pub listing: UncheckedAccount<'info>,
+    /// CHECK: `migrate_listing` only, for a listing with bids: the standing
+    /// high bidder's instance-0 `BidRefund` PDA, created in instruction body.
+    #[account(mut)]
+    pub leader_refund: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PaymentMintConfigUpdated {
+    pub mint: Pubkey,
+    pub enabled: bool,
+    pub allowed_categories: u8,
+    pub allow_core: bool,
+    pub min_price: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[error_code]
+pub enum AuctionError {
+    #[msg("Listing is not active")]
+    ListingNotActive,
+    #[msg("Auction has already ended")]
+    AuctionEnded,
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+    #[msg("Bid is below the required minimum")]
+    BidTooLow,
+    #[msg("Calculation error")]
+    CalculationError,
+    #[msg("Unauthorized")]
+    Unauthorized,
+    #[msg("Cannot cancel auction with existing bids")]
+    CannotCancelWithBids,
+    #[msg("Invalid listing type for this operation")]
+    NotAnAuction,
+    #[msg("Invalid listing type for this operation")]
+    NotFixedPrice,
+    #[msg("Invalid duration for auction")]
+    InvalidDuration,
+    #[msg("Invalid payment mint for this category")]
+    InvalidPaymentMint,
+    #[msg("Price must be greater than zero")]
+    InvalidPrice,
+    #[msg("Token-2022 transfer with hook failed")]
+    TransferFailed,
+    #[msg("Seller cannot bid on their own auction")]
+    SellerCannotBid,
+    #[msg("Invalid refund account — must be previous bidder's ATA")]
+    InvalidRefundAccount,
+    #[msg("Royalty basis points too high (max 1000 = 10%)")]
+    RoyaltyTooHigh,
+    #[msg("Invalid buyer account — must be owned by highest bidder")]
+    InvalidBuyerAccount,
+    #[msg("Invalid creator account — must be creator's ATA for payment mint")]
+    InvalidCreatorAccount,
+    #[msg("Invalid token program — must be SPL Token or Token-2022")]
+    InvalidTokenProgram,
+    #[msg("Invalid royalty basis points — must be 0 or >= 100 (1%)")]
+    InvalidRoyaltyBps,
+    #[msg("Invalid Metaplex Core plugin state")]
+    InvalidCorePluginState,
+    #[msg("Core listing is stale because the seller no longer owns the asset")]
+    StaleCoreListing,
+    #[msg("Core listing is not stale")]
+    CoreListingNotStale,
+    #[msg("Invalid soft-close settings — window and extension must be positive")]
+    InvalidSoftClose,
+    #[msg("Invalid reserve price — must exceed the opening price")]
+    InvalidReservePrice,
+    #[msg("Listing has no hidden reserve to reveal")]
+    NoHiddenReserve,
+    #[msg("Reserve reveal does not match the committed hash")]
+    InvalidReserveReveal,
+    #[msg("Hidden reserve has not been revealed yet")]
+    ReserveNotRevealed,
+    #[msg("Invalid buy-now price — must exceed the opening price and reserve")]
+    InvalidBuyNowPrice,
+    #[msg("Buy-now is not available on this auction")]
+    BuyNowUnavailable,
+    #[msg("Invalid bid increment — step must be positive and percentages at most 100%")]
+    InvalidBidIncrement,
+    #[msg("Scheduled start time must not be in the past")]
+    InvalidStartTime,
+    #[msg("Listing has not started yet")]
+    ListingNotStarted,
+    #[msg("Invalid Dutch auction settings — floor must be positive and below the start price")]
+    InvalidDutchConfig,
+    #[msg("Invalid maximum bid — must be at least the bid and above any existing maximum")]
+    InvalidMaxBid,
+    #[msg("Invalid sealed auction settings — reveal window must be positive")]
+    InvalidSealedConfig,
+    #[msg("Listing is not a sealed-bid auction")]
+    NotSealedAuction,
+    #[msg("Sealed bid does not match its commitment or was already revealed")]
+    InvalidSealedBid,
+    #[msg("Outside the sealed-bid reveal window")]
+    NotInRevealWindow,
+    #[msg("Winning sealed bid is locked until the auction is settled")]
+    WinningBidLocked,
+    #[msg("Price is below the payment mint's minimum")]
+    PriceBelowMinimum,
+    #[msg("Offer expiry must be in the future")]
+    InvalidOfferExpiry,
+    #[msg("Offer has expired")]
+    OfferExpired,
+    #[msg("Offer has not expired yet")]
+    OfferNotExpired,
+    #[msg("Offers can only be made on SPL / Token-2022 mints or Core assets, and accepted with the matching accounts")]
+    InvalidOfferAsset,
+    #[msg("Collection offer quantity must be positive")]
+    InvalidOfferQuantity,
+    #[msg("Collection offer is fully filled")]
+    OfferFilled,
+    #[msg("Attribute predicates must be unique, non-empty and within size limits")]
+    InvalidAttributePredicate,
+    #[msg("Asset attributes do not match the offer")]
+    AttributeMismatch,
+    #[msg("Cannot reprice an auction with bids")]
+    CannotUpdateWithBids,
+    #[msg("Listing has expired")]
+    ListingExpired,
+    #[msg("Listing has not expired")]
+    ListingNotExpired,
+    #[msg("Bundle is already open, full, empty, or already holds this item")]
+    InvalidBundle,
+    #[msg("Bundle weights must be all zero or all positive")]
+    InvalidBundleWeights,
+    #[msg("Bundle item accounts do not match the bundle")]
+    InvalidBundleAccounts,
+    #[msg("Core asset is already listed, bundled or offered in a swap")]
+    CoreAssetCommitted,
+    #[msg("Swap must want another asset or collection, with attributes only for collections and a future expiry")]
+    InvalidSwapTerms,
+    #[msg("Swap accounts do not match the swap")]
+    InvalidSwapAccounts,
+    #[msg("Asset does not satisfy the swap")]
+    SwapMismatch,
+    #[msg("Listing is stale because the seller's token account no longer holds the NFT under its delegation")]
+    StaleListing,
+    #[msg("Listing is not stale")]
+    ListingNotStale,
+    #[msg("Merkle proof does not verify the compressed NFT leaf")]
+    InvalidCompressedProof,
+    #[msg("Transfer-hook program missing from remaining_accounts")]
+    MissingTransferHookProgram,
+    #[msg("Transfer-hook extra-account-metas PDA missing from remaining_accounts")]
+    MissingExtraMetasAccount,
+    #[msg("remaining_accounts lack accounts listed by the transfer hook's extra-account-metas")]
+    InvalidTransferHookAccounts,
+    #[msg("WNS remaining_accounts must be: wns_program, extra_metas, approve_account, distribution_program, distribution_account, distribution_token_account, system_program, member, group")]
+    InvalidWnsAccountLayout,
+    #[msg("WNS approve_transfer failed")]
+    WnsApproveFailed,
+    #[msg("Seller bond accounts are missing or invalid")]
+    InvalidSellerBond,
+    #[msg("Account is not a listing in the legacy layout")]
+    NotLegacyListing,
+    #[msg("Hidden reserve reveal window has closed")]
+    ReserveRevealClosed,
+    #[msg("Lamport payout would leave its wallet below the rent-exempt minimum")]
+    PayoutBelowRent,
+}
+
+// ============================================================================
+// Metaplex Core (Artifacte v2) — accounts, state, events, helpers, errors
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ListCoreItem<'info> {
+    /// Current holder-signed.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset. Validated in handler against owner + collection.
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection. Pubkey validated against ARTIFACTE_COLLECTION_PUBKEY in handler.
+    #[account(mut)]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Payment mint (validated in handler against its registry entry).
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+
+    /// CoreListing PDA — created on list, closed on cancel/buy.
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + CoreListing::INIT_SPACE,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// CHECK: Program-controlled PDA that becomes the TransferDelegate authority.
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ListCoreAuction<'info> {
+    /// Current holder-signed; posts the seller bond.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset. Validated in handler against owner + collection.
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection. Pubkey validated against ARTIFACTE_COLLECTION_PUBKEY in handler.
+    #[account(mut)]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Payment mint (validated in handler against its registry entry).
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+
+    /// CoreListing PDA — created on list, closed on settle/cancel.
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + CoreListing::INIT_SPACE,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// Seller bond escrow — emptied and closed whenever the auction ends
+    #[account(
+        init,
+        payer = seller,
+        token::mint = payment_mint,
+        token::authority = core_bond,
+        token::token_program = token_program,
+        seeds = [b"core_bond", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_bond: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = seller,
+    )]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+
+    /// CHECK: Program-controlled PDA that becomes the Transfer/FreezeDelegate authority.
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelCoreListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset.
+    #[account(mut, address = core_listing.asset)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection.
+    #[account(mut, address = core_listing.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+        close = seller,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// CHECK: Program-controlled PDA = TransferDelegate authority.
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    /// Core auctions only: payment mint, bond escrow and seller payment account
+    /// the seller bond is returned through
+    #[account(address = core_listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
+    #[account(
+        mut,
+        seeds = [b"core_bond", asset.key().as_ref()],
+        bump,
+        token::mint = core_listing.payment_mint,
+    )]
+    pub core_bond: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    #[account(mut, token::mint = core_listing.payment_mint)]
+    pub seller_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredCoreListing<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Original seller — receives PDA rent on close.
+    #[account(mut, address = core_listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core asset.
+    #[account(mut, address = core_listing.asset)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection.
+    #[account(mut, address = core_listing.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+        close = seller,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// CHECK: Program-controlled PDA = TransferDelegate authority (signs revoke).
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCoreListing<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"core_listing", core_listing.asset.as_ref()],
+        bump = core_listing.bump,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// Registry entry for the listing's payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", core_listing.payment_mint.as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseStaleCoreListing<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset.
+    #[account(mut, address = core_listing.asset)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection.
+    #[account(mut, address = core_listing.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+        close = holder,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// CHECK: Program-controlled PDA = TransferDelegate authority.
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    /// Core auctions only: payment mint, bond escrow and seller payment account
+    /// the seller bond is returned through
+    #[account(address = core_listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
+    #[account(
+        mut,
+        seeds = [b"core_bond", asset.key().as_ref()],
+        bump,
+        token::mint = core_listing.payment_mint,
+    )]
+    pub core_bond: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    #[account(mut, token::mint = core_listing.payment_mint)]
+    pub seller_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowCore<'info> {
+    /// Public buyer.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset.
+    #[account(mut, address = core_listing.asset)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection.
+    #[account(mut, address = core_listing.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+        close = seller,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// CHECK: Original seller — receives PDA rent on close.
+    #[account(mut, address = core_listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Program-controlled PDA = TransferDelegate authority (signs CPI).
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    /// Listing payment mint (validated in handler).
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+
+    #[account(mut)]
+    pub buyer_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub seller_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub treasury_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// CHECK: Royalty creator's payment account — validated against on-chain plugin in handler.
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury wallet — validated in handler against treasury_config or fallback.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceCoreBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset.
+    #[account(mut, address = core_listing.asset)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection.
+    #[account(mut, address = core_listing.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// CHECK: Program-controlled PDA = FreezeDelegate authority (signs CPI).
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    #[account(address = core_listing.payment_mint)]
+    pub payment_mint: InterfaceAccount<'info, IfaceMint>,
+
+    /// Bid escrow PDA — keyed by the asset, same seeds as SPL auctions
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        token::mint = payment_mint,
+        token::authority = bid_escrow,
+        token::token_program = token_program,
+        seeds = [
+            b"bid_escrow",
+            asset.key().as_ref(),
+            &listing_instance_seed(core_listing.created_at),
+        ],
+        bump,
+    )]
+    pub bid_escrow: InterfaceAccount<'info, IfaceTokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+
+    /// Bidder's own refund record (see `PlaceBid`)
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BidRefund::INIT_SPACE,
+        seeds = [
+            b"bid_refund",
+            asset.key().as_ref(),
+            &listing_instance_seed(core_listing.created_at),
+            bidder.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bidder_refund: Box<Account<'info, BidRefund>>,
+
+    /// Previous high bidder's refund record — required once a bid exists
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            asset.key().as_ref(),
+            &listing_instance_seed(core_listing.created_at),
+            core_listing.highest_bidder.as_ref(),
+        ],
+        bump = previous_bidder_refund.bump,
+        constraint = previous_bidder_refund.payment_mint == core_listing.payment_mint
+            && !previous_bidder_refund.native
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub previous_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleCoreAuction<'info> {
+    /// Anyone may settle; pays any Core CPI storage fees.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset.
+    #[account(mut, address = core_listing.asset)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection.
+    #[account(mut, address = core_listing.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+        close = seller,
+    )]
+    pub core_listing: Account<'info, CoreListing>,
+
+    /// CHECK: Original seller — receives PDA rent on close.
+    #[account(mut, address = core_listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Auction winner — receives the asset.
+    #[account(address = core_listing.highest_bidder)]
+    pub winner: UncheckedAccount<'info>,
+
+    /// CHECK: Program-controlled PDA = Transfer/FreezeDelegate authority (signs CPI).
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    #[account(address = core_listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+
+    /// Bid escrow PDA — absent when the auction drew no bids
+    #[account(
+        mut,
+        seeds = [
+            b"bid_escrow",
+            asset.key().as_ref(),
+            &listing_instance_seed(core_listing.created_at),
+        ],
+        bump,
+        token::mint = core_listing.payment_mint,
+    )]
+    pub bid_escrow: Option<InterfaceAccount<'info, IfaceTokenAccount>>,
+
+    /// Seller bond escrow — absent only for listings that posted no bond
+    #[account(
+        mut,
+        seeds = [b"core_bond", asset.key().as_ref()],
+        bump,
+        token::mint = core_listing.payment_mint,
+    )]
+    pub core_bond: Option<InterfaceAccount<'info, IfaceTokenAccount>>,
+
+    #[account(mut)]
+    pub seller_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    #[account(mut)]
+    pub treasury_payment_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    /// CHECK: Royalty creator's payment account — validated against on-chain plugin in handler.
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+    /// Winner's refund record — required only if the asset can't be delivered
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            asset.key().as_ref(),
+            &listing_instance_seed(core_listing.created_at),
+            core_listing.highest_bidder.as_ref(),
+        ],
+        bump = winner_bid_refund.bump,
+        constraint = winner_bid_refund.payment_mint == core_listing.payment_mint
+            && !winner_bid_refund.native
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub winner_bid_refund: Option<Box<Account<'info, BidRefund>>>,
+
+    /// CHECK: Treasury wallet — validated in handler against treasury_config or fallback.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CoreListing {
+    pub seller: Pubkey,
+    pub asset: Pubkey,
+    pub collection: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    /// Purchases are rejected before this time
+    pub start_time: i64,
+    /// FixedPrice or Auction — other listing types are not offered for Core
+    pub listing_type: ListingType,
+    /// Auctions: bidding closes at this time. Fixed price: expiry (0 = none)
+    pub end_time: i64,
+    pub current_bid: u64,
+    pub highest_bidder: Pubkey,
+    pub bid_increment: BidIncrement,
+    /// Auctions: bond held in `core_bond` until settlement or cancellation
+    pub seller_bond: u64,
+}
+
+#[event]
+pub struct CoreListingCreated {
+    pub asset: Pubkey,
+    pub seller: Pubkey,
+    pub price_usdc: u64,
+    pub payment_mint: Pubkey,
+    pub start_time: i64,
+    /// Auction close, or fixed-price expiry (0 = none)
+    pub end_time: i64,
+}
+
+#[event]
+pub struct CoreListingCancelled {
+    pub asset: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct SellerBondForfeited {
+    pub asset: Pubkey,
+    pub seller: Pubkey,
+    pub winner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CorePurchased {
+    pub asset: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price_usdc: u64,
+    pub platform_fee: u64,
+    /// Amounts each party received, net of any payment-mint transfer fee
+    pub seller_amount: u64,
+    pub treasury_amount: u64,
+    pub creator_royalty: u64,
+}
+
+// ============================================================================
+// Offers
+// ============================================================================
+
+#[account]
+#[derive(InitSpace)]
+pub struct Offer {
+    pub buyer: Pubkey,
+    /// NFT mint or Metaplex Core asset
+    pub asset: Pubkey,
+    pub payment_mint: Pubkey,
+    /// Escrowed in `offer_escrow`; the full sale price on acceptance
+    pub amount: u64,
+    pub expires_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub escrow_bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct MakeOffer<'info> {
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + Offer::INIT_SPACE,
+        seeds = [b"offer", asset.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+    /// CHECK: NFT mint or Core asset the offer is for; owner program checked in handler.
+    pub asset: UncheckedAccount<'info>,
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    #[account(
+        init,
+        payer = buyer,
+        token::mint = payment_mint,
+        token::authority = offer_escrow,
+        token::token_program = token_program,
+        seeds = [b"offer_escrow", asset.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(
+        mut,
+        seeds = [b"offer", offer.asset.as_ref(), buyer.key().as_ref()],
+        bump = offer.bump,
+        close = buyer,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+    #[account(
+        mut,
+        seeds = [b"offer_escrow", offer.asset.as_ref(), buyer.key().as_ref()],
+        bump = offer.escrow_bump,
+    )]
+    pub offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(address = offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredOffer<'info> {
+    #[account(
+        mut,
+        seeds = [b"offer", offer.asset.as_ref(), offer.buyer.as_ref()],
+        bump = offer.bump,
+        close = buyer,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+    #[account(
+        mut,
+        seeds = [b"offer_escrow", offer.asset.as_ref(), offer.buyer.as_ref()],
+        bump = offer.escrow_bump,
+    )]
+    pub offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(address = offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: The offer's buyer, validated against offer.buyer; receives rent.
+    #[account(mut, address = offer.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    #[account(
+        mut,
+        seeds = [b"offer", nft_mint.key().as_ref(), offer.buyer.as_ref()],
+        bump = offer.bump,
+        close = buyer,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+    #[account(
+        mut,
+        seeds = [b"offer_escrow", nft_mint.key().as_ref(), offer.buyer.as_ref()],
+        bump = offer.escrow_bump,
+    )]
+    pub offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: The offer's buyer, validated against offer.buyer; receives rent.
+    #[account(mut, address = offer.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(address = offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// The item's listing — required when it is listed
+    #[account(
+        mut,
+        seeds = [b"listing", nft_mint.key().as_ref()],
+        bump = listing.bump,
+    )]
+    pub listing: Option<Box<Account<'info, Listing>>>,
+    /// Escrowed NFT — required with `listing`
+    #[account(
+        mut,
+        seeds = [b"escrow_nft", nft_mint.key().as_ref()],
+        bump,
+        token::mint = nft_mint,
+        token::token_program = nft_token_program,
+    )]
+    pub escrow_nft: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    /// Seller's NFT account — required when the item is not listed
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = seller,
+    )]
+    pub seller_nft_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — must be owned by the seller
+    #[account(mut, constraint = seller_payment_account.owner == seller.key() @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    /// CHECK: Treasury wallet for rent collection. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOfferPnft<'info> {
+    #[account(
+        mut,
+        seeds = [b"offer", nft_mint.key().as_ref(), offer.buyer.as_ref()],
+        bump = offer.bump,
+        close = buyer,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+    #[account(
+        mut,
+        seeds = [b"offer_escrow", nft_mint.key().as_ref(), offer.buyer.as_ref()],
+        bump = offer.escrow_bump,
+    )]
+    pub offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: The offer's buyer, validated against offer.buyer; receives rent.
+    #[account(mut, address = offer.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: pNFT mint — the offer PDA seeds bind it to the offer
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
+    pub nft_edition: UncheckedAccount<'info>,
+
+    #[account(address = offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+
+    /// The item's listing — required when it is listed
+    #[account(
+        mut,
+        seeds = [b"listing", nft_mint.key().as_ref()],
+        bump = listing.bump,
+    )]
+    pub listing: Option<Box<Account<'info, Listing>>>,
+
+    /// Escrow authority PDA — source owner for listed pNFTs
+    #[account(
+        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: SystemAccount<'info>,
+
+    /// CHECK: Escrow token account if listed, seller's otherwise — verified by Token Metadata CPI
+    #[account(mut)]
+    pub source_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Source token record — verified by Token Metadata CPI
+    #[account(mut)]
+    pub source_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer NFT token account — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub buyer_nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer token record — created/verified by Token Metadata CPI
+    #[account(mut)]
+    pub buyer_token_record: UncheckedAccount<'info>,
+
+    /// Seller payment account — must be owned by the seller
+    #[account(mut, constraint = seller_payment_account.owner == seller.key() @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+
+    /// Seller; pays for the buyer's token account / token record if missing
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Treasury wallet for rent collection. Validated in instruction body.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Treasury config PDA — if present, overrides hardcoded treasury address
+    #[account(
+        seeds = [b"treasury_config"],
+        bump,
+    )]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Token program for the payment legs (SPL Token or Token-2022)
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: SPL ATA program
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Sysvar instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: Optional authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional authorization rules account
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOfferCore<'info> {
+    #[account(
+        mut,
+        seeds = [b"offer", asset.key().as_ref(), offer.buyer.as_ref()],
+        bump = offer.bump,
+        close = buyer,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+    #[account(
+        mut,
+        seeds = [b"offer_escrow", asset.key().as_ref(), offer.buyer.as_ref()],
+        bump = offer.escrow_bump,
+    )]
+    pub offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: The offer's buyer, validated against offer.buyer; receives the asset and rent.
+    #[account(mut, address = offer.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// Current owner.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset. Validated in handler against owner + collection.
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection. Validated in handler.
+    #[account(mut)]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Fixed-price listing for the asset, if any — closed by the sale
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+        close = seller,
+    )]
+    pub core_listing: Option<Account<'info, CoreListing>>,
+
+    /// CHECK: Program-controlled PDA = TransferDelegate authority of listed assets.
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    #[account(address = offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+
+    /// Seller payment account — must be owned by the seller
+    #[account(mut, constraint = seller_payment_account.owner == seller.key() @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in handler against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Royalty creator's payment account — validated against on-chain plugin in handler.
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury wallet — validated in handler against treasury_config or fallback.
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct OfferMade {
+    pub asset: Pubkey,
+    pub buyer: Pubkey,
+    pub payment_mint: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct OfferCancelled {
+    pub asset: Pubkey,
+    pub buyer: Pubkey,
+    /// Closed after expiry rather than withdrawn by the buyer
+    pub expired: bool,
+}
+
+#[event]
+pub struct OfferAccepted {
+    pub asset: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub platform_fee: u64,
+    /// Amounts each party received, net of any payment-mint transfer fee
+    pub seller_amount: u64,
+    pub treasury_amount: u64,
+    pub creator_royalty: u64,
+}
+
+/// Exact-match requirement on a Core `Attributes` plugin entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct AttributePredicate {
+    #[max_len(MAX_ATTRIBUTE_LEN)]
+    pub key: String,
+    #[max_len(MAX_ATTRIBUTE_LEN)]
+    pub value: String,
+}
+
+/// Standing bid for up to `quantity_remaining` assets of a Core collection at
+/// `price` each, escrowed in `collection_offer_escrow`.
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionOffer {
+    pub buyer: Pubkey,
+    pub collection: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub quantity_remaining: u32,
+    pub expires_at: i64,
+    pub created_at: i64,
+    /// Asset must match all of these (empty = any asset in the collection)
+    #[max_len(MAX_OFFER_ATTRIBUTES)]
+    pub attributes: Vec<AttributePredicate>,
+    /// `attribute_predicates_hash(attributes)`, part of the PDA seeds
+    pub attributes_hash: [u8; 32],
+    pub bump: u8,
+    pub escrow_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(price: u64, quantity: u32, expires_at: i64, attributes: Vec<AttributePredicate>)]
+pub struct MakeCollectionOffer<'info> {
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + CollectionOffer::INIT_SPACE,
+        seeds = [b"collection_offer", collection.key().as_ref(), buyer.key().as_ref(), &attribute_predicates_hash(&attributes)],
+        bump,
+    )]
+    pub collection_offer: Box<Account<'info, CollectionOffer>>,
+    /// CHECK: Metaplex Core collection. Pubkey validated in handler.
+    pub collection: UncheckedAccount<'info>,
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    #[account(
+        init,
+        payer = buyer,
+        token::mint = payment_mint,
+        token::authority = collection_offer_escrow,
+        token::token_program = token_program,
+        seeds = [b"collection_offer_escrow", collection.key().as_ref(), buyer.key().as_ref(), &attribute_predicates_hash(&attributes)],
+        bump,
+    )]
+    pub collection_offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FillCollectionOffer<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection_offer", collection.key().as_ref(), collection_offer.buyer.as_ref(), collection_offer.attributes_hash.as_ref()],
+        bump = collection_offer.bump,
+    )]
+    pub collection_offer: Box<Account<'info, CollectionOffer>>,
+    #[account(
+        mut,
+        seeds = [b"collection_offer_escrow", collection.key().as_ref(), collection_offer.buyer.as_ref(), collection_offer.attributes_hash.as_ref()],
+        bump = collection_offer.escrow_bump,
+    )]
+    pub collection_offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: The offer's buyer, validated against collection_offer.buyer; receives the asset.
+    #[account(mut, address = collection_offer.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// Current owner.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Metaplex Core asset. Validated in handler against owner + collection.
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core collection — bound by the offer PDA seeds.
+    #[account(mut)]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Fixed-price listing for the asset, if any — closed by the sale
+    #[account(
+        mut,
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump = core_listing.bump,
+        close = seller,
+    )]
+    pub core_listing: Option<Account<'info, CoreListing>>,
+
+    /// CHECK: Program-controlled PDA = TransferDelegate authority of listed assets.
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+
+    #[account(address = collection_offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+
+    /// Seller payment account — must be owned by the seller
+    #[account(mut, constraint = seller_payment_account.owner == seller.key() @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in handler against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Royalty creator's payment account — validated against on-chain plugin in handler.
+    #[account(mut)]
+    pub creator_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury wallet — validated in handler against treasury_config or fallback.
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelCollectionOffer<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection_offer", collection_offer.collection.as_ref(), buyer.key().as_ref(), collection_offer.attributes_hash.as_ref()],
+        bump = collection_offer.bump,
+        close = buyer,
+    )]
+    pub collection_offer: Box<Account<'info, CollectionOffer>>,
+    #[account(
+        mut,
+        seeds = [b"collection_offer_escrow", collection_offer.collection.as_ref(), buyer.key().as_ref(), collection_offer.attributes_hash.as_ref()],
+        bump = collection_offer.escrow_bump,
+    )]
+    pub collection_offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(address = collection_offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredCollectionOffer<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection_offer", collection_offer.collection.as_ref(), collection_offer.buyer.as_ref(), collection_offer.attributes_hash.as_ref()],
+        bump = collection_offer.bump,
+        close = buyer,
+    )]
+    pub collection_offer: Box<Account<'info, CollectionOffer>>,
+    #[account(
+        mut,
+        seeds = [b"collection_offer_escrow", collection_offer.collection.as_ref(), collection_offer.buyer.as_ref(), collection_offer.attributes_hash.as_ref()],
+        bump = collection_offer.escrow_bump,
+    )]
+    pub collection_offer_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(address = collection_offer.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: The offer's buyer, validated against collection_offer.buyer; receives rent.
+    #[account(mut, address = collection_offer.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct CollectionOfferMade {
+    pub collection: Pubkey,
+    pub buyer: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub quantity: u32,
+    pub expires_at: i64,
+    pub attributes: Vec<AttributePredicate>,
+}
+
+#[event]
+pub struct CollectionOfferFilled {
+    pub collection: Pubkey,
+    pub asset: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub platform_fee: u64,
+    /// Amounts each party received, net of any payment-mint transfer fee
+    pub seller_amount: u64,
+    pub treasury_amount: u64,
+    pub creator_royalty: u64,
+    pub quantity_remaining: u32,
+}
+
+#[event]
+pub struct CollectionOfferCancelled {
+    pub collection: Pubkey,
+    pub buyer: Pubkey,
+    pub quantity_remaining: u32,
+    /// Closed after expiry rather than withdrawn by the buyer
+    pub expired: bool,
+}
+
+// ============================================================================
+// Bundles — state, accounts, events
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BundleItemKind {
+    Spl,
+    Token2022,
+    Core,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct BundleItem {
+    /// NFT mint or Core asset
+    pub asset: Pubkey,
+    pub kind: BundleItemKind,
+    /// Share of the bundle price (0 on every item = even split)
+    pub weight: u16,
+    /// Seller-declared royalty (SPL / Token-2022 only; Core reads the plugin)
+    pub royalty_basis_points: u16,
+    pub creator_address: Pubkey,
+    /// `bundle_escrow` bump, or `core_authority` bump for Core items
+    pub bump: u8,
+}
+
+/// Several NFTs sold as one lot, at a fixed price or by English auction.
+#[account]
+#[derive(InitSpace)]
+pub struct Bundle {
+    pub seller: Pubkey,
+    pub bundle_id: u64,
+    pub payment_mint: Pubkey,
+    /// Fixed price, or auction starting price
+    pub price: u64,
+    pub listing_type: ListingType,
+    pub category: ItemCategory,
+    /// Applied when the bundle opens (0 = fixed price without expiry)
+    pub duration_seconds: i64,
+    pub start_time: i64,
+    pub end_time: i64,
+    /// Set by `open_bundle`; items can only be added before
+    pub open: bool,
+    pub current_bid: u64,
+    pub highest_bidder: Pubkey,
+    pub bid_increment: BidIncrement,
+    #[max_len(MAX_BUNDLE_ITEMS)]
+    pub items: Vec<BundleItem>,
+    pub bump: u8,
+    /// Identifies this bundle instance; scopes its bid escrow and refund
+    /// records, since a closed bundle's PDA can be created again
+    pub created_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(bundle_id: u64)]
+pub struct CreateBundle<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Bundle::INIT_SPACE,
+        seeds = [b"bundle", seller.key().as_ref(), bundle_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddBundleItem<'info> {
+    #[account(
+        mut,
+        seeds = [b"bundle", seller.key().as_ref(), bundle.bundle_id.to_le_bytes().as_ref()],
+        bump = bundle.bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(
+        init,
+        payer = seller,
+        token::mint = nft_mint,
+        token::authority = bundle_escrow,
+        token::token_program = nft_token_program,
+        seeds = [b"bundle_escrow", bundle.key().as_ref(), nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub bundle_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub seller_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddBundleCoreItem<'info> {
+    #[account(
+        mut,
+        seeds = [b"bundle", seller.key().as_ref(), bundle.bundle_id.to_le_bytes().as_ref()],
+        bump = bundle.bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+    /// CHECK: Metaplex Core asset. Ownership and collection validated in handler.
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+    /// CHECK: Metaplex Core collection. Pubkey validated in handler.
+    #[account(mut)]
+    pub collection: UncheckedAccount<'info>,
+    /// CHECK: Core listing PDA for the asset — must not exist.
+    #[account(
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_listing: UncheckedAccount<'info>,
+    /// CHECK: Program-controlled PDA = Transfer/FreezeDelegate authority (signs CPI).
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenBundle<'info> {
+    #[account(
+        mut,
+        seeds = [b"bundle", seller.key().as_ref(), bundle.bundle_id.to_le_bytes().as_ref()],
+        bump = bundle.bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", bundle.payment_mint.as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    pub seller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyBundle<'info> {
+    #[account(
+        mut,
+        seeds = [b"bundle", bundle.seller.as_ref(), bundle.bundle_id.to_le_bytes().as_ref()],
+        bump = bundle.bump,
+        close = seller,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+    /// CHECK: Original seller — receives bundle and escrow rent on close.
+    #[account(mut, address = bundle.seller)]
+    pub seller: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(address = bundle.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    #[account(mut)]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — must be owned by bundle.seller
+    #[account(mut, constraint = seller_payment_account.owner == bundle.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Treasury wallet. Validated in instruction body.
+    pub treasury: UncheckedAccount<'info>,
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
+    /// CHECK: Artifacte collection — required when the bundle holds Core items.
+    #[account(mut, address = ARTIFACTE_COLLECTION_PUBKEY)]
+    pub collection: Option<UncheckedAccount<'info>>,
+    pub nft_token_program: Program<'info, Token>,
+    pub nft_token_2022_program: Program<'info, Token2022>,
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceBundleBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bundle", bundle.seller.as_ref(), bundle.bundle_id.to_le_bytes().as_ref()],
+        bump = bundle.bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    #[account(address = bundle.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+
+    /// Bid escrow PDA — keyed by the bundle instance
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        token::mint = payment_mint,
+        token::authority = bid_escrow,
+        token::token_program = token_program,
+        seeds = [b"bid_escrow", bundle.key().as_ref(), &listing_instance_seed(bundle.created_at)],
+        bump,
+    )]
+    pub bid_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+
+    /// Bidder's own refund record (see `PlaceBid`)
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BidRefund::INIT_SPACE,
+        seeds = [
+            b"bid_refund",
+            bundle.key().as_ref(),
+            &listing_instance_seed(bundle.created_at),
+            bidder.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bidder_refund: Box<Account<'info, BidRefund>>,
+
+    /// Previous high bidder's refund record — required once a bid exists
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            bundle.key().as_ref(),
+            &listing_instance_seed(bundle.created_at),
+            bundle.highest_bidder.as_ref(),
+        ],
+        bump = previous_bidder_refund.bump,
+        constraint = previous_bidder_refund.payment_mint == bundle.payment_mint
+            && !previous_bidder_refund.native
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub previous_bidder_refund: Option<Box<Account<'info, BidRefund>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleBundle<'info> {
+    /// Anyone may settle; pays any Core CPI storage fees.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bundle", bundle.seller.as_ref(), bundle.bundle_id.to_le_bytes().as_ref()],
+        bump = bundle.bump,
+        close = seller,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
 
-        require_keys_eq!(asset_collection, ctx.accounts.collection.key(), AuctionError::Unauthorized);
-        require_keys_eq!(asset_owner, ctx.accounts.holder.key(), AuctionError::Unauthorized);
-        require!(listing.seller != ctx.accounts.holder.key(), AuctionError::CoreListingNotStale);
+    /// CHECK: Original seller — receives bundle and escrow rent on close.
+    #[account(mut, address = bundle.seller)]
+    pub seller: UncheckedAccount<'info>,
 
-        match read_core_transfer_delegate_state(&ctx.accounts.asset.to_account_info())? {
-            CoreTransferDelegateState::Address(address)
-                if address == ctx.accounts.core_authority.key() =>
-            {
-                mpl_core::instructions::RevokePluginAuthorityV1Cpi {
-                    __program: &ctx.accounts.mpl_core_program.to_account_info(),
-                    asset: &ctx.accounts.asset.to_account_info(),
-                    collection: Some(&ctx.accounts.collection.to_account_info()),
-                    payer: &ctx.accounts.holder.to_account_info(),
-                    authority: Some(&ctx.accounts.holder.to_account_info()),
-                    system_program: &ctx.accounts.system_program.to_account_info(),
-                    log_wrapper: None,
-                    __args: mpl_core::instructions::RevokePluginAuthorityV1InstructionArgs {
-                        plugin_type: mpl_core::types::PluginType::TransferDelegate,
-                    },
-                }
-                .invoke()?;
-            }
-            _ => {}
-        }
+    /// CHECK: Auction winner — receives the items.
+    #[account(address = bundle.highest_bidder)]
+    pub winner: UncheckedAccount<'info>,
 
-        emit!(CoreListingCancelled {
-            asset: listing.asset,
-            seller: listing.seller,
-        });
+    #[account(address = bundle.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
 
-        Ok(())
-    }
+    /// Bid escrow PDA — absent when the auction drew no bids
+    #[account(
+        mut,
+        seeds = [b"bid_escrow", bundle.key().as_ref(), &listing_instance_seed(bundle.created_at)],
+        bump,
+        token::mint = bundle.payment_mint,
+    )]
+    pub bid_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
 
-    /// Public buy of a Core listing. Artifacte collection buys waive the
-    /// platform fee and only split USDC between creator royalty and seller.
-    /// Then CPI TransferV1 moves the asset to the buyer.
-    pub fn buy_now_core(ctx: Context<BuyNowCore>) -> Result<()> {
-        let listing = &ctx.accounts.core_listing;
+    #[account(mut)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    #[account(mut)]
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Winner's refund record — required only if the bundle can't be delivered
+    #[account(
+        mut,
+        seeds = [
+            b"bid_refund",
+            bundle.key().as_ref(),
+            &listing_instance_seed(bundle.created_at),
+            bundle.highest_bidder.as_ref(),
+        ],
+        bump = winner_bid_refund.bump,
+        constraint = winner_bid_refund.payment_mint == bundle.payment_mint
+            && !winner_bid_refund.native
+            @ AuctionError::InvalidRefundAccount,
+    )]
+    pub winner_bid_refund: Option<Box<Account<'info, BidRefund>>>,
 
-        // Re-validate state (defence in depth)
-        require_keys_eq!(listing.payment_mint, USDC_MINT_PUBKEY, AuctionError::InvalidPaymentMint);
-        require!(
-            ctx.accounts.payment_mint.key() == listing.payment_mint,
-            AuctionError::InvalidPaymentMint
-        );
-        require!(
-            ctx.accounts.asset.key() == listing.asset,
-            AuctionError::Unauthorized
-        );
-        require!(
-            ctx.accounts.collection.key() == listing.collection,
-            AuctionError::Unauthorized
-        );
-        verify_active_core_listing_owner(
-            &ctx.accounts.asset.to_account_info(),
-            listing.seller,
-            ctx.accounts.collection.key(),
-        )?;
+    /// CHECK: Treasury wallet — validated in handler against treasury_config or fallback.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
 
-        // Resolve treasury (config PDA if initialized, else fallback constant)
-        let treasury_address = if let Some(ref config) = ctx.accounts.treasury_config {
-            config.treasury
-        } else {
-            TREASURY_FALLBACK.parse::<Pubkey>().unwrap()
-        };
-        require!(
-            ctx.accounts.treasury_payment_account.owner == treasury_address,
-            AuctionError::Unauthorized
-        );
-        require!(
-            ctx.accounts.treasury.key() == treasury_address,
-            AuctionError::Unauthorized
-        );
-        require!(
-            ctx.accounts.seller_payment_account.owner == listing.seller,
-            AuctionError::Unauthorized
-        );
+    #[account(seeds = [b"treasury_config"], bump)]
+    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
 
-        // Read on-chain Royalties plugin (asset first, fallback to collection)
-        // and validate creator_payment_account.
-        let (royalty_bps, royalty_creator) = read_core_royalties(
-            &ctx.accounts.asset.to_account_info(),
-            &ctx.accounts.collection.to_account_info(),
-        )?;
-        require!(royalty_bps <= 1000, AuctionError::RoyaltyTooHigh);
+    /// CHECK: Artifacte collection — required when the bundle holds Core items.
+    #[account(mut, address = ARTIFACTE_COLLECTION_PUBKEY)]
+    pub collection: Option<UncheckedAccount<'info>>,
 
-        let platform_fee = listing
-            .price
-            .checked_mul(core_platform_fee_bps(listing.collection))
-            .and_then(|x| x.checked_div(10000))
-            .ok_or(AuctionError::CalculationError)?;
-        let creator_royalty = listing
-            .price
-            .checked_mul(royalty_bps as u64)
-            .and_then(|x| x.checked_div(10000))
-            .ok_or(AuctionError::CalculationError)?;
+    pub nft_token_program: Program<'info, Token>,
+    pub nft_token_2022_program: Program<'info, Token2022>,
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
 
-        if creator_royalty > 0 {
-            let expected_creator_ata =
-                anchor_spl::associated_token::get_associated_token_address(
-                    &royalty_creator,
-                    &listing.payment_mint,
-                );
-            require!(
-                ctx.accounts.creator_payment_account.key() == expected_creator_ata,
-                AuctionError::InvalidCreatorAccount
-            );
-        }
+#[derive(Accounts)]
+pub struct CancelBundle<'info> {
+    #[account(
+        mut,
+        seeds = [b"bundle", bundle.seller.as_ref(), bundle.bundle_id.to_le_bytes().as_ref()],
+        bump = bundle.bump,
+        close = seller,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+    /// CHECK: Original seller — receives the items and all rent.
+    #[account(mut, address = bundle.seller)]
+    pub seller: UncheckedAccount<'info>,
+    /// The seller, or anyone once a fixed-price bundle has expired; pays any
+    /// Core CPI storage fees.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Artifacte collection — required when the bundle holds Core items.
+    #[account(mut, address = ARTIFACTE_COLLECTION_PUBKEY)]
+    pub collection: Option<UncheckedAccount<'info>>,
+    pub nft_token_program: Program<'info, Token>,
+    pub nft_token_2022_program: Program<'info, Token2022>,
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
 
-        let seller_amount = listing
-            .price
-            .checked_sub(platform_fee)
-            .ok_or(AuctionError::CalculationError)?
-            .checked_sub(creator_royalty)
-            .ok_or(AuctionError::CalculationError)?;
+#[event]
+pub struct BundleCreated {
+    pub bundle: Pubkey,
+    pub seller: Pubkey,
+    pub bundle_id: u64,
+    pub listing_type: ListingType,
+    pub price: u64,
+    pub payment_mint: Pubkey,
+}
 
-        // USDC: buyer → seller
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.buyer_payment_account.to_account_info(),
-                    to: ctx.accounts.seller_payment_account.to_account_info(),
-                    authority: ctx.accounts.buyer.to_account_info(),
-                },
-            ),
-            seller_amount,
-        )?;
-        if platform_fee > 0 {
-            // USDC: buyer → treasury (platform fee)
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.buyer_payment_account.to_account_info(),
-                        to: ctx.accounts.treasury_payment_account.to_account_info(),
-                        authority: ctx.accounts.buyer.to_account_info(),
-                    },
-                ),
-                platform_fee,
-            )?;
-        }
-        if creator_royalty > 0 {
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.buyer_payment_account.to_account_info(),
-                        to: ctx.accounts.creator_payment_account.to_account_info(),
-                        authority: ctx.accounts.buyer.to_account_info(),
-                    },
-                ),
-                creator_royalty,
-            )?;
-        }
+#[event]
+pub struct BundleItemAdded {
+    pub bundle: Pubkey,
+    pub asset: Pubkey,
+    pub weight: u16,
+}
 
-        // Transfer the Core asset (signed by the program's delegate PDA)
-        let asset_key = ctx.accounts.asset.key();
-        let core_authority_bump = ctx.bumps.core_authority;
-        let core_authority_seeds: &[&[u8]] = &[
-            b"core_authority",
-            asset_key.as_ref(),
-            &[core_authority_bump],
-        ];
+#[event]
+pub struct BundleOpened {
+    pub bundle: Pubkey,
+    pub seller: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub item_count: u8,
+}
 
-        mpl_core::instructions::TransferV1Cpi {
-            __program: &ctx.accounts.mpl_core_program.to_account_info(),
-            asset: &ctx.accounts.asset.to_account_info(),
-            collection: Some(&ctx.accounts.collection.to_account_info()),
-            payer: &ctx.accounts.buyer.to_account_info(),
-            authority: Some(&ctx.accounts.core_authority.to_account_info()),
-            new_owner: &ctx.accounts.buyer.to_account_info(),
-            system_program: Some(&ctx.accounts.system_program.to_account_info()),
-            log_wrapper: None,
-            __args: mpl_core::instructions::TransferV1InstructionArgs {
-                compression_proof: None,
-            },
-        }
-        .invoke_signed(&[core_authority_seeds])?;
+#[event]
+pub struct BundleSold {
+    pub bundle: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub platform_fee: u64,
+    /// Amounts each party received, net of any payment-mint transfer fee
+    pub seller_amount: u64,
+    pub treasury_amount: u64,
+    pub creator_royalty: u64,
+    pub item_count: u8,
+}
 
-        emit!(CorePurchased {
-            asset: listing.asset,
-            seller: listing.seller,
-            buyer: ctx.accounts.buyer.key(),
-            price_usdc: listing.price,
-            platform_fee,
-            creator_royalty,
-        });
-        // CoreListing PDA closed via `close = seller` constraint.
-        Ok(())
-    }
+#[event]
+pub struct BundleCancelled {
+    pub bundle: Pubkey,
+    pub seller: Pubkey,
+    /// Unwound after expiry rather than cancelled by the seller
+    pub expired: bool,
 }
 
 // ============================================================================
-// Helper Functions
+// Swaps — state, accounts, events
 // ============================================================================
 
-fn validate_category_and_payment(category: &ItemCategory, payment_mint: Pubkey) -> Result<()> {
-    let payment_str = payment_mint.to_string();
-    
-    match category {
-        ItemCategory::DigitalArt => {
-            require!(
-                payment_str == SOL_MINT,
-                AuctionError::InvalidPaymentMint
-            );
-        }
-        ItemCategory::Spirits
-        | ItemCategory::TCGCards
-        | ItemCategory::SportsCards
-        | ItemCategory::Watches => {
-            // USDC-only — USD1 removed per Artifacte v2 workflow.
-            require!(
-                payment_str == USDC_MINT,
-                AuctionError::InvalidPaymentMint
-            );
-        }
-    }
-    
-    Ok(())
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SwapAssetKind {
+    Spl,
+    Token2022,
+    Pnft,
+    Core,
 }
 
-// ============================================================================
-// Instructions
-// ============================================================================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SwapWant {
+    /// Exactly the `wanted` mint / Core asset
+    Asset,
+    /// Any 1/1 of the `wanted` verified Metaplex or Core collection
+    Collection,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum TopUpPayer {
+    Taker,
+    Maker,
+}
+
+/// What a swap maker wants back, and the optional cash leg.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SwapTerms {
+    pub want: SwapWant,
+    pub wanted: Pubkey,
+    /// Core attributes the taker's asset must carry (collection swaps only)
+    pub attributes: Vec<AttributePredicate>,
+    /// Payment-mint amount that changes hands with the assets (0 = none)
+    pub top_up: u64,
+    pub top_up_payer: TopUpPayer,
+    /// 0 = never expires
+    pub expires_at: i64,
+}
+
+/// One escrowed asset offered for another, keyed by the offered asset.
+#[account]
+#[derive(InitSpace)]
+pub struct Swap {
+    pub maker: Pubkey,
+    pub offered_asset: Pubkey,
+    pub offered_kind: SwapAssetKind,
+    pub want: SwapWant,
+    pub wanted: Pubkey,
+    #[max_len(MAX_OFFER_ATTRIBUTES)]
+    pub attributes: Vec<AttributePredicate>,
+    /// Default pubkey when there is no top-up
+    pub payment_mint: Pubkey,
+    pub top_up: u64,
+    pub top_up_payer: TopUpPayer,
+    pub expires_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+    /// Bump of the escrow PDA for `offered_kind` (see `swap_escrow_seed`)
+    pub escrow_bump: u8,
+    /// `swap_payment_escrow` bump when the maker pays the top-up
+    pub payment_escrow_bump: u8,
+}
 
 #[derive(Accounts)]
-#[instruction(listing_type: ListingType, price: u64, duration_seconds: Option<i64>, category: ItemCategory, royalty_basis_points: u16, creator_address: Pubkey)]
-pub struct ListItem<'info> {
+pub struct MakeSwap<'info> {
     #[account(
         init,
-        payer = seller,
-        space = 8 + Listing::INIT_SPACE,
-        seeds = [b"listing", nft_mint.key().as_ref()],
+        payer = maker,
+        space = 8 + Swap::INIT_SPACE,
+        seeds = [b"swap", nft_mint.key().as_ref()],
         bump,
     )]
-    pub listing: Account<'info, Listing>,
-    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
-    pub payment_mint: Account<'info, anchor_spl::token::Mint>,
+    pub swap: Box<Account<'info, Swap>>,
+    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
     #[account(
-        init_if_needed,
-        payer = seller,
+        init,
+        payer = maker,
         token::mint = nft_mint,
-        token::authority = escrow_nft,
+        token::authority = swap_escrow,
         token::token_program = nft_token_program,
-        seeds = [b"escrow_nft", nft_mint.key().as_ref()],
+        seeds = [b"swap_escrow", nft_mint.key().as_ref()],
         bump,
     )]
-    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
+    pub swap_escrow: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
     #[account(mut)]
-    pub seller_nft_account: InterfaceAccount<'info, IfaceTokenAccount>,
+    pub maker_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub maker: Signer<'info>,
+    /// Top-up payment mint — required when `terms.top_up > 0`
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
+    /// Registry entry for the payment mint — validated in handler
+    pub payment_mint_config: Option<Box<Account<'info, PaymentMintConfig>>>,
+    /// Source of a maker-paid top-up
+    #[account(mut)]
+    pub maker_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    /// Escrow for a maker-paid top-up — pass only when the maker pays
+    #[account(
+        init,
+        payer = maker,
+        token::mint = payment_mint,
+        token::authority = swap_payment_escrow,
+        token::token_program = token_program,
+        seeds = [b"swap_payment_escrow", swap.key().as_ref()],
+        bump,
+    )]
+    pub swap_payment_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
     pub nft_token_program: Interface<'info, TokenInterface>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
-// ============================================================================
-// pNFT Account Structs
-// ============================================================================
-
 #[derive(Accounts)]
-#[instruction(listing_type: ListingType, price: u64, duration_seconds: Option<i64>, category: ItemCategory, royalty_basis_points: u16, creator_address: Pubkey)]
-pub struct ListItemPnft<'info> {
+pub struct MakeSwapPnft<'info> {
     #[account(
         init,
-        payer = seller,
-        space = 8 + Listing::INIT_SPACE,
-        seeds = [b"listing", nft_mint.key().as_ref()],
+        payer = maker,
+        space = 8 + Swap::INIT_SPACE,
+        seeds = [b"swap", nft_mint.key().as_ref()],
         bump,
     )]
-    pub listing: Account<'info, Listing>,
+    pub swap: Box<Account<'info, Swap>>,
 
     /// CHECK: Mint — verified by Token Metadata CPI program constraints
     pub nft_mint: UncheckedAccount<'info>,
@@ -1733,42 +12708,61 @@ pub struct ListItemPnft<'info> {
     /// CHECK: Metaplex master edition PDA — verified by Token Metadata
     pub nft_edition: UncheckedAccount<'info>,
 
-    /// CHECK: Seller NFT token account — verified by Token Metadata CPI
+    /// CHECK: Maker NFT token account — verified by Token Metadata CPI
     #[account(mut)]
-    pub seller_nft_token: UncheckedAccount<'info>,
+    pub maker_nft_token: UncheckedAccount<'info>,
 
-    /// CHECK: Seller token record PDA — verified by Token Metadata CPI
+    /// CHECK: Maker token record PDA — verified by Token Metadata CPI
     #[account(mut)]
-    pub seller_token_record: UncheckedAccount<'info>,
+    pub maker_token_record: UncheckedAccount<'info>,
 
-    /// Escrow authority PDA — owns the escrow token account
+    /// Swap authority PDA — owns the escrow token account
     #[account(
-        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        seeds = [b"swap_authority", nft_mint.key().as_ref()],
         bump,
     )]
-    pub escrow_authority: SystemAccount<'info>,
+    pub swap_authority: SystemAccount<'info>,
 
-    /// Escrow token account — created by Token Metadata via ATA
+    /// CHECK: Escrow token account — created by Token Metadata via ATA
     #[account(mut)]
     pub escrow_nft_token: UncheckedAccount<'info>,
 
-    /// Escrow token record (pNFT programmable config)
+    /// CHECK: Escrow token record (pNFT programmable config)
     #[account(mut)]
     pub escrow_token_record: UncheckedAccount<'info>,
 
-    pub payment_mint: Account<'info, anchor_spl::token::Mint>,
+    #[account(mut)]
+    pub maker: Signer<'info>,
 
+    /// Top-up payment mint — required when `terms.top_up > 0`
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
+    /// Registry entry for the payment mint — validated in handler
+    pub payment_mint_config: Option<Box<Account<'info, PaymentMintConfig>>>,
+    /// Source of a maker-paid top-up
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub maker_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    /// Escrow for a maker-paid top-up — pass only when the maker pays
+    #[account(
+        init,
+        payer = maker,
+        token::mint = payment_mint,
+        token::authority = swap_payment_escrow,
+        token::token_program = token_program,
+        seeds = [b"swap_payment_escrow", swap.key().as_ref()],
+        bump,
+    )]
+    pub swap_payment_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
 
     /// CHECK: Metaplex Token Metadata program
     pub token_metadata_program: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub nft_token_program: Program<'info, Token>,
 
     /// CHECK: SPL Associated Token Account program
     pub ata_program: UncheckedAccount<'info>,
 
+    pub token_program: Interface<'info, TokenInterface>,
+
     pub system_program: Program<'info, System>,
 
     /// CHECK: Sysvar instructions
@@ -1782,750 +12776,1626 @@ pub struct ListItemPnft<'info> {
 }
 
 #[derive(Accounts)]
-pub struct CancelListingPnft<'info> {
-    #[account(mut, seeds = [b"listing", nft_mint.key().as_ref()], bump = listing.bump)]
-    pub listing: Account<'info, Listing>,
-
-    /// CHECK: Must match listing.nft_mint — enforced by PDA seed constraint above
-    #[account(constraint = nft_mint.key() == listing.nft_mint @ AuctionError::Unauthorized)]
-    pub nft_mint: UncheckedAccount<'info>,
-
-    /// CHECK: Metaplex metadata PDA — verified by Token Metadata program during CPI
-    #[account(mut)]
-    pub nft_metadata: UncheckedAccount<'info>,
-
-    /// CHECK: Metaplex master edition PDA — verified by Token Metadata
-    pub nft_edition: UncheckedAccount<'info>,
-
-    /// Escrow authority PDA
+pub struct MakeSwapCore<'info> {
     #[account(
-        seeds = [b"escrow_authority", nft_mint.key().as_ref()],
+        init,
+        payer = maker,
+        space = 8 + Swap::INIT_SPACE,
+        seeds = [b"swap", asset.key().as_ref()],
         bump,
     )]
-    pub escrow_authority: SystemAccount<'info>,
-
-    /// CHECK: Escrow token account — verified by Token Metadata CPI
+    pub swap: Box<Account<'info, Swap>>,
+    /// CHECK: Metaplex Core asset. Ownership and collection validated in handler.
     #[account(mut)]
-    pub escrow_nft_token: UncheckedAccount<'info>,
-
-    /// CHECK: Escrow token record — verified by Token Metadata CPI
+    pub asset: UncheckedAccount<'info>,
+    /// CHECK: Metaplex Core collection. Pubkey validated in handler.
     #[account(mut)]
-    pub escrow_token_record: UncheckedAccount<'info>,
-
-    /// CHECK: Seller NFT token account — verified by Token Metadata CPI
+    pub collection: UncheckedAccount<'info>,
+    /// CHECK: Core listing PDA for the asset — must not exist.
+    #[account(
+        seeds = [b"core_listing", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_listing: UncheckedAccount<'info>,
+    /// CHECK: Program-controlled PDA = Transfer/FreezeDelegate authority (signs CPI).
+    #[account(
+        seeds = [b"core_authority", asset.key().as_ref()],
+        bump,
+    )]
+    pub core_authority: UncheckedAccount<'info>,
     #[account(mut)]
-    pub seller_nft_token: UncheckedAccount<'info>,
-
-    /// CHECK: Seller token record — verified by Token Metadata CPI
+    pub maker: Signer<'info>,
+    /// Top-up payment mint — required when `terms.top_up > 0`
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
+    /// Registry entry for the payment mint — validated in handler
+    pub payment_mint_config: Option<Box<Account<'info, PaymentMintConfig>>>,
+    /// Source of a maker-paid top-up
     #[account(mut)]
-    pub seller_token_record: UncheckedAccount<'info>,
+    pub maker_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    /// Escrow for a maker-paid top-up — pass only when the maker pays
+    #[account(
+        init,
+        payer = maker,
+        token::mint = payment_mint,
+        token::authority = swap_payment_escrow,
+        token::token_program = token_program,
+        seeds = [b"swap_payment_escrow", swap.key().as_ref()],
+        bump,
+    )]
+    pub swap_payment_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
 
+#[derive(Accounts)]
+pub struct FillSwap<'info> {
+    #[account(
+        mut,
+        seeds = [b"swap", swap.offered_asset.as_ref()],
+        bump = swap.bump,
+        close = maker,
+    )]
+    pub swap: Box<Account<'info, Swap>>,
+    /// CHECK: Swap maker — receives the taker's asset and all rent.
+    #[account(mut, address = swap.maker)]
+    pub maker: UncheckedAccount<'info>,
     #[account(mut)]
-    pub seller: Signer<'info>,
-
-    /// CHECK: Metaplex Token Metadata program
+    pub taker: Signer<'info>,
+    /// CHECK: Escrow PDA for the offered asset — validated in handler.
+    #[account(mut)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    /// Top-up payment mint — required when the swap has a top-up
+    #[account(address = swap.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
+    #[account(
+        mut,
+        token::mint = swap.payment_mint,
+        token::authority = taker,
+    )]
+    pub taker_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    #[account(
+        mut,
+        token::mint = swap.payment_mint,
+        token::authority = maker,
+    )]
+    pub maker_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    #[account(
+        mut,
+        seeds = [b"swap_payment_escrow", swap.key().as_ref()],
+        bump = swap.payment_escrow_bump,
+    )]
+    pub swap_payment_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    pub nft_token_program: Program<'info, Token>,
+    pub nft_token_2022_program: Program<'info, Token2022>,
+    /// CHECK: Metaplex Token Metadata program.
+    #[account(address = anchor_spl::metadata::mpl_token_metadata::ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
-
-    pub token_program: Program<'info, Token>,
-
-    /// CHECK: SPL ATA program
-    pub ata_program: UncheckedAccount<'info>,
-
-    pub system_program: Program<'info, System>,
-
-    /// CHECK: Sysvar instructions
+    /// CHECK: Sysvar instructions.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub sysvar_instructions: UncheckedAccount<'info>,
-
-    /// CHECK: Optional authorization rules program
+    /// CHECK: SPL Associated Token Account program.
+    #[account(address = anchor_spl::associated_token::ID)]
+    pub ata_program: UncheckedAccount<'info>,
+    /// CHECK: Optional Metaplex authorization rules program
     pub authorization_rules_program: Option<UncheckedAccount<'info>>,
-
-    /// CHECK: Optional authorization rules account
-    pub authorization_rules: Option<UncheckedAccount<'info>>,
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct PlaceBid<'info> {
+pub struct CancelSwap<'info> {
+    #[account(
+        mut,
+        seeds = [b"swap", swap.offered_asset.as_ref()],
+        bump = swap.bump,
+        close = maker,
+    )]
+    pub swap: Box<Account<'info, Swap>>,
+    /// CHECK: Swap maker — receives the asset, any top-up and all rent.
+    #[account(mut, address = swap.maker)]
+    pub maker: UncheckedAccount<'info>,
+    /// The maker, or anyone once the swap has expired; pays any CPI fees.
     #[account(mut)]
-    pub listing: Account<'info, Listing>,
-    /// Payment mint — must match the listing's payment mint
-    #[account(constraint = payment_mint.key() == listing.payment_mint @ AuctionError::InvalidPaymentMint)]
-    pub payment_mint: Account<'info, anchor_spl::token::Mint>,
+    pub authority: Signer<'info>,
+    /// CHECK: Escrow PDA for the offered asset — validated in handler.
+    #[account(mut)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    #[account(address = swap.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, IfaceMint>>>,
     #[account(
-        init_if_needed,
-        payer = bidder,
-        token::mint = payment_mint,
-        token::authority = bid_escrow,
-        seeds = [b"bid_escrow", listing.nft_mint.as_ref()],
-        bump,
+        mut,
+        token::mint = swap.payment_mint,
+        token::authority = maker,
     )]
-    pub bid_escrow: Account<'info, TokenAccount>,
+    pub maker_payment_account: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
     #[account(
         mut,
-        token::mint = payment_mint,
-        token::authority = bidder,
+        seeds = [b"swap_payment_escrow", swap.key().as_ref()],
+        bump = swap.payment_escrow_bump,
     )]
-    pub bidder_token_account: Account<'info, TokenAccount>,
-    /// CHECK: Only used when refunding previous bidder (current_bid > 0). Validated in instruction body.
-    #[account(mut)]
-    pub previous_bidder_account: UncheckedAccount<'info>,
-    #[account(mut)]
-    pub bidder: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub swap_payment_escrow: Option<Box<InterfaceAccount<'info, IfaceTokenAccount>>>,
+    pub nft_token_program: Program<'info, Token>,
+    pub nft_token_2022_program: Program<'info, Token2022>,
+    /// CHECK: Metaplex Token Metadata program.
+    #[account(address = anchor_spl::metadata::mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    /// CHECK: Sysvar instructions.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+    /// CHECK: SPL Associated Token Account program.
+    #[account(address = anchor_spl::associated_token::ID)]
+    pub ata_program: UncheckedAccount<'info>,
+    /// CHECK: Optional Metaplex authorization rules program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
-#[derive(Accounts)]
-pub struct BuyNow<'info> {
-    #[account(mut)]
-    pub listing: Box<Account<'info, Listing>>,
-    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
-    #[account(
-        mut,
-        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
-        bump,
-        token::mint = listing.nft_mint,
-        token::token_program = nft_token_program,
-    )]
-    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
-    #[account(mut)]
-    pub buyer_payment_account: Account<'info, TokenAccount>,
-    /// Seller payment account — must be owned by listing.seller
-    #[account(mut, constraint = seller_payment_account.owner == listing.seller @ AuctionError::Unauthorized)]
-    pub seller_payment_account: Account<'info, TokenAccount>,
-    /// Treasury payment account — validated in instruction body against treasury_config or fallback
-    #[account(mut)]
-    pub treasury_payment_account: Account<'info, TokenAccount>,
-    /// CHECK: Creator payment account — validated in instruction body
-    #[account(mut)]
-    pub creator_payment_account: UncheckedAccount<'info>,
-    #[account(mut)]
-    pub buyer_nft_account: InterfaceAccount<'info, IfaceTokenAccount>,
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-    /// CHECK: Treasury wallet for rent collection. Validated in instruction body.
-    #[account(mut)]
-    pub treasury: UncheckedAccount<'info>,
-    /// Treasury config PDA — if present, overrides hardcoded treasury address
-    #[account(
-        seeds = [b"treasury_config"],
-        bump,
-    )]
-    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
-    pub nft_token_program: Interface<'info, TokenInterface>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct SwapCreated {
+    pub swap: Pubkey,
+    pub maker: Pubkey,
+    pub offered_asset: Pubkey,
+    pub offered_kind: SwapAssetKind,
+    pub want: SwapWant,
+    pub wanted: Pubkey,
+    pub attributes: Vec<AttributePredicate>,
+    pub payment_mint: Pubkey,
+    pub top_up: u64,
+    pub top_up_payer: TopUpPayer,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct SwapFilled {
+    pub swap: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub offered_asset: Pubkey,
+    pub received_asset: Pubkey,
+    pub payment_mint: Pubkey,
+    pub top_up: u64,
+    pub top_up_payer: TopUpPayer,
+}
+
+#[event]
+pub struct SwapCancelled {
+    pub swap: Pubkey,
+    pub maker: Pubkey,
+    pub offered_asset: Pubkey,
+    /// Unwound after expiry rather than cancelled by the maker
+    pub expired: bool,
+}
+
+/// Verify that a Metaplex Core asset is owned by `expected_owner` and belongs
+/// to `expected_collection`. Reads the BaseAssetV1 header directly from the
+/// account data.
+fn read_core_asset_owner_and_collection(asset_account: &AccountInfo) -> Result<(Pubkey, Pubkey)> {
+    require_keys_eq!(*asset_account.owner, mpl_core::ID, AuctionError::Unauthorized);
+    let data = asset_account.try_borrow_data()?;
+    let asset = mpl_core::accounts::BaseAssetV1::from_bytes(&data)
+        .map_err(|_| error!(AuctionError::Unauthorized))?;
+    let collection = match asset.update_authority {
+        mpl_core::types::UpdateAuthority::Collection(collection) => collection,
+        _ => return err!(AuctionError::Unauthorized),
+    };
+
+    Ok((asset.owner, collection))
+}
+
+fn verify_core_asset_ownership(
+    asset_account: &AccountInfo,
+    expected_owner: Pubkey,
+    expected_collection: Pubkey,
+) -> Result<()> {
+    let (asset_owner, asset_collection) = read_core_asset_owner_and_collection(asset_account)?;
+    require_keys_eq!(asset_owner, expected_owner, AuctionError::Unauthorized);
+    require_keys_eq!(asset_collection, expected_collection, AuctionError::Unauthorized);
+
+    Ok(())
+}
+
+fn verify_active_core_listing_owner(
+    asset_account: &AccountInfo,
+    expected_owner: Pubkey,
+    expected_collection: Pubkey,
+) -> Result<()> {
+    let (asset_owner, asset_collection) = read_core_asset_owner_and_collection(asset_account)?;
+    require_keys_eq!(asset_collection, expected_collection, AuctionError::Unauthorized);
+    require_keys_eq!(asset_owner, expected_owner, AuctionError::StaleCoreListing);
+
+    Ok(())
+}
+
+/// Pay a Core auction's whole seller bond to `destination` and close the bond
+/// escrow, rent to `rent_destination`. Returns what `destination` received.
+fn release_core_bond<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    core_bond: &InterfaceAccount<'info, IfaceTokenAccount>,
+    destination: &AccountInfo<'info>,
+    rent_destination: &AccountInfo<'info>,
+    asset: Pubkey,
+    core_bond_bump: u8,
+) -> Result<u64> {
+    let core_bond_info = core_bond.to_account_info();
+    let core_bond_seeds: &[&[u8]] = &[b"core_bond", asset.as_ref(), &[core_bond_bump]];
+    let received = transfer_payment(
+        token_program,
+        payment_mint,
+        &core_bond_info,
+        destination,
+        &core_bond_info,
+        core_bond.amount,
+        &[core_bond_seeds],
+    )?;
+    close_payment_escrow(token_program, payment_mint, &core_bond_info, rent_destination, core_bond_seeds)?;
+    Ok(received)
+}
+
+/// Return the seller bond of a Core auction closing without bids. Listings
+/// without a bond need none of the accounts.
+fn return_core_bond<'info>(
+    listing: &CoreListing,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    payment_mint: Option<&InterfaceAccount<'info, IfaceMint>>,
+    core_bond: Option<(&InterfaceAccount<'info, IfaceTokenAccount>, u8)>,
+    seller_payment_account: Option<&InterfaceAccount<'info, IfaceTokenAccount>>,
+    rent_destination: &AccountInfo<'info>,
+) -> Result<()> {
+    if listing.seller_bond == 0 {
+        return Ok(());
+    }
+    let (Some(token_program), Some(payment_mint), Some((core_bond, core_bond_bump)), Some(seller_payment_account)) =
+        (token_program, payment_mint, core_bond, seller_payment_account)
+    else {
+        return err!(AuctionError::InvalidSellerBond);
+    };
+    require_keys_eq!(seller_payment_account.owner, listing.seller, AuctionError::Unauthorized);
+
+    release_core_bond(
+        &token_program.to_account_info(),
+        &payment_mint.to_account_info(),
+        core_bond,
+        &seller_payment_account.to_account_info(),
+        rent_destination,
+        listing.asset,
+        core_bond_bump,
+    )?;
+    Ok(())
+}
+
+/// Read the Royalties plugin from a Metaplex Core asset (or fall back to the
+/// collection if absent). Returns `(basis_points, royalty_creator)`.
+///
+/// The royalty creator is the first creator listed in the plugin (matching the
+/// Artifacte mint flow which sets a single creator at 100%). Returns
+/// `(0, default_pubkey)` if no Royalties plugin exists at all (no royalty owed).
+fn read_core_royalties(
+    asset_account: &AccountInfo,
+    collection_account: &AccountInfo,
+) -> Result<(u16, Pubkey)> {
+    use mpl_core::{
+        fetch_asset_plugin,
+        fetch_collection_plugin,
+        types::{PluginType, Royalties},
+    };
+
+    if let Ok((_auth, royalties, _offset)) =
+        fetch_asset_plugin::<Royalties>(asset_account, PluginType::Royalties)
+    {
+        let creator = royalties
+            .creators
+            .first()
+            .map(|c| c.address)
+            .unwrap_or_default();
+        return Ok((royalties.basis_points, creator));
+    }
+
+    if let Ok((_auth, royalties, _offset)) =
+        fetch_collection_plugin::<Royalties>(collection_account, PluginType::Royalties)
+    {
+        let creator = royalties
+            .creators
+            .first()
+            .map(|c| c.address)
+            .unwrap_or_default();
+        return Ok((royalties.basis_points, creator));
+    }
+
+    Ok((0u16, Pubkey::default()))
+}
+
+/// Read the Attributes plugin from a Metaplex Core asset. An asset without the
+/// plugin has no attributes.
+fn read_core_attributes(asset_account: &AccountInfo) -> Result<Vec<mpl_core::types::Attribute>> {
+    use mpl_core::{
+        fetch_asset_plugin,
+        types::{Attributes, PluginType},
+    };
+
+    match fetch_asset_plugin::<Attributes>(asset_account, PluginType::Attributes) {
+        Ok((_auth, attributes, _offset)) => Ok(attributes.attribute_list),
+        Err(error)
+            if error.kind() == std::io::ErrorKind::Other
+                && (error.to_string()
+                    == mpl_core::errors::MplCoreError::PluginNotFound.to_string()
+                    || error.to_string()
+                        == mpl_core::errors::MplCoreError::PluginsNotInitialized.to_string()) =>
+        {
+            Ok(Vec::new())
+        }
+        Err(_error) => Err(error!(AuctionError::InvalidCorePluginState)),
+    }
+}
+
+/// Core sale split at `price` (offers, bundles), with `creator_payment_account`
+/// checked against the on-chain Royalties plugin.
+fn core_royalty_sale_split(
+    asset: &AccountInfo,
+    collection: &AccountInfo,
+    price: u64,
+    payment_mint: Pubkey,
+    creator_payment_account: Pubkey,
+) -> Result<SaleSplit> {
+    let (royalty_bps, royalty_creator) = read_core_royalties(asset, collection)?;
+    let core_split = calculate_core_sale_split(price, collection.key(), royalty_bps)?;
+    validate_creator_ata(
+        royalty_creator,
+        payment_mint,
+        creator_payment_account,
+        core_split.creator_royalty,
+    )?;
+    Ok(SaleSplit {
+        platform_fee: core_split.platform_fee,
+        baxus_fee: 0,
+        creator_royalty: core_split.creator_royalty,
+        seller_amount: core_split.seller_amount,
+    })
 }
 
-#[derive(Accounts)]
-pub struct CancelListing<'info> {
-    #[account(mut)]
-    pub listing: Account<'info, Listing>,
-    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
-    #[account(
-        mut,
-        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
-        bump,
-        token::mint = listing.nft_mint,
-        token::token_program = nft_token_program,
-    )]
-    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
-    #[account(mut)]
-    pub seller_nft_account: InterfaceAccount<'info, IfaceTokenAccount>,
-    pub seller: Signer<'info>,
-    pub nft_token_program: Interface<'info, TokenInterface>,
+/// Move a Core asset to `new_owner` with `authority` (the owner, or a
+/// delegate PDA signing with `signer_seeds`).
+#[allow(clippy::too_many_arguments)]
+fn transfer_core_asset<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    collection: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    new_owner: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    mpl_core::instructions::TransferV1Cpi {
+        __program: mpl_core_program,
+        asset,
+        collection: Some(collection),
+        payer,
+        authority: Some(authority),
+        new_owner,
+        system_program: Some(system_program),
+        log_wrapper: None,
+        __args: mpl_core::instructions::TransferV1InstructionArgs {
+            compression_proof: None,
+        },
+    }
+    .invoke_signed(signer_seeds)?;
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct CloseStaleListing<'info> {
-    #[account(mut)]
-    pub listing: Account<'info, Listing>,
-    pub nft_mint: InterfaceAccount<'info, IfaceMint>,
-    #[account(
-        mut,
-        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
-        bump,
-        token::mint = listing.nft_mint,
-        token::token_program = nft_token_program,
-    )]
-    pub escrow_nft: InterfaceAccount<'info, IfaceTokenAccount>,
-    #[account(mut)]
-    pub seller: Signer<'info>,
-    pub nft_token_program: Interface<'info, TokenInterface>,
+/// Append-time checks for a bundle item: the bundle is still being built, has
+/// room, and does not hold `asset` already.
+fn ensure_bundle_accepts(bundle: &Bundle, asset: Pubkey) -> Result<()> {
+    require!(!bundle.open, AuctionError::InvalidBundle);
+    require!(bundle.items.len() < MAX_BUNDLE_ITEMS, AuctionError::InvalidBundle);
+    require!(
+        bundle.items.iter().all(|item| item.asset != asset),
+        AuctionError::InvalidBundle
+    );
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct SettleAuction<'info> {
-    #[account(mut)]
-    pub listing: Box<Account<'info, Listing>>,
-    pub nft_mint: Box<InterfaceAccount<'info, IfaceMint>>,
-    #[account(
-        mut,
-        seeds = [b"bid_escrow", listing.nft_mint.as_ref()],
-        bump,
-        token::mint = listing.payment_mint,
-    )]
-    pub bid_escrow: Box<Account<'info, TokenAccount>>,
-    #[account(
-        mut,
-        seeds = [b"escrow_nft", listing.nft_mint.as_ref()],
-        bump,
-        token::mint = listing.nft_mint,
-        token::token_program = nft_token_program,
-    )]
-    pub escrow_nft: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
-    /// Seller's payment token account — must be owned by listing.seller
-    #[account(mut, constraint = seller_payment_account.owner == listing.seller @ AuctionError::Unauthorized)]
-    pub seller_payment_account: Box<Account<'info, TokenAccount>>,
-    /// Treasury payment account — validated in instruction body against treasury_config or fallback
-    #[account(mut)]
-    pub treasury_payment_account: Box<Account<'info, TokenAccount>>,
-    /// CHECK: Creator payment account — validated in instruction body if royalty > 0
-    #[account(mut)]
-    pub creator_payment_account: UncheckedAccount<'info>,
-    /// Buyer NFT account — must be owned by highest bidder (or seller if no bids for return)
-    #[account(mut)]
-    pub buyer_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
-    /// Seller NFT account — must be owned by listing.seller (for no-bid return)
-    #[account(mut)]
-    pub seller_nft_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
-    /// CHECK: The original seller, validated against listing.seller.
-    #[account(mut, constraint = seller.key() == listing.seller)]
-    pub seller: UncheckedAccount<'info>,
-    /// CHECK: Treasury wallet for rent collection on sales. Validated in instruction body.
-    #[account(mut)]
-    pub treasury: UncheckedAccount<'info>,
-    /// Treasury config PDA — if present, overrides hardcoded treasury address
-    #[account(
-        seeds = [b"treasury_config"],
-        bump,
-    )]
-    pub treasury_config: Option<Account<'info, TreasuryConfig>>,
-    pub nft_token_program: Interface<'info, TokenInterface>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
+/// Programs (and the shared Core collection) bundle item transfers CPI into.
+struct BundleItemPrograms<'info> {
+    nft_token_program: AccountInfo<'info>,
+    nft_token_2022_program: AccountInfo<'info>,
+    mpl_core_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    /// Artifacte collection — required when the bundle holds Core items
+    collection: Option<AccountInfo<'info>>,
 }
 
-// ============================================================================
-// State
-// ============================================================================
+impl<'info> BundleItemPrograms<'info> {
+    fn collection(&self) -> Result<&AccountInfo<'info>> {
+        self.collection
+            .as_ref()
+            .ok_or(error!(AuctionError::InvalidBundleAccounts))
+    }
+}
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum ListingType {
-    FixedPrice,
-    Auction,
+/// Split `remaining_accounts` into one group per bundle item (layout in the
+/// Bundles section) plus the trailing transfer-hook accounts. Each group's
+/// mint / asset and escrow / `core_authority` PDA are checked against the item.
+fn bundle_item_accounts<'a, 'info>(
+    bundle: Pubkey,
+    items: &[BundleItem],
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(Vec<&'a [AccountInfo<'info>]>, &'a [AccountInfo<'info>])> {
+    let mut groups = Vec::with_capacity(items.len());
+    let mut rest = remaining_accounts;
+    for item in items {
+        let len = if item.kind == BundleItemKind::Core { 3 } else { 4 };
+        require!(rest.len() >= len, AuctionError::InvalidBundleAccounts);
+        let (group, tail) = rest.split_at(len);
+        require_keys_eq!(group[0].key(), item.asset, AuctionError::InvalidBundleAccounts);
+        let expected = match item.kind {
+            BundleItemKind::Core => Pubkey::create_program_address(
+                &[b"core_authority", item.asset.as_ref(), &[item.bump]],
+                &crate::ID,
+            ),
+            BundleItemKind::Spl | BundleItemKind::Token2022 => Pubkey::create_program_address(
+                &[b"bundle_escrow", bundle.as_ref(), item.asset.as_ref(), &[item.bump]],
+                &crate::ID,
+            ),
+        }
+        .map_err(|_| error!(AuctionError::InvalidBundleAccounts))?;
+        require_keys_eq!(group[1].key(), expected, AuctionError::InvalidBundleAccounts);
+        groups.push(group);
+        rest = tail;
+    }
+    Ok((groups, rest))
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum ItemCategory {
-    DigitalArt,
-    Spirits,
-    TCGCards,
-    SportsCards,
-    Watches,
+/// WNS layout of each bundle item (`None` for other items), read from the
+/// front of the trailing transfer-hook accounts in bundle order.
+fn bundle_wns_accounts<'a, 'info>(
+    groups: &[&'a [AccountInfo<'info>]],
+    hook_accounts: &'a [AccountInfo<'info>],
+) -> Result<Vec<Option<WnsAccounts<'a, 'info>>>> {
+    let assets: Vec<&AccountInfo<'info>> = groups.iter().map(|group| &group[0]).collect();
+    wns_accounts_each(&assets, hook_accounts)
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum ListingStatus {
-    Active,
-    Settled,
-    Cancelled,
+/// Escrowed items are always deliverable; Core items only while the seller
+/// still owns them with `core_authority` as TransferDelegate.
+fn bundle_items_deliverable(items: &[BundleItem], groups: &[&[AccountInfo]], seller: Pubkey) -> Result<bool> {
+    for (item, group) in items.iter().zip(groups) {
+        if item.kind != BundleItemKind::Core {
+            continue;
+        }
+        let (asset_owner, _collection) = read_core_asset_owner_and_collection(&group[0])?;
+        let delegated = matches!(
+            read_core_transfer_delegate_state(&group[0])?,
+            CoreDelegateState::Address(address) if address == group[1].key()
+        );
+        if asset_owner != seller || !delegated {
+            return Ok(false);
+        }
+    }
+    Ok(true)
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct Listing {
-    pub seller: Pubkey,
-    pub nft_mint: Pubkey,
-    pub payment_mint: Pubkey,
-    pub price: u64,
-    pub listing_type: ListingType,
-    pub category: ItemCategory,
-    pub start_time: i64,
-    pub end_time: i64,
-    pub status: ListingStatus,
-    pub escrow_nft_account: Pubkey,
-    pub current_bid: u64,
-    pub highest_bidder: Pubkey,
-    pub baxus_fee: bool,
-    pub is_token2022: bool,
-    pub is_pnft: bool,
-    pub royalty_basis_points: u16,
-    pub creator_address: Pubkey,
-    pub bump: u8,
+/// Bundle sale split summed over items, keeping each item's royalty with the
+/// creator payment account it is owed to.
+struct BundleSplit<'a, 'info> {
+    platform_fee: u64,
+    seller_amount: u64,
+    royalties: Vec<(&'a AccountInfo<'info>, u64)>,
+    /// Royalty on WNS items, which WNS collects itself on approval
+    wns_royalty: u64,
 }
 
-// ============================================================================
-// Events
-// ============================================================================
+/// Split `price` across the bundle's items by weight, then price each item
+/// like a single sale: `calculate_sale_split` with the seller-declared royalty
+/// for SPL / Token-2022 items (the metadata royalty for WNS items), the
+/// on-chain Royalties plugin for Core items.
+fn bundle_sale_split<'a, 'info>(
+    bundle: &Bundle,
+    price: u64,
+    groups: &[&'a [AccountInfo<'info>]],
+    wns: &[Option<WnsAccounts<'_, 'info>>],
+    programs: &BundleItemPrograms<'info>,
+) -> Result<BundleSplit<'a, 'info>> {
+    let weights: Vec<u16> = bundle.items.iter().map(|item| item.weight).collect();
+    let item_prices = bundle_item_prices(price, &weights)?;
+
+    let mut split = BundleSplit {
+        platform_fee: 0,
+        seller_amount: 0,
+        royalties: Vec::new(),
+        wns_royalty: 0,
+    };
+    for (((item, group), wns), item_price) in bundle.items.iter().zip(groups).zip(wns).zip(item_prices) {
+        let creator_payment_account = &group[group.len() - 1];
+        let item_split = match item.kind {
+            BundleItemKind::Core => core_royalty_sale_split(
+                &group[0],
+                programs.collection()?,
+                item_price,
+                bundle.payment_mint,
+                creator_payment_account.key(),
+            )?,
+            BundleItemKind::Token2022 if wns.is_some() => {
+                calculate_sale_split(item_price, false, read_wns_royalty_bps(&group[0])?)?
+            }
+            BundleItemKind::Spl | BundleItemKind::Token2022 => {
+                let item_split = calculate_sale_split(item_price, false, item.royalty_basis_points)?;
+                validate_creator_ata(
+                    item.creator_address,
+                    bundle.payment_mint,
+                    creator_payment_account.key(),
+                    item_split.creator_royalty,
+                )?;
+                item_split
+            }
+        };
+        split.platform_fee = split
+            .platform_fee
+            .checked_add(item_split.treasury_amount()?)
+            .ok_or(AuctionError::CalculationError)?;
+        split.seller_amount = split
+            .seller_amount
+            .checked_add(item_split.seller_amount)
+            .ok_or(AuctionError::CalculationError)?;
+        if wns.is_some() {
+            split.wns_royalty = split
+                .wns_royalty
+                .checked_add(item_split.creator_royalty)
+                .ok_or(AuctionError::CalculationError)?;
+        } else if item_split.creator_royalty > 0 {
+            split.royalties.push((creator_payment_account, item_split.creator_royalty));
+        }
+    }
+    Ok(split)
+}
 
-#[event]
-pub struct ListingCreated {
-    pub nft_mint: Pubkey,
-    pub seller: Pubkey,
-    pub listing_type: ListingType,
-    pub price: u64,
-    pub category: ItemCategory,
-    pub end_time: i64,
-    pub payment_mint: Pubkey,
+/// Approve each WNS item's move for its share of `price`, WNS taking its
+/// royalty from `payment`. Made before the items leave escrow.
+#[allow(clippy::too_many_arguments)]
+fn approve_bundle_wns_sale<'info>(
+    bundle: &Bundle,
+    price: u64,
+    groups: &[&[AccountInfo<'info>]],
+    wns: &[Option<WnsAccounts<'_, 'info>>],
+    payer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    payment: WnsPayment<'_, 'info>,
+    programs: &BundleItemPrograms<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let weights: Vec<u16> = bundle.items.iter().map(|item| item.weight).collect();
+    let item_prices = bundle_item_prices(price, &weights)?;
+    for ((group, wns), item_price) in groups.iter().zip(wns).zip(item_prices) {
+        if let Some(wns) = wns {
+            approve_wns_transfer(
+                wns,
+                payer,
+                authority,
+                &group[0],
+                &programs.nft_token_2022_program,
+                item_price,
+                Some(payment),
+                signer_seeds,
+            )?;
+        }
+    }
+    Ok(())
 }
 
-#[event]
-pub struct BidPlaced {
-    pub nft_mint: Pubkey,
-    pub bidder: Pubkey,
-    pub amount: u64,
-    pub timestamp: i64,
+/// Pay a bundle sale out of `source`: one leg each to seller and treasury,
+/// plus one royalty leg per item that owes one.
+#[allow(clippy::too_many_arguments)]
+fn pay_bundle_split<'info>(
+    token_program: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    seller_payment_account: &AccountInfo<'info>,
+    treasury_payment_account: &AccountInfo<'info>,
+    split: &BundleSplit<'_, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<SalePayout> {
+    let pay = |to: &AccountInfo<'info>, amount: u64| {
+        transfer_payment(token_program, payment_mint, source, to, authority, amount, signer_seeds)
+    };
+
+    let mut creator_royalty = 0u64;
+    for (creator_payment_account, royalty) in &split.royalties {
+        creator_royalty = creator_royalty
+            .checked_add(pay(creator_payment_account, *royalty)?)
+            .ok_or(AuctionError::CalculationError)?;
+    }
+    Ok(SalePayout {
+        seller_amount: pay(seller_payment_account, split.seller_amount)?,
+        treasury_amount: pay(treasury_payment_account, split.platform_fee)?,
+        creator_royalty,
+    })
 }
 
-#[event]
-pub struct ItemPurchased {
-    pub nft_mint: Pubkey,
-    pub seller: Pubkey,
-    pub buyer: Pubkey,
-    pub price: u64,
-    pub platform_fee: u64,
-    pub creator_royalty: u64,
+/// NFT token account `account` must hold `mint` for `owner`.
+fn validate_nft_destination(account: &AccountInfo, mint: Pubkey, owner: Pubkey) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    let state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    require!(
+        state.base.mint == mint && state.base.owner == owner,
+        AuctionError::InvalidBundleAccounts
+    );
+    Ok(())
 }
 
-#[event]
-pub struct AuctionSettled {
-    pub nft_mint: Pubkey,
-    pub winner: Pubkey,
-    pub price: u64,
-    pub platform_fee: u64,
+/// Move an escrowed bundle NFT to `recipient`'s token account, then close the
+/// escrow with its rent to the seller. With `wns`, the escrow approves the
+/// move for 0 first (returns; sales are approved by `approve_bundle_wns_sale`).
+#[allow(clippy::too_many_arguments)]
+fn release_bundle_escrow<'info>(
+    bundle: Pubkey,
+    item: &BundleItem,
+    group: &[AccountInfo<'info>],
+    hook_accounts: &[AccountInfo<'info>],
+    wns: Option<&WnsAccounts<'_, 'info>>,
+    recipient: Pubkey,
+    seller: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    programs: &BundleItemPrograms<'info>,
+) -> Result<()> {
+    validate_nft_destination(&group[2], item.asset, recipient)?;
+    let is_token2022 = item.kind == BundleItemKind::Token2022;
+    let nft_token_program = if is_token2022 {
+        &programs.nft_token_2022_program
+    } else {
+        &programs.nft_token_program
+    };
+    let escrow_seeds: &[&[u8]] = &[b"bundle_escrow", bundle.as_ref(), item.asset.as_ref(), &[item.bump]];
+    if let Some(wns) = wns {
+        approve_wns_transfer(wns, payer, &group[1], &group[0], nft_token_program, 0, None, &[escrow_seeds])?;
+    }
+    transfer_nft_from_escrow(
+        is_token2022,
+        nft_token_program,
+        &group[1],
+        &group[0],
+        &group[2],
+        hook_accounts,
+        escrow_seeds,
+    )?;
+    close_token_account_cpi(nft_token_program, &group[1], seller, &group[1], &[escrow_seeds])
 }
 
-#[event]
-pub struct ListingCancelled {
-    pub nft_mint: Pubkey,
-    pub seller: Pubkey,
+/// Deliver every bundle item to `recipient`: escrowed NFTs leave escrow, Core
+/// assets are thawed and transferred by their `core_authority`.
+#[allow(clippy::too_many_arguments)]
+fn deliver_bundle_items<'info>(
+    bundle: Pubkey,
+    items: &[BundleItem],
+    groups: &[&[AccountInfo<'info>]],
+    hook_accounts: &[AccountInfo<'info>],
+    recipient: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    programs: &BundleItemPrograms<'info>,
+) -> Result<()> {
+    for (item, group) in items.iter().zip(groups) {
+        match item.kind {
+            BundleItemKind::Core => {
+                let core_authority_seeds: &[&[u8]] = &[b"core_authority", item.asset.as_ref(), &[item.bump]];
+                set_core_asset_frozen(
+                    &programs.mpl_core_program,
+                    &group[0],
+                    programs.collection()?,
+                    payer,
+                    &group[1],
+                    &programs.system_program,
+                    false,
+                    core_authority_seeds,
+                )?;
+                transfer_core_asset(
+                    &programs.mpl_core_program,
+                    &group[0],
+                    programs.collection()?,
+                    payer,
+                    &group[1],
+                    recipient,
+                    &programs.system_program,
+                    &[core_authority_seeds],
+                )?;
+            }
+            BundleItemKind::Spl | BundleItemKind::Token2022 => {
+                // WNS items were approved for the sale by `approve_bundle_wns_sale`
+                release_bundle_escrow(
+                    bundle,
+                    item,
+                    group,
+                    hook_accounts,
+                    None,
+                    recipient.key(),
+                    seller,
+                    payer,
+                    programs,
+                )?;
+            }
+        }
+    }
+    Ok(())
 }
 
-#[event]
-pub struct AuctionCancelled {
-    pub nft_mint: Pubkey,
-    pub reason: String,
+/// Hand every bundle item back to the seller: escrowed NFTs return, Core
+/// assets still held by the seller are thawed and both delegates revoked.
+#[allow(clippy::too_many_arguments)]
+fn return_bundle_items<'info>(
+    bundle: Pubkey,
+    items: &[BundleItem],
+    groups: &[&[AccountInfo<'info>]],
+    hook_accounts: &[AccountInfo<'info>],
+    wns: &[Option<WnsAccounts<'_, 'info>>],
+    seller: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    programs: &BundleItemPrograms<'info>,
+) -> Result<()> {
+    for ((item, group), wns) in items.iter().zip(groups).zip(wns) {
+        match item.kind {
+            BundleItemKind::Core => {
+                let (asset_owner, _collection) = read_core_asset_owner_and_collection(&group[0])?;
+                if asset_owner != seller.key() {
+                    continue;
+                }
+                let core_authority_seeds: &[&[u8]] = &[b"core_authority", item.asset.as_ref(), &[item.bump]];
+                let core_authority = group[1].key();
+                if matches!(
+                    read_core_freeze_delegate_state(&group[0])?,
+                    CoreDelegateState::Address(address) if address == core_authority
+                ) {
+                    set_core_asset_frozen(
+                        &programs.mpl_core_program,
+                        &group[0],
+                        programs.collection()?,
+                        payer,
+                        &group[1],
+                        &programs.system_program,
+                        false,
+                        core_authority_seeds,
+                    )?;
+                    revoke_core_delegate(
+                        &programs.mpl_core_program,
+                        &group[0],
+                        programs.collection()?,
+                        payer,
+                        &group[1],
+                        &programs.system_program,
+                        mpl_core::types::PluginType::FreezeDelegate,
+                        core_authority_seeds,
+                    )?;
+                }
+                if matches!(
+                    read_core_transfer_delegate_state(&group[0])?,
+                    CoreDelegateState::Address(address) if address == core_authority
+                ) {
+                    revoke_core_delegate(
+                        &programs.mpl_core_program,
+                        &group[0],
+                        programs.collection()?,
+                        payer,
+                        &group[1],
+                        &programs.system_program,
+                        mpl_core::types::PluginType::TransferDelegate,
+                        core_authority_seeds,
+                    )?;
+                }
+            }
+            BundleItemKind::Spl | BundleItemKind::Token2022 => {
+                release_bundle_escrow(
+                    bundle,
+                    item,
+                    group,
+                    hook_accounts,
+                    wns.as_ref(),
+                    seller.key(),
+                    seller,
+                    payer,
+                    programs,
+                )?;
+            }
+        }
+    }
+    Ok(())
 }
 
-// ============================================================================
-// Treasury Config
-// ============================================================================
-
-#[account]
-#[derive(InitSpace)]
-pub struct TreasuryConfig {
-    pub treasury: Pubkey,
-    pub authority: Pubkey,
-    pub bump: u8,
+/// `core_authority` gives up its own delegate plugin authority on a Core asset.
+#[allow(clippy::too_many_arguments)]
+fn revoke_core_delegate<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    collection: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    core_authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    plugin_type: mpl_core::types::PluginType,
+    core_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    mpl_core::instructions::RevokePluginAuthorityV1Cpi {
+        __program: mpl_core_program,
+        asset,
+        collection: Some(collection),
+        payer,
+        authority: Some(core_authority),
+        system_program,
+        log_wrapper: None,
+        __args: mpl_core::instructions::RevokePluginAuthorityV1InstructionArgs { plugin_type },
+    }
+    .invoke_signed(&[core_authority_seeds])?;
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct InitializeTreasury<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + TreasuryConfig::INIT_SPACE,
-        seeds = [b"treasury_config"],
-        bump,
-    )]
-    pub treasury_config: Account<'info, TreasuryConfig>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
+/// Programs the swap asset legs CPI into.
+struct SwapPrograms<'info> {
+    nft_token_program: AccountInfo<'info>,
+    nft_token_2022_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+    sysvar_instructions: AccountInfo<'info>,
+    ata_program: AccountInfo<'info>,
+    authorization_rules_program: Option<AccountInfo<'info>>,
+    mpl_core_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
 }
 
-#[derive(Accounts)]
-pub struct UpdateTreasury<'info> {
-    #[account(
-        mut,
-        seeds = [b"treasury_config"],
-        bump = treasury_config.bump,
-    )]
-    pub treasury_config: Account<'info, TreasuryConfig>,
-    pub authority: Signer<'info>,
+impl<'info> SwapPrograms<'info> {
+    fn nft_token_program(&self, kind: SwapAssetKind) -> &AccountInfo<'info> {
+        if kind == SwapAssetKind::Token2022 {
+            &self.nft_token_2022_program
+        } else {
+            &self.nft_token_program
+        }
+    }
 }
 
-#[event]
-pub struct TreasuryUpdated {
-    pub old_treasury: Pubkey,
-    pub new_treasury: Pubkey,
-    pub timestamp: i64,
+/// Split off one swap asset leg (layout in the Swaps section) from `remaining_accounts`.
+fn swap_leg_accounts<'a, 'info>(
+    kind: SwapAssetKind,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    let len = match kind {
+        SwapAssetKind::Spl | SwapAssetKind::Token2022 => 4,
+        SwapAssetKind::Pnft => 8,
+        SwapAssetKind::Core => 2,
+    };
+    require!(remaining_accounts.len() >= len, AuctionError::InvalidSwapAccounts);
+    Ok(remaining_accounts.split_at(len))
 }
 
-// ============================================================================
-// Errors
-// ============================================================================
+/// Seed prefix of the PDA holding a swap's escrowed asset: the escrow token
+/// account itself, the pNFT escrow owner, or the Core asset's delegate.
+fn swap_escrow_seed(kind: SwapAssetKind) -> &'static [u8] {
+    match kind {
+        SwapAssetKind::Spl | SwapAssetKind::Token2022 => b"swap_escrow",
+        SwapAssetKind::Pnft => b"swap_authority",
+        SwapAssetKind::Core => b"core_authority",
+    }
+}
 
-#[error_code]
-pub enum AuctionError {
-    #[msg("Listing is not active")]
-    ListingNotActive,
-    #[msg("Auction has already ended")]
-    AuctionEnded,
-    #[msg("Auction has not ended yet")]
-    AuctionNotEnded,
-    #[msg("Bid is too low")]
-    BidTooLow,
-    #[msg("Calculation error")]
-    CalculationError,
-    #[msg("Unauthorized")]
-    Unauthorized,
-    #[msg("Cannot cancel auction with existing bids")]
-    CannotCancelWithBids,
-    #[msg("Invalid listing type for this operation")]
-    NotAnAuction,
-    #[msg("Invalid listing type for this operation")]
-    NotFixedPrice,
-    #[msg("Invalid duration for auction")]
-    InvalidDuration,
-    #[msg("Invalid payment mint for this category")]
-    InvalidPaymentMint,
-    #[msg("Price must be greater than zero")]
-    InvalidPrice,
-    #[msg("Token-2022 transfer with hook failed")]
-    TransferFailed,
-    #[msg("Seller cannot bid on their own auction")]
-    SellerCannotBid,
-    #[msg("Invalid refund account — must be previous bidder's ATA")]
-    InvalidRefundAccount,
-    #[msg("Royalty basis points too high (max 1000 = 10%)")]
-    RoyaltyTooHigh,
-    #[msg("Invalid buyer account — must be owned by highest bidder")]
-    InvalidBuyerAccount,
-    #[msg("Invalid creator account — must be creator's ATA for payment mint")]
-    InvalidCreatorAccount,
-    #[msg("Invalid token program — must be SPL Token or Token-2022")]
-    InvalidTokenProgram,
-    #[msg("Invalid royalty basis points — must be 0 or >= 100 (1%)")]
-    InvalidRoyaltyBps,
-    #[msg("Invalid Metaplex Core plugin state")]
-    InvalidCorePluginState,
-    #[msg("Core listing is stale because the seller no longer owns the asset")]
-    StaleCoreListing,
-    #[msg("Core listing is not stale")]
-    CoreListingNotStale,
+fn swap_escrow_authority(swap: &Swap) -> Result<Pubkey> {
+    Pubkey::create_program_address(
+        &[swap_escrow_seed(swap.offered_kind), swap.offered_asset.as_ref(), &[swap.escrow_bump]],
+        &crate::ID,
+    )
+    .map_err(|_| error!(AuctionError::InvalidSwapAccounts))
 }
 
-// ============================================================================
-// Metaplex Core (Artifacte v2) — accounts, state, events, helpers, errors
-// ============================================================================
+/// Verified Metaplex collection of an SPL / Token-2022 / pNFT mint, if any.
+fn read_verified_collection(mint: &AccountInfo, metadata: &AccountInfo) -> Result<Option<Pubkey>> {
+    use anchor_spl::metadata::mpl_token_metadata::accounts::Metadata;
 
-#[derive(Accounts)]
-pub struct ListCoreItem<'info> {
-    /// Current holder-signed.
-    #[account(mut)]
-    pub seller: Signer<'info>,
+    require_keys_eq!(
+        metadata.key(),
+        Metadata::find_pda(&mint.key()).0,
+        AuctionError::InvalidSwapAccounts
+    );
+    require_keys_eq!(
+        *metadata.owner,
+        anchor_spl::metadata::mpl_token_metadata::ID,
+        AuctionError::InvalidSwapAccounts
+    );
+    let metadata = Metadata::try_from(metadata).map_err(|_| error!(AuctionError::InvalidSwapAccounts))?;
+    Ok(metadata
+        .collection
+        .filter(|collection| collection.verified)
+        .map(|collection| collection.key))
+}
 
-    /// CHECK: Metaplex Core asset. Validated in handler against owner + collection.
-    #[account(mut)]
-    pub asset: UncheckedAccount<'info>,
+/// The taker's asset must be the wanted asset, or a 1/1 of the wanted
+/// collection matching every attribute predicate (Core only).
+fn ensure_swap_wants(swap: &Swap, kind: SwapAssetKind, leg: &[AccountInfo]) -> Result<()> {
+    match swap.want {
+        SwapWant::Asset => require_keys_eq!(leg[0].key(), swap.wanted, AuctionError::SwapMismatch),
+        SwapWant::Collection if kind == SwapAssetKind::Core => {
+            let (_owner, collection) = read_core_asset_owner_and_collection(&leg[0])?;
+            require_keys_eq!(collection, swap.wanted, AuctionError::SwapMismatch);
+            require!(
+                attributes_match(&read_core_attributes(&leg[0])?, &swap.attributes),
+                AuctionError::AttributeMismatch
+            );
+        }
+        SwapWant::Collection => {
+            require!(swap.attributes.is_empty(), AuctionError::AttributeMismatch);
+            {
+                let data = leg[0].try_borrow_data()?;
+                let mint =
+                    spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+                require!(
+                    mint.base.decimals == 0 && mint.base.supply == 1,
+                    AuctionError::SwapMismatch
+                );
+            }
+            require!(
+                read_verified_collection(&leg[0], &leg[3])? == Some(swap.wanted),
+                AuctionError::SwapMismatch
+            );
+        }
+    }
+    Ok(())
+}
 
-    /// CHECK: Metaplex Core collection. Pubkey validated against ARTIFACTE_COLLECTION_ID in handler.
-    #[account(mut)]
-    pub collection: UncheckedAccount<'info>,
+/// Move the taker's asset from their wallet to `recipient`; `owner` signs
+/// (and approves a WNS move).
+fn transfer_swap_asset<'info>(
+    kind: SwapAssetKind,
+    leg: &[AccountInfo<'info>],
+    hook_accounts: &[AccountInfo<'info>],
+    wns: Option<&WnsAccounts<'_, 'info>>,
+    owner: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    programs: &SwapPrograms<'info>,
+) -> Result<()> {
+    match kind {
+        SwapAssetKind::Spl | SwapAssetKind::Token2022 => {
+            validate_nft_destination(&leg[2], leg[0].key(), recipient.key())?;
+            if let Some(wns) = wns {
+                let nft_token_program = programs.nft_token_program(kind);
+                approve_wns_transfer(wns, owner, owner, &leg[0], nft_token_program, 0, None, &[])?;
+            }
+            transfer_nft_from_owner(
+                kind == SwapAssetKind::Token2022,
+                programs.nft_token_program(kind),
+                &leg[1],
+                &leg[0],
+                &leg[2],
+                owner,
+                hook_accounts,
+            )
+        }
+        SwapAssetKind::Pnft => transfer_pnft(
+            &programs.token_metadata_program,
+            &leg[1],
+            owner,
+            &leg[2],
+            recipient,
+            &leg[0],
+            &leg[3],
+            &leg[4],
+            &leg[5],
+            &leg[6],
+            owner,
+            owner,
+            &programs.system_program,
+            &programs.sysvar_instructions,
+            &programs.nft_token_program,
+            &programs.ata_program,
+            programs.authorization_rules_program.as_ref(),
+            (leg[7].key() != crate::ID).then_some(&leg[7]),
+            &[],
+        ),
+        SwapAssetKind::Core => transfer_core_asset(
+            &programs.mpl_core_program,
+            &leg[0],
+            &leg[1],
+            owner,
+            owner,
+            recipient,
+            &programs.system_program,
+            &[],
+        ),
+    }
+}
 
-    /// USDC mint (validated in handler).
-    pub payment_mint: Account<'info, anchor_spl::token::Mint>,
+/// Release the maker's escrowed asset to `recipient`, signed by its escrow
+/// PDA. A Core asset is thawed first; returned to the maker, it just drops the
+/// program's delegates. SPL escrow rent goes back to the maker. A WNS move is
+/// approved by the escrow PDA.
+#[allow(clippy::too_many_arguments)]
+fn release_swap_asset<'info>(
+    swap: &Swap,
+    leg: &[AccountInfo<'info>],
+    hook_accounts: &[AccountInfo<'info>],
+    wns: Option<&WnsAccounts<'_, 'info>>,
+    escrow_authority: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    maker: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    programs: &SwapPrograms<'info>,
+) -> Result<()> {
+    require_keys_eq!(leg[0].key(), swap.offered_asset, AuctionError::InvalidSwapAccounts);
+    let kind = swap.offered_kind;
+    let escrow_seeds: &[&[u8]] = &[swap_escrow_seed(kind), swap.offered_asset.as_ref(), &[swap.escrow_bump]];
+
+    match kind {
+        SwapAssetKind::Spl | SwapAssetKind::Token2022 => {
+            require_keys_eq!(leg[1].key(), escrow_authority.key(), AuctionError::InvalidSwapAccounts);
+            validate_nft_destination(&leg[2], swap.offered_asset, recipient.key())?;
+            let nft_token_program = programs.nft_token_program(kind);
+            if let Some(wns) = wns {
+                approve_wns_transfer(
+                    wns,
+                    payer,
+                    escrow_authority,
+                    &leg[0],
+                    nft_token_program,
+                    0,
+                    None,
+                    &[escrow_seeds],
+                )?;
+            }
+            transfer_nft_from_escrow(
+                kind == SwapAssetKind::Token2022,
+                nft_token_program,
+                &leg[1],
+                &leg[0],
+                &leg[2],
+                hook_accounts,
+                escrow_seeds,
+            )?;
+            close_token_account_cpi(nft_token_program, &leg[1], maker, &leg[1], &[escrow_seeds])
+        }
+        SwapAssetKind::Pnft => transfer_pnft(
+            &programs.token_metadata_program,
+            &leg[1],
+            escrow_authority,
+            &leg[2],
+            recipient,
+            &leg[0],
+            &leg[3],
+            &leg[4],
+            &leg[5],
+            &leg[6],
+            escrow_authority,
+            payer,
+            &programs.system_program,
+            &programs.sysvar_instructions,
+            &programs.nft_token_program,
+            &programs.ata_program,
+            programs.authorization_rules_program.as_ref(),
+            (leg[7].key() != crate::ID).then_some(&leg[7]),
+            &[escrow_seeds],
+        ),
+        SwapAssetKind::Core => {
+            set_core_asset_frozen(
+                &programs.mpl_core_program,
+                &leg[0],
+                &leg[1],
+                payer,
+                escrow_authority,
+                &programs.system_program,
+                false,
+                escrow_seeds,
+            )?;
+            if recipient.key() != maker.key() {
+                return transfer_core_asset(
+                    &programs.mpl_core_program,
+                    &leg[0],
+                    &leg[1],
+                    payer,
+                    escrow_authority,
+                    recipient,
+                    &programs.system_program,
+                    &[escrow_seeds],
+                );
+            }
+            for plugin_type in [
+                mpl_core::types::PluginType::FreezeDelegate,
+                mpl_core::types::PluginType::TransferDelegate,
+            ] {
+                revoke_core_delegate(
+                    &programs.mpl_core_program,
+                    &leg[0],
+                    &leg[1],
+                    payer,
+                    escrow_authority,
+                    &programs.system_program,
+                    plugin_type,
+                    escrow_seeds,
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
 
-    /// CoreListing PDA — created on list, closed on cancel/buy.
-    #[account(
-        init,
-        payer = seller,
-        space = 8 + CoreListing::INIT_SPACE,
-        seeds = [b"core_listing", asset.key().as_ref()],
-        bump,
-    )]
-    pub core_listing: Account<'info, CoreListing>,
+enum CoreDelegateState {
+    Missing,
+    Address(Pubkey),
+    Other,
+}
 
-    /// CHECK: Program-controlled PDA that becomes the TransferDelegate authority.
-    #[account(
-        seeds = [b"core_authority", asset.key().as_ref()],
-        bump,
-    )]
-    pub core_authority: UncheckedAccount<'info>,
+/// Make `core_authority` the authority of an owner-managed delegate plugin,
+/// adding the plugin if the asset does not have it yet. `owner` signs and pays.
+#[allow(clippy::too_many_arguments)]
+fn approve_core_delegate<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    collection: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    core_authority: Pubkey,
+    state: CoreDelegateState,
+    plugin: mpl_core::types::Plugin,
+    plugin_type: mpl_core::types::PluginType,
+) -> Result<()> {
+    let expected_authority = mpl_core::types::PluginAuthority::Address {
+        address: core_authority,
+    };
 
-    /// CHECK: Metaplex Core program.
-    #[account(address = mpl_core::ID)]
-    pub mpl_core_program: UncheckedAccount<'info>,
+    match state {
+        CoreDelegateState::Missing => {
+            mpl_core::instructions::AddPluginV1Cpi {
+                __program: mpl_core_program,
+                asset,
+                collection: Some(collection),
+                payer: owner,
+                authority: Some(owner),
+                system_program,
+                log_wrapper: None,
+                __args: mpl_core::instructions::AddPluginV1InstructionArgs {
+                    plugin,
+                    init_authority: Some(expected_authority),
+                },
+            }
+            .invoke()?;
+        }
+        CoreDelegateState::Address(address) if address == core_authority => {}
+        CoreDelegateState::Address(_) | CoreDelegateState::Other => {
+            mpl_core::instructions::ApprovePluginAuthorityV1Cpi {
+                __program: mpl_core_program,
+                asset,
+                collection: Some(collection),
+                payer: owner,
+                authority: Some(owner),
+                system_program,
+                log_wrapper: None,
+                __args: mpl_core::instructions::ApprovePluginAuthorityV1InstructionArgs {
+                    plugin_type,
+                    new_authority: expected_authority,
+                },
+            }
+            .invoke()?;
+        }
+    }
 
-    pub system_program: Program<'info, System>,
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct CancelCoreListing<'info> {
-    #[account(mut)]
-    pub seller: Signer<'info>,
-
-    /// CHECK: Metaplex Core asset.
-    #[account(mut, address = core_listing.asset)]
-    pub asset: UncheckedAccount<'info>,
-
-    /// CHECK: Metaplex Core collection.
-    #[account(mut, address = core_listing.collection)]
-    pub collection: UncheckedAccount<'info>,
+/// Freeze or thaw a Core asset via its FreezeDelegate, signed by `core_authority`.
+#[allow(clippy::too_many_arguments)]
+fn set_core_asset_frozen<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    collection: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    core_authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    frozen: bool,
+    core_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    mpl_core::instructions::UpdatePluginV1Cpi {
+        __program: mpl_core_program,
+        asset,
+        collection: Some(collection),
+        payer,
+        authority: Some(core_authority),
+        system_program,
+        log_wrapper: None,
+        __args: mpl_core::instructions::UpdatePluginV1InstructionArgs {
+            plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate { frozen }),
+        },
+    }
+    .invoke_signed(&[core_authority_seeds])?;
 
-    #[account(
-        mut,
-        seeds = [b"core_listing", asset.key().as_ref()],
-        bump = core_listing.bump,
-        close = seller,
-    )]
-    pub core_listing: Account<'info, CoreListing>,
+    Ok(())
+}
 
-    /// CHECK: Program-controlled PDA = TransferDelegate authority.
-    #[account(
-        seeds = [b"core_authority", asset.key().as_ref()],
-        bump,
-    )]
-    pub core_authority: UncheckedAccount<'info>,
+/// A Core asset whose FreezeDelegate is still its `core_authority` is already
+/// held by a Core auction, bundle or swap.
+fn ensure_core_asset_uncommitted(asset_account: &AccountInfo, core_authority: Pubkey) -> Result<()> {
+    require!(
+        !matches!(
+            read_core_freeze_delegate_state(asset_account)?,
+            CoreDelegateState::Address(address) if address == core_authority
+        ),
+        AuctionError::CoreAssetCommitted
+    );
+    Ok(())
+}
 
-    /// CHECK: Metaplex Core program.
-    #[account(address = mpl_core::ID)]
-    pub mpl_core_program: UncheckedAccount<'info>,
+fn read_core_transfer_delegate_state(asset_account: &AccountInfo) -> Result<CoreDelegateState> {
+    read_core_delegate_state::<mpl_core::types::TransferDelegate>(
+        asset_account,
+        mpl_core::types::PluginType::TransferDelegate,
+    )
+}
 
-    pub system_program: Program<'info, System>,
+fn read_core_freeze_delegate_state(asset_account: &AccountInfo) -> Result<CoreDelegateState> {
+    read_core_delegate_state::<mpl_core::types::FreezeDelegate>(
+        asset_account,
+        mpl_core::types::PluginType::FreezeDelegate,
+    )
 }
 
-#[derive(Accounts)]
-pub struct CloseStaleCoreListing<'info> {
-    #[account(mut)]
-    pub holder: Signer<'info>,
+fn read_core_delegate_state<T: AnchorDeserialize>(
+    asset_account: &AccountInfo,
+    plugin_type: mpl_core::types::PluginType,
+) -> Result<CoreDelegateState> {
+    use mpl_core::{fetch_asset_plugin, types::PluginAuthority};
 
-    /// CHECK: Metaplex Core asset.
-    #[account(mut, address = core_listing.asset)]
-    pub asset: UncheckedAccount<'info>,
+    match fetch_asset_plugin::<T>(asset_account, plugin_type) {
+        Ok((PluginAuthority::Address { address }, _plugin, _offset)) => {
+            Ok(CoreDelegateState::Address(address))
+        }
+        Ok((_authority, _plugin, _offset)) => Ok(CoreDelegateState::Other),
+        Err(error)
+            if error.kind() == std::io::ErrorKind::Other
+                && (error.to_string()
+                    == mpl_core::errors::MplCoreError::PluginNotFound.to_string()
+                    || error.to_string()
+                        == mpl_core::errors::MplCoreError::PluginsNotInitialized.to_string()) =>
+        {
+            Ok(CoreDelegateState::Missing)
+        }
+        Err(_error) => Err(error!(AuctionError::InvalidCorePluginState)),
+    }
+}
 
-    /// CHECK: Metaplex Core collection.
-    #[account(mut, address = core_listing.collection)]
-    pub collection: UncheckedAccount<'info>,
+// ============================================================================
+// Compressed NFTs (Bubblegum) — state, accounts, helpers
+// ============================================================================
 
-    #[account(
-        mut,
-        seeds = [b"core_listing", asset.key().as_ref()],
-        bump = core_listing.bump,
-        close = holder,
-    )]
-    pub core_listing: Account<'info, CoreListing>,
+/// A Bubblegum leaf: its hashes and position, plus the root its proof is against.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CompressedLeaf {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
 
-    /// CHECK: Program-controlled PDA = TransferDelegate authority.
+/// Fixed-price listing of a cNFT; the PDA is the leaf delegate.
+#[account]
+#[derive(InitSpace)]
+pub struct CompressedListing {
+    pub seller: Pubkey,
+    /// Bubblegum asset id, derived from tree and nonce
+    pub asset_id: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub leaf_nonce: u64,
+    pub leaf_index: u32,
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub category: ItemCategory,
+    pub start_time: i64,
+    /// 0 = no expiry
+    pub end_time: i64,
+    pub royalty_basis_points: u16,
+    pub creator_address: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(leaf: CompressedLeaf)]
+pub struct ListCompressedItem<'info> {
     #[account(
-        seeds = [b"core_authority", asset.key().as_ref()],
+        init,
+        payer = seller,
+        space = 8 + CompressedListing::INIT_SPACE,
+        seeds = [b"compressed_listing", merkle_tree.key().as_ref(), &leaf.nonce.to_le_bytes()],
         bump,
     )]
-    pub core_authority: UncheckedAccount<'info>,
-
-    /// CHECK: Metaplex Core program.
-    #[account(address = mpl_core::ID)]
-    pub mpl_core_program: UncheckedAccount<'info>,
-
+    pub compressed_listing: Box<Account<'info, CompressedListing>>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    /// CHECK: Current leaf delegate (the seller if none) — proven by Bubblegum
+    pub previous_leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: Bubblegum tree config PDA — verified by Bubblegum
+    pub tree_config: UncheckedAccount<'info>,
+    /// CHECK: Concurrent merkle tree — verified by Bubblegum / account compression
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
+    /// Registry entry for the payment mint — validated in handler
+    #[account(
+        seeds = [b"payment_mint_config", payment_mint.key().as_ref()],
+        bump = payment_mint_config.bump,
+    )]
+    pub payment_mint_config: Box<Account<'info, PaymentMintConfig>>,
+    /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: Metaplex Bubblegum program
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct BuyNowCore<'info> {
-    /// Public buyer.
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-
-    /// CHECK: Metaplex Core asset.
-    #[account(mut, address = core_listing.asset)]
-    pub asset: UncheckedAccount<'info>,
-
-    /// CHECK: Metaplex Core collection.
-    #[account(mut, address = core_listing.collection)]
-    pub collection: UncheckedAccount<'info>,
-
+pub struct BuyNowCompressed<'info> {
     #[account(
         mut,
-        seeds = [b"core_listing", asset.key().as_ref()],
-        bump = core_listing.bump,
+        seeds = [
+            b"compressed_listing",
+            compressed_listing.merkle_tree.as_ref(),
+            &compressed_listing.leaf_nonce.to_le_bytes(),
+        ],
+        bump = compressed_listing.bump,
         close = seller,
     )]
-    pub core_listing: Account<'info, CoreListing>,
-
-    /// CHECK: Original seller — receives PDA rent on close.
-    #[account(mut, address = core_listing.seller)]
+    pub compressed_listing: Box<Account<'info, CompressedListing>>,
+    /// CHECK: Original seller — leaf owner; receives PDA rent on close.
+    #[account(mut, address = compressed_listing.seller)]
     pub seller: UncheckedAccount<'info>,
-
-    /// CHECK: Program-controlled PDA = TransferDelegate authority (signs CPI).
-    #[account(
-        seeds = [b"core_authority", asset.key().as_ref()],
-        bump,
-    )]
-    pub core_authority: UncheckedAccount<'info>,
-
-    /// USDC mint (validated in handler).
-    pub payment_mint: Account<'info, anchor_spl::token::Mint>,
-
+    /// CHECK: Bubblegum tree config PDA — verified by Bubblegum
+    pub tree_config: UncheckedAccount<'info>,
+    /// CHECK: Concurrent merkle tree — must match the listing
+    #[account(mut, address = compressed_listing.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(address = compressed_listing.payment_mint @ AuctionError::InvalidPaymentMint)]
+    pub payment_mint: Box<InterfaceAccount<'info, IfaceMint>>,
     #[account(mut)]
-    pub buyer_payment_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub seller_payment_account: Account<'info, TokenAccount>,
+    pub buyer_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Seller payment account — must be owned by compressed_listing.seller
+    #[account(mut, constraint = seller_payment_account.owner == compressed_listing.seller @ AuctionError::Unauthorized)]
+    pub seller_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// Treasury payment account — validated in instruction body against treasury_config or fallback
     #[account(mut)]
-    pub treasury_payment_account: Account<'info, TokenAccount>,
-    /// CHECK: Royalty creator's payment account — validated against on-chain plugin in handler.
+    pub treasury_payment_account: Box<InterfaceAccount<'info, IfaceTokenAccount>>,
+    /// CHECK: Creator payment account — validated in instruction body
     #[account(mut)]
     pub creator_payment_account: UncheckedAccount<'info>,
-
-    /// CHECK: Treasury wallet — validated in handler against treasury_config or fallback.
     #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Treasury wallet. Validated in instruction body.
     pub treasury: UncheckedAccount<'info>,
-
     #[account(seeds = [b"treasury_config"], bump)]
     pub treasury_config: Option<Account<'info, TreasuryConfig>>,
-
-    /// CHECK: Metaplex Core program.
-    #[account(address = mpl_core::ID)]
-    pub mpl_core_program: UncheckedAccount<'info>,
-
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: Metaplex Bubblegum program
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct CoreListing {
-    pub seller: Pubkey,
-    pub asset: Pubkey,
-    pub collection: Pubkey,
-    pub payment_mint: Pubkey,
-    pub price: u64,
-    pub created_at: i64,
-    pub bump: u8,
+#[derive(Accounts)]
+pub struct CancelCompressedListing<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"compressed_listing",
+            compressed_listing.merkle_tree.as_ref(),
+            &compressed_listing.leaf_nonce.to_le_bytes(),
+        ],
+        bump = compressed_listing.bump,
+        close = seller,
+    )]
+    pub compressed_listing: Box<Account<'info, CompressedListing>>,
+    #[account(mut, address = compressed_listing.seller @ AuctionError::Unauthorized)]
+    pub seller: Signer<'info>,
+    /// CHECK: Bubblegum tree config PDA — verified by Bubblegum
+    pub tree_config: UncheckedAccount<'info>,
+    /// CHECK: Concurrent merkle tree — must match the listing
+    #[account(mut, address = compressed_listing.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: Metaplex Bubblegum program
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct CoreListingCreated {
-    pub asset: Pubkey,
-    pub seller: Pubkey,
-    pub price_usdc: u64,
-    pub payment_mint: Pubkey,
+#[derive(Accounts)]
+pub struct CloseStaleCompressedListing<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"compressed_listing",
+            compressed_listing.merkle_tree.as_ref(),
+            &compressed_listing.leaf_nonce.to_le_bytes(),
+        ],
+        bump = compressed_listing.bump,
+        close = seller,
+    )]
+    pub compressed_listing: Box<Account<'info, CompressedListing>>,
+    /// CHECK: Original seller — receives PDA rent on close.
+    #[account(mut, address = compressed_listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: Concurrent merkle tree — must match the listing
+    #[account(address = compressed_listing.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
 }
 
-#[event]
-pub struct CoreListingCancelled {
-    pub asset: Pubkey,
-    pub seller: Pubkey,
+/// Accounts every Bubblegum leaf instruction takes besides its owners/delegates.
+struct BubblegumTree<'info> {
+    tree_config: AccountInfo<'info>,
+    merkle_tree: AccountInfo<'info>,
+    log_wrapper: AccountInfo<'info>,
+    compression_program: AccountInfo<'info>,
+    bubblegum_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
 }
 
-#[event]
-pub struct CorePurchased {
-    pub asset: Pubkey,
-    pub seller: Pubkey,
-    pub buyer: Pubkey,
-    pub price_usdc: u64,
-    pub platform_fee: u64,
-    pub creator_royalty: u64,
+/// Bubblegum asset id: PDA of `["asset", tree, nonce]` under Bubblegum.
+fn compressed_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &BUBBLEGUM_PROGRAM_ID,
+    )
+    .0
 }
 
-/// Verify that a Metaplex Core asset is owned by `expected_owner` and belongs
-/// to `expected_collection`. Reads the BaseAssetV1 header directly from the
-/// account data.
-fn read_core_asset_owner_and_collection(asset_account: &AccountInfo) -> Result<(Pubkey, Pubkey)> {
-    require_keys_eq!(*asset_account.owner, mpl_core::ID, AuctionError::Unauthorized);
-    let data = asset_account.try_borrow_data()?;
-    let asset = mpl_core::accounts::BaseAssetV1::from_bytes(&data)
-        .map_err(|_| error!(AuctionError::Unauthorized))?;
-    let collection = match asset.update_authority {
-        mpl_core::types::UpdateAuthority::Collection(collection) => collection,
-        _ => return err!(AuctionError::Unauthorized),
-    };
-
-    Ok((asset.owner, collection))
+/// Bubblegum leaf-instruction data: discriminator, then
+/// `(root, data_hash, creator_hash, nonce, index)`.
+fn bubblegum_leaf_data(discriminator: [u8; 8], leaf: &CompressedLeaf) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&leaf.root);
+    data.extend_from_slice(&leaf.data_hash);
+    data.extend_from_slice(&leaf.creator_hash);
+    data.extend_from_slice(&leaf.nonce.to_le_bytes());
+    data.extend_from_slice(&leaf.index.to_le_bytes());
+    data
 }
 
-fn verify_core_asset_ownership(
-    asset_account: &AccountInfo,
-    expected_owner: Pubkey,
-    expected_collection: Pubkey,
+/// Invoke a Bubblegum leaf instruction whose accounts are `[tree_config,
+/// <leaf accounts>, merkle_tree, log_wrapper, compression_program,
+/// system_program]` followed by the proof.
+fn invoke_bubblegum_leaf_ix<'info>(
+    tree: &BubblegumTree<'info>,
+    leaf_accounts: &[(&AccountInfo<'info>, bool)],
+    data: Vec<u8>,
+    proof: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
-    let (asset_owner, asset_collection) = read_core_asset_owner_and_collection(asset_account)?;
-    require_keys_eq!(asset_owner, expected_owner, AuctionError::Unauthorized);
-    require_keys_eq!(asset_collection, expected_collection, AuctionError::Unauthorized);
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
 
+    let mut accounts = vec![AccountMeta::new_readonly(tree.tree_config.key(), false)];
+    accounts.extend(
+        leaf_accounts
+            .iter()
+            .map(|(account, is_signer)| AccountMeta::new_readonly(account.key(), *is_signer)),
+    );
+    accounts.push(AccountMeta::new(tree.merkle_tree.key(), false));
+    accounts.push(AccountMeta::new_readonly(tree.log_wrapper.key(), false));
+    accounts.push(AccountMeta::new_readonly(tree.compression_program.key(), false));
+    accounts.push(AccountMeta::new_readonly(tree.system_program.key(), false));
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+
+    let mut account_infos = vec![tree.tree_config.clone()];
+    account_infos.extend(leaf_accounts.iter().map(|(account, _)| (*account).clone()));
+    account_infos.extend([
+        tree.merkle_tree.clone(),
+        tree.log_wrapper.clone(),
+        tree.compression_program.clone(),
+        tree.system_program.clone(),
+    ]);
+    account_infos.extend_from_slice(proof);
+    account_infos.push(tree.bubblegum_program.clone());
+
+    let ix = Instruction {
+        program_id: BUBBLEGUM_PROGRAM_ID,
+        accounts,
+        data,
+    };
+    invoke_signed(&ix, &account_infos, signer_seeds).map_err(|_| error!(AuctionError::TransferFailed))?;
     Ok(())
 }
 
-fn verify_active_core_listing_owner(
-    asset_account: &AccountInfo,
-    expected_owner: Pubkey,
-    expected_collection: Pubkey,
+/// Bubblegum `delegate`, signed by the leaf owner.
+fn delegate_compressed_leaf<'info>(
+    tree: &BubblegumTree<'info>,
+    leaf_owner: &AccountInfo<'info>,
+    previous_leaf_delegate: &AccountInfo<'info>,
+    new_leaf_delegate: &AccountInfo<'info>,
+    leaf: &CompressedLeaf,
+    proof: &[AccountInfo<'info>],
 ) -> Result<()> {
-    let (asset_owner, asset_collection) = read_core_asset_owner_and_collection(asset_account)?;
-    require_keys_eq!(asset_collection, expected_collection, AuctionError::Unauthorized);
-    require_keys_eq!(asset_owner, expected_owner, AuctionError::StaleCoreListing);
-
-    Ok(())
+    invoke_bubblegum_leaf_ix(
+        tree,
+        &[(leaf_owner, true), (previous_leaf_delegate, false), (new_leaf_delegate, false)],
+        bubblegum_leaf_data(BUBBLEGUM_DELEGATE_DISCRIMINATOR, leaf),
+        proof,
+        &[],
+    )
 }
 
-/// Read the Royalties plugin from a Metaplex Core asset (or fall back to the
-/// collection if absent). Returns `(basis_points, royalty_creator)`.
-///
-/// The royalty creator is the first creator listed in the plugin (matching the
-/// Artifacte mint flow which sets a single creator at 100%). Returns
-/// `(0, default_pubkey)` if no Royalties plugin exists at all (no royalty owed).
-fn read_core_royalties(
-    asset_account: &AccountInfo,
-    collection_account: &AccountInfo,
-) -> Result<(u16, Pubkey)> {
-    use mpl_core::{
-        fetch_asset_plugin,
-        fetch_collection_plugin,
-        types::{PluginType, Royalties},
+/// Bubblegum `transfer` of a listed leaf, signed by the listing PDA as delegate.
+#[allow(clippy::too_many_arguments)]
+fn transfer_compressed_leaf<'info>(
+    tree: &BubblegumTree<'info>,
+    listing: &CompressedListing,
+    root: [u8; 32],
+    leaf_owner: &AccountInfo<'info>,
+    leaf_delegate: &AccountInfo<'info>,
+    new_leaf_owner: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    delegate_seeds: &[&[u8]],
+) -> Result<()> {
+    let leaf = CompressedLeaf {
+        root,
+        data_hash: listing.data_hash,
+        creator_hash: listing.creator_hash,
+        nonce: listing.leaf_nonce,
+        index: listing.leaf_index,
     };
-
-    if let Ok((_auth, royalties, _offset)) =
-        fetch_asset_plugin::<Royalties>(asset_account, PluginType::Royalties)
-    {
-        let creator = royalties
-            .creators
-            .first()
-            .map(|c| c.address)
-            .unwrap_or_default();
-        return Ok((royalties.basis_points, creator));
-    }
-
-    if let Ok((_auth, royalties, _offset)) =
-        fetch_collection_plugin::<Royalties>(collection_account, PluginType::Royalties)
-    {
-        let creator = royalties
-            .creators
-            .first()
-            .map(|c| c.address)
-            .unwrap_or_default();
-        return Ok((royalties.basis_points, creator));
-    }
-
-    Ok((0u16, Pubkey::default()))
-}
-
-enum CoreTransferDelegateState {
-    Missing,
-    Address(Pubkey),
-    Other,
+    invoke_bubblegum_leaf_ix(
+        tree,
+        &[(leaf_owner, false), (leaf_delegate, true), (new_leaf_owner, false)],
+        bubblegum_leaf_data(BUBBLEGUM_TRANSFER_DISCRIMINATOR, &leaf),
+        proof,
+        &[delegate_seeds],
+    )
 }
 
-fn read_core_transfer_delegate_state(asset_account: &AccountInfo) -> Result<CoreTransferDelegateState> {
-    use mpl_core::{
-        fetch_asset_plugin,
-        types::{PluginAuthority, PluginType, TransferDelegate},
+/// SPL Account Compression `verify_leaf`: fails unless `leaf` is at `index`
+/// under `root`.
+fn verify_compressed_leaf<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+    proof: &[AccountInfo<'info>],
+) -> Result<()> {
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let mut accounts = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+    let mut account_infos = vec![merkle_tree.clone()];
+    account_infos.extend_from_slice(proof);
+    account_infos.push(compression_program.clone());
+
+    let ix = Instruction {
+        program_id: SPL_ACCOUNT_COMPRESSION_ID,
+        accounts,
+        data,
     };
-
-    match fetch_asset_plugin::<TransferDelegate>(asset_account, PluginType::TransferDelegate) {
-        Ok((PluginAuthority::Address { address }, _plugin, _offset)) => {
-            Ok(CoreTransferDelegateState::Address(address))
-        }
-        Ok((_authority, _plugin, _offset)) => Ok(CoreTransferDelegateState::Other),
-        Err(error)
-            if error.kind() == std::io::ErrorKind::Other
-                && (error.to_string()
-                    == mpl_core::errors::MplCoreError::PluginNotFound.to_string()
-                    || error.to_string()
-                        == mpl_core::errors::MplCoreError::PluginsNotInitialized.to_string()) =>
-        {
-            Ok(CoreTransferDelegateState::Missing)
-        }
-        Err(_error) => Err(error!(AuctionError::InvalidCorePluginState)),
-    }
+    anchor_lang::solana_program::program::invoke(&ix, &account_infos)
+        .map_err(|_| error!(AuctionError::InvalidCompressedProof))?;
+    Ok(())
 }
@@ -0,0 +1,3715 @@
+//! Instruction-level tests: the program runs natively under solana-program-test
+//! against the bundled SPL Token, Token-2022 and ATA programs. Metaplex Core,
+//! Token Metadata and Bubblegum are replaced by stubs that only move the asset.
+
+use anchor_lang::prelude::{AccountInfo, ProgramError, Pubkey};
+use anchor_lang::solana_program::{
+    entrypoint::ProgramResult, program::invoke, program_option::COption, program_pack::Pack,
+    pubkey, sysvar,
+};
+use anchor_lang::system_program;
+use anchor_lang::{
+    AccountDeserialize, AccountSerialize, AnchorSerialize, Discriminator, InstructionData, Space,
+    ToAccountMetas,
+};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::metadata::mpl_token_metadata::{
+    self,
+    accounts::{MasterEdition, Metadata, TokenRecord},
+};
+use auction::{
+    AuctionError, BidIncrement, BidRefund, Bundle, CollectionOffer, CompressedLeaf, CoreListing,
+    ItemCategory, Listing, ListingOptions, ListingStatus, ListingType, PaymentMintConfig,
+    ReservePrice, SwapAssetKind, SwapTerms, SwapWant, TopUpPayer,
+};
+use sha2::{Digest, Sha256};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token_2022::extension::{
+    transfer_hook::TransferHook, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+};
+use spl_token_2022::state::Mint as Mint2022;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+const TREASURY: Pubkey = pubkey!("82v8xATLqdvq3cS1CXwpygVUH926QKdAd4NVxD91r4a6");
+const ARTIFACTE_COLLECTION: Pubkey = pubkey!("jzkJTGAuDcWthM91S1ch7wPcfMUQB5CdYH6hA25K4CS");
+const WNS_PROGRAM: Pubkey = pubkey!("wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM");
+const SOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+const BUBBLEGUM_PROGRAM: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+const SPL_ACCOUNT_COMPRESSION: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+const SPL_NOOP: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+const PAYMENT_DECIMALS: u8 = 6;
+const WALLET_LAMPORTS: u64 = 100_000_000_000;
+const AUCTION_SECONDS: i64 = 3600;
+const RESERVE_REVEAL_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+const MPL_CORE_ADD_PLUGIN_V1: u8 = 2;
+const MPL_CORE_UPDATE_PLUGIN_V1: u8 = 6;
+const MPL_CORE_TRANSFER_V1: u8 = 14;
+const TOKEN_METADATA_DELEGATE: u8 = 44;
+const TOKEN_METADATA_REVOKE: u8 = 45;
+const TOKEN_METADATA_TRANSFER: u8 = 49;
+const BUBBLEGUM_TRANSFER: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+const BUBBLEGUM_DELEGATE: [u8; 8] = [90, 147, 75, 178, 85, 88, 4, 137];
+const CNFT_DATA_HASH: [u8; 32] = [1; 32];
+const CNFT_CREATOR_HASH: [u8; 32] = [2; 32];
+// Byte range of `owner` in a Core `BaseAssetV1`, after its one-byte key
+const CORE_ASSET_OWNER: std::ops::Range<usize> = 1..33;
+
+// ----------------------------------------------------------------------------
+// Programs
+// ----------------------------------------------------------------------------
+
+fn process_auction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // `entry` ties the account slice to the accounts' own lifetime
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    auction::entry(program_id, accounts, data)
+}
+
+/// Stand-in for Metaplex Core: `TransferV1` moves the asset when its owner signs.
+/// Plugins are not stored, so `AddPluginV1` and `UpdatePluginV1` succeed without
+/// effect and the program always finds an asset's delegates missing.
+fn process_mpl_core(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    match data.first() {
+        Some(&MPL_CORE_TRANSFER_V1) => {}
+        Some(&MPL_CORE_ADD_PLUGIN_V1 | &MPL_CORE_UPDATE_PLUGIN_V1) => return Ok(()),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    let [asset, _collection, _payer, authority, new_owner, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let mut asset_data = asset.try_borrow_mut_data()?;
+    if !authority.is_signer || asset_data[CORE_ASSET_OWNER] != authority.key.to_bytes() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    asset_data[CORE_ASSET_OWNER].copy_from_slice(new_owner.key.as_ref());
+    Ok(())
+}
+
+/// Stand-in for Token Metadata: `TransferV1` moves the token with a plain SPL
+/// transfer signed by its authority, and the Sale `DelegateV1` / `RevokeV1`
+/// approve or revoke the delegate as an SPL delegate. Token records, freezing
+/// and rule sets are ignored.
+fn process_token_metadata(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    match data.first() {
+        Some(&TOKEN_METADATA_TRANSFER) => {
+            let [token, _, destination_token, _, _, _, _, _, _, authority, _, _, _, spl_token_program, ..] =
+                accounts
+            else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            invoke(
+                &spl_token::instruction::transfer(
+                    spl_token_program.key,
+                    token.key,
+                    destination_token.key,
+                    authority.key,
+                    &[],
+                    1,
+                )?,
+                &[
+                    token.clone(),
+                    destination_token.clone(),
+                    authority.clone(),
+                    spl_token_program.clone(),
+                ],
+            )
+        }
+        Some(&(TOKEN_METADATA_DELEGATE | TOKEN_METADATA_REVOKE)) => {
+            let [_, delegate, _, _, _, _, token, authority, _, _, _, spl_token_program, ..] =
+                accounts
+            else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            if data[0] == TOKEN_METADATA_DELEGATE {
+                invoke(
+                    &spl_token::instruction::approve(
+                        spl_token_program.key,
+                        token.key,
+                        delegate.key,
+                        authority.key,
+                        &[],
+                        1,
+                    )?,
+                    &[
+                        token.clone(),
+                        delegate.clone(),
+                        authority.clone(),
+                        spl_token_program.clone(),
+                    ],
+                )
+            } else {
+                invoke(
+                    &spl_token::instruction::revoke(
+                        spl_token_program.key,
+                        token.key,
+                        authority.key,
+                        &[],
+                    )?,
+                    &[token.clone(), authority.clone(), spl_token_program.clone()],
+                )
+            }
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Stand-in for Bubblegum over one-leaf trees: the tree account stores only its
+/// leaf, which is also its root, so proofs are empty. `delegate` and `transfer`
+/// check the signer and the leaf they describe against it, then store the
+/// updated leaf.
+fn process_bubblegum(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [_tree_config, owner, delegate, new_account, merkle_tree, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if data.len() != 8 + 32 * 3 + 8 + 4 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (signer, new_owner, new_delegate) = match data[..8].try_into().unwrap() {
+        BUBBLEGUM_DELEGATE => (owner, owner.key, new_account.key),
+        BUBBLEGUM_TRANSFER => (delegate, new_account.key, new_account.key),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    let root = &data[8..40];
+    let nonce = u64::from_le_bytes(data[104..112].try_into().unwrap());
+    let mut tree = merkle_tree.try_borrow_mut_data()?;
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let current = compressed_leaf(*merkle_tree.key, *owner.key, *delegate.key, nonce);
+    if root != &tree[..] || current[..] != tree[..] {
+        return Err(ProgramError::InvalidArgument);
+    }
+    tree.copy_from_slice(&compressed_leaf(
+        *merkle_tree.key,
+        *new_owner,
+        *new_delegate,
+        nonce,
+    ));
+    Ok(())
+}
+
+fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new("auction", auction::ID, processor!(process_auction));
+    program_test.add_program("mpl_core", mpl_core::ID, processor!(process_mpl_core));
+    program_test.add_program(
+        "mpl_token_metadata",
+        mpl_token_metadata::ID,
+        processor!(process_token_metadata),
+    );
+    program_test.add_program(
+        "bubblegum",
+        BUBBLEGUM_PROGRAM,
+        processor!(process_bubblegum),
+    );
+    program_test
+}
+
+// ----------------------------------------------------------------------------
+// Genesis accounts
+// ----------------------------------------------------------------------------
+
+fn rent_exempt(len: usize) -> u64 {
+    Rent::default().minimum_balance(len)
+}
+
+fn add_wallet(program_test: &mut ProgramTest) -> Keypair {
+    let wallet = Keypair::new();
+    program_test.add_account(
+        wallet.pubkey(),
+        Account::new(WALLET_LAMPORTS, 0, &system_program::ID),
+    );
+    wallet
+}
+
+fn add_mint_at(program_test: &mut ProgramTest, mint: Pubkey, decimals: u8, supply: u64) {
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::Some(Pubkey::new_unique()),
+        supply,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    program_test.add_account(
+        mint,
+        Account {
+            lamports: rent_exempt(data.len()),
+            data,
+            owner: spl_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+fn add_mint(program_test: &mut ProgramTest, decimals: u8, supply: u64) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    add_mint_at(program_test, mint, decimals, supply);
+    mint
+}
+
+fn add_token_account_at(
+    program_test: &mut ProgramTest,
+    address: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) {
+    program_test.add_account(address, token_account(mint, owner, amount));
+}
+
+fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    Account {
+        lamports: rent_exempt(data.len()),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn add_token_account(
+    program_test: &mut ProgramTest,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let address = Pubkey::new_unique();
+    add_token_account_at(program_test, address, mint, owner, amount);
+    address
+}
+
+/// A 1/1 SPL NFT held by `owner`; returns the mint and `owner`'s token account.
+fn add_nft(program_test: &mut ProgramTest, owner: Pubkey) -> (Pubkey, Pubkey) {
+    let mint = add_mint(program_test, 0, 1);
+    (mint, add_token_account(program_test, mint, owner, 1))
+}
+
+/// A 1/1 Token-2022 NFT on the WNS transfer hook whose metadata carries
+/// `royalty_basis_points`; returns the mint and `owner`'s token account.
+fn add_wns_nft(
+    program_test: &mut ProgramTest,
+    owner: Pubkey,
+    royalty_basis_points: &str,
+) -> (Pubkey, Pubkey) {
+    let mint = Pubkey::new_unique();
+    let metadata = TokenMetadata {
+        mint,
+        additional_metadata: vec![(
+            "royalty_basis_points".to_string(),
+            royalty_basis_points.to_string(),
+        )],
+        ..TokenMetadata::default()
+    };
+    let len = ExtensionType::try_calculate_account_len::<Mint2022>(&[ExtensionType::TransferHook])
+        .unwrap()
+        + metadata.tlv_size_of().unwrap();
+    let mut data = vec![0; len];
+    let mut state = StateWithExtensionsMut::<Mint2022>::unpack_uninitialized(&mut data).unwrap();
+    state
+        .init_extension::<TransferHook>(true)
+        .unwrap()
+        .program_id = Some(WNS_PROGRAM).try_into().unwrap();
+    state.init_variable_len_extension(&metadata, false).unwrap();
+    state.base = Mint2022 {
+        mint_authority: COption::None,
+        supply: 1,
+        decimals: 0,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    program_test.add_account(
+        mint,
+        Account {
+            lamports: rent_exempt(data.len()),
+            data,
+            owner: spl_token_2022::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut account = token_account(mint, owner, 1);
+    account.owner = spl_token_2022::ID;
+    let address = Pubkey::new_unique();
+    program_test.add_account(address, account);
+    (mint, address)
+}
+
+/// Payment mint registrations are deploy-authority only, so tests install the
+/// config account directly: enabled for every category and Core, no minimum.
+fn add_payment_mint_config(program_test: &mut ProgramTest, mint: Pubkey) {
+    let (address, bump) =
+        Pubkey::find_program_address(&[b"payment_mint_config", mint.as_ref()], &auction::ID);
+    let config = PaymentMintConfig {
+        mint,
+        enabled: true,
+        allowed_categories: u8::MAX,
+        allow_core: true,
+        min_price: 0,
+        bid_increment: BidIncrement::Standard,
+        bump,
+    };
+    let mut data = Vec::new();
+    config.try_serialize(&mut data).unwrap();
+    program_test.add_account(
+        address,
+        Account {
+            lamports: rent_exempt(data.len()),
+            data,
+            owner: auction::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// A Core asset of `collection` owned by `owner`, without plugins.
+fn add_core_asset(program_test: &mut ProgramTest, owner: Pubkey, collection: Pubkey) -> Pubkey {
+    let asset = Pubkey::new_unique();
+    let mut data = vec![1]; // Key::AssetV1
+    data.extend_from_slice(owner.as_ref());
+    data.push(2); // UpdateAuthority::Collection
+    data.extend_from_slice(collection.as_ref());
+    for field in ["Card", "https://example.com/card.json"] {
+        data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        data.extend_from_slice(field.as_bytes());
+    }
+    data.push(0); // seq: None
+    program_test.add_account(
+        asset,
+        Account {
+            lamports: rent_exempt(data.len()),
+            data,
+            owner: mpl_core::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    asset
+}
+
+// ----------------------------------------------------------------------------
+// PDAs
+// ----------------------------------------------------------------------------
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &auction::ID).0
+}
+
+fn instance_seed(created_at: i64) -> Vec<u8> {
+    if created_at == 0 {
+        Vec::new()
+    } else {
+        created_at.to_le_bytes().to_vec()
+    }
+}
+
+fn listing_pda(nft_mint: Pubkey) -> Pubkey {
+    pda(&[b"listing", nft_mint.as_ref()])
+}
+
+fn escrow_nft_pda(nft_mint: Pubkey) -> Pubkey {
+    pda(&[b"escrow_nft", nft_mint.as_ref()])
+}
+
+fn payment_mint_config_pda(mint: Pubkey) -> Pubkey {
+    pda(&[b"payment_mint_config", mint.as_ref()])
+}
+
+fn bid_escrow_pda(key: Pubkey, created_at: i64) -> Pubkey {
+    pda(&[b"bid_escrow", key.as_ref(), &instance_seed(created_at)])
+}
+
+fn sol_escrow_pda(nft_mint: Pubkey, created_at: i64) -> Pubkey {
+    pda(&[b"sol_escrow", nft_mint.as_ref(), &instance_seed(created_at)])
+}
+
+fn bid_refund_pda(key: Pubkey, created_at: i64, bidder: Pubkey) -> Pubkey {
+    pda(&[
+        b"bid_refund",
+        key.as_ref(),
+        &instance_seed(created_at),
+        bidder.as_ref(),
+    ])
+}
+
+fn offer_pda(asset: Pubkey, buyer: Pubkey) -> Pubkey {
+    pda(&[b"offer", asset.as_ref(), buyer.as_ref()])
+}
+
+fn offer_escrow_pda(asset: Pubkey, buyer: Pubkey) -> Pubkey {
+    pda(&[b"offer_escrow", asset.as_ref(), buyer.as_ref()])
+}
+
+fn core_authority_pda(asset: Pubkey) -> Pubkey {
+    pda(&[b"core_authority", asset.as_ref()])
+}
+
+fn core_listing_pda(asset: Pubkey) -> Pubkey {
+    pda(&[b"core_listing", asset.as_ref()])
+}
+
+fn core_bond_pda(asset: Pubkey) -> Pubkey {
+    pda(&[b"core_bond", asset.as_ref()])
+}
+
+fn delegate_listing_pda(nft_mint: Pubkey) -> Pubkey {
+    pda(&[b"delegate_listing", nft_mint.as_ref()])
+}
+
+fn compressed_listing_pda(merkle_tree: Pubkey, nonce: u64) -> Pubkey {
+    pda(&[
+        b"compressed_listing",
+        merkle_tree.as_ref(),
+        &nonce.to_le_bytes(),
+    ])
+}
+
+fn escrow_authority_pda(nft_mint: Pubkey) -> Pubkey {
+    pda(&[b"escrow_authority", nft_mint.as_ref()])
+}
+
+fn bundle_pda(seller: Pubkey, bundle_id: u64) -> Pubkey {
+    pda(&[b"bundle", seller.as_ref(), &bundle_id.to_le_bytes()])
+}
+
+fn bundle_escrow_pda(bundle: Pubkey, nft_mint: Pubkey) -> Pubkey {
+    pda(&[b"bundle_escrow", bundle.as_ref(), nft_mint.as_ref()])
+}
+
+// ----------------------------------------------------------------------------
+// Transactions and state
+// ----------------------------------------------------------------------------
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: auction::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn with_remaining_accounts(
+    mut instruction: Instruction,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+async fn send(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    // A fresh blockhash keeps a retried transaction, e.g. one that failed
+    // before a clock warp, from being rejected as already processed
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+fn assert_auction_error(result: Result<(), BanksClientError>, expected: AuctionError) {
+    match result.expect_err("instruction should fail").unwrap() {
+        TransactionError::InstructionError(0, InstructionError::Custom(code)) => {
+            assert_eq!(code, u32::from(expected), "expected {expected:?}")
+        }
+        other => panic!("expected {expected:?}, got {other:?}"),
+    }
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+async fn warp_to(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    context.set_sysvar(&Clock {
+        unix_timestamp,
+        ..clock
+    });
+}
+
+async fn account(context: &mut ProgramTestContext, address: Pubkey) -> Option<Account> {
+    context.banks_client.get_account(address).await.unwrap()
+}
+
+async fn lamports(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    account(context, address)
+        .await
+        .map_or(0, |account| account.lamports)
+}
+
+async fn token_balance(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    let account = account(context, address)
+        .await
+        .expect("token account exists");
+    spl_token::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+async fn anchor_account<T: AccountDeserialize>(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+) -> T {
+    let account = account(context, address).await.expect("account exists");
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+async fn core_asset_owner(context: &mut ProgramTestContext, asset: Pubkey) -> Pubkey {
+    let account = account(context, asset).await.expect("asset exists");
+    Pubkey::try_from(&account.data[CORE_ASSET_OWNER]).unwrap()
+}
+
+// ----------------------------------------------------------------------------
+// Marketplace fixture
+// ----------------------------------------------------------------------------
+
+/// Seller with one SPL NFT, two funded bidders and a registered payment mint.
+struct Market {
+    seller: Keypair,
+    alice: Keypair,
+    bob: Keypair,
+    nft_mint: Pubkey,
+    nft_token_program: Pubkey,
+    seller_nft_account: Pubkey,
+    alice_nft_account: Pubkey,
+    bob_nft_account: Pubkey,
+    payment_mint: Pubkey,
+    seller_payment_account: Pubkey,
+    alice_payment_account: Pubkey,
+    bob_payment_account: Pubkey,
+    treasury_payment_account: Pubkey,
+    creator: Pubkey,
+    creator_payment_account: Pubkey,
+}
+
+impl Market {
+    fn new(program_test: &mut ProgramTest, payment_mint: Pubkey) -> Self {
+        let seller = add_wallet(program_test);
+        let alice = add_wallet(program_test);
+        let bob = add_wallet(program_test);
+        let (nft_mint, seller_nft_account) = add_nft(program_test, seller.pubkey());
+        let creator = Pubkey::new_unique();
+        let creator_payment_account = get_associated_token_address(&creator, &payment_mint);
+        add_token_account_at(
+            program_test,
+            creator_payment_account,
+            payment_mint,
+            creator,
+            0,
+        );
+        add_payment_mint_config(program_test, payment_mint);
+        Self {
+            alice_nft_account: add_token_account(program_test, nft_mint, alice.pubkey(), 0),
+            bob_nft_account: add_token_account(program_test, nft_mint, bob.pubkey(), 0),
+            seller_payment_account: add_token_account(
+                program_test,
+                payment_mint,
+                seller.pubkey(),
+                0,
+            ),
+            alice_payment_account: add_token_account(
+                program_test,
+                payment_mint,
+                alice.pubkey(),
+                10_000_000,
+            ),
+            bob_payment_account: add_token_account(
+                program_test,
+                payment_mint,
+                bob.pubkey(),
+                10_000_000,
+            ),
+            treasury_payment_account: add_token_account(program_test, payment_mint, TREASURY, 0),
+            seller,
+            alice,
+            bob,
+            nft_mint,
+            nft_token_program: spl_token::ID,
+            seller_nft_account,
+            payment_mint,
+            creator,
+            creator_payment_account,
+        }
+    }
+
+    fn with_payment_mint(program_test: &mut ProgramTest) -> Self {
+        let payment_mint = add_mint(program_test, PAYMENT_DECIMALS, 1_000_000_000);
+        Self::new(program_test, payment_mint)
+    }
+
+    fn list_item(
+        &self,
+        listing_type: ListingType,
+        price: u64,
+        royalty_basis_points: u16,
+    ) -> Instruction {
+        let auction = listing_type == ListingType::Auction;
+        let options = ListingOptions {
+            bid_increment: auction.then_some(BidIncrement::Absolute(100_000)),
+            ..ListingOptions::default()
+        };
+        self.list_item_with_options(listing_type, price, royalty_basis_points, options)
+    }
+
+    fn list_item_with_options(
+        &self,
+        listing_type: ListingType,
+        price: u64,
+        royalty_basis_points: u16,
+        options: ListingOptions,
+    ) -> Instruction {
+        let auction = listing_type == ListingType::Auction;
+        let duration_seconds = auction.then_some(AUCTION_SECONDS);
+        instruction(
+            auction::accounts::ListItem {
+                listing: listing_pda(self.nft_mint),
+                nft_mint: self.nft_mint,
+                payment_mint: self.payment_mint,
+                payment_mint_config: payment_mint_config_pda(self.payment_mint),
+                escrow_nft: escrow_nft_pda(self.nft_mint),
+                seller_nft_account: self.seller_nft_account,
+                seller: self.seller.pubkey(),
+                nft_token_program: self.nft_token_program,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            auction::instruction::ListItem {
+                listing_type,
+                price,
+                duration_seconds,
+                category: ItemCategory::DigitalArt,
+                royalty_basis_points,
+                creator_address: self.creator,
+                options,
+            },
+        )
+    }
+
+    fn place_bid(
+        &self,
+        bidder: &Keypair,
+        bidder_payment_account: Pubkey,
+        amount: u64,
+        listing: &Listing,
+    ) -> Instruction {
+        let outbids = listing.current_bid > 0;
+        instruction(
+            auction::accounts::PlaceBid {
+                listing: listing_pda(self.nft_mint),
+                payment_mint: self.payment_mint,
+                bid_escrow: bid_escrow_pda(self.nft_mint, listing.created_at),
+                bidder_token_account: bidder_payment_account,
+                bidder_refund: bid_refund_pda(self.nft_mint, listing.created_at, bidder.pubkey()),
+                previous_bidder_refund: outbids.then(|| {
+                    bid_refund_pda(self.nft_mint, listing.created_at, listing.highest_bidder)
+                }),
+                bidder: bidder.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            auction::instruction::PlaceBid {
+                amount,
+                max_amount: None,
+            },
+        )
+    }
+
+    fn claim_refund(
+        &self,
+        bidder: &Keypair,
+        bidder_payment_account: Pubkey,
+        key: Pubkey,
+        listing: Pubkey,
+        created_at: i64,
+    ) -> Instruction {
+        instruction(
+            auction::accounts::ClaimRefund {
+                bid_refund: bid_refund_pda(key, created_at, bidder.pubkey()),
+                listing,
+                payment_mint: Some(self.payment_mint),
+                bid_escrow: Some(bid_escrow_pda(key, created_at)),
+                sol_escrow: None,
+                bidder_payment_account: Some(bidder_payment_account),
+                bidder: bidder.pubkey(),
+                token_program: spl_token::ID,
+            },
+            auction::instruction::ClaimRefund {},
+        )
+    }
+
+    fn make_offer(
+        &self,
+        buyer: &Keypair,
+        buyer_payment_account: Pubkey,
+        asset: Pubkey,
+        amount: u64,
+        expires_at: i64,
+    ) -> Instruction {
+        instruction(
+            auction::accounts::MakeOffer {
+                offer: offer_pda(asset, buyer.pubkey()),
+                asset,
+                payment_mint: self.payment_mint,
+                payment_mint_config: payment_mint_config_pda(self.payment_mint),
+                offer_escrow: offer_escrow_pda(asset, buyer.pubkey()),
+                buyer_payment_account,
+                buyer: buyer.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            auction::instruction::MakeOffer { amount, expires_at },
+        )
+    }
+}
+
+/// An English auction opening at 1.0 token with a 5% royalty, where Alice bid
+/// 1.0 and Bob outbid her at 1.5. Returns the listing after Bob's bid.
+async fn auction_with_two_bids(context: &mut ProgramTestContext, market: &Market) -> Listing {
+    send(
+        context,
+        market.list_item(ListingType::Auction, 1_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(context, listing_pda(market.nft_mint)).await;
+    send(
+        context,
+        market.place_bid(
+            &market.alice,
+            market.alice_payment_account,
+            1_000_000,
+            &listing,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(context, listing_pda(market.nft_mint)).await;
+    send(
+        context,
+        market.place_bid(&market.bob, market.bob_payment_account, 1_500_000, &listing),
+        &[&market.bob],
+    )
+    .await
+    .unwrap();
+    anchor_account(context, listing_pda(market.nft_mint)).await
+}
+
+fn settle_auction(market: &Market, listing: &Listing, buyer_nft_account: Pubkey) -> Instruction {
+    instruction(
+        auction::accounts::SettleAuction {
+            listing: listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            payment_mint: market.payment_mint,
+            bid_escrow: bid_escrow_pda(market.nft_mint, listing.created_at),
+            escrow_nft: escrow_nft_pda(market.nft_mint),
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            buyer_nft_account,
+            seller_nft_account: market.seller_nft_account,
+            highest_bidder_refund: Some(bid_refund_pda(
+                market.nft_mint,
+                listing.created_at,
+                listing.highest_bidder,
+            )),
+            winner_sealed_bid: None,
+            seller: market.seller.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            nft_token_program: spl_token::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::SettleAuction {},
+    )
+}
+
+// ----------------------------------------------------------------------------
+// claim_refund / settle_auction / settle_auction_sol / reveal_reserve / buy_now_sol
+// ----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn claim_refund_returns_an_outbid_bid_while_the_auction_runs() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let listing = auction_with_two_bids(&mut context, &market).await;
+    assert_eq!(listing.highest_bidder, market.bob.pubkey());
+
+    let alice_refund = bid_refund_pda(market.nft_mint, listing.created_at, market.alice.pubkey());
+    let refund: BidRefund = anchor_account(&mut context, alice_refund).await;
+    assert_eq!(refund.amount, 1_000_000);
+
+    send(
+        &mut context,
+        market.claim_refund(
+            &market.alice,
+            market.alice_payment_account,
+            market.nft_mint,
+            listing_pda(market.nft_mint),
+            listing.created_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        10_000_000
+    );
+    assert!(account(&mut context, alice_refund).await.is_none());
+    // Bob's bid is still escrowed for the running auction
+    let bid_escrow = bid_escrow_pda(market.nft_mint, listing.created_at);
+    assert_eq!(token_balance(&mut context, bid_escrow).await, 1_500_000);
+}
+
+#[tokio::test]
+async fn claim_refund_rejects_another_bidders_refund_record() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let listing = auction_with_two_bids(&mut context, &market).await;
+
+    // Alice cannot claim through Bob's refund record: its seeds bind the bidder
+    let mut claim = market.claim_refund(
+        &market.alice,
+        market.alice_payment_account,
+        market.nft_mint,
+        listing_pda(market.nft_mint),
+        listing.created_at,
+    );
+    claim.accounts[0].pubkey =
+        bid_refund_pda(market.nft_mint, listing.created_at, market.bob.pubkey());
+    let result = send(&mut context, claim, &[&market.alice]).await;
+    assert!(matches!(
+        result.expect_err("claim should fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(2006)) // ConstraintSeeds
+    ));
+}
+
+#[tokio::test]
+async fn settle_auction_pays_the_split_and_delivers_to_the_winner() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let listing = auction_with_two_bids(&mut context, &market).await;
+
+    let early = send(
+        &mut context,
+        settle_auction(&market, &listing, market.bob_nft_account),
+        &[],
+    )
+    .await;
+    assert_auction_error(early, AuctionError::AuctionNotEnded);
+
+    warp_to(&mut context, listing.end_time).await;
+    send(
+        &mut context,
+        settle_auction(&market, &listing, market.bob_nft_account),
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // 1.5 tokens: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_395_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        30_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        75_000
+    );
+    assert_eq!(token_balance(&mut context, market.bob_nft_account).await, 1);
+    assert!(account(&mut context, listing_pda(market.nft_mint))
+        .await
+        .is_none());
+    assert!(account(&mut context, escrow_nft_pda(market.nft_mint))
+        .await
+        .is_none());
+
+    // Alice's outbid funds outlive the listing; her claim closes the escrow
+    let bid_escrow = bid_escrow_pda(market.nft_mint, listing.created_at);
+    assert_eq!(token_balance(&mut context, bid_escrow).await, 1_000_000);
+    send(
+        &mut context,
+        market.claim_refund(
+            &market.alice,
+            market.alice_payment_account,
+            market.nft_mint,
+            listing_pda(market.nft_mint),
+            listing.created_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        10_000_000
+    );
+    assert!(account(&mut context, bid_escrow).await.is_none());
+}
+
+#[tokio::test]
+async fn settle_auction_rejects_a_buyer_account_not_owned_by_the_winner() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let listing = auction_with_two_bids(&mut context, &market).await;
+
+    warp_to(&mut context, listing.end_time).await;
+    let result = send(
+        &mut context,
+        settle_auction(&market, &listing, market.alice_nft_account),
+        &[],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::InvalidBuyerAccount);
+}
+
+#[tokio::test]
+async fn reveal_reserve_closes_with_the_reveal_window_and_the_high_bid_wins() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+
+    let salt = [7u8; 32];
+    let reserve_price = 2_000_000u64;
+    let commitment: [u8; 32] = Sha256::new()
+        .chain_update(reserve_price.to_le_bytes())
+        .chain_update(salt)
+        .finalize()
+        .into();
+    send(
+        &mut context,
+        market.list_item_with_options(
+            ListingType::Auction,
+            1_000_000,
+            500,
+            ListingOptions {
+                reserve: Some(ReservePrice::Hidden(commitment)),
+                bid_increment: Some(BidIncrement::Absolute(100_000)),
+                ..ListingOptions::default()
+            },
+        ),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    send(
+        &mut context,
+        market.place_bid(
+            &market.alice,
+            market.alice_payment_account,
+            1_500_000,
+            &listing,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+
+    // The seller sat on the reveal until the window lapsed; revealing a
+    // reserve above the high bid now would void a sale that already cleared
+    warp_to(
+        &mut context,
+        listing.end_time + RESERVE_REVEAL_WINDOW_SECONDS,
+    )
+    .await;
+    let reveal = instruction(
+        auction::accounts::RevealReserve {
+            listing: listing_pda(market.nft_mint),
+            seller: market.seller.pubkey(),
+        },
+        auction::instruction::RevealReserve {
+            reserve_price,
+            salt,
+        },
+    );
+    let result = send(&mut context, reveal, &[&market.seller]).await;
+    assert_auction_error(result, AuctionError::ReserveRevealClosed);
+
+    send(
+        &mut context,
+        settle_auction(&market, &listing, market.alice_nft_account),
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // 1.5 tokens: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_395_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+}
+
+fn place_bid_sol(market: &Market, bidder: &Keypair, amount: u64, listing: &Listing) -> Instruction {
+    let outbids = listing.current_bid > 0;
+    instruction(
+        auction::accounts::PlaceBidSol {
+            listing: listing_pda(market.nft_mint),
+            sol_escrow: sol_escrow_pda(market.nft_mint, listing.created_at),
+            bidder_refund: bid_refund_pda(market.nft_mint, listing.created_at, bidder.pubkey()),
+            previous_bidder_refund: outbids.then(|| {
+                bid_refund_pda(market.nft_mint, listing.created_at, listing.highest_bidder)
+            }),
+            bidder: bidder.pubkey(),
+            system_program: system_program::ID,
+        },
+        auction::instruction::PlaceBidSol {
+            amount,
+            max_amount: None,
+        },
+    )
+}
+
+fn settle_auction_sol(
+    market: &Market,
+    listing: &Listing,
+    buyer_nft_account: Pubkey,
+) -> Instruction {
+    instruction(
+        auction::accounts::SettleAuctionSol {
+            listing: listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            sol_escrow: Some(sol_escrow_pda(market.nft_mint, listing.created_at)),
+            escrow_nft: escrow_nft_pda(market.nft_mint),
+            creator: market.creator,
+            buyer_nft_account,
+            seller_nft_account: market.seller_nft_account,
+            highest_bidder_refund: Some(bid_refund_pda(
+                market.nft_mint,
+                listing.created_at,
+                listing.highest_bidder,
+            )),
+            seller: market.seller.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            payer: None,
+            nft_token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::SettleAuctionSol {},
+    )
+}
+
+#[tokio::test]
+async fn settle_auction_sol_pays_the_split_in_lamports_and_delivers_to_the_winner() {
+    let mut program_test = program_test();
+    add_mint_at(&mut program_test, SOL_MINT, 9, 0);
+    program_test.add_account(
+        TREASURY,
+        Account::new(WALLET_LAMPORTS, 0, &system_program::ID),
+    );
+    let market = Market::new(&mut program_test, SOL_MINT);
+    program_test.add_account(
+        market.creator,
+        Account::new(WALLET_LAMPORTS, 0, &system_program::ID),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        market.list_item(ListingType::Auction, 1_000_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    send(
+        &mut context,
+        place_bid_sol(&market, &market.alice, 1_000_000_000, &listing),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+
+    // The NFT only goes to an account the winner owns
+    warp_to(&mut context, listing.end_time).await;
+    let result = send(
+        &mut context,
+        settle_auction_sol(&market, &listing, market.bob_nft_account),
+        &[],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::InvalidBuyerAccount);
+
+    let seller_before = lamports(&mut context, market.seller.pubkey()).await;
+    let treasury_before = lamports(&mut context, TREASURY).await;
+    let creator_before = lamports(&mut context, market.creator).await;
+    // Everything the escrows hold beyond the 1 SOL bid is rent
+    let sol_escrow = sol_escrow_pda(market.nft_mint, listing.created_at);
+    let closed_rent = lamports(&mut context, listing_pda(market.nft_mint)).await
+        + lamports(&mut context, escrow_nft_pda(market.nft_mint)).await
+        + lamports(&mut context, sol_escrow).await
+        - 1_000_000_000;
+    send(
+        &mut context,
+        settle_auction_sol(&market, &listing, market.alice_nft_account),
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // 1 SOL: 2% platform fee, 5% royalty, the rest to the seller. The closed
+    // listing and escrows' rent goes to the treasury
+    assert_eq!(
+        lamports(&mut context, market.seller.pubkey()).await,
+        seller_before + 930_000_000
+    );
+    assert_eq!(
+        lamports(&mut context, TREASURY).await,
+        treasury_before + 20_000_000 + closed_rent
+    );
+    assert_eq!(
+        lamports(&mut context, market.creator).await,
+        creator_before + 50_000_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    assert!(account(&mut context, sol_escrow).await.is_none());
+}
+
+#[tokio::test]
+async fn claim_refund_returns_outbid_lamports_from_the_sol_escrow() {
+    let mut program_test = program_test();
+    add_mint_at(&mut program_test, SOL_MINT, 9, 0);
+    let market = Market::new(&mut program_test, SOL_MINT);
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        market.list_item(ListingType::Auction, 1_000_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    assert!(listing.native_sol);
+
+    // SPL bids are refused on native-SOL auctions
+    let spl_bid = market.place_bid(
+        &market.alice,
+        market.alice_payment_account,
+        1_000_000_000,
+        &listing,
+    );
+    assert!(send(&mut context, spl_bid, &[&market.alice]).await.is_err());
+
+    let alice_before = lamports(&mut context, market.alice.pubkey()).await;
+    send(
+        &mut context,
+        place_bid_sol(&market, &market.alice, 1_000_000_000, &listing),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    send(
+        &mut context,
+        place_bid_sol(&market, &market.bob, 2_000_000_000, &listing),
+        &[&market.bob],
+    )
+    .await
+    .unwrap();
+
+    let sol_escrow = sol_escrow_pda(market.nft_mint, listing.created_at);
+    let escrow_rent = rent_exempt(account(&mut context, sol_escrow).await.unwrap().data.len());
+    assert_eq!(
+        lamports(&mut context, sol_escrow).await,
+        escrow_rent + 3_000_000_000
+    );
+
+    let alice_refund = bid_refund_pda(market.nft_mint, listing.created_at, market.alice.pubkey());
+    send(
+        &mut context,
+        instruction(
+            auction::accounts::ClaimRefund {
+                bid_refund: alice_refund,
+                listing: listing_pda(market.nft_mint),
+                payment_mint: None,
+                bid_escrow: None,
+                sol_escrow: Some(sol_escrow),
+                bidder_payment_account: None,
+                bidder: market.alice.pubkey(),
+                token_program: spl_token::ID,
+            },
+            auction::instruction::ClaimRefund {},
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+
+    // Alice recovers her bid and the refund record's rent; the first bidder
+    // funded the escrow's rent, and Bob's bid stays put
+    assert_eq!(
+        lamports(&mut context, market.alice.pubkey()).await,
+        alice_before - escrow_rent
+    );
+    assert!(account(&mut context, alice_refund).await.is_none());
+    assert_eq!(
+        lamports(&mut context, sol_escrow).await,
+        escrow_rent + 2_000_000_000
+    );
+
+    warp_to(&mut context, listing.end_time - 1).await;
+    let early = send(
+        &mut context,
+        instruction(
+            auction::accounts::SettleAuctionSol {
+                listing: listing_pda(market.nft_mint),
+                nft_mint: market.nft_mint,
+                sol_escrow: Some(sol_escrow),
+                escrow_nft: escrow_nft_pda(market.nft_mint),
+                creator: market.creator,
+                buyer_nft_account: market.bob_nft_account,
+                seller_nft_account: market.seller_nft_account,
+                highest_bidder_refund: Some(bid_refund_pda(
+                    market.nft_mint,
+                    listing.created_at,
+                    market.bob.pubkey(),
+                )),
+                seller: market.seller.pubkey(),
+                treasury: TREASURY,
+                treasury_config: None,
+                payer: None,
+                nft_token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            auction::instruction::SettleAuctionSol {},
+        ),
+        &[],
+    )
+    .await;
+    assert_auction_error(early, AuctionError::AuctionNotEnded);
+}
+
+#[tokio::test]
+async fn buy_now_sol_fails_rather_than_rerouting_a_leg_below_rent() {
+    let mut program_test = program_test();
+    add_mint_at(&mut program_test, SOL_MINT, 9, 0);
+    program_test.add_account(
+        TREASURY,
+        Account::new(WALLET_LAMPORTS, 0, &system_program::ID),
+    );
+    let market = Market::new(&mut program_test, SOL_MINT);
+    let mut context = program_test.start_with_context().await;
+    send(
+        &mut context,
+        market.list_item(ListingType::FixedPrice, 10_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let buy_now_sol = || {
+        instruction(
+            auction::accounts::BuyNowSol {
+                listing: listing_pda(market.nft_mint),
+                nft_mint: market.nft_mint,
+                escrow_nft: escrow_nft_pda(market.nft_mint),
+                seller: market.seller.pubkey(),
+                creator: market.creator,
+                buyer_nft_account: market.alice_nft_account,
+                buyer: market.alice.pubkey(),
+                treasury: TREASURY,
+                treasury_config: None,
+                nft_token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            auction::instruction::BuyNowSol {},
+        )
+    };
+
+    // The 5% royalty on 0.01 SOL cannot fund the creator's empty wallet, and
+    // it is not the seller's to keep either
+    let seller_before = lamports(&mut context, market.seller.pubkey()).await;
+    let result = send(&mut context, buy_now_sol(), &[&market.alice]).await;
+    assert_auction_error(result, AuctionError::PayoutBelowRent);
+    assert_eq!(
+        lamports(&mut context, market.seller.pubkey()).await,
+        seller_before
+    );
+
+    // Once the wallet exists, the same sale goes through
+    let wallet_rent = rent_exempt(0);
+    context.set_account(
+        &market.creator,
+        &Account::new(wallet_rent, 0, &system_program::ID).into(),
+    );
+    send(&mut context, buy_now_sol(), &[&market.alice])
+        .await
+        .unwrap();
+    assert_eq!(
+        lamports(&mut context, market.creator).await,
+        wallet_rent + 500_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+}
+
+// ----------------------------------------------------------------------------
+// accept_offer / accept_offer_core / fill_collection_offer
+// ----------------------------------------------------------------------------
+
+fn accept_offer(
+    market: &Market,
+    buyer: &Keypair,
+    buyer_nft_account: Pubkey,
+    listed: bool,
+) -> Instruction {
+    let nft_mint = market.nft_mint;
+    instruction(
+        auction::accounts::AcceptOffer {
+            offer: offer_pda(nft_mint, buyer.pubkey()),
+            offer_escrow: offer_escrow_pda(nft_mint, buyer.pubkey()),
+            buyer: buyer.pubkey(),
+            nft_mint,
+            payment_mint: market.payment_mint,
+            listing: listed.then(|| listing_pda(nft_mint)),
+            escrow_nft: listed.then(|| escrow_nft_pda(nft_mint)),
+            seller_nft_account: (!listed).then_some(market.seller_nft_account),
+            buyer_nft_account,
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            seller: market.seller.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            nft_token_program: spl_token::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::AcceptOffer {
+            royalty_basis_points: 500,
+            creator_address: market.creator,
+        },
+    )
+}
+
+#[tokio::test]
+async fn accept_offer_sells_an_unlisted_nft_from_the_wallet() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let expires_at = now(&mut context).await + 3600;
+
+    send(
+        &mut context,
+        market.make_offer(
+            &market.alice,
+            market.alice_payment_account,
+            market.nft_mint,
+            2_000_000,
+            expires_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        8_000_000
+    );
+
+    send(
+        &mut context,
+        accept_offer(&market, &market.alice, market.alice_nft_account, false),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_860_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        40_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        100_000
+    );
+    let offer = offer_pda(market.nft_mint, market.alice.pubkey());
+    assert!(account(&mut context, offer).await.is_none());
+    assert!(account(
+        &mut context,
+        offer_escrow_pda(market.nft_mint, market.alice.pubkey())
+    )
+    .await
+    .is_none());
+}
+
+#[tokio::test]
+async fn accept_offer_closes_the_fixed_price_listing_it_fills() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let expires_at = now(&mut context).await + 3600;
+
+    send(
+        &mut context,
+        market.list_item(ListingType::FixedPrice, 3_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    send(
+        &mut context,
+        market.make_offer(
+            &market.alice,
+            market.alice_payment_account,
+            market.nft_mint,
+            2_000_000,
+            expires_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+
+    // The NFT is in escrow, so the wallet path has nothing to move
+    let unlisted = accept_offer(&market, &market.alice, market.alice_nft_account, false);
+    assert!(send(&mut context, unlisted, &[&market.seller])
+        .await
+        .is_err());
+
+    send(
+        &mut context,
+        accept_offer(&market, &market.alice, market.alice_nft_account, true),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_860_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        100_000
+    );
+    assert!(account(&mut context, listing_pda(market.nft_mint))
+        .await
+        .is_none());
+    assert!(account(&mut context, escrow_nft_pda(market.nft_mint))
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn accept_offer_rejects_an_expired_offer() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let expires_at = now(&mut context).await + 3600;
+
+    send(
+        &mut context,
+        market.make_offer(
+            &market.alice,
+            market.alice_payment_account,
+            market.nft_mint,
+            2_000_000,
+            expires_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    warp_to(&mut context, expires_at).await;
+
+    let result = send(
+        &mut context,
+        accept_offer(&market, &market.alice, market.alice_nft_account, false),
+        &[&market.seller],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::OfferExpired);
+}
+
+fn accept_offer_core(
+    market: &Market,
+    buyer: &Keypair,
+    seller: &Keypair,
+    seller_payment_account: Pubkey,
+    asset: Pubkey,
+) -> Instruction {
+    instruction(
+        auction::accounts::AcceptOfferCore {
+            offer: offer_pda(asset, buyer.pubkey()),
+            offer_escrow: offer_escrow_pda(asset, buyer.pubkey()),
+            buyer: buyer.pubkey(),
+            seller: seller.pubkey(),
+            asset,
+            collection: ARTIFACTE_COLLECTION,
+            core_listing: None,
+            core_authority: core_authority_pda(asset),
+            payment_mint: market.payment_mint,
+            seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            treasury: TREASURY,
+            treasury_config: None,
+            mpl_core_program: mpl_core::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::AcceptOfferCore {},
+    )
+}
+
+#[tokio::test]
+async fn accept_offer_core_transfers_the_asset_and_pays_the_owner() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let asset = add_core_asset(
+        &mut program_test,
+        market.seller.pubkey(),
+        ARTIFACTE_COLLECTION,
+    );
+    let mut context = program_test.start_with_context().await;
+    let expires_at = now(&mut context).await + 3600;
+
+    send(
+        &mut context,
+        market.make_offer(
+            &market.alice,
+            market.alice_payment_account,
+            asset,
+            2_000_000,
+            expires_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    send(
+        &mut context,
+        accept_offer_core(
+            &market,
+            &market.alice,
+            &market.seller,
+            market.seller_payment_account,
+            asset,
+        ),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        core_asset_owner(&mut context, asset).await,
+        market.alice.pubkey()
+    );
+    // Artifacte collection sales carry no platform fee; the asset has no royalty
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        2_000_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        0
+    );
+    assert!(
+        account(&mut context, offer_pda(asset, market.alice.pubkey()))
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn accept_offer_core_rejects_a_signer_who_does_not_own_the_asset() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let asset = add_core_asset(
+        &mut program_test,
+        market.seller.pubkey(),
+        ARTIFACTE_COLLECTION,
+    );
+    let mut context = program_test.start_with_context().await;
+    let expires_at = now(&mut context).await + 3600;
+
+    send(
+        &mut context,
+        market.make_offer(
+            &market.alice,
+            market.alice_payment_account,
+            asset,
+            2_000_000,
+            expires_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let result = send(
+        &mut context,
+        accept_offer_core(
+            &market,
+            &market.alice,
+            &market.bob,
+            market.bob_payment_account,
+            asset,
+        ),
+        &[&market.bob],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::Unauthorized);
+    assert_eq!(
+        core_asset_owner(&mut context, asset).await,
+        market.seller.pubkey()
+    );
+}
+
+fn collection_offer_pdas(buyer: Pubkey) -> (Pubkey, Pubkey) {
+    // No attribute predicates: the seed is the hash of an empty list
+    let attributes_hash: [u8; 32] = Sha256::new().finalize().into();
+    (
+        pda(&[
+            b"collection_offer",
+            ARTIFACTE_COLLECTION.as_ref(),
+            buyer.as_ref(),
+            &attributes_hash,
+        ]),
+        pda(&[
+            b"collection_offer_escrow",
+            ARTIFACTE_COLLECTION.as_ref(),
+            buyer.as_ref(),
+            &attributes_hash,
+        ]),
+    )
+}
+
+fn fill_collection_offer(market: &Market, buyer: &Keypair, asset: Pubkey) -> Instruction {
+    let (collection_offer, collection_offer_escrow) = collection_offer_pdas(buyer.pubkey());
+    instruction(
+        auction::accounts::FillCollectionOffer {
+            collection_offer,
+            collection_offer_escrow,
+            buyer: buyer.pubkey(),
+            seller: market.seller.pubkey(),
+            asset,
+            collection: ARTIFACTE_COLLECTION,
+            core_listing: None,
+            core_authority: core_authority_pda(asset),
+            payment_mint: market.payment_mint,
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            treasury: TREASURY,
+            treasury_config: None,
+            mpl_core_program: mpl_core::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::FillCollectionOffer {},
+    )
+}
+
+#[tokio::test]
+async fn fill_collection_offer_fills_each_unit_and_closes_on_the_last() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let first = add_core_asset(
+        &mut program_test,
+        market.seller.pubkey(),
+        ARTIFACTE_COLLECTION,
+    );
+    let second = add_core_asset(
+        &mut program_test,
+        market.seller.pubkey(),
+        ARTIFACTE_COLLECTION,
+    );
+    let outsider = add_core_asset(
+        &mut program_test,
+        market.seller.pubkey(),
+        Pubkey::new_unique(),
+    );
+    let mut context = program_test.start_with_context().await;
+    let expires_at = now(&mut context).await + 3600;
+
+    let (collection_offer, collection_offer_escrow) = collection_offer_pdas(market.alice.pubkey());
+    send(
+        &mut context,
+        instruction(
+            auction::accounts::MakeCollectionOffer {
+                collection_offer,
+                collection: ARTIFACTE_COLLECTION,
+                payment_mint: market.payment_mint,
+                payment_mint_config: payment_mint_config_pda(market.payment_mint),
+                collection_offer_escrow,
+                buyer_payment_account: market.alice_payment_account,
+                buyer: market.alice.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            auction::instruction::MakeCollectionOffer {
+                price: 1_000_000,
+                quantity: 2,
+                expires_at,
+                attributes: Vec::new(),
+            },
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, collection_offer_escrow).await,
+        2_000_000
+    );
+
+    // Assets outside the offer's collection are refused
+    let result = send(
+        &mut context,
+        fill_collection_offer(&market, &market.alice, outsider),
+        &[&market.seller],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::Unauthorized);
+
+    send(
+        &mut context,
+        fill_collection_offer(&market, &market.alice, first),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let offer: CollectionOffer = anchor_account(&mut context, collection_offer).await;
+    assert_eq!(offer.quantity_remaining, 1);
+    assert_eq!(
+        core_asset_owner(&mut context, first).await,
+        market.alice.pubkey()
+    );
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_000_000
+    );
+
+    send(
+        &mut context,
+        fill_collection_offer(&market, &market.alice, second),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        core_asset_owner(&mut context, second).await,
+        market.alice.pubkey()
+    );
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        2_000_000
+    );
+    assert!(account(&mut context, collection_offer).await.is_none());
+    assert!(account(&mut context, collection_offer_escrow)
+        .await
+        .is_none());
+}
+
+// ----------------------------------------------------------------------------
+// buy_bundle / settle_bundle
+// ----------------------------------------------------------------------------
+
+/// A second SPL NFT for the seller, with empty token accounts for both bidders.
+struct BundleItemAccounts {
+    nft_mint: Pubkey,
+    seller_nft_account: Pubkey,
+    alice_nft_account: Pubkey,
+    bob_nft_account: Pubkey,
+}
+
+impl BundleItemAccounts {
+    fn first(market: &Market) -> Self {
+        Self {
+            nft_mint: market.nft_mint,
+            seller_nft_account: market.seller_nft_account,
+            alice_nft_account: market.alice_nft_account,
+            bob_nft_account: market.bob_nft_account,
+        }
+    }
+
+    fn new(program_test: &mut ProgramTest, market: &Market) -> Self {
+        let (nft_mint, seller_nft_account) = add_nft(program_test, market.seller.pubkey());
+        Self {
+            nft_mint,
+            seller_nft_account,
+            alice_nft_account: add_token_account(program_test, nft_mint, market.alice.pubkey(), 0),
+            bob_nft_account: add_token_account(program_test, nft_mint, market.bob.pubkey(), 0),
+        }
+    }
+}
+
+/// Create, fill and open bundle 1 with `items`; returns the bundle PDA.
+async fn open_bundle(
+    context: &mut ProgramTestContext,
+    market: &Market,
+    items: &[BundleItemAccounts],
+    listing_type: ListingType,
+    price: u64,
+) -> Pubkey {
+    let seller = market.seller.pubkey();
+    let bundle = bundle_pda(seller, 1);
+    let auction = listing_type == ListingType::Auction;
+    let duration_seconds = auction.then_some(AUCTION_SECONDS);
+    send(
+        context,
+        instruction(
+            auction::accounts::CreateBundle {
+                bundle,
+                payment_mint: market.payment_mint,
+                payment_mint_config: payment_mint_config_pda(market.payment_mint),
+                seller,
+                system_program: system_program::ID,
+            },
+            auction::instruction::CreateBundle {
+                bundle_id: 1,
+                listing_type,
+                price,
+                duration_seconds,
+                category: ItemCategory::TCGCards,
+                bid_increment: auction.then_some(BidIncrement::Absolute(100_000)),
+            },
+        ),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    for item in items {
+        send(
+            context,
+            instruction(
+                auction::accounts::AddBundleItem {
+                    bundle,
+                    nft_mint: item.nft_mint,
+                    bundle_escrow: bundle_escrow_pda(bundle, item.nft_mint),
+                    seller_nft_account: item.seller_nft_account,
+                    seller,
+                    nft_token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                },
+                auction::instruction::AddBundleItem {
+                    royalty_basis_points: 0,
+                    creator_address: Pubkey::default(),
+                    weight: 0,
+                },
+            ),
+            &[&market.seller],
+        )
+        .await
+        .unwrap();
+    }
+    send(
+        context,
+        instruction(
+            auction::accounts::OpenBundle {
+                bundle,
+                payment_mint_config: payment_mint_config_pda(market.payment_mint),
+                seller,
+            },
+            auction::instruction::OpenBundle { start_time: None },
+        ),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    bundle
+}
+
+/// Per-item remaining accounts delivering `items` to the accounts `destination` picks.
+fn bundle_item_metas(
+    market: &Market,
+    bundle: Pubkey,
+    items: &[BundleItemAccounts],
+    destination: impl Fn(&BundleItemAccounts) -> Pubkey,
+) -> Vec<AccountMeta> {
+    items
+        .iter()
+        .flat_map(|item| {
+            [
+                AccountMeta::new_readonly(item.nft_mint, false),
+                AccountMeta::new(bundle_escrow_pda(bundle, item.nft_mint), false),
+                AccountMeta::new(destination(item), false),
+                AccountMeta::new(market.creator_payment_account, false),
+            ]
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn buy_bundle_delivers_every_item_for_one_price() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let second = BundleItemAccounts::new(&mut program_test, &market);
+    let mut context = program_test.start_with_context().await;
+    let items = [BundleItemAccounts::first(&market), second];
+    let bundle = open_bundle(
+        &mut context,
+        &market,
+        &items,
+        ListingType::FixedPrice,
+        2_000_000,
+    )
+    .await;
+
+    let buy = instruction(
+        auction::accounts::BuyBundle {
+            bundle,
+            seller: market.seller.pubkey(),
+            buyer: market.alice.pubkey(),
+            payment_mint: market.payment_mint,
+            buyer_payment_account: market.alice_payment_account,
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            treasury: TREASURY,
+            treasury_config: None,
+            collection: None,
+            nft_token_program: spl_token::ID,
+            nft_token_2022_program: spl_token_2022::ID,
+            mpl_core_program: mpl_core::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::BuyBundle {},
+    );
+
+    // Every item's accounts must be passed
+    let partial = with_remaining_accounts(
+        buy.clone(),
+        bundle_item_metas(&market, bundle, &items[..1], |item| item.alice_nft_account),
+    );
+    assert_auction_error(
+        send(&mut context, partial, &[&market.alice]).await,
+        AuctionError::InvalidBundleAccounts,
+    );
+
+    let buy = with_remaining_accounts(
+        buy,
+        bundle_item_metas(&market, bundle, &items, |item| item.alice_nft_account),
+    );
+    send(&mut context, buy, &[&market.alice]).await.unwrap();
+
+    for item in &items {
+        assert_eq!(token_balance(&mut context, item.alice_nft_account).await, 1);
+        assert!(
+            account(&mut context, bundle_escrow_pda(bundle, item.nft_mint))
+                .await
+                .is_none()
+        );
+    }
+    // 2% platform fee on each item's half of the price
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_960_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        40_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        8_000_000
+    );
+    assert!(account(&mut context, bundle).await.is_none());
+}
+
+fn place_bundle_bid(
+    market: &Market,
+    bundle: Pubkey,
+    state: &Bundle,
+    bidder: &Keypair,
+    bidder_payment_account: Pubkey,
+    amount: u64,
+) -> Instruction {
+    instruction(
+        auction::accounts::PlaceBundleBid {
+            bidder: bidder.pubkey(),
+            bundle,
+            payment_mint: market.payment_mint,
+            bid_escrow: bid_escrow_pda(bundle, state.created_at),
+            bidder_payment_account,
+            bidder_refund: bid_refund_pda(bundle, state.created_at, bidder.pubkey()),
+            previous_bidder_refund: (state.current_bid > 0)
+                .then(|| bid_refund_pda(bundle, state.created_at, state.highest_bidder)),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::PlaceBundleBid { amount },
+    )
+}
+
+#[tokio::test]
+async fn settle_bundle_delivers_to_the_winner_and_leaves_the_outbid_refund_claimable() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let second = BundleItemAccounts::new(&mut program_test, &market);
+    let mut context = program_test.start_with_context().await;
+    let items = [BundleItemAccounts::first(&market), second];
+    let bundle = open_bundle(
+        &mut context,
+        &market,
+        &items,
+        ListingType::Auction,
+        1_000_000,
+    )
+    .await;
+
+    let state: Bundle = anchor_account(&mut context, bundle).await;
+    send(
+        &mut context,
+        place_bundle_bid(
+            &market,
+            bundle,
+            &state,
+            &market.alice,
+            market.alice_payment_account,
+            1_000_000,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let state: Bundle = anchor_account(&mut context, bundle).await;
+    let low = place_bundle_bid(
+        &market,
+        bundle,
+        &state,
+        &market.bob,
+        market.bob_payment_account,
+        1_050_000,
+    );
+    assert_auction_error(
+        send(&mut context, low, &[&market.bob]).await,
+        AuctionError::BidTooLow,
+    );
+    send(
+        &mut context,
+        place_bundle_bid(
+            &market,
+            bundle,
+            &state,
+            &market.bob,
+            market.bob_payment_account,
+            1_200_000,
+        ),
+        &[&market.bob],
+    )
+    .await
+    .unwrap();
+    let state: Bundle = anchor_account(&mut context, bundle).await;
+    assert_eq!(state.highest_bidder, market.bob.pubkey());
+
+    let settle = with_remaining_accounts(
+        instruction(
+            auction::accounts::SettleBundle {
+                payer: context.payer.pubkey(),
+                bundle,
+                seller: market.seller.pubkey(),
+                winner: market.bob.pubkey(),
+                payment_mint: market.payment_mint,
+                bid_escrow: Some(bid_escrow_pda(bundle, state.created_at)),
+                seller_payment_account: market.seller_payment_account,
+                treasury_payment_account: market.treasury_payment_account,
+                winner_bid_refund: None,
+                treasury: TREASURY,
+                treasury_config: None,
+                collection: None,
+                nft_token_program: spl_token::ID,
+                nft_token_2022_program: spl_token_2022::ID,
+                mpl_core_program: mpl_core::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            auction::instruction::SettleBundle {},
+        ),
+        bundle_item_metas(&market, bundle, &items, |item| item.bob_nft_account),
+    );
+    assert_auction_error(
+        send(&mut context, settle.clone(), &[]).await,
+        AuctionError::AuctionNotEnded,
+    );
+
+    warp_to(&mut context, state.end_time).await;
+    send(&mut context, settle, &[]).await.unwrap();
+
+    for item in &items {
+        assert_eq!(token_balance(&mut context, item.bob_nft_account).await, 1);
+    }
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_176_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        24_000
+    );
+    assert!(account(&mut context, bundle).await.is_none());
+
+    // Alice's outbid bid is still escrowed under the closed bundle instance
+    let bid_escrow = bid_escrow_pda(bundle, state.created_at);
+    assert_eq!(token_balance(&mut context, bid_escrow).await, 1_000_000);
+    send(
+        &mut context,
+        market.claim_refund(
+            &market.alice,
+            market.alice_payment_account,
+            bundle,
+            bundle,
+            state.created_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        10_000_000
+    );
+    assert!(account(&mut context, bid_escrow).await.is_none());
+}
+
+// ----------------------------------------------------------------------------
+// fill_swap
+// ----------------------------------------------------------------------------
+
+/// The seller escrows their NFT for `wanted` plus a 0.1 token top-up from the taker.
+async fn make_swap(context: &mut ProgramTestContext, market: &Market, wanted: Pubkey) {
+    let maker = market.seller.pubkey();
+    send(
+        context,
+        instruction(
+            auction::accounts::MakeSwap {
+                swap: pda(&[b"swap", market.nft_mint.as_ref()]),
+                nft_mint: market.nft_mint,
+                swap_escrow: pda(&[b"swap_escrow", market.nft_mint.as_ref()]),
+                maker_nft_account: market.seller_nft_account,
+                maker,
+                payment_mint: Some(market.payment_mint),
+                payment_mint_config: Some(payment_mint_config_pda(market.payment_mint)),
+                maker_payment_account: None,
+                swap_payment_escrow: None,
+                nft_token_program: spl_token::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            auction::instruction::MakeSwap {
+                terms: SwapTerms {
+                    want: SwapWant::Asset,
+                    wanted,
+                    attributes: Vec::new(),
+                    top_up: 100_000,
+                    top_up_payer: TopUpPayer::Taker,
+                    expires_at: 0,
+                },
+            },
+        ),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+}
+
+/// Alice fills the swap with `taker_mint` from `taker_nft_account`.
+fn fill_swap(
+    market: &Market,
+    taker_mint: Pubkey,
+    taker_nft_account: Pubkey,
+    maker_nft_account: Pubkey,
+) -> Instruction {
+    let swap_escrow = pda(&[b"swap_escrow", market.nft_mint.as_ref()]);
+    with_remaining_accounts(
+        instruction(
+            auction::accounts::FillSwap {
+                swap: pda(&[b"swap", market.nft_mint.as_ref()]),
+                maker: market.seller.pubkey(),
+                taker: market.alice.pubkey(),
+                escrow_authority: swap_escrow,
+                payment_mint: Some(market.payment_mint),
+                taker_payment_account: Some(market.alice_payment_account),
+                maker_payment_account: Some(market.seller_payment_account),
+                swap_payment_escrow: None,
+                nft_token_program: spl_token::ID,
+                nft_token_2022_program: spl_token_2022::ID,
+                token_metadata_program: anchor_spl::metadata::mpl_token_metadata::ID,
+                sysvar_instructions: sysvar::instructions::ID,
+                ata_program: anchor_spl::associated_token::ID,
+                authorization_rules_program: None,
+                mpl_core_program: mpl_core::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            auction::instruction::FillSwap {
+                taker_kind: SwapAssetKind::Spl,
+            },
+        ),
+        vec![
+            // Maker's leg: escrowed NFT to Alice
+            AccountMeta::new_readonly(market.nft_mint, false),
+            AccountMeta::new(swap_escrow, false),
+            AccountMeta::new(market.alice_nft_account, false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            // Taker's leg: Alice's NFT to the maker
+            AccountMeta::new_readonly(taker_mint, false),
+            AccountMeta::new(taker_nft_account, false),
+            AccountMeta::new(maker_nft_account, false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn fill_swap_exchanges_both_nfts_and_the_top_up() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let (wanted_mint, alice_wanted_account) = add_nft(&mut program_test, market.alice.pubkey());
+    let seller_wanted_account =
+        add_token_account(&mut program_test, wanted_mint, market.seller.pubkey(), 0);
+    let (other_mint, alice_other_account) = add_nft(&mut program_test, market.alice.pubkey());
+    let seller_other_account =
+        add_token_account(&mut program_test, other_mint, market.seller.pubkey(), 0);
+    let mut context = program_test.start_with_context().await;
+    make_swap(&mut context, &market, wanted_mint).await;
+
+    // Only the wanted mint fills the swap
+    let result = send(
+        &mut context,
+        fill_swap(
+            &market,
+            other_mint,
+            alice_other_account,
+            seller_other_account,
+        ),
+        &[&market.alice],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::SwapMismatch);
+
+    send(
+        &mut context,
+        fill_swap(
+            &market,
+            wanted_mint,
+            alice_wanted_account,
+            seller_wanted_account,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    assert_eq!(token_balance(&mut context, seller_wanted_account).await, 1);
+    assert_eq!(token_balance(&mut context, alice_wanted_account).await, 0);
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        100_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        9_900_000
+    );
+    assert!(
+        account(&mut context, pda(&[b"swap", market.nft_mint.as_ref()]))
+            .await
+            .is_none()
+    );
+    assert!(account(
+        &mut context,
+        pda(&[b"swap_escrow", market.nft_mint.as_ref()])
+    )
+    .await
+    .is_none());
+}
+
+// ----------------------------------------------------------------------------
+// migrate_listing
+// ----------------------------------------------------------------------------
+
+/// Escrow the market's NFT and `leader`'s `bid` under an English auction in the
+/// 241-byte layout written before the auction settings were appended. Legacy
+/// auctions used the unscoped (instance 0) escrow PDAs.
+async fn install_legacy_auction(
+    context: &mut ProgramTestContext,
+    market: &Market,
+    leader: Pubkey,
+    bid: u64,
+) -> i64 {
+    let end_time = now(context).await + AUCTION_SECONDS;
+    let (listing, bump) =
+        Pubkey::find_program_address(&[b"listing", market.nft_mint.as_ref()], &auction::ID);
+    let mut data = Listing::DISCRIMINATOR.to_vec();
+    (
+        market.seller.pubkey(),
+        market.nft_mint,
+        market.payment_mint,
+        1_000_000u64,
+    )
+        .serialize(&mut data)
+        .unwrap();
+    (
+        ListingType::Auction,
+        ItemCategory::DigitalArt,
+        end_time - AUCTION_SECONDS,
+        end_time,
+        ListingStatus::Active,
+    )
+        .serialize(&mut data)
+        .unwrap();
+    (
+        escrow_nft_pda(market.nft_mint),
+        bid,
+        leader,
+        false,
+        false,
+        false,
+        500u16,
+        market.creator,
+        bump,
+    )
+        .serialize(&mut data)
+        .unwrap();
+    assert_eq!(data.len(), 241);
+
+    let escrow_nft = escrow_nft_pda(market.nft_mint);
+    let bid_escrow = bid_escrow_pda(market.nft_mint, 0);
+    for (address, account) in [
+        (
+            listing,
+            Account {
+                lamports: rent_exempt(data.len()),
+                data,
+                owner: auction::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        ),
+        (
+            market.seller_nft_account,
+            token_account(market.nft_mint, market.seller.pubkey(), 0),
+        ),
+        (escrow_nft, token_account(market.nft_mint, escrow_nft, 1)),
+        (
+            bid_escrow,
+            token_account(market.payment_mint, bid_escrow, bid),
+        ),
+    ] {
+        context.set_account(&address, &account.into());
+    }
+    end_time
+}
+
+fn migrate_listing(market: &Market, leader_refund: Option<Pubkey>) -> Instruction {
+    instruction(
+        auction::accounts::MigrateListing {
+            listing: listing_pda(market.nft_mint),
+            leader_refund,
+            payer: market.seller.pubkey(),
+            system_program: system_program::ID,
+        },
+        auction::instruction::MigrateListing {},
+    )
+}
+
+#[tokio::test]
+async fn migrated_legacy_auction_settles_to_its_leader() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    install_legacy_auction(&mut context, &market, market.alice.pubkey(), 1_500_000).await;
+
+    // The standing leader's refund record must be created with the migration
+    let result = send(
+        &mut context,
+        migrate_listing(&market, None),
+        &[&market.seller],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::InvalidRefundAccount);
+
+    let alice_refund = bid_refund_pda(market.nft_mint, 0, market.alice.pubkey());
+    send(
+        &mut context,
+        migrate_listing(&market, Some(alice_refund)),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let migrated = account(&mut context, listing_pda(market.nft_mint))
+        .await
+        .unwrap();
+    assert_eq!(migrated.data.len(), 8 + Listing::INIT_SPACE);
+    let refund: BidRefund = anchor_account(&mut context, alice_refund).await;
+    assert_eq!(
+        (refund.bidder, refund.amount, refund.listing_created_at),
+        (market.alice.pubkey(), 0, 0)
+    );
+
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    assert_eq!(listing.highest_bidder_max, 1_500_000);
+    warp_to(&mut context, listing.end_time).await;
+    send(
+        &mut context,
+        settle_auction(&market, &listing, market.alice_nft_account),
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // 1.5 tokens: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_395_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        75_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    // The whole legacy bid was the price, so the leader has nothing to claim
+    let refund: BidRefund = anchor_account(&mut context, alice_refund).await;
+    assert_eq!(refund.amount, 0);
+}
+
+#[tokio::test]
+async fn migrated_legacy_auction_credits_its_leader_when_outbid() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let bob_payment_account = add_token_account(
+        &mut program_test,
+        market.payment_mint,
+        market.bob.pubkey(),
+        200_000_000,
+    );
+    let mut context = program_test.start_with_context().await;
+    install_legacy_auction(&mut context, &market, market.alice.pubkey(), 1_500_000).await;
+    let alice_refund = bid_refund_pda(market.nft_mint, 0, market.alice.pubkey());
+    send(
+        &mut context,
+        migrate_listing(&market, Some(alice_refund)),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+
+    // Migrated listings keep the legacy flat step of 100 tokens
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    send(
+        &mut context,
+        market.place_bid(&market.bob, bob_payment_account, 101_500_000, &listing),
+        &[&market.bob],
+    )
+    .await
+    .unwrap();
+    let refund: BidRefund = anchor_account(&mut context, alice_refund).await;
+    assert_eq!(refund.amount, 1_500_000);
+
+    send(
+        &mut context,
+        market.claim_refund(
+            &market.alice,
+            market.alice_payment_account,
+            market.nft_mint,
+            listing_pda(market.nft_mint),
+            0,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        11_500_000
+    );
+    let bid_escrow = bid_escrow_pda(market.nft_mint, 0);
+    assert_eq!(token_balance(&mut context, bid_escrow).await, 101_500_000);
+}
+
+// ----------------------------------------------------------------------------
+// list_item
+// ----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn list_item_rejects_a_wns_royalty_no_sale_could_split() {
+    for (royalty_basis_points, expected) in [
+        ("50", AuctionError::InvalidRoyaltyBps),
+        ("1500", AuctionError::RoyaltyTooHigh),
+    ] {
+        let mut program_test = program_test();
+        let mut market = Market::with_payment_mint(&mut program_test);
+        (market.nft_mint, market.seller_nft_account) = add_wns_nft(
+            &mut program_test,
+            market.seller.pubkey(),
+            royalty_basis_points,
+        );
+        market.nft_token_program = spl_token_2022::ID;
+        let mut context = program_test.start_with_context().await;
+
+        // WNS charges its own royalty at sale time, which the split must absorb
+        let result = send(
+            &mut context,
+            market.list_item(ListingType::FixedPrice, 1_000_000, 0),
+            &[&market.seller],
+        )
+        .await;
+        assert_auction_error(result, expected);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// buy_now_pnft / settle_auction_pnft
+// ----------------------------------------------------------------------------
+
+/// The escrow authority's token account, which Token Metadata would create on
+/// the first transfer into escrow.
+fn add_pnft_escrow(program_test: &mut ProgramTest, market: &Market) -> Pubkey {
+    let escrow_authority = escrow_authority_pda(market.nft_mint);
+    let escrow_nft_token = get_associated_token_address(&escrow_authority, &market.nft_mint);
+    add_token_account_at(
+        program_test,
+        escrow_nft_token,
+        market.nft_mint,
+        escrow_authority,
+        0,
+    );
+    escrow_nft_token
+}
+
+fn token_record(nft_mint: Pubkey, token: Pubkey) -> Pubkey {
+    TokenRecord::find_pda(&nft_mint, &token).0
+}
+
+fn list_item_pnft(
+    market: &Market,
+    listing_type: ListingType,
+    price: u64,
+    royalty_basis_points: u16,
+) -> Instruction {
+    let auction = listing_type == ListingType::Auction;
+    let escrow_authority = escrow_authority_pda(market.nft_mint);
+    let escrow_nft_token = get_associated_token_address(&escrow_authority, &market.nft_mint);
+    instruction(
+        auction::accounts::ListItemPnft {
+            listing: listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            nft_metadata: Metadata::find_pda(&market.nft_mint).0,
+            nft_edition: MasterEdition::find_pda(&market.nft_mint).0,
+            seller_nft_token: market.seller_nft_account,
+            seller_token_record: token_record(market.nft_mint, market.seller_nft_account),
+            escrow_authority,
+            escrow_nft_token,
+            escrow_token_record: token_record(market.nft_mint, escrow_nft_token),
+            payment_mint: market.payment_mint,
+            payment_mint_config: payment_mint_config_pda(market.payment_mint),
+            seller: market.seller.pubkey(),
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token::ID,
+            ata_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            sysvar_instructions: sysvar::instructions::ID,
+            authorization_rules_program: None,
+            authorization_rules: None,
+        },
+        auction::instruction::ListItemPnft {
+            listing_type,
+            price,
+            duration_seconds: auction.then_some(AUCTION_SECONDS),
+            category: ItemCategory::DigitalArt,
+            royalty_basis_points,
+            creator_address: market.creator,
+            options: ListingOptions {
+                bid_increment: auction.then_some(BidIncrement::Absolute(100_000)),
+                ..ListingOptions::default()
+            },
+        },
+    )
+}
+
+fn buy_now_pnft(
+    market: &Market,
+    buyer: &Keypair,
+    buyer_nft_token: Pubkey,
+    buyer_payment_account: Pubkey,
+) -> Instruction {
+    let escrow_authority = escrow_authority_pda(market.nft_mint);
+    let escrow_nft_token = get_associated_token_address(&escrow_authority, &market.nft_mint);
+    instruction(
+        auction::accounts::BuyNowPnft {
+            listing: listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            nft_metadata: Metadata::find_pda(&market.nft_mint).0,
+            nft_edition: MasterEdition::find_pda(&market.nft_mint).0,
+            escrow_authority,
+            escrow_nft_token,
+            escrow_token_record: token_record(market.nft_mint, escrow_nft_token),
+            buyer_nft_token,
+            buyer_token_record: token_record(market.nft_mint, buyer_nft_token),
+            payment_mint: market.payment_mint,
+            buyer_payment_account,
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            buyer: buyer.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token::ID,
+            payment_token_program: spl_token::ID,
+            ata_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            sysvar_instructions: sysvar::instructions::ID,
+            authorization_rules_program: None,
+            authorization_rules: None,
+        },
+        auction::instruction::BuyNowPnft {},
+    )
+}
+
+fn settle_auction_pnft(
+    market: &Market,
+    listing: &Listing,
+    destination_owner: Pubkey,
+    destination_nft_token: Pubkey,
+) -> Instruction {
+    let escrow_authority = escrow_authority_pda(market.nft_mint);
+    let escrow_nft_token = get_associated_token_address(&escrow_authority, &market.nft_mint);
+    instruction(
+        auction::accounts::SettleAuctionPnft {
+            listing: listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            nft_metadata: Metadata::find_pda(&market.nft_mint).0,
+            nft_edition: MasterEdition::find_pda(&market.nft_mint).0,
+            escrow_authority,
+            escrow_nft_token,
+            escrow_token_record: token_record(market.nft_mint, escrow_nft_token),
+            destination_owner,
+            destination_nft_token,
+            destination_token_record: token_record(market.nft_mint, destination_nft_token),
+            payment_mint: market.payment_mint,
+            bid_escrow: bid_escrow_pda(market.nft_mint, listing.created_at),
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            highest_bidder_refund: Some(bid_refund_pda(
+                market.nft_mint,
+                listing.created_at,
+                listing.highest_bidder,
+            )),
+            winner_sealed_bid: None,
+            seller: market.seller.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            payer: market.seller.pubkey(),
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token::ID,
+            payment_token_program: spl_token::ID,
+            ata_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            sysvar_instructions: sysvar::instructions::ID,
+            authorization_rules_program: None,
+            authorization_rules: None,
+        },
+        auction::instruction::SettleAuctionPnft {},
+    )
+}
+
+#[tokio::test]
+async fn buy_now_pnft_pays_the_split_and_transfers_through_token_metadata() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let escrow_nft_token = add_pnft_escrow(&mut program_test, &market);
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        list_item_pnft(&market, ListingType::FixedPrice, 1_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(token_balance(&mut context, escrow_nft_token).await, 1);
+    assert_eq!(
+        token_balance(&mut context, market.seller_nft_account).await,
+        0
+    );
+
+    // The pNFT only moves through Metaplex's Token Metadata program
+    let mut spoofed = buy_now_pnft(
+        &market,
+        &market.alice,
+        market.alice_nft_account,
+        market.alice_payment_account,
+    );
+    let program_index = spoofed
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == mpl_token_metadata::ID)
+        .unwrap();
+    spoofed.accounts[program_index].pubkey = mpl_core::ID;
+    let result = send(&mut context, spoofed, &[&market.alice]).await;
+    assert_auction_error(result, AuctionError::Unauthorized);
+
+    send(
+        &mut context,
+        buy_now_pnft(
+            &market,
+            &market.alice,
+            market.alice_nft_account,
+            market.alice_payment_account,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+
+    // 1.0 token: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        930_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        20_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        50_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    assert!(account(&mut context, listing_pda(market.nft_mint))
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn settle_auction_pnft_delivers_only_to_the_winner() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    add_pnft_escrow(&mut program_test, &market);
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        list_item_pnft(&market, ListingType::Auction, 1_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    assert!(listing.is_pnft);
+    send(
+        &mut context,
+        market.place_bid(
+            &market.alice,
+            market.alice_payment_account,
+            1_000_000,
+            &listing,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+    send(
+        &mut context,
+        market.place_bid(&market.bob, market.bob_payment_account, 1_500_000, &listing),
+        &[&market.bob],
+    )
+    .await
+    .unwrap();
+    let listing: Listing = anchor_account(&mut context, listing_pda(market.nft_mint)).await;
+
+    warp_to(&mut context, listing.end_time).await;
+    let result = send(
+        &mut context,
+        settle_auction_pnft(
+            &market,
+            &listing,
+            market.alice.pubkey(),
+            market.alice_nft_account,
+        ),
+        &[&market.seller],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::InvalidBuyerAccount);
+
+    send(
+        &mut context,
+        settle_auction_pnft(
+            &market,
+            &listing,
+            market.bob.pubkey(),
+            market.bob_nft_account,
+        ),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+
+    // 1.5 tokens: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        1_395_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        75_000
+    );
+    assert_eq!(token_balance(&mut context, market.bob_nft_account).await, 1);
+}
+
+// ----------------------------------------------------------------------------
+// list_core_auction / place_core_bid / settle_core_auction
+// ----------------------------------------------------------------------------
+
+fn list_core_auction(market: &Market, asset: Pubkey, start_price: u64) -> Instruction {
+    instruction(
+        auction::accounts::ListCoreAuction {
+            seller: market.seller.pubkey(),
+            asset,
+            collection: ARTIFACTE_COLLECTION,
+            payment_mint: market.payment_mint,
+            payment_mint_config: payment_mint_config_pda(market.payment_mint),
+            core_listing: core_listing_pda(asset),
+            core_bond: core_bond_pda(asset),
+            seller_payment_account: market.seller_payment_account,
+            core_authority: core_authority_pda(asset),
+            mpl_core_program: mpl_core::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::ListCoreAuction {
+            start_price_usdc: start_price,
+            duration_seconds: AUCTION_SECONDS,
+            start_time: None,
+            bid_increment: None,
+        },
+    )
+}
+
+fn place_core_bid(
+    market: &Market,
+    bidder: &Keypair,
+    bidder_payment_account: Pubkey,
+    amount: u64,
+    listing: &CoreListing,
+) -> Instruction {
+    let previous_bidder_refund = (listing.current_bid > 0)
+        .then(|| bid_refund_pda(listing.asset, listing.created_at, listing.highest_bidder));
+    instruction(
+        auction::accounts::PlaceCoreBid {
+            bidder: bidder.pubkey(),
+            asset: listing.asset,
+            collection: ARTIFACTE_COLLECTION,
+            core_listing: core_listing_pda(listing.asset),
+            core_authority: core_authority_pda(listing.asset),
+            payment_mint: market.payment_mint,
+            bid_escrow: bid_escrow_pda(listing.asset, listing.created_at),
+            bidder_payment_account,
+            bidder_refund: bid_refund_pda(listing.asset, listing.created_at, bidder.pubkey()),
+            previous_bidder_refund,
+            mpl_core_program: mpl_core::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::PlaceCoreBid { amount },
+    )
+}
+
+fn settle_core_auction(market: &Market, listing: &CoreListing, with_bond: bool) -> Instruction {
+    instruction(
+        auction::accounts::SettleCoreAuction {
+            payer: market.seller.pubkey(),
+            asset: listing.asset,
+            collection: ARTIFACTE_COLLECTION,
+            core_listing: core_listing_pda(listing.asset),
+            seller: listing.seller,
+            winner: listing.highest_bidder,
+            core_authority: core_authority_pda(listing.asset),
+            payment_mint: market.payment_mint,
+            bid_escrow: Some(bid_escrow_pda(listing.asset, listing.created_at)),
+            core_bond: with_bond.then(|| core_bond_pda(listing.asset)),
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            winner_bid_refund: Some(bid_refund_pda(
+                listing.asset,
+                listing.created_at,
+                listing.highest_bidder,
+            )),
+            treasury: TREASURY,
+            treasury_config: None,
+            mpl_core_program: mpl_core::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::SettleCoreAuction {},
+    )
+}
+
+#[tokio::test]
+async fn settle_core_auction_forfeits_the_bond_to_the_winner_on_non_delivery() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let asset = add_core_asset(
+        &mut program_test,
+        market.seller.pubkey(),
+        ARTIFACTE_COLLECTION,
+    );
+    let mut context = program_test.start_with_context().await;
+    context.set_account(
+        &market.seller_payment_account,
+        &token_account(market.payment_mint, market.seller.pubkey(), 1_000_000).into(),
+    );
+    let alice_before = token_balance(&mut context, market.alice_payment_account).await;
+
+    // A 1.0 token start price posts a 0.1 token bond
+    send(
+        &mut context,
+        list_core_auction(&market, asset, 1_000_000),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, core_bond_pda(asset)).await,
+        100_000
+    );
+    let listing: CoreListing = anchor_account(&mut context, core_listing_pda(asset)).await;
+    send(
+        &mut context,
+        place_core_bid(
+            &market,
+            &market.alice,
+            market.alice_payment_account,
+            1_000_000,
+            &listing,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    let listing: CoreListing = anchor_account(&mut context, core_listing_pda(asset)).await;
+
+    // The stub keeps no plugins, so the asset reads as having had its
+    // TransferDelegate revoked by the seller
+    warp_to(&mut context, listing.end_time).await;
+    let result = send(
+        &mut context,
+        settle_core_auction(&market, &listing, false),
+        &[&market.seller],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::InvalidSellerBond);
+
+    send(
+        &mut context,
+        settle_core_auction(&market, &listing, true),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        core_asset_owner(&mut context, asset).await,
+        market.seller.pubkey()
+    );
+    assert!(account(&mut context, core_bond_pda(asset)).await.is_none());
+    assert!(account(&mut context, core_listing_pda(asset))
+        .await
+        .is_none());
+    let refund: BidRefund = anchor_account(
+        &mut context,
+        bid_refund_pda(asset, listing.created_at, market.alice.pubkey()),
+    )
+    .await;
+    assert_eq!(refund.amount, 1_100_000);
+
+    send(
+        &mut context,
+        market.claim_refund(
+            &market.alice,
+            market.alice_payment_account,
+            asset,
+            core_listing_pda(asset),
+            listing.created_at,
+        ),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        alice_before + 100_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        900_000
+    );
+}
+
+// ----------------------------------------------------------------------------
+// list_item_delegated / buy_now_delegated / close_stale_delegated_listing
+// ----------------------------------------------------------------------------
+
+fn list_item_delegated(market: &Market, price: u64, royalty_basis_points: u16) -> Instruction {
+    instruction(
+        auction::accounts::ListItemDelegated {
+            delegate_listing: delegate_listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            seller_nft_account: market.seller_nft_account,
+            payment_mint: market.payment_mint,
+            payment_mint_config: payment_mint_config_pda(market.payment_mint),
+            seller: market.seller.pubkey(),
+            nft_token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        auction::instruction::ListItemDelegated {
+            price,
+            category: ItemCategory::DigitalArt,
+            royalty_basis_points,
+            creator_address: market.creator,
+            start_time: None,
+            duration_seconds: None,
+        },
+    )
+}
+
+fn buy_now_delegated(market: &Market) -> Instruction {
+    instruction(
+        auction::accounts::BuyNowDelegated {
+            delegate_listing: delegate_listing_pda(market.nft_mint),
+            seller: market.seller.pubkey(),
+            nft_mint: market.nft_mint,
+            seller_nft_account: market.seller_nft_account,
+            buyer_nft_account: market.alice_nft_account,
+            payment_mint: market.payment_mint,
+            buyer_payment_account: market.alice_payment_account,
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            buyer: market.alice.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            nft_token_program: spl_token::ID,
+            token_program: spl_token::ID,
+        },
+        auction::instruction::BuyNowDelegated {},
+    )
+}
+
+fn close_stale_delegated_listing(market: &Market) -> Instruction {
+    instruction(
+        auction::accounts::CloseStaleDelegatedListing {
+            delegate_listing: delegate_listing_pda(market.nft_mint),
+            seller_nft_account: market.seller_nft_account,
+            seller: market.seller.pubkey(),
+        },
+        auction::instruction::CloseStaleDelegatedListing {},
+    )
+}
+
+#[tokio::test]
+async fn buy_now_delegated_moves_the_nft_out_of_the_sellers_wallet() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        list_item_delegated(&market, 1_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut context, market.seller_nft_account).await,
+        1
+    );
+
+    // Nobody may close a listing that can still sell
+    let result = send(&mut context, close_stale_delegated_listing(&market), &[]).await;
+    assert_auction_error(result, AuctionError::ListingNotStale);
+
+    send(&mut context, buy_now_delegated(&market), &[&market.alice])
+        .await
+        .unwrap();
+
+    // 1.0 token: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        930_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        20_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        50_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.seller_nft_account).await,
+        0
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    assert!(account(&mut context, delegate_listing_pda(market.nft_mint))
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn revoked_delegate_listing_refuses_buyers_and_closes_as_stale() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        list_item_delegated(&market, 1_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    send(
+        &mut context,
+        spl_token::instruction::revoke(
+            &spl_token::ID,
+            &market.seller_nft_account,
+            &market.seller.pubkey(),
+            &[],
+        )
+        .unwrap(),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+
+    let result = send(&mut context, buy_now_delegated(&market), &[&market.alice]).await;
+    assert_auction_error(result, AuctionError::StaleListing);
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        10_000_000
+    );
+
+    // Anyone may close it; the rent goes back to the seller
+    let listing_rent = lamports(&mut context, delegate_listing_pda(market.nft_mint)).await;
+    let seller_before = lamports(&mut context, market.seller.pubkey()).await;
+    send(&mut context, close_stale_delegated_listing(&market), &[])
+        .await
+        .unwrap();
+    assert!(account(&mut context, delegate_listing_pda(market.nft_mint))
+        .await
+        .is_none());
+    assert_eq!(
+        lamports(&mut context, market.seller.pubkey()).await,
+        seller_before + listing_rent
+    );
+    assert_eq!(
+        token_balance(&mut context, market.seller_nft_account).await,
+        1
+    );
+}
+
+// ----------------------------------------------------------------------------
+// list_item_pnft_delegated / buy_now_pnft_delegated / cancel_delegated_listing_pnft
+// ----------------------------------------------------------------------------
+
+fn list_item_pnft_delegated(market: &Market, price: u64, royalty_basis_points: u16) -> Instruction {
+    instruction(
+        auction::accounts::ListItemPnftDelegated {
+            delegate_listing: delegate_listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            nft_metadata: Metadata::find_pda(&market.nft_mint).0,
+            nft_edition: MasterEdition::find_pda(&market.nft_mint).0,
+            seller_nft_token: market.seller_nft_account,
+            seller_token_record: token_record(market.nft_mint, market.seller_nft_account),
+            payment_mint: market.payment_mint,
+            payment_mint_config: payment_mint_config_pda(market.payment_mint),
+            seller: market.seller.pubkey(),
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            sysvar_instructions: sysvar::instructions::ID,
+            authorization_rules_program: None,
+            authorization_rules: None,
+        },
+        auction::instruction::ListItemPnftDelegated {
+            price,
+            category: ItemCategory::DigitalArt,
+            royalty_basis_points,
+            creator_address: market.creator,
+            start_time: None,
+            duration_seconds: None,
+        },
+    )
+}
+
+fn buy_now_pnft_delegated(market: &Market) -> Instruction {
+    instruction(
+        auction::accounts::BuyNowPnftDelegated {
+            delegate_listing: delegate_listing_pda(market.nft_mint),
+            seller: market.seller.pubkey(),
+            nft_mint: market.nft_mint,
+            nft_metadata: Metadata::find_pda(&market.nft_mint).0,
+            nft_edition: MasterEdition::find_pda(&market.nft_mint).0,
+            seller_nft_token: market.seller_nft_account,
+            seller_token_record: token_record(market.nft_mint, market.seller_nft_account),
+            buyer_nft_token: market.alice_nft_account,
+            buyer_token_record: token_record(market.nft_mint, market.alice_nft_account),
+            payment_mint: market.payment_mint,
+            buyer_payment_account: market.alice_payment_account,
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            buyer: market.alice.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token::ID,
+            payment_token_program: spl_token::ID,
+            ata_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            sysvar_instructions: sysvar::instructions::ID,
+            authorization_rules_program: None,
+            authorization_rules: None,
+        },
+        auction::instruction::BuyNowPnftDelegated {},
+    )
+}
+
+fn cancel_delegated_listing_pnft(market: &Market, seller: &Keypair) -> Instruction {
+    instruction(
+        auction::accounts::CancelDelegatedListingPnft {
+            delegate_listing: delegate_listing_pda(market.nft_mint),
+            nft_mint: market.nft_mint,
+            nft_metadata: Metadata::find_pda(&market.nft_mint).0,
+            nft_edition: MasterEdition::find_pda(&market.nft_mint).0,
+            seller_nft_token: market.seller_nft_account,
+            seller_token_record: token_record(market.nft_mint, market.seller_nft_account),
+            seller: seller.pubkey(),
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            sysvar_instructions: sysvar::instructions::ID,
+            authorization_rules_program: None,
+            authorization_rules: None,
+        },
+        auction::instruction::CancelDelegatedListingPnft {},
+    )
+}
+
+async fn sale_delegate(context: &mut ProgramTestContext, token: Pubkey) -> COption<Pubkey> {
+    let account = account(context, token).await.unwrap();
+    spl_token::state::Account::unpack(&account.data)
+        .unwrap()
+        .delegate
+}
+
+#[tokio::test]
+async fn buy_now_pnft_delegated_transfers_as_the_sale_delegate() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        list_item_pnft_delegated(&market, 1_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sale_delegate(&mut context, market.seller_nft_account).await,
+        COption::Some(delegate_listing_pda(market.nft_mint))
+    );
+
+    send(
+        &mut context,
+        buy_now_pnft_delegated(&market),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+
+    // 1.0 token: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        930_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        50_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.alice_nft_account).await,
+        1
+    );
+    assert!(account(&mut context, delegate_listing_pda(market.nft_mint))
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn cancel_delegated_listing_pnft_revokes_the_sale_delegate_for_the_seller_only() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+
+    send(
+        &mut context,
+        list_item_pnft_delegated(&market, 1_000_000, 500),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        cancel_delegated_listing_pnft(&market, &market.alice),
+        &[&market.alice],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::Unauthorized);
+
+    send(
+        &mut context,
+        cancel_delegated_listing_pnft(&market, &market.seller),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sale_delegate(&mut context, market.seller_nft_account).await,
+        COption::None
+    );
+    assert!(account(&mut context, delegate_listing_pda(market.nft_mint))
+        .await
+        .is_none());
+
+    // Without a listing there is nothing left to buy
+    let result = send(
+        &mut context,
+        buy_now_pnft_delegated(&market),
+        &[&market.alice],
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(
+        token_balance(&mut context, market.seller_nft_account).await,
+        1
+    );
+}
+
+// ----------------------------------------------------------------------------
+// list_compressed_item / buy_now_compressed / cancel_compressed_listing
+// ----------------------------------------------------------------------------
+
+/// Bubblegum V1 hash of the leaf at `nonce` of `merkle_tree`.
+fn compressed_leaf(merkle_tree: Pubkey, owner: Pubkey, delegate: Pubkey, nonce: u64) -> [u8; 32] {
+    let asset_id = Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &BUBBLEGUM_PROGRAM,
+    )
+    .0;
+    solana_keccak_hasher::hashv(&[
+        &[1u8],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        &CNFT_DATA_HASH,
+        &CNFT_CREATOR_HASH,
+    ])
+    .to_bytes()
+}
+
+/// A one-leaf tree (see `process_bubblegum`) whose cNFT `owner` holds undelegated.
+fn add_compressed_tree(program_test: &mut ProgramTest, owner: Pubkey) -> Pubkey {
+    let merkle_tree = Pubkey::new_unique();
+    program_test.add_account(
+        merkle_tree,
+        Account {
+            lamports: rent_exempt(32),
+            data: compressed_leaf(merkle_tree, owner, owner, 0).to_vec(),
+            owner: BUBBLEGUM_PROGRAM,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    merkle_tree
+}
+
+async fn tree_leaf(context: &mut ProgramTestContext, merkle_tree: Pubkey) -> [u8; 32] {
+    account(context, merkle_tree).await.unwrap().data[..]
+        .try_into()
+        .unwrap()
+}
+
+fn tree_config(merkle_tree: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BUBBLEGUM_PROGRAM).0
+}
+
+fn list_compressed_item(market: &Market, merkle_tree: Pubkey, root: [u8; 32]) -> Instruction {
+    instruction(
+        auction::accounts::ListCompressedItem {
+            compressed_listing: compressed_listing_pda(merkle_tree, 0),
+            seller: market.seller.pubkey(),
+            previous_leaf_delegate: market.seller.pubkey(),
+            tree_config: tree_config(merkle_tree),
+            merkle_tree,
+            payment_mint: market.payment_mint,
+            payment_mint_config: payment_mint_config_pda(market.payment_mint),
+            log_wrapper: SPL_NOOP,
+            compression_program: SPL_ACCOUNT_COMPRESSION,
+            bubblegum_program: BUBBLEGUM_PROGRAM,
+            system_program: system_program::ID,
+        },
+        auction::instruction::ListCompressedItem {
+            leaf: CompressedLeaf {
+                root,
+                data_hash: CNFT_DATA_HASH,
+                creator_hash: CNFT_CREATOR_HASH,
+                nonce: 0,
+                index: 0,
+            },
+            price: 1_000_000,
+            category: ItemCategory::DigitalArt,
+            royalty_basis_points: 500,
+            creator_address: market.creator,
+            start_time: None,
+            duration_seconds: None,
+        },
+    )
+}
+
+fn buy_now_compressed(market: &Market, merkle_tree: Pubkey, root: [u8; 32]) -> Instruction {
+    instruction(
+        auction::accounts::BuyNowCompressed {
+            compressed_listing: compressed_listing_pda(merkle_tree, 0),
+            seller: market.seller.pubkey(),
+            tree_config: tree_config(merkle_tree),
+            merkle_tree,
+            payment_mint: market.payment_mint,
+            buyer_payment_account: market.alice_payment_account,
+            seller_payment_account: market.seller_payment_account,
+            treasury_payment_account: market.treasury_payment_account,
+            creator_payment_account: market.creator_payment_account,
+            buyer: market.alice.pubkey(),
+            treasury: TREASURY,
+            treasury_config: None,
+            token_program: spl_token::ID,
+            log_wrapper: SPL_NOOP,
+            compression_program: SPL_ACCOUNT_COMPRESSION,
+            bubblegum_program: BUBBLEGUM_PROGRAM,
+            system_program: system_program::ID,
+        },
+        auction::instruction::BuyNowCompressed { root },
+    )
+}
+
+fn cancel_compressed_listing(merkle_tree: Pubkey, seller: &Keypair, root: [u8; 32]) -> Instruction {
+    instruction(
+        auction::accounts::CancelCompressedListing {
+            compressed_listing: compressed_listing_pda(merkle_tree, 0),
+            seller: seller.pubkey(),
+            tree_config: tree_config(merkle_tree),
+            merkle_tree,
+            log_wrapper: SPL_NOOP,
+            compression_program: SPL_ACCOUNT_COMPRESSION,
+            bubblegum_program: BUBBLEGUM_PROGRAM,
+            system_program: system_program::ID,
+        },
+        auction::instruction::CancelCompressedListing { root },
+    )
+}
+
+#[tokio::test]
+async fn buy_now_compressed_transfers_the_listed_leaf_to_the_buyer() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let merkle_tree = add_compressed_tree(&mut program_test, market.seller.pubkey());
+    let mut context = program_test.start_with_context().await;
+    let unlisted_root = tree_leaf(&mut context, merkle_tree).await;
+
+    send(
+        &mut context,
+        list_compressed_item(&market, merkle_tree, unlisted_root),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let listing = compressed_listing_pda(merkle_tree, 0);
+    let listed_root = tree_leaf(&mut context, merkle_tree).await;
+    assert_eq!(
+        listed_root,
+        compressed_leaf(merkle_tree, market.seller.pubkey(), listing, 0)
+    );
+
+    // A proof against a root the leaf has since left is rejected
+    let result = send(
+        &mut context,
+        buy_now_compressed(&market, merkle_tree, unlisted_root),
+        &[&market.alice],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::TransferFailed);
+    assert_eq!(
+        token_balance(&mut context, market.alice_payment_account).await,
+        10_000_000
+    );
+
+    send(
+        &mut context,
+        buy_now_compressed(&market, merkle_tree, listed_root),
+        &[&market.alice],
+    )
+    .await
+    .unwrap();
+
+    // 1.0 token: 2% platform fee, 5% royalty, the rest to the seller
+    assert_eq!(
+        token_balance(&mut context, market.seller_payment_account).await,
+        930_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.treasury_payment_account).await,
+        20_000
+    );
+    assert_eq!(
+        token_balance(&mut context, market.creator_payment_account).await,
+        50_000
+    );
+    assert_eq!(
+        tree_leaf(&mut context, merkle_tree).await,
+        compressed_leaf(merkle_tree, market.alice.pubkey(), market.alice.pubkey(), 0)
+    );
+    assert!(account(&mut context, listing).await.is_none());
+}
+
+#[tokio::test]
+async fn cancel_compressed_listing_hands_delegation_back_to_the_seller_only() {
+    let mut program_test = program_test();
+    let market = Market::with_payment_mint(&mut program_test);
+    let merkle_tree = add_compressed_tree(&mut program_test, market.seller.pubkey());
+    let mut context = program_test.start_with_context().await;
+    let unlisted_root = tree_leaf(&mut context, merkle_tree).await;
+
+    send(
+        &mut context,
+        list_compressed_item(&market, merkle_tree, unlisted_root),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    let listed_root = tree_leaf(&mut context, merkle_tree).await;
+
+    let result = send(
+        &mut context,
+        cancel_compressed_listing(merkle_tree, &market.alice, listed_root),
+        &[&market.alice],
+    )
+    .await;
+    assert_auction_error(result, AuctionError::Unauthorized);
+
+    send(
+        &mut context,
+        cancel_compressed_listing(merkle_tree, &market.seller, listed_root),
+        &[&market.seller],
+    )
+    .await
+    .unwrap();
+    assert_eq!(tree_leaf(&mut context, merkle_tree).await, unlisted_root);
+    assert!(
+        account(&mut context, compressed_listing_pda(merkle_tree, 0))
+            .await
+            .is_none()
+    );
+}